@@ -670,10 +670,12 @@ pub mod node_command {
                 .add_optional_param("client_port","Client port. Note that it is mandatory for adding node case")
                 .add_optional_param("blskey",  "Node BLS key")
                 .add_optional_param("blskey_pop",  "Node BLS key proof of possession. Note that it is mandatory if blskey specified")
+                .add_optional_param("bls_seed",  "Node seed to generate blskey and blskey_pop from. Can't be combined with blskey and blskey_pop")
                 .add_optional_param("services", "Node type. One of: VALIDATOR, OBSERVER or empty in case of blacklisting node")
                 .add_optional_param("sign","Sign the request (True by default)")
                 .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
                 .add_example("ledger node target=A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y node_ip=127.0.0.1 node_port=9710 client_ip=127.0.0.1 client_port=9711 alias=Node5 services=VALIDATOR blskey=2zN3bHM1m4rLz54MJHYSwvqzPchYp8jkHswveCLAEJVcX6Mm1wHQD1SkPYMzUDTZvWvhuE6VNAkK3KxVeEmsanSmvjVkReDeBEMxeDaayjcZjFGPydyey1qxBHmTvAnBKoPydvuTAqx5f7YNNRAdeLmUi99gERUU7TD8KfAa6MpQ9bw blskey_pop=RPLagxaR5xdimFzwmzYnz4ZhWtYQEj8iR5ZU53T2gitPCyCHQneUn2Huc4oeLd2B2HzkGnjAff4hWTJT6C7qHYB1Mv2wU5iHHGFWkhnTX9WsEAbunJCV2qcaXScKj4tTfvdDKfLiVuU2av6hbsMztirRze7LvYBkRHV3tGwyCptsrP")
+                .add_example("ledger node target=A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y node_ip=127.0.0.1 node_port=9710 client_ip=127.0.0.1 client_port=9711 alias=Node5 services=VALIDATOR bls_seed=000000000000000000000000000Node5")
                 .add_example("ledger node target=A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y node_ip=127.0.0.1 node_port=9710 client_ip=127.0.0.1 client_port=9711 alias=Node5 services=VALIDATOR")
                 .add_example("ledger node target=A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y alias=Node5 services=VALIDATOR")
                 .add_example("ledger node target=A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y alias=Node5 services=")
@@ -694,8 +696,20 @@ pub mod node_command {
         let alias = get_opt_str_param("alias", params).map_err(error_err!())?;
         let blskey = get_opt_str_param("blskey", params).map_err(error_err!())?;
         let blskey_pop = get_opt_str_param("blskey_pop", params).map_err(error_err!())?;
+        let bls_seed = get_opt_str_param("bls_seed", params).map_err(error_err!())?;
         let services = get_opt_str_array_param("services", params).map_err(error_err!())?;
 
+        let (blskey, blskey_pop) = match bls_seed {
+            Some(_) if blskey.is_some() || blskey_pop.is_some() =>
+                return Err(println_err!("Parameter \"bls_seed\" can't be combined with \"blskey\" and \"blskey_pop\"")),
+            Some(bls_seed) => {
+                let (blskey, blskey_pop) = Ledger::generate_bls_keys(Some(bls_seed))
+                    .map_err(|err| handle_indy_error(err, None, None, None))?;
+                (Some(blskey), Some(blskey_pop))
+            }
+            None => (blskey.map(String::from), blskey_pop.map(String::from))
+        };
+
         let node_data = {
             let mut json = JSONMap::new();
             update_json_map_opt_key!(json, "node_ip", node_ip);
@@ -945,6 +959,169 @@ pub mod pool_upgrade_command {
     }
 }
 
+pub mod ledgers_freeze_command {
+    use super::*;
+
+    command!(CommandMetadata::build("ledgers-freeze", "Freeze ledgers (plugin ledgers only). Frozen ledgers can't be written anymore.")
+                .add_required_param("ledgers_ids", "List of ids of ledgers to freeze.")
+                .add_optional_param("sign","Sign the request (True by default)")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger ledgers-freeze ledgers_ids=10")
+                .add_example("ledger ledgers-freeze ledgers_ids=10,11")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let submitter_did = ensure_active_did(&ctx)?;
+
+        let ledgers_ids = get_str_array_param("ledgers_ids", params).map_err(error_err!())?
+            .into_iter()
+            .map(|ledger_id| ledger_id.trim().parse::<u64>()
+                .map_err(|_| println_err!("Invalid ledger id: {}", ledger_id)))
+            .collect::<Result<Vec<u64>, ()>>()?;
+
+        let ledgers_ids = serde_json::to_string(&ledgers_ids)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        let request = Ledger::build_ledgers_freeze_request(&submitter_did, &ledgers_ids)
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response): (String, Response<serde_json::Value>) =
+            send_write_request!(ctx, params, &request, wallet_handle, &wallet_name, &submitter_did);
+
+        let res = handle_transaction_response(response)
+            .map(|result| print_transaction_response(result,
+                                                     "LedgersFreeze request has been sent to Ledger.",
+                                                     None,
+                                                     &[("ledgers_ids", "Ledgers Ids")],
+                                                     true));
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod get_frozen_ledgers_command {
+    use super::*;
+
+    command!(CommandMetadata::build("get-frozen-ledgers", "Get a list of frozen ledgers.")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then created request will be printed and stored into CLI context.")
+                .add_example("ledger get-frozen-ledgers")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let submitter_did = get_active_did(&ctx);
+
+        let request = Ledger::build_get_frozen_ledgers_request(submitter_did.as_ref().map(String::as_str))
+            .map_err(|err| handle_indy_error(err, None, None, None))?;
+
+        let (_, response) = send_read_request!(&ctx, params, &request, submitter_did.as_ref().map(String::as_str));
+
+        let result = handle_transaction_response(response)?;
+
+        let frozen_ledgers = result["data"].as_object()
+            .map(|data| data.iter()
+                .map(|(ledger_id, ledger)| json!({
+                    "ledger_id": ledger_id,
+                    "ledger": ledger["ledger"],
+                    "state": ledger["state"],
+                    "seq_no": ledger["seq_no"],
+                }))
+                .collect::<Vec<serde_json::Value>>())
+            .unwrap_or_default();
+
+        print_list_table(&frozen_ledgers,
+                         &[("ledger_id", "Ledger Id"),
+                             ("ledger", "Ledger Root Hash"),
+                             ("state", "State Root Hash"),
+                             ("seq_no", "Last Seq No")],
+                         "There are no frozen ledgers");
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod export_command {
+    use super::*;
+    use std::fs::File;
+    use std::io::{BufWriter, Write};
+
+    command!(CommandMetadata::build("export", "Export a range of ledger transactions into a file. \
+                Transactions are verified against the ledger root hash and written one JSON object per line.")
+                .add_required_param("from", "Sequence number of the first transaction to export.")
+                .add_optional_param("to", "Sequence number of the last transaction to export (the last transaction of the ledger by default).")
+                .add_required_param("file", "The path to file.")
+                .add_optional_param("ledger", "Type of the ledger the transactions belong to: DOMAIN - used default, POOL, CONFIG, any number")
+                .add_example("ledger export from=1 file=/home/domain.ndjson")
+                .add_example("ledger export from=1 to=100 file=/home/pool.ndjson ledger=POOL")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let from = _get_int_param::<i64>("from", params).map_err(error_err!())?;
+        let to = get_opt_number_param::<i64>("to", params).map_err(error_err!())?;
+        let file = get_str_param("file", params).map_err(error_err!())?;
+        let ledger = get_opt_str_param("ledger", params).map_err(error_err!())?;
+
+        let mut file = File::create(file)
+            .map(BufWriter::new)
+            .map_err(|err| println_err!("Cannot create the file: {:?}", err))?;
+
+        let iterator_handle = Ledger::open_ledger_iterator(pool_handle, ledger, from, to.unwrap_or(-1))
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let res = _export_txns(iterator_handle, &mut file, &pool_name);
+
+        Ledger::close_ledger_iterator(iterator_handle)
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let res = res.map(|count| println_succ!("{} transactions have been exported.", count));
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn _export_txns<W: Write>(iterator_handle: i32, file: &mut W, pool_name: &str) -> Result<usize, ()> {
+        let mut count = 0;
+
+        loop {
+            let txns = Ledger::ledger_iterator_next(iterator_handle)
+                .map_err(|err| handle_indy_error(err, None, Some(pool_name), None))?;
+
+            let txns = serde_json::from_str::<Vec<serde_json::Value>>(&txns)
+                .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+            if txns.is_empty() {
+                break;
+            }
+
+            for txn in txns.iter() {
+                writeln!(file, "{}", txn)
+                    .map_err(|err| println_err!("Cannot write the transaction into the file: {:?}", err))?;
+            }
+
+            count += txns.len();
+        }
+
+        file.flush()
+            .map_err(|err| println_err!("Cannot write the transaction into the file: {:?}", err))?;
+
+        Ok(count)
+    }
+}
+
 pub mod custom_command {
     use super::*;
 
@@ -1571,7 +1748,11 @@ pub mod save_transaction_command {
 
     command!(CommandMetadata::build("save-transaction", "Save transaction from CLI context into a file.")
                 .add_required_param("file", "The path to file.")
+                .add_optional_param("endorser", "DID of the Endorser. If specified, the Endorser is appended to the transaction, \
+                the transaction is signed by active DID and saved as a bundle to be reviewed and endorsed by `ledger review` and `ledger endorse`.")
+                .add_optional_param("note", "Note to the Endorser saved into the bundle along with the transaction.")
                 .add_example(r#"ledger save-transaction /home/transaction.txt"#)
+                .add_example(r#"ledger save-transaction /home/transaction.txt endorser=V4SGRU86Z58d6TV7PBUe6f note="New schema of our company""#)
                 .finalize()
     );
 
@@ -1579,6 +1760,8 @@ pub mod save_transaction_command {
         trace!("execute >> ctx {:?} params {:?}", ctx, params);
 
         let file = get_str_param("file", params).map_err(error_err!())?;
+        let endorser = get_opt_str_param("endorser", params).map_err(error_err!())?;
+        let note = get_opt_str_param("note", params).map_err(error_err!())?;
 
         let transaction = ensure_set_transaction(ctx)?;
 
@@ -1591,6 +1774,11 @@ pub mod save_transaction_command {
             return Ok(println!("The transaction has not been saved."));
         }
 
+        let transaction = match endorser {
+            Some(endorser) => _build_endorsement_bundle(ctx, &transaction, endorser, note)?,
+            None => transaction
+        };
+
         write_file(file, &transaction)
             .map_err(|err| println_err!("Cannot store transaction into the file: {:?}", err))?;
 
@@ -1602,6 +1790,18 @@ pub mod save_transaction_command {
     }
 }
 
+fn _build_endorsement_bundle(ctx: &CommandContext, transaction: &str, endorser: &str, note: Option<&str>) -> Result<String, ()> {
+    let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+    let submitter_did = ensure_active_did(&ctx)?;
+
+    let metadata = note.map(|note| json!({"note": note}).to_string());
+
+    Ledger::append_request_endorser(transaction, endorser)
+        .and_then(|request| Ledger::sign_request(wallet_handle, &submitter_did, &request))
+        .and_then(|request| Ledger::build_endorsement_bundle(&request, metadata.as_ref().map(String::as_str)))
+        .map_err(|err| handle_indy_error(err, Some(&submitter_did), None, Some(&wallet_name)))
+}
+
 pub mod load_transaction_command {
     use super::*;
 
@@ -1641,6 +1841,132 @@ pub mod load_transaction_command {
     }
 }
 
+pub mod review_command {
+    use super::*;
+
+    command!(CommandMetadata::build("review", "Review a bundle of transaction prepared by its author for the Endorser.\
+                Signatures of the transaction are verified against verkeys written on the ledger.")
+                .add_required_param("file", "The path to file containing a bundle saved by `ledger save-transaction` with `endorser` parameter.")
+                .add_example(r#"ledger review /home/bundle.txt"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+
+        let bundle = read_file(file)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let review = Ledger::review_endorsement_bundle(pool_handle, &bundle)
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let review = serde_json::from_str::<serde_json::Value>(&review)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        print_endorsement_review(&review);
+
+        let res = if review["valid"].as_bool().unwrap_or(false) {
+            Ok(println_succ!("The transaction is signed by its author and can be endorsed."))
+        } else {
+            Err(println_err!("The transaction isn't signed by its author or contains invalid signatures."))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+pub mod endorse_command {
+    use super::*;
+
+    command!(CommandMetadata::build("endorse", "Endorse a bundle of transaction prepared by its author. \
+                The transaction is reviewed, multi signed by active DID and sent to the Ledger.")
+                .add_required_param("file", "The path to file containing a bundle saved by `ledger save-transaction` with `endorser` parameter.")
+                .add_optional_param("send","Send the request to the Ledger (True by default). If false then endorsed request will be printed and stored into CLI context.")
+                .add_example(r#"ledger endorse /home/bundle.txt"#)
+                .add_example(r#"ledger endorse /home/bundle.txt send=false"#)
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+        let (wallet_handle, wallet_name) = ensure_opened_wallet(&ctx)?;
+        let endorser_did = ensure_active_did(&ctx)?;
+
+        let file = get_str_param("file", params).map_err(error_err!())?;
+
+        let bundle = read_file(file)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let review = Ledger::review_endorsement_bundle(pool_handle, &bundle)
+            .map_err(|err| handle_indy_error(err, None, Some(&pool_name), None))?;
+
+        let review = serde_json::from_str::<serde_json::Value>(&review)
+            .map_err(|err| println_err!("Invalid data has been received: {:?}", err))?;
+
+        print_endorsement_review(&review);
+
+        println!("Would you like to endorse it? (y/n)");
+
+        let endorse = ::command_executor::wait_for_user_reply(ctx);
+
+        if !endorse {
+            return Ok(println!("The transaction has not been endorsed."));
+        }
+
+        let request = Ledger::endorse_request(pool_handle, wallet_handle, &endorser_did, &bundle)
+            .map_err(|err| handle_indy_error(err, Some(&endorser_did), Some(&pool_name), Some(&wallet_name)))?;
+
+        let (_, response) = send_request!(&ctx, params, &request, Some(&wallet_name), Some(&endorser_did));
+
+        let res = handle_transaction_response(response)
+            .map(|result| println_succ!("Endorsed transaction has been written to the Ledger with seqNo {}", result["txnMetadata"]["seqNo"]));
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
+fn print_endorsement_review(review: &serde_json::Value) {
+    let mut summary = review.clone();
+    summary["txnType"] = get_txn_title(&review["txnType"]);
+    summary["created"] = serde_json::Value::String(review["created"].as_i64().map(timestamp_to_datetime).unwrap_or_default());
+    summary["metadata"] = match review["metadata"] {
+        serde_json::Value::Null => serde_json::Value::String("-".to_string()),
+        ref metadata => serde_json::Value::String(metadata.to_string())
+    };
+
+    print_table(&summary,
+                &[("author", "Author"),
+                  ("endorser", "Endorser"),
+                  ("txnType", "Type"),
+                  ("summary", "Summary"),
+                  ("created", "Created"),
+                  ("metadata", "Metadata")]);
+
+    let signatures: Vec<serde_json::Value> = review["signatures"].as_array()
+        .map(|signatures| signatures.iter()
+            .map(|signature| json!({
+                "did": signature["did"],
+                "verkey": signature["verkey"].as_str().unwrap_or("-"),
+                "valid": signature["valid"].as_bool().unwrap_or(false)
+            }))
+            .collect())
+        .unwrap_or_default();
+
+    print_list_table(&signatures,
+                     &[("did", "Signer"),
+                       ("verkey", "Ledger Verkey"),
+                       ("valid", "Valid")],
+                     "The transaction isn't signed");
+}
+
 pub mod taa_command {
     use super::*;
 
@@ -1976,6 +2302,8 @@ fn get_txn_title(role: &serde_json::Value) -> serde_json::Value {
         Some("0") => "NODE",
         Some("1") => "NYM",
         Some("3") => "GET_TXN",
+        Some("9") => "LEDGERS_FREEZE",
+        Some("10") => "GET_FROZEN_LEDGERS",
         Some("100") => "ATTRIB",
         Some("101") => "SCHEMA",
         Some("104") => "GET_ATTR",
@@ -3371,6 +3699,60 @@ pub mod tests {
             }
             tear_down_with_wallet_and_pool(&ctx);
         }
+
+        #[test]
+        pub fn node_works_for_bls_seed() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = node_command::new();
+                let mut params = CommandParams::new();
+                params.insert("target", "A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y".to_string());
+                params.insert("alias", "Node5".to_string());
+                params.insert("bls_seed", "000000000000000000000000000Node1".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let request: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert_eq!("4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba",
+                       request["operation"]["data"]["blskey"].as_str().unwrap());
+            assert!(request["operation"]["data"]["blskey_pop"].is_string());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn node_works_for_bls_seed_and_blskey() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = node_command::new();
+                let mut params = CommandParams::new();
+                params.insert("target", "A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y".to_string());
+                params.insert("alias", "Node5".to_string());
+                params.insert("bls_seed", "000000000000000000000000000Node1".to_string());
+                params.insert("blskey", "2zN3bHM1m4rLz54MJHYSwvqzPchYp8jkHswveCLAEJVcX6Mm1wHQD1SkPYMzUDTZvWvhuE6VNAkK3KxVeEmsanSmvjVkReDeBEMxeDaayjcZjFGPydyey1qxBHmTvAnBKoPydvuTAqx5f7YNNRAdeLmUi99gERUU7TD8KfAa6MpQ9bw".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn node_works_for_pop_not_corresponding_blskey() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = node_command::new();
+                let mut params = CommandParams::new();
+                params.insert("target", "A5iWQVT3k8Zo9nXj4otmeqaUziPQPCiDqcydXkAJBk1Y".to_string());
+                params.insert("alias", "Node5".to_string());
+                params.insert("blskey", "2zN3bHM1m4rLz54MJHYSwvqzPchYp8jkHswveCLAEJVcX6Mm1wHQD1SkPYMzUDTZvWvhuE6VNAkK3KxVeEmsanSmvjVkReDeBEMxeDaayjcZjFGPydyey1qxBHmTvAnBKoPydvuTAqx5f7YNNRAdeLmUi99gERUU7TD8KfAa6MpQ9bw".to_string());
+                params.insert("blskey_pop", "RahHYiCvoNCtPTrVtP7nMC5eTYrsUA8WjXbdhNc8debh1agE9bGiJxWBXYNFbnJXoXhWFMvyqhqhRoq737YQemH5ik9oL7R4NTTCz2LEZhkgLJzB3QRQqJyBNyv7acbdHrAT8nQ9UkLbaVL9NBpnWXBTw4LEMePaSHEw66RzPNdAX1".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
     }
 
     mod pool_config {
@@ -3491,6 +3873,114 @@ pub mod tests {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+
+        #[test]
+        pub fn ledgers_freeze_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "10,11".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let transaction: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert_eq!(json!([10, 11]), transaction["operation"]["ledgers_ids"]);
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledgers_freeze_works_for_invalid_ledger_id() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "ledger".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn ledgers_freeze_works_for_base_ledger() {
+            let ctx = setup_with_wallet_and_pool();
+            use_trustee(&ctx);
+            {
+                let cmd = ledgers_freeze_command::new();
+                let mut params = CommandParams::new();
+                params.insert("ledgers_ids", "1".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod get_frozen_ledgers {
+        use super::*;
+
+        #[test]
+        pub fn get_frozen_ledgers_works_without_sending() {
+            let ctx = setup_with_wallet_and_pool();
+            {
+                let cmd = get_frozen_ledgers_command::new();
+                let mut params = CommandParams::new();
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            assert!(get_transaction(&ctx).is_some());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
+    mod export {
+        use super::*;
+
+        #[test]
+        pub fn export_works() {
+            let ctx = setup_with_wallet_and_pool();
+
+            let (_, path_str) = _path();
+            {
+                let cmd = export_command::new();
+                let mut params = CommandParams::new();
+                params.insert("from", "2".to_string());
+                params.insert("to", "3".to_string());
+                params.insert("file", path_str.clone());
+                params.insert("ledger", "POOL".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            let txns = read_file(&path_str).unwrap();
+            let seq_nos = txns.lines()
+                .map(|txn| serde_json::from_str::<serde_json::Value>(txn).unwrap()["seqNo"].as_u64().unwrap())
+                .collect::<Vec<u64>>();
+            assert_eq!(vec![2, 3], seq_nos);
+
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn export_works_for_invalid_range() {
+            let ctx = setup_with_wallet_and_pool();
+
+            let (_, path_str) = _path();
+            {
+                let cmd = export_command::new();
+                let mut params = CommandParams::new();
+                params.insert("from", "3".to_string());
+                params.insert("to", "2".to_string());
+                params.insert("file", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     mod custom {
         use super::*;
 
@@ -4589,6 +5079,104 @@ pub mod tests {
         }
     }
 
+    mod endorse {
+        use super::*;
+
+        fn _save_schema_bundle(ctx: &CommandContext) -> (String, String) {
+            use_trustee(ctx);
+            let (endorser_did, endorser_verkey) = create_new_did(ctx);
+            send_nym(ctx, &endorser_did, &endorser_verkey, Some("ENDORSER"));
+            let (author_did, author_verkey) = create_new_did(ctx);
+            send_nym(ctx, &author_did, &author_verkey, None);
+            use_did(ctx, &author_did);
+            {
+                let cmd = schema_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", "gvt".to_string());
+                params.insert("version", "1.0".to_string());
+                params.insert("attr_names", "name,age".to_string());
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let (_, path_str) = _path();
+            {
+                let cmd = save_transaction_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str.clone());
+                params.insert("endorser", endorser_did.clone());
+                params.insert("note", "New schema".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            (endorser_did, path_str)
+        }
+
+        #[test]
+        pub fn endorse_works() {
+            let ctx = setup_with_wallet_and_pool();
+            let (endorser_did, path_str) = _save_schema_bundle(&ctx);
+            use_did(&ctx, &endorser_did);
+            {
+                let cmd = review_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str.clone());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn endorse_works_for_send_false() {
+            let ctx = setup_with_wallet_and_pool();
+            let (endorser_did, path_str) = _save_schema_bundle(&ctx);
+            use_did(&ctx, &endorser_did);
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str);
+                params.insert("send", "false".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            let context_txn: serde_json::Value = serde_json::from_str(&get_transaction(&ctx).unwrap()).unwrap();
+            assert!(context_txn["signatures"][endorser_did.as_str()].is_string());
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn endorse_works_for_other_endorser() {
+            let ctx = setup_with_wallet_and_pool();
+            let (_, path_str) = _save_schema_bundle(&ctx);
+            use_trustee(&ctx);
+            {
+                let cmd = endorse_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+
+        #[test]
+        pub fn review_works_for_not_bundle() {
+            let ctx = setup_with_wallet_and_pool();
+
+            let (_, path_str) = _path();
+            write_file(&path_str, TRANSACTION).unwrap();
+            {
+                let cmd = review_command::new();
+                let mut params = CommandParams::new();
+                params.insert("file", path_str);
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down_with_wallet_and_pool(&ctx);
+        }
+    }
+
     mod aml {
         use super::*;
 
@@ -5,8 +5,10 @@ use command_executor::{Command, CommandContext, CommandMetadata, CommandParams,
 use commands::*;
 
 use indy::{ErrorCode, IndyError};
+use libindy::ledger::Ledger;
 use libindy::pool::Pool;
-use utils::table::print_list_table;
+use utils::file::{read_file, write_file};
+use utils::table::{print_list_table, print_table};
 
 use self::chrono::prelude::*;
 use serde_json::Value as JSONValue;
@@ -61,6 +63,76 @@ pub mod create_command {
     }
 }
 
+pub mod generate_genesis_command {
+    use super::*;
+
+    command!(CommandMetadata::build("generate-genesis", "Generate pool and domain genesis transactions of a new network.")
+                .add_required_param("nodes_config", "Path to file with json describing trustees and nodes of the network: \
+                    {\"trustee_seeds\": [seed], \"nodes\": [{\"alias\", \"seed\", \"steward_seed\", \"node_ip\", \"node_port\", \"client_ip\", \"client_port\"}]}")
+                .add_required_param("pool_txn_file", "Path to not existing file to write pool genesis transactions into")
+                .add_required_param("domain_txn_file", "Path to not existing file to write domain genesis transactions into")
+                .add_example("pool generate-genesis nodes_config=/home/nodes_config.json pool_txn_file=/home/pool_genesis_transactions domain_txn_file=/home/domain_genesis_transactions")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let nodes_config = get_str_param("nodes_config", params).map_err(error_err!())?;
+        let pool_txn_file = get_str_param("pool_txn_file", params).map_err(error_err!())?;
+        let domain_txn_file = get_str_param("domain_txn_file", params).map_err(error_err!())?;
+
+        for txn_file in &[pool_txn_file, domain_txn_file] {
+            if ::std::path::Path::new(txn_file).exists() {
+                return Err(println_err!("File \"{}\" already exists", txn_file));
+            }
+        }
+
+        let nodes_config = read_file(nodes_config)
+            .map_err(|err| println_err!("{}", err))?;
+
+        let (pool_txns, domain_txns) = match Ledger::build_genesis_transactions(&nodes_config) {
+            Ok(txns) => txns,
+            Err(err) => {
+                return match err.error_code {
+                    ErrorCode::CommonInvalidStructure => Err(println_err!("Invalid nodes config: {}", err.message)),
+                    _ => Err(handle_indy_error(err, None, None, None)),
+                };
+            }
+        };
+
+        write_file(pool_txn_file, &pool_txns)
+            .map_err(|err| println_err!("Cannot store pool genesis transactions into the file: {:?}", err))?;
+
+        write_file(domain_txn_file, &domain_txns)
+            .map_err(|err| println_err!("Cannot store domain genesis transactions into the file: {:?}", err))?;
+
+        let nodes = pool_txns.lines()
+            .map(|txn| serde_json::from_str::<JSONValue>(txn)
+                .map(|txn| json!({
+                    "alias": txn["txn"]["data"]["data"]["alias"],
+                    "dest": txn["txn"]["data"]["dest"],
+                    "steward": txn["txn"]["metadata"]["from"],
+                })))
+            .collect::<Result<Vec<JSONValue>, _>>()
+            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+        print_list_table(&nodes,
+                         &vec![("alias", "Alias"),
+                               ("dest", "Verkey"),
+                               ("steward", "Steward DID")],
+                         "There are no nodes");
+
+        println_succ!("Pool genesis transactions have been saved into \"{}\"", pool_txn_file);
+        println_succ!("Domain genesis transactions have been saved into \"{}\"", domain_txn_file);
+
+        let res = Ok(());
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod connect_command {
     use super::*;
 
@@ -70,11 +142,15 @@ pub mod connect_command {
                 .add_optional_param("timeout", "Timeout for network request (in sec)")
                 .add_optional_param("extended-timeout", "Extended timeout for network request (in sec)")
                 .add_optional_param("pre-ordered-nodes", "Names of nodes which will have a priority during request sending")
+                .add_optional_param("read-strategy", "Order of nodes asked by reads expecting a single verifiable reply. One of: preordered, random, adaptive. (preordered by default)")
+                .add_optional_param("force-rebuild", "Drop pool ledger snapshot and cache and rebuild pool ledger from genesis transactions (false by default)")
                 .add_example("pool connect pool1")
                 .add_example("pool connect pool1 protocol-version=2")
                 .add_example("pool connect pool1 protocol-version=2 timeout=100")
                 .add_example("pool connect pool1 protocol-version=2 extended-timeout=100")
                 .add_example("pool connect pool1 protocol-version=2 pre-ordered-nodes=Node2,Node1")
+                .add_example("pool connect pool1 protocol-version=2 read-strategy=adaptive")
+                .add_example("pool connect pool1 protocol-version=2 force-rebuild=true")
                 .finalize());
 
     fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
@@ -85,12 +161,16 @@ pub mod connect_command {
         let timeout = get_opt_number_param::<i64>("timeout", params).map_err(error_err!())?;
         let extended_timeout = get_opt_number_param::<i64>("extended-timeout", params).map_err(error_err!())?;
         let pre_ordered_nodes = get_opt_str_array_param("pre-ordered-nodes", params).map_err(error_err!())?;
+        let read_strategy = get_opt_str_param("read-strategy", params).map_err(error_err!())?;
+        let force_rebuild = get_opt_bool_param("force-rebuild", params).map_err(error_err!())?;
 
         let config = {
             let mut json = JSONMap::new();
             update_json_map_opt_key!(json, "timeout", timeout);
             update_json_map_opt_key!(json, "extended_timeout", extended_timeout);
             update_json_map_opt_key!(json, "preordered_nodes", pre_ordered_nodes);
+            update_json_map_opt_key!(json, "read_strategy", read_strategy);
+            update_json_map_opt_key!(json, "force_rebuild", force_rebuild);
             JSONValue::from(json).to_string()
         };
 
@@ -238,6 +318,148 @@ pub mod refresh_command {
     }
 }
 
+pub mod metrics_command {
+    use super::*;
+
+    command!(CommandMetadata::build("metrics", "Show health and latency metrics of nodes of current pool.")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let (pool_handle, pool_name) = ensure_connected_pool(&ctx)?;
+
+        let res = match Pool::get_metrics(pool_handle) {
+            Ok(metrics) => {
+                trace!("metrics {:?}", metrics);
+                let metrics: JSONValue = serde_json::from_str(&metrics)
+                    .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                println_succ!("Pool \"{}\" catchup state: {}", pool_name, metrics["catchup_state"].as_str().unwrap_or("-"));
+                println_succ!("Consensus failures: {}", metrics["consensus_failures"].as_u64().unwrap_or(0));
+
+                let nodes: Vec<JSONValue> = metrics["nodes"].as_object()
+                    .map(|nodes| nodes.iter().map(|(alias, node)| _node_metrics_row(alias, node)).collect())
+                    .unwrap_or(vec![]);
+
+                print_list_table(&nodes,
+                                 &vec![("alias", "Node"),
+                                       ("replies", "Replies"),
+                                       ("acks", "ACKs"),
+                                       ("nacks", "NACKs"),
+                                       ("rejects", "Rejects"),
+                                       ("timeouts", "Timeouts"),
+                                       ("min_ms", "Min latency, ms"),
+                                       ("avg_ms", "Avg latency, ms"),
+                                       ("max_ms", "Max latency, ms"),
+                                       ("histogram", "Latency histogram, ms")],
+                                 "There are no node responses yet");
+                Ok(())
+            }
+            Err(err) => Err(handle_indy_error(err, None, Some(&pool_name), None))
+        };
+
+        trace!("execute << {:?}", res);
+        res
+    }
+
+    fn _node_metrics_row(alias: &str, node: &JSONValue) -> JSONValue {
+        let histogram = node["latency"]["buckets"].as_array()
+            .map(|buckets| buckets.iter()
+                .filter(|bucket| bucket["count"].as_u64().unwrap_or(0) > 0)
+                .map(|bucket| match bucket["le_ms"].as_u64() {
+                    Some(le_ms) => format!("<={}: {}", le_ms, bucket["count"]),
+                    None => format!(">{}: {}", buckets.iter().filter_map(|bucket| bucket["le_ms"].as_u64()).max().unwrap_or(0), bucket["count"]),
+                })
+                .collect::<Vec<String>>()
+                .join(", "))
+            .unwrap_or_default();
+
+        json!({
+            "alias": alias,
+            "replies": node["replies"],
+            "acks": node["acks"],
+            "nacks": node["nacks"],
+            "rejects": node["rejects"],
+            "timeouts": node["timeouts"],
+            "min_ms": node["latency"]["min_ms"],
+            "avg_ms": node["latency"]["avg_ms"],
+            "max_ms": node["latency"]["max_ms"],
+            "histogram": histogram,
+        })
+    }
+}
+
+pub mod snapshot_command {
+    use super::*;
+
+    command!(CommandMetadata::build("snapshot", "Save snapshot of locally cached pool ledger to speed up next connect.")
+                .add_main_param_with_dynamic_completion("name", "The name of pool", DynamicCompletionType::Pool)
+                .add_optional_param("protocol-version", "Pool protocol version of genesis transactions. One of: 1, 2. (2 by default)")
+                .add_example("pool snapshot pool1")
+                .add_example("pool snapshot pool1 protocol-version=2")
+                .finalize()
+    );
+
+    fn execute(ctx: &CommandContext, params: &CommandParams) -> Result<(), ()> {
+        trace!("execute >> ctx {:?} params {:?}", ctx, params);
+
+        let name = get_str_param("name", params).map_err(error_err!())?;
+        let protocol_version = get_opt_number_param::<usize>("protocol-version", params).map_err(error_err!())?.unwrap_or(PROTOCOL_VERSION);
+
+        let res = Ok(())
+            .and_then(|_| {
+                match Pool::set_protocol_version(protocol_version) {
+                    Ok(_) => Ok(()),
+                    Err(IndyError { error_code: ErrorCode::PoolIncompatibleProtocolVersion, .. }) =>
+                        Err(println_err!("Unsupported Protocol Version has been specified \"{}\".", protocol_version)),
+                    Err(err) => Err(handle_indy_error(err, None, Some(&name), None)),
+                }
+            })
+            .and_then(|_| {
+                match Pool::create_snapshot(name) {
+                    Ok(snapshot) => {
+                        trace!("snapshot {:?}", snapshot);
+                        let snapshot: JSONValue = serde_json::from_str(&snapshot)
+                            .map_err(|_| println_err!("Wrong data has been received"))?;
+
+                        println_succ!("Snapshot of pool \"{}\" has been saved", name);
+
+                        let nodes = snapshot["nodes"].as_array()
+                            .map(|nodes| nodes.iter().filter_map(JSONValue::as_str).collect::<Vec<&str>>().join(", "))
+                            .unwrap_or_default();
+
+                        print_table(&json!({
+                                        "size": snapshot["size"],
+                                        "rootHash": snapshot["rootHash"],
+                                        "timestamp": snapshot["timestamp"].as_i64()
+                                            .map(|timestamp| NaiveDateTime::from_timestamp(timestamp, 0).to_string())
+                                            .unwrap_or_default(),
+                                        "nodes": nodes,
+                                    }),
+                                    &vec![("size", "Transactions"),
+                                          ("rootHash", "Root hash"),
+                                          ("timestamp", "Timestamp"),
+                                          ("nodes", "Nodes")]);
+                        Ok(())
+                    }
+                    Err(err) => {
+                        match err.error_code {
+                            ErrorCode::PoolLedgerNotCreatedError => Err(println_err!("Pool \"{}\" does not exist.", name)),
+                            ErrorCode::PoolIncompatibleProtocolVersion =>
+                                Err(println_err!("Pool \"{}\" is not compatible with Protocol Version \"{}\".", name, protocol_version)),
+                            _ => Err(handle_indy_error(err, None, Some(&name), None)),
+                        }
+                    }
+                }
+            });
+
+        trace!("execute << {:?}", res);
+        res
+    }
+}
+
 pub mod disconnect_command {
     use super::*;
 
@@ -438,6 +660,110 @@ pub mod tests {
         }
     }
 
+    mod generate_genesis {
+        use super::*;
+        use utils::environment::EnvironmentUtils;
+
+        fn _nodes_config(nodes: &[(&str, &str, &str)]) -> String {
+            let nodes: Vec<JSONValue> = nodes.iter().enumerate().map(|(i, &(alias, seed, steward_seed))| json!({
+                "alias": alias,
+                "seed": seed,
+                "steward_seed": steward_seed,
+                "node_ip": "10.0.0.2",
+                "node_port": 9701 + 2 * i as i32,
+                "client_ip": "10.0.0.2",
+                "client_port": 9702 + 2 * i as i32,
+            })).collect();
+
+            json!({"trustee_seeds": ["000000000000000000000000Trustee1"], "nodes": nodes}).to_string()
+        }
+
+        fn _generate_genesis(ctx: &CommandContext, nodes_config: &str) -> Result<(String, String), ()> {
+            let nodes_config_path = EnvironmentUtils::tmp_file_path("nodes_config.json");
+            write_file(&nodes_config_path, nodes_config).unwrap();
+
+            let pool_txn_file = EnvironmentUtils::tmp_file_path("pool_genesis_transactions").to_str().unwrap().to_string();
+            let domain_txn_file = EnvironmentUtils::tmp_file_path("domain_genesis_transactions").to_str().unwrap().to_string();
+
+            let cmd = generate_genesis_command::new();
+            let mut params = CommandParams::new();
+            params.insert("nodes_config", nodes_config_path.to_str().unwrap().to_string());
+            params.insert("pool_txn_file", pool_txn_file.clone());
+            params.insert("domain_txn_file", domain_txn_file.clone());
+            cmd.execute(&ctx, &params)?;
+
+            Ok((pool_txn_file, domain_txn_file))
+        }
+
+        #[test]
+        pub fn generate_genesis_works() {
+            let ctx = setup();
+
+            let nodes_config = _nodes_config(&[("Node1", "000000000000000000000000000Node1", "000000000000000000000000Steward1"),
+                                               ("Node2", "000000000000000000000000000Node2", "000000000000000000000000Steward2")]);
+            let (pool_txn_file, domain_txn_file) = _generate_genesis(&ctx, &nodes_config).unwrap();
+
+            let pool_txns = read_file(&pool_txn_file).unwrap();
+            let node1: JSONValue = serde_json::from_str(pool_txns.lines().next().unwrap()).unwrap();
+            assert_eq!(2, pool_txns.lines().count());
+            assert_eq!("Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv", node1["txn"]["data"]["dest"].as_str().unwrap());
+            assert_eq!("Th7MpTaRZVRYnPiabds81Y", node1["txn"]["metadata"]["from"].as_str().unwrap());
+
+            let domain_txns = read_file(&domain_txn_file).unwrap();
+            assert_eq!(3, domain_txns.lines().count());
+
+            {
+                let cmd = create_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                params.insert("gen_txn_file", pool_txn_file);
+                cmd.execute(&ctx, &params).unwrap();
+            }
+
+            delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn generate_genesis_works_for_empty_nodes() {
+            let ctx = setup();
+            _generate_genesis(&ctx, &_nodes_config(&[])).unwrap_err();
+            tear_down();
+        }
+
+        #[test]
+        pub fn generate_genesis_works_for_shared_steward_seed() {
+            let ctx = setup();
+            let nodes_config = _nodes_config(&[("Node1", "000000000000000000000000000Node1", "000000000000000000000000Steward1"),
+                                               ("Node2", "000000000000000000000000000Node2", "000000000000000000000000Steward1")]);
+            _generate_genesis(&ctx, &nodes_config).unwrap_err();
+            tear_down();
+        }
+
+        #[test]
+        pub fn generate_genesis_works_for_existing_txn_file() {
+            let ctx = setup();
+            let nodes_config = _nodes_config(&[("Node1", "000000000000000000000000000Node1", "000000000000000000000000Steward1")]);
+            _generate_genesis(&ctx, &nodes_config).unwrap();
+            _generate_genesis(&ctx, &nodes_config).unwrap_err();
+            tear_down();
+        }
+
+        #[test]
+        pub fn generate_genesis_works_for_unknown_nodes_config_file() {
+            let ctx = setup();
+            {
+                let cmd = generate_genesis_command::new();
+                let mut params = CommandParams::new();
+                params.insert("nodes_config", "unknown_nodes_config.json".to_string());
+                params.insert("pool_txn_file", "pool_genesis_transactions".to_string());
+                params.insert("domain_txn_file", "domain_genesis_transactions".to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
     mod connect {
         use super::*;
 
@@ -557,6 +883,38 @@ pub mod tests {
             disconnect_and_delete_pool(&ctx);
             tear_down();
         }
+
+        #[test]
+        pub fn connect_works_for_read_strategy() {
+            let ctx = setup();
+            create_pool(&ctx);
+            {
+                let cmd = connect_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                params.insert("read-strategy", "adaptive".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            ensure_connected_pool_handle(&ctx).unwrap();
+            disconnect_and_delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn connect_works_for_force_rebuild() {
+            let ctx = setup();
+            create_pool(&ctx);
+            {
+                let cmd = connect_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                params.insert("force-rebuild", "true".to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            ensure_connected_pool_handle(&ctx).unwrap();
+            disconnect_and_delete_pool(&ctx);
+            tear_down();
+        }
     }
 
     mod list {
@@ -617,6 +975,72 @@ pub mod tests {
         }
     }
 
+    mod metrics {
+        use super::*;
+
+        #[test]
+        pub fn metrics_works() {
+            let ctx = setup();
+            create_and_connect_pool(&ctx);
+            {
+                let cmd = metrics_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            disconnect_and_delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn metrics_works_for_not_opened() {
+            let ctx = setup();
+            create_pool(&ctx);
+            {
+                let cmd = metrics_command::new();
+                let params = CommandParams::new();
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            delete_pool(&ctx);
+            tear_down();
+        }
+    }
+
+    mod snapshot {
+        use super::*;
+
+        #[test]
+        pub fn snapshot_works() {
+            let ctx = setup();
+            create_pool(&ctx);
+            {
+                let cmd = snapshot_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            {
+                let cmd = connect_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                cmd.execute(&ctx, &params).unwrap();
+            }
+            disconnect_and_delete_pool(&ctx);
+            tear_down();
+        }
+
+        #[test]
+        pub fn snapshot_works_for_not_created() {
+            let ctx = setup();
+            {
+                let cmd = snapshot_command::new();
+                let mut params = CommandParams::new();
+                params.insert("name", POOL.to_string());
+                cmd.execute(&ctx, &params).unwrap_err();
+            }
+            tear_down();
+        }
+    }
+
     mod disconnect {
         use super::*;
 
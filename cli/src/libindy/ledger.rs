@@ -66,6 +66,14 @@ impl Ledger {
         ledger::build_node_request(submitter_did, target_did, data).wait()
     }
 
+    pub fn generate_bls_keys(seed: Option<&str>) -> Result<(String, String), IndyError> {
+        ledger::generate_bls_keys(seed).wait()
+    }
+
+    pub fn build_genesis_transactions(nodes_config: &str) -> Result<(String, String), IndyError> {
+        ledger::build_genesis_transactions(nodes_config).wait()
+    }
+
     pub fn indy_build_pool_config_request(submitter_did: &str, writes: bool, force: bool) -> Result<String, IndyError> {
         ledger::build_pool_config_request(submitter_did, writes, force).wait()
     }
@@ -81,6 +89,14 @@ impl Ledger {
                                            reinstall, force, package).wait()
     }
 
+    pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+        ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+    }
+
+    pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+        ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+    }
+
     pub fn build_auth_rule_request(submitter_did: &str,
                                    txn_type: &str,
                                    action: &str,
@@ -133,4 +149,32 @@ impl Ledger {
                                                                   acc_mech_type,
                                                                   time_of_acceptance).wait()
     }
+
+    pub fn append_request_endorser(request_json: &str, endorser_did: &str) -> Result<String, IndyError> {
+        ledger::append_request_endorser(request_json, endorser_did).wait()
+    }
+
+    pub fn build_endorsement_bundle(request_json: &str, metadata_json: Option<&str>) -> Result<String, IndyError> {
+        ledger::build_endorsement_bundle(request_json, metadata_json).wait()
+    }
+
+    pub fn review_endorsement_bundle(pool_handle: i32, bundle_json: &str) -> Result<String, IndyError> {
+        ledger::review_endorsement_bundle(pool_handle, bundle_json).wait()
+    }
+
+    pub fn endorse_request(pool_handle: i32, wallet_handle: i32, endorser_did: &str, bundle_json: &str) -> Result<String, IndyError> {
+        ledger::endorse_request(pool_handle, wallet_handle, endorser_did, bundle_json).wait()
+    }
+
+    pub fn open_ledger_iterator(pool_handle: i32, ledger_type: Option<&str>, from: i64, to: i64) -> Result<i32, IndyError> {
+        ledger::open_ledger_iterator(pool_handle, ledger_type, from, to).wait()
+    }
+
+    pub fn ledger_iterator_next(iterator_handle: i32) -> Result<String, IndyError> {
+        ledger::ledger_iterator_next(iterator_handle).wait()
+    }
+
+    pub fn close_ledger_iterator(iterator_handle: i32) -> Result<(), IndyError> {
+        ledger::close_ledger_iterator(iterator_handle).wait()
+    }
 }
\ No newline at end of file
@@ -29,6 +29,14 @@ impl Pool {
         pool::delete_pool_ledger(pool_name).wait()
     }
 
+    pub fn get_metrics(pool_handle: i32) -> Result<String, IndyError> {
+        pool::get_pool_metrics(pool_handle).wait()
+    }
+
+    pub fn create_snapshot(pool_name: &str) -> Result<String, IndyError> {
+        pool::create_pool_snapshot(pool_name).wait()
+    }
+
     pub fn set_protocol_version(protocol_version: usize) -> Result<(), IndyError> {
         pool::set_protocol_version(protocol_version).wait()
     }
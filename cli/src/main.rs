@@ -136,8 +136,11 @@ fn build_executor() -> CommandExecutor {
         .finalize_group()
         .add_group(pool::group::new())
         .add_command(pool::create_command::new())
+        .add_command(pool::generate_genesis_command::new())
         .add_command(pool::connect_command::new())
         .add_command(pool::refresh_command::new())
+        .add_command(pool::metrics_command::new())
+        .add_command(pool::snapshot_command::new())
         .add_command(pool::list_command::new())
         .add_command(pool::disconnect_command::new())
         .add_command(pool::delete_command::new())
@@ -168,6 +171,9 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::pool_config_command::new())
         .add_command(ledger::pool_restart_command::new())
         .add_command(ledger::pool_upgrade_command::new())
+        .add_command(ledger::ledgers_freeze_command::new())
+        .add_command(ledger::get_frozen_ledgers_command::new())
+        .add_command(ledger::export_command::new())
         .add_command(ledger::custom_command::new())
         .add_command(ledger::get_payment_sources_command::new())
         .add_command(ledger::payment_command::new())
@@ -181,6 +187,8 @@ fn build_executor() -> CommandExecutor {
         .add_command(ledger::get_auth_rule_command::new())
         .add_command(ledger::save_transaction_command::new())
         .add_command(ledger::load_transaction_command::new())
+        .add_command(ledger::review_command::new())
+        .add_command(ledger::endorse_command::new())
         .add_command(ledger::taa_command::new())
         .add_command(ledger::aml_command::new())
         .finalize_group()
@@ -103,6 +103,44 @@ extern "C" {
                                                                                           const char*   merged_rev_reg_delta)
                                                                      );
 
+    extern indy_error_t indy_issuer_enable_revoc_reg_rotation(indy_handle_t command_handle,
+                                                              indy_handle_t wallet_handle,
+                                                              const char *  issuer_did,
+                                                              const char *  cred_def_id,
+                                                              const char *  config_json,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   active_revoc_reg_json)
+                                                              );
+
+    extern indy_error_t indy_issuer_get_active_revoc_reg(indy_handle_t command_handle,
+                                                         indy_handle_t wallet_handle,
+                                                         const char *  cred_def_id,
+
+                                                         void           (*cb)(indy_handle_t command_handle_,
+                                                                              indy_error_t  err,
+                                                                              const char*   active_revoc_reg_json)
+                                                         );
+
+    extern indy_error_t indy_issuer_get_pending_revoc_reg_publications(indy_handle_t command_handle,
+                                                                       indy_handle_t wallet_handle,
+                                                                       const char *  cred_def_id,
+
+                                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                                            indy_error_t  err,
+                                                                                            const char*   publications_json)
+                                                                       );
+
+    extern indy_error_t indy_issuer_confirm_revoc_reg_publication(indy_handle_t command_handle,
+                                                                  indy_handle_t wallet_handle,
+                                                                  const char *  cred_def_id,
+                                                                  const char *  rev_reg_id,
+
+                                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                                       indy_error_t  err)
+                                                                  );
+
     extern indy_error_t indy_prover_create_master_secret(indy_handle_t command_handle,
                                                          indy_handle_t wallet_handle,
                                                          const char *  master_secret_id,
@@ -257,6 +295,20 @@ extern "C" {
                                                    );
 
 
+    extern indy_error_t indy_verifier_verify_proof_with_report(indy_handle_t command_handle,
+                                                               const char *  proof_request_json,
+                                                               const char *  proof_json,
+                                                               const char *  schemas_json,
+                                                               const char *  credential_defs_jsons,
+                                                               const char *  rev_reg_defs_json,
+                                                               const char *  rev_regs_json,
+
+                                                               void           (*cb)(indy_handle_t command_handle_,
+                                                                                    indy_error_t  err,
+                                                                                    const char*   report_json)
+                                                               );
+
+
     extern indy_error_t indy_create_revocation_state(indy_handle_t command_handle,
                                                      indy_handle_t blob_storage_reader_handle,
                                                      const char *  rev_reg_def_json,
@@ -123,6 +123,229 @@ extern "C" {
                                                   void          (*cb)(indy_handle_t command_handle_,
                                                                       indy_error_t  err)
                                                  );
+
+    /// Gets Rich Schema object json data for specified object id.
+    /// If data is present inside of cache, cached data is returned.
+    /// Otherwise data is fetched from the ledger by GET_RICH_SCHEMA_OBJECT_BY_ID request and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// submitter_did: DID of the submitter stored in secured Wallet.
+    /// id: identifier of Rich Schema object.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// #Returns
+    /// Rich Schema object json (look at `indy_parse_get_rich_schema_object_response`).
+    extern indy_error_t indy_get_rich_schema_object(indy_handle_t command_handle,
+                                                    indy_handle_t pool_handle,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  submitter_did,
+                                                    const char *  id,
+                                                    const char *  options_json,
+                                                    void          (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   object_json)
+                                                   );
+
+    /// Purge Rich Schema objects cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    extern indy_error_t indy_purge_rich_schema_cache(indy_handle_t command_handle,
+                                                     indy_handle_t wallet_handle,
+                                                     const char *  options_json,
+                                                     void          (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err)
+                                                    );
+
+    /// Gets reply on read request to the ledger (GET_NYM, GET_ATTRIB, GET_REVOC_REG_DEF, GET_REVOC_REG_DELTA, etc.).
+    /// If reply is present inside of cache, cached data is returned.
+    /// Otherwise request is sent to the ledger and reply confirmed by state proof is stored inside of cache for future use.
+    /// Replies which are accepted by consensus of nodes without state proof are never cached.
+    ///
+    /// Cache entries are identified by the request operation,
+    /// so requests which differ only in reqId, submitter or signature share the same entry.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// request_json: read request (built by one of indy_build_get_*_request functions).
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    ///
+    /// #Returns
+    /// Reply of the ledger on request.
+    extern indy_error_t indy_get_cached_request(indy_handle_t command_handle,
+                                                indy_handle_t pool_handle,
+                                                indy_handle_t wallet_handle,
+                                                const char *  request_json,
+                                                const char *  options_json,
+                                                void          (*cb)(indy_handle_t command_handle_,
+                                                                    indy_error_t  err,
+                                                                    const char*   response_json)
+                                               );
+
+    /// Purge cache of ledger replies.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///    txnType: (string, optional) Purge only replies on requests of this transaction type (for example "105" for GET_NYM).
+    ///             All cached replies are purged by default.
+    ///  }
+    extern indy_error_t indy_purge_request_cache(indy_handle_t command_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  options_json,
+                                                 void          (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err)
+                                                );
+
+    /// Resolves did:sov DID into W3C DID Core document.
+    /// DID Document is assembled from NYM transaction (verification method) and raw `endpoint` attribute (services) of the DID.
+    /// If DID Document is present inside of cache, cached document is returned.
+    /// Otherwise it is built from ledger replies and stored inside of cache for future use.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// did: DID to resolve, either unqualified or qualified with `did:sov:` prefix.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+    ///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// DID resolution result json:
+    /// {
+    ///     didDocument: <W3C DID Core document>,
+    ///     didResolutionMetadata: {
+    ///         contentType: "application/did+ld+json",
+    ///         nym: {seqNo: int, txnTime: int, stateProof: bool}, - metadata of NYM read
+    ///         endpoint: {seqNo: int, txnTime: int, stateProof: bool} or null - metadata of endpoint attribute read
+    ///     }
+    /// }
+    extern indy_error_t indy_resolve_did(indy_handle_t command_handle,
+                                         indy_handle_t pool_handle,
+                                         indy_handle_t wallet_handle,
+                                         const char *  did,
+                                         const char *  options_json,
+                                         void          (*cb)(indy_handle_t command_handle_,
+                                                             indy_error_t  err,
+                                                             const char*   resolution_result_json)
+                                        );
+
+    /// Purge DID Document cache.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_purge_did_doc_cache(indy_handle_t command_handle,
+                                                 indy_handle_t wallet_handle,
+                                                 const char *  options_json,
+                                                 void          (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err)
+                                                );
+
+    /// Gets history of revocation registry: indices issued and revoked by each REVOC_REG_ENTRY transaction
+    /// written in the given interval along with its ledger timestamp and accumulator value.
+    /// States of revocation registry are stored inside of cache, so only the latest state is read from the ledger next time.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// rev_reg_def_id: ID of the revocation registry definition.
+    /// from: Requested time represented as a total number of seconds from Unix Epoch. Pass 0 to get the whole history.
+    /// to: Requested time represented as a total number of seconds from Unix Epoch.
+    /// options_json:
+    ///  {
+    ///    noCache: (bool, optional, false by default) Skip usage of cache,
+    ///    noStore: (bool, optional, false by default) Skip storing fresh data,
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Revocation history json:
+    /// {
+    ///     revRegDefId: string,
+    ///     entries: [{timestamp: int, value: {accum: string}, issued: [int], revoked: [int]}] - in order of timestamps,
+    ///     delta: <revocation registry delta json> - all entries merged into one delta, null if there are no entries
+    /// }
+    extern indy_error_t indy_get_revocation_history(indy_handle_t command_handle,
+                                                    indy_handle_t pool_handle,
+                                                    indy_handle_t wallet_handle,
+                                                    const char *  rev_reg_def_id,
+                                                    indy_i64_t    from,
+                                                    indy_i64_t    to,
+                                                    const char *  options_json,
+                                                    void          (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   history_json)
+                                                   );
+
+    /// Purge revocation registry state cache used by indy_get_revocation_history.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// options_json:
+    ///  {
+    ///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    extern indy_error_t indy_purge_rev_reg_state_cache(indy_handle_t command_handle,
+                                                       indy_handle_t wallet_handle,
+                                                       const char *  options_json,
+                                                       void          (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err)
+                                                      );
 #ifdef __cplusplus
 }
 #endif
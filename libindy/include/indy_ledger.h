@@ -68,6 +68,76 @@ extern "C" {
                                                                  const char*   request_result_json)
                                            );
 
+    /// Publishes write request to validator pool and waits until written transaction
+    /// can be read back with valid state proof.
+    ///
+    /// The write is submitted as is (see submit_request). Then seqNo is taken from the reply
+    /// and the corresponding GET request (GET_NYM for NYM, GET_ATTRIB for ATTRIB, etc.) is sent
+    /// periodically until its reply has state proof verified against BLS keys of pool nodes
+    /// and refers to transaction not older than the written one.
+    ///
+    /// Supported for NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF and REVOC_REG_ENTRY requests.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: Request data json (signed).
+    /// options_json: (optional)
+    ///     {
+    ///         "timeout": int (optional, 60 by default), deadline of confirmation in seconds,
+    ///         "interval": int (optional, 1000 by default), delay between reads in milliseconds
+    ///     }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Write reply as json.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// PoolLedgerTimeout if transaction isn't confirmed before deadline.
+
+    extern indy_error_t indy_submit_request_and_confirm(indy_handle_t command_handle,
+                                                        indy_handle_t pool_handle,
+                                                        const char *  request_json,
+                                                        const char *  options_json,
+
+                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                             indy_error_t  err,
+                                                                             const char*   request_result_json)
+                                                        );
+
+    /// Sends several requests to validator pool concurrently and returns all replies at once.
+    ///
+    /// Requests are sent as is (see submit_request), so it's intended mainly for read requests
+    /// like GET_SCHEMA, GET_CRED_DEF or GET_REVOC_REG_DELTA needed to verify a proof.
+    /// No more requests than `batch_limit` of the pool config (`conn_limit` by default) are in flight at the same time,
+    /// the next one is sent as soon as a reply to a previous one is received.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// requests_json: array of requests. Every request is either json object or string with json.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Array of results in the same order as requests. Every result is either
+    ///     {"response": string} - reply of the pool
+    /// or
+    ///     {"error": {"code": int, "message": string}} - error code and message the request failed with.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_submit_requests_batch(indy_handle_t command_handle,
+                                                   indy_handle_t pool_handle,
+                                                   const char *  requests_json,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   results_json)
+                                                  );
+
     /// Send action to particular nodes of validator pool.
     ///
     /// The list of requests can be send:
@@ -169,6 +239,9 @@ extern "C" {
 
     /// Builds a request to get a DDO.
     ///
+    /// Deprecated: GET_DDO transaction isn't supported by the ledger, GET_NYM request is built for compatibility.
+    /// Use indy_resolve_did to get DID Document of the DID.
+    ///
     /// #Params
     /// command_handle: command handle to map callback to caller context.
     /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -285,6 +358,37 @@ extern "C" {
                                                                            const char*   request_json)
                                                      );
 
+    /// Parse a GET_ATTRIB response to get attribute in the normalized form
+    /// independent of reply and transaction format versions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_attrib_response: response of GET_ATTRIB request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Attribute json. Only one of raw, hash and enc is set.
+    /// {
+    ///     did: DID the attribute belongs to,
+    ///     raw: (optional) raw attribute decoded from json string, e.g. {"endpoint":{"ha":"127.0.0.1:5555"}},
+    ///     hash: (optional) sha256 hash of attribute data,
+    ///     enc: (optional) encrypted attribute data,
+    ///     seqNo: (optional) sequence number of ATTRIB transaction in the ledger,
+    ///     txnTime: (optional) time of ATTRIB transaction in the ledger
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_attrib_response(indy_handle_t command_handle,
+                                                       const char *  get_attrib_response,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   attrib_json)
+                                                       );
+
     /// Builds a GET_NYM request. Request to get information about a DID (NYM).
     ///
     /// #Params
@@ -308,6 +412,36 @@ extern "C" {
                                                                         const char*   request_json)
                                                   );
 
+    /// Parse a GET_NYM response to get NYM data in the normalized form
+    /// independent of reply and transaction format versions.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_nym_response: response of GET_NYM request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// NYM json.
+    /// {
+    ///     did: DID as base58-encoded string,
+    ///     verkey: (optional) full verkey, abbreviated one is expanded,
+    ///     role: (optional) role name: TRUSTEE, STEWARD, ENDORSER or NETWORK_MONITOR,
+    ///     seqNo: (optional) sequence number of NYM transaction in the ledger,
+    ///     txnTime: (optional) time of NYM transaction in the ledger
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_nym_response(indy_handle_t command_handle,
+                                                    const char *  get_nym_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   nym_json)
+                                                    );
+
     /// Builds a SCHEMA request. Request to add Credential's schema.
     ///
     /// #Params
@@ -494,6 +628,8 @@ extern "C" {
     ///     alias: string - Node's alias
     ///     blskey: string - (Optional) BLS multi-signature key as base58-encoded string.
     ///     blskey_pop: string - (Optional) BLS key proof of possession as base58-encoded string.
+    ///                 Must be specified together with blskey. It is verified against blskey before the request is built
+    ///                 (look at `indy_generate_bls_keys` to get the keys).
     ///     client_ip: string - (Optional) Node's client listener IP address.
     ///     client_port: string - (Optional) Node's client listener port.
     ///     node_ip: string - (Optional) The IP address other Nodes use to communicate with this Node.
@@ -518,6 +654,73 @@ extern "C" {
                                                                      const char*   request_json)
                                                );
 
+        /// Generates BLS keys of a validator node to be used in NODE transaction.
+        ///
+        /// #Params
+        /// command_handle: command handle to map callback to caller context.
+        /// seed: (optional) seed of the node. Keys are derived from the seed the same way as validator node does it,
+        ///       so the node must be initialized with the same seed. Random keys are generated if seed is not specified.
+        ///       It can be either 32 bytes string or base64 string or 64 bytes HEX string.
+        /// cb: Callback that takes command result as parameter.
+        ///
+        /// #Returns
+        /// blskey: BLS key as base58-encoded string.
+        /// blskey_pop: BLS key proof of possession as base58-encoded string.
+        ///
+        /// #Errors
+        /// Common*
+
+        extern indy_error_t indy_generate_bls_keys(indy_handle_t command_handle,
+                                                   const char *  seed,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err,
+                                                                        const char*   blskey,
+                                                                        const char*   blskey_pop)
+                                                  );
+
+        /// Builds genesis transactions of a new network: NODE transactions of the pool ledger
+        /// and NYM transactions of trustees and node stewards of the domain ledger.
+        ///
+        /// Node verkeys (dest), BLS keys and their proofs of possession are derived from node seeds
+        /// the same way as validator nodes do it, so the nodes must be initialized with the same seeds.
+        ///
+        /// #Params
+        /// command_handle: command handle to map callback to caller context.
+        /// nodes_config: description of the network
+        /// {
+        ///     "trustee_seeds": array<string> - seeds of trustee DIDs. At least one is required.
+        ///                                      Stewards are written to the domain ledger by the first trustee.
+        ///     "nodes": [{
+        ///         "alias": string - unique node name,
+        ///         "seed": string - seed of node keys,
+        ///         "steward_seed": string - seed of node steward DID,
+        ///         "node_ip": string,
+        ///         "node_port": int,
+        ///         "client_ip": string,
+        ///         "client_port": int,
+        ///         "services": (optional) array<string> - the service of the node.
+        ///                     VALIDATOR is the only supported one (default).
+        ///     }]
+        /// }
+        /// cb: Callback that takes command result as parameter.
+        ///
+        /// #Returns
+        /// pool_genesis_txns: pool ledger genesis transactions, one json per line.
+        /// domain_genesis_txns: domain ledger genesis transactions, one json per line.
+        ///
+        /// #Errors
+        /// Common*
+
+        extern indy_error_t indy_build_genesis_transactions(indy_handle_t command_handle,
+                                                            const char *  nodes_config,
+
+                                                            void           (*cb)(indy_handle_t command_handle_,
+                                                                                 indy_error_t  err,
+                                                                                 const char*   pool_genesis_txns,
+                                                                                 const char*   domain_genesis_txns)
+                                                           );
+
         /// Builds a GET_VALIDATOR_INFO request.
         ///
         /// #Params
@@ -568,6 +771,38 @@ extern "C" {
                                                                         const char*   request_json)
                                                    );
 
+    /// Parse a GET_TXN response to get transaction in the normalized form
+    /// independent of transaction format version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_txn_response: response of GET_TXN request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Transaction json.
+    /// {
+    ///     type: transaction type code, e.g. "1" for NYM,
+    ///     seqNo: sequence number of transaction in the ledger,
+    ///     txnTime: (optional) time of transaction in the ledger,
+    ///     txnId: (optional) transaction id,
+    ///     from: (optional) DID of transaction author,
+    ///     reqId: (optional) id of request the transaction was written by,
+    ///     data: transaction specific data, e.g. {"dest": ..., "verkey": ...} for NYM
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_txn_response(indy_handle_t command_handle,
+                                                    const char *  get_txn_response,
+
+                                                    void           (*cb)(indy_handle_t command_handle_,
+                                                                         indy_error_t  err,
+                                                                         const char*   txn_json)
+                                                    );
+
     /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
     ///
     /// #Params
@@ -666,6 +901,63 @@ extern "C" {
                                                                              const char*   request_json)
                                                         );
 
+    /// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers (sent by Trustee).
+    /// Frozen ledgers can't be written anymore, but their transactions are still available for reading.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// ledgers_ids: list of ids of ledgers to freeze as json array, for example: [10, 11].
+    ///              Base ledgers (0 - pool, 1 - domain, 2 - config, 3 - audit) can't be frozen.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_ledgers_freeze_request(indy_handle_t command_handle,
+                                                          const char *  submitter_did,
+                                                          const char *  ledgers_ids,
+
+                                                          void           (*cb)(indy_handle_t command_handle_,
+                                                                               indy_error_t  err,
+                                                                               const char*   request_json)
+                                                          );
+
+    /// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+    /// Frozen ledgers are defined by LEDGERS_FREEZE request.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    /// Reply of the request contains map of frozen ledgers:
+    /// {
+    ///     <ledger_id>: {
+    ///         "ledger": String - Ledger root hash,
+    ///         "state": String - State root hash,
+    ///         "seq_no": u64 - the latest transaction seqNo for particular Node,
+    ///     },
+    ///     ...
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_frozen_ledgers_request(indy_handle_t command_handle,
+                                                              const char *  submitter_did,
+
+                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                   indy_error_t  err,
+                                                                                   const char*   request_json)
+                                                              );
+
     /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
     /// to an exists credential definition.
     ///
@@ -922,6 +1214,130 @@ extern "C" {
                                                                                      unsigned long long      timestamp)
                                                                );
 
+    /// Builds a request to add a Rich Schema object: JSON-LD context, rich schema, encoding, mapping,
+    /// credential definition or presentation definition.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+    ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+    /// id: identifier of the object. Must match `@id` field of content if it is present.
+    /// content: JSON-LD content of the object. Content of JSON-LD context must contain `@context` field.
+    /// rs_name: name of the object.
+    /// rs_version: version of the object.
+    /// rs_type: type of the object. One of:
+    ///     "ctx" - JSON-LD context,
+    ///     "sch" - rich schema,
+    ///     "enc" - encoding,
+    ///     "map" - mapping,
+    ///     "cdf" - credential definition,
+    ///     "pdf" - presentation definition
+    /// ver: (Optional) version of the object format. "1" is used by default.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_rich_schema_request(indy_handle_t command_handle,
+                                                       const char *  submitter_did,
+                                                       const char *  id,
+                                                       const char *  content,
+                                                       const char *  rs_name,
+                                                       const char *  rs_version,
+                                                       const char *  rs_type,
+                                                       const char *  ver,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   request_json)
+                                                      );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get Rich Schema object by its identifier.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// id: identifier of the object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_rich_schema_object_by_id_request(indy_handle_t command_handle,
+                                                                        const char *  submitter_did,
+                                                                        const char *  id,
+
+                                                                        void           (*cb)(indy_handle_t command_handle_,
+                                                                                             indy_error_t  err,
+                                                                                             const char*   request_json)
+                                                                       );
+
+    /// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request. Request to get Rich Schema object by its type, name and version.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+    /// rs_type: type of the object (look at `indy_build_rich_schema_request`).
+    /// rs_name: name of the object.
+    /// rs_version: version of the object.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Request result as json.
+    ///
+    /// #Errors
+    /// Common*
+
+    extern indy_error_t indy_build_get_rich_schema_object_by_metadata_request(indy_handle_t command_handle,
+                                                                              const char *  submitter_did,
+                                                                              const char *  rs_type,
+                                                                              const char *  rs_name,
+                                                                              const char *  rs_version,
+
+                                                                              void           (*cb)(indy_handle_t command_handle_,
+                                                                                                   indy_error_t  err,
+                                                                                                   const char*   request_json)
+                                                                             );
+
+    /// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Rich Schema object Id and Rich Schema object json.
+    /// {
+    ///     id: identifier of the object
+    ///     content: JSON-LD content of the object as string
+    ///     rsName: name of the object
+    ///     rsVersion: version of the object
+    ///     rsType: type of the object
+    ///     ver: version of the object format
+    ///     seqNo: (Optional) sequence number of the object transaction on the ledger
+    ///     txnTime: (Optional) time of the object transaction on the ledger
+    /// }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_parse_get_rich_schema_object_response(indy_handle_t command_handle,
+                                                                   const char *  get_rich_schema_object_response,
+
+                                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                                        indy_error_t  err,
+                                                                                        const char*   id,
+                                                                                        const char*   object_json)
+                                                                  );
+
 
     /// Parse transaction response to fetch metadata.
     /// The important use case for this method is validation of Node's response freshens.
@@ -1088,6 +1504,60 @@ extern "C" {
                                                                               const char*   request_json)
                                                         );
 
+    /// Predicts whether the ledger accepts write request from the submitter without sending it.
+    ///
+    /// Auth rules of the pool are read with GET_AUTH_RULE request and cached for the pool handle.
+    /// Constraints of the rules applying to the request are evaluated against role info of request signers.
+    ///
+    /// Actions performed by the request are determined in the following way:
+    ///     NYM - adding of new DID (`role` field) or editing of `role` and `verkey` fields of existing one (current NYM is read from the ledger)
+    ///     NODE - adding of new node if node and client addresses, blskey and services are specified,
+    ///            otherwise editing of specified fields (promotion or demotion for `services`)
+    ///     ATTRIB, SCHEMA, CRED_DEF - adding of new entry
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// request_json: write request.
+    /// submitter_role_info: role info of request signers
+    ///  {
+    ///     role: (string, optional) role of the submitter (TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR or role code), no role by default,
+    ///     isOwner: (bool, optional, false by default) the submitter is an owner of changed ledger entry,
+    ///     offLedger: (bool, optional, false by default) the submitter DID isn't written on the ledger,
+    ///     additionalSigners: (optional) [{role, isOwner, offLedger}] - other signers of the request (Endorser, multi-signature)
+    ///  }
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Auth check result json
+    ///  {
+    ///     authorized: bool - whether all actions of the request are authorized,
+    ///     actions: [{
+    ///         auth_type, auth_action, field, old_value, new_value - key of the action,
+    ///         authorized: bool,
+    ///         constraint: <constraint> - constraint of the rule (null if the ledger has no rule for the action),
+    ///         satisfied: <constraint> - part of the constraint satisfied by signers (its metadata contains fees),
+    ///         missing: <constraint> - part of the constraint which must be satisfied additionally,
+    ///                                 `sig_count` of ROLE constraints is the number of missing signatures
+    ///     }]
+    ///  }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+
+    extern indy_error_t indy_check_request_auth(indy_handle_t command_handle,
+                                                indy_handle_t pool_handle,
+                                                const char *  request_json,
+                                                const char *  submitter_role_info,
+
+                                                void           (*cb)(indy_handle_t command_handle_,
+                                                                     indy_error_t  err,
+                                                                     const char*   auth_check_json)
+                                               );
+
     /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
     ///
     /// EXPERIMENTAL
@@ -1250,6 +1720,49 @@ extern "C" {
                                                                                                     const char*   request_with_meta_json)
                                                                                );
 
+    /// Set transaction author agreement acceptance for the pool session.
+    /// Once set, acceptance is appended to every write request of the domain ledger
+    /// sent with `indy_sign_and_submit_request` through this pool handle
+    /// if the request doesn't contain its own acceptance yet.
+    ///
+    /// EXPERIMENTAL
+    ///
+    /// Acceptance is checked against the latest transaction author agreement and
+    /// acceptance mechanisms list read from the ledger (GET_TXN_AUTHR_AGRMT and GET_TXN_AUTHR_AGRMT_AML):
+    /// digest must match the agreement, mechanism must be in the list and time must be
+    /// between ratification of the agreement and current time.
+    /// Setting new acceptance replaces the previous one of the pool session.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// text and version - (optional) raw data about TAA from ledger.
+    ///     These parameters should be passed together.
+    ///     These parameters are required if taa_digest parameter is omitted.
+    /// taa_digest - (optional) hash on text and version. This parameter is required if text and version parameters are omitted.
+    /// mechanism - mechanism how user has accepted the TAA
+    /// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+    ///
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// Error code
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_set_txn_author_agreement_acceptance(indy_handle_t command_handle,
+                                                                 indy_handle_t pool_handle,
+                                                                 const char *  text,
+                                                                 const char *  version,
+                                                                 const char *  taa_digest,
+                                                                 const char *  mechanism,
+                                                                 indy_u64_t    time,
+
+                                                                 void           (*cb)(indy_handle_t command_handle_,
+                                                                                      indy_error_t  err)
+                                                                 );
+
     /// Append Endorser to an existing request.
     ///
     /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1278,6 +1791,234 @@ extern "C" {
                                                                           const char*   out_request_json)
                                                      );
 
+    /// Wraps request into bundle which Transaction Author hands over to Endorser.
+    ///
+    /// Bundle contains the request, its author and endorser, type of transaction,
+    /// human-readable summary of the request operation and optional metadata passed by the author.
+    ///
+    /// Note: Endorser must be appended to the request (see `indy_append_request_endorser`)
+    /// and the request must be signed by its author before building of the bundle.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// request_json: request with Endorser appended signed by its author.
+    /// metadata_json: (Optional) arbitrary json passed to the Endorser along with the request.
+    /// cb: Callback that takes command result as parameter.
+    ///     The command result is a bundle json:
+    ///     {
+    ///         "request": <request json>,
+    ///         "author": string - DID of transaction author,
+    ///         "endorser": string - DID of endorser,
+    ///         "txnType": string - code of transaction type,
+    ///         "summary": string - human-readable summary of the request operation,
+    ///         "created": int - time of bundle creation as timestamp,
+    ///         "metadata": <metadata json> (if set)
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_build_endorsement_bundle(indy_handle_t command_handle,
+                                                      const char *  request_json,
+                                                      const char *  metadata_json,
+
+                                                      void           (*cb)(indy_handle_t command_handle_,
+                                                                           indy_error_t  err,
+                                                                           const char*   bundle_json)
+                                                      );
+
+    /// Reviews endorsement bundle built by Transaction Author.
+    ///
+    /// Checks that author, endorser, transaction type and summary of the bundle match the request and verifies
+    /// all signatures of the request against verkeys of signers written on the ledger.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// bundle_json: endorsement bundle built by `indy_build_endorsement_bundle`.
+    /// cb: Callback that takes command result as parameter.
+    ///     The command result is a review json:
+    ///     {
+    ///         "author": string - DID of transaction author,
+    ///         "endorser": string - DID of endorser,
+    ///         "txnType": string - code of transaction type,
+    ///         "summary": string - human-readable summary of the request operation,
+    ///         "created": int - time of bundle creation as timestamp,
+    ///         "metadata": <metadata json> (if set),
+    ///         "signatures": [{
+    ///             "did": string - DID of signer,
+    ///             "verkey": string - verkey of signer on the ledger (null if DID isn't written),
+    ///             "valid": bool - whether signature is valid
+    ///         }],
+    ///         "valid": bool - whether request is signed by its author and all signatures are valid
+    ///     }
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_review_endorsement_bundle(indy_handle_t command_handle,
+                                                       indy_handle_t pool_handle,
+                                                       const char *  bundle_json,
+
+                                                       void           (*cb)(indy_handle_t command_handle_,
+                                                                            indy_error_t  err,
+                                                                            const char*   review_json)
+                                                       );
+
+    /// Endorses request of endorsement bundle built by Transaction Author.
+    ///
+    /// Checks that Endorser of the request is `endorser_did`, verifies signatures of the request
+    /// against verkeys written on the ledger (see `indy_review_endorsement_bundle`)
+    /// and adds multi signature of the Endorser to the request.
+    /// Result request can be sent to the ledger with `indy_submit_request`.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// wallet_handle: wallet handle (created by open_wallet).
+    /// endorser_did: DID of the Endorser stored in the wallet.
+    /// bundle_json: endorsement bundle built by `indy_build_endorsement_bundle`.
+    /// cb: Callback that takes command result as parameter.
+    ///     The command result is a request json signed by the Endorser.
+    ///
+    /// #Errors
+    /// Common*
+    /// Wallet*
+    /// Ledger*
+    /// Crypto*
+    extern indy_error_t indy_endorse_request(indy_handle_t command_handle,
+                                             indy_handle_t pool_handle,
+                                             indy_handle_t wallet_handle,
+                                             const char *  endorser_did,
+                                             const char *  bundle_json,
+
+                                             void           (*cb)(indy_handle_t command_handle_,
+                                                                  indy_error_t  err,
+                                                                  const char*   signed_request_json)
+                                             );
+
+    /// Verifies that a transaction is included into the ledger by checking its Merkle audit path
+    /// against the ledger root hash (RFC 6962 inclusion proof).
+    ///
+    /// The transaction is identified by `txnMetadata.seqNo` field.
+    /// `result` field of a write reply or `data` field of GET_TXN reply can be passed as is:
+    /// if `audit_path_json` is omitted `auditPath` field of the transaction is used.
+    /// `rootHash` field of the transaction is never used, as it comes with the data being verified.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// root_hash: base58 encoded root hash of the ledger of `ledger_size` size the caller trusts
+    ///            (e.g. verified by BLS multi-signature of nodes).
+    /// txn_json: ledger transaction json.
+    /// audit_path_json: (Optional) json array of base58 encoded hashes of the audit path.
+    /// ledger_size: size of the ledger the root hash corresponds to.
+    ///              Pass 0 or negative value to use the sequence number of the transaction.
+    /// cb: Callback that takes command result as parameter.
+    ///     The command result is true if the transaction is included into the ledger.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    extern indy_error_t indy_verify_txn_inclusion(indy_handle_t command_handle,
+                                                  const char *  root_hash,
+                                                  const char *  txn_json,
+                                                  const char *  audit_path_json,
+                                                  indy_i32_t    ledger_size,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       indy_bool_t   valid)
+                                                  );
+
+    /// Opens iterator over transactions of the ledger.
+    ///
+    /// Transactions are fetched from nodes by batches with CATCHUP_REQ messages the same way as nodes catch up.
+    /// Each batch is verified by consistency proof against the size and merkle root of the ledger
+    /// which f+1 nodes agree on at the moment of opening, so transactions written later are not returned.
+    ///
+    /// NOTE: Verification requires all transactions preceding the requested range,
+    /// so the ledger is always fetched starting from the first transaction (seq_no 1)
+    /// and transactions preceding `from` are fetched too but aren't returned.
+    /// Only one iterator over the same ledger of the pool can be open at a time.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// pool_handle: pool handle (created by open_pool_ledger).
+    /// ledger_type: (Optional) type of the ledger to iterate over:
+    ///     DOMAIN - used default,
+    ///     POOL,
+    ///     CONFIG
+    ///     any number
+    /// from: sequence number of the first transaction to return (starting from 1).
+    /// to: sequence number of the last transaction to return.
+    ///     Pass -1 to iterate till the end of the ledger.
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// iterator_handle: Ledger iterator handle that can be used later to fetch transactions by batches (with indy_ledger_iterator_next)
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// Pool*
+    extern indy_error_t indy_open_ledger_iterator(indy_handle_t command_handle,
+                                                  indy_handle_t pool_handle,
+                                                  const char *  ledger_type,
+                                                  indy_i64_t    from,
+                                                  indy_i64_t    to,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       indy_handle_t iterator_handle)
+                                                  );
+
+    /// Fetches the next batch of verified transactions of the ledger iterator.
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// iterator_handle: Ledger iterator handle (created by indy_open_ledger_iterator).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Returns
+    /// txns_json: List of transactions ordered by sequence number:
+    ///     [{
+    ///         "type": string - transaction type,
+    ///         "seqNo": int - sequence number of the transaction,
+    ///         "txnTime": Optional<int> - time of the transaction ordering,
+    ///         "txnId": Optional<string> - identifier of the transaction,
+    ///         "from": Optional<string> - DID of the transaction author,
+    ///         "reqId": Optional<int> - id of the request written the transaction,
+    ///         "data": object - transaction specific data
+    ///     }]
+    /// NOTE: Empty list means the ledger iterator is completed.
+    ///
+    /// #Errors
+    /// Common*
+    /// Ledger*
+    /// Pool*
+    extern indy_error_t indy_ledger_iterator_next(indy_handle_t command_handle,
+                                                  indy_handle_t iterator_handle,
+
+                                                  void           (*cb)(indy_handle_t command_handle_,
+                                                                       indy_error_t  err,
+                                                                       const char*   txns_json)
+                                                  );
+
+    /// Closes ledger iterator (make iterator handle invalid).
+    ///
+    /// #Params
+    /// command_handle: command handle to map callback to caller context.
+    /// iterator_handle: Ledger iterator handle (created by indy_open_ledger_iterator).
+    /// cb: Callback that takes command result as parameter.
+    ///
+    /// #Errors
+    /// Common*
+    extern indy_error_t indy_close_ledger_iterator(indy_handle_t command_handle,
+                                                   indy_handle_t iterator_handle,
+
+                                                   void           (*cb)(indy_handle_t command_handle_,
+                                                                        indy_error_t  err)
+                                                   );
+
 #ifdef __cplusplus
 }
 #endif
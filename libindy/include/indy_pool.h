@@ -43,6 +43,28 @@ extern "C" {
                                                   indy_u64_t    protocol_version,
                                                   void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
                                                   );
+
+    extern indy_error_t indy_get_pool_metrics(indy_handle_t command_handle,
+                                              indy_handle_t handle,
+                                              void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const metrics_json)
+                                              );
+
+    extern indy_error_t indy_create_pool_snapshot(indy_handle_t command_handle,
+                                                  const char *  config_name,
+                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err, const char *const snapshot_json)
+                                                  );
+
+    extern indy_error_t indy_register_pool_event_callback(indy_handle_t command_handle,
+                                                          indy_handle_t handle,
+                                                          void          (*event_cb)(indy_handle_t pool_handle, const char *const event_json),
+                                                          void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
+                                                          );
+
+    extern indy_error_t indy_register_pool_event_callback_by_name(indy_handle_t command_handle,
+                                                                  const char *  config_name,
+                                                                  void          (*event_cb)(indy_handle_t pool_handle, const char *const event_json),
+                                                                  void          (*cb)(indy_handle_t command_handle_, indy_error_t err)
+                                                                  );
 #ifdef __cplusplus
 }
 #endif
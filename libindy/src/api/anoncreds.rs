@@ -13,6 +13,7 @@ use domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use domain::anoncreds::credential::{Credential, AttributeValues};
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition};
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
+use domain::anoncreds::revocation_registry_rotation::RevocationRegistryRotationConfig;
 use domain::anoncreds::proof::Proof;
 use domain::anoncreds::proof_request::{ProofRequest, ProofRequestExtraQuery};
 use domain::anoncreds::requested_credential::RequestedCredentials;
@@ -668,6 +669,253 @@ pub extern fn indy_issuer_merge_revocation_registry_deltas(command_handle: Comma
     res
 }
 
+/// Enables automatic rotation of revocation registries for the credential definition stored in the wallet.
+///
+/// Creates and stores the first revocation registry of the rotation (see indy_issuer_create_and_store_revoc_reg)
+/// and makes it active. Each time indy_issuer_create_credential issues a credential in the active registry
+/// its capacity is checked:
+/// - when the part of used indices reaches the fill threshold the next registry and its tails are created in advance;
+/// - when the active registry is full the next one becomes active if its publication is already confirmed
+///   (see indy_issuer_confirm_revoc_reg_publication), otherwise it becomes active on the confirmation.
+///   Until that issuance in the full registry fails with AnoncredsRevocationRegistryFullError.
+///
+/// Issuer must use revocation registry returned by indy_issuer_get_active_revoc_reg for credential issuance.
+/// Created registries must be published to the ledger: REVOC_REG_DEF and REVOC_REG_ENTRY requests
+/// are available through indy_issuer_get_pending_revoc_reg_publications.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// issuer_did: a DID of the issuer signing transactions to the Ledger
+/// cred_def_id: id of stored in ledger credential definition supporting revocation
+/// config_json: rotation configuration as json:
+///     {
+///         "tag": (optional) prefix of registry tags, registries are tagged "<tag>_<n>" or "<n>" if it is omitted,
+///         "issuance_type": (optional) type of issuance (see indy_issuer_create_and_store_revoc_reg),
+///         "max_cred_num": (optional) maximum number of credentials each registry can process (default 100000),
+///         "fill_threshold": (optional) part of used registry indices in (0, 1] to create the next registry at (default 0.8),
+///         "tails_writer_type": type of blob storage writer to store tails (see indy_open_blob_storage_writer),
+///         "tails_writer_config": config of blob storage writer to store tails (see indy_open_blob_storage_writer)
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// active_revoc_reg_json: active revocation registry (see indy_issuer_get_active_revoc_reg)
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_enable_revoc_reg_rotation(command_handle: CommandHandle,
+                                                    wallet_handle: WalletHandle,
+                                                    issuer_did: *const c_char,
+                                                    cred_def_id: *const c_char,
+                                                    config_json: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                         active_revoc_reg_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_enable_revoc_reg_rotation: >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json);
+
+    check_useful_c_str!(issuer_did, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam4);
+    check_useful_json!(config_json, ErrorCode::CommonInvalidParam5, RevocationRegistryRotationConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_issuer_enable_revoc_reg_rotation: entities >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config_json: {:?}",
+           wallet_handle, issuer_did, cred_def_id, config_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::EnableRevocationRegistryRotation(
+                    wallet_handle,
+                    issuer_did,
+                    cred_def_id,
+                    config_json,
+                    Box::new(move |result| {
+                        let (err, active_revoc_reg_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_enable_revoc_reg_rotation: active_revoc_reg_json: {:?}", active_revoc_reg_json);
+                        let active_revoc_reg_json = ctypes::string_to_cstring(active_revoc_reg_json);
+                        cb(command_handle, err, active_revoc_reg_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_enable_revoc_reg_rotation: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets revocation registry that must be used to issue credentials of the credential definition
+/// with enabled revocation registry rotation (see indy_issuer_enable_revoc_reg_rotation).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition with enabled revocation registry rotation
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// active_revoc_reg_json:
+///     {
+///         "rev_reg_id": string - id of active revocation registry,
+///         "rev_reg_def": <revoc_reg_def_json> - public part of revocation registry definition
+///                        (see indy_issuer_create_and_store_revoc_reg),
+///         "issued": number - number of used registry indices,
+///         "max_cred_num": number - maximum number of credentials the registry can process,
+///         "published": bool - false if there are pending publications for the registry,
+///         "next_rev_reg_id": Optional<string> - id of registry created in advance to replace the active one
+///     }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_get_active_revoc_reg(command_handle: CommandHandle,
+                                               wallet_handle: WalletHandle,
+                                               cred_def_id: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                    active_revoc_reg_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_active_revoc_reg: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_get_active_revoc_reg: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetActiveRevocationRegistry(
+                    wallet_handle,
+                    cred_def_id,
+                    Box::new(move |result| {
+                        let (err, active_revoc_reg_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_get_active_revoc_reg: active_revoc_reg_json: {:?}", active_revoc_reg_json);
+                        let active_revoc_reg_json = ctypes::string_to_cstring(active_revoc_reg_json);
+                        cb(command_handle, err, active_revoc_reg_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_active_revoc_reg: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets ledger requests publishing revocation registries created by revocation registry rotation
+/// (see indy_issuer_enable_revoc_reg_rotation) that aren't confirmed yet.
+///
+/// Requests must be signed and sent to the ledger in the returned order.
+/// After registry requests are written use indy_issuer_confirm_revoc_reg_publication to remove them from the list.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition with enabled revocation registry rotation
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// publications_json:
+///     [{
+///         "rev_reg_id": string - id of revocation registry,
+///         "txn_type": string - REVOC_REG_DEF or REVOC_REG_ENTRY,
+///         "request": <request_json> - ledger request (see indy_build_revoc_reg_def_request and indy_build_revoc_reg_entry_request)
+///     }]
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_get_pending_revoc_reg_publications(command_handle: CommandHandle,
+                                                             wallet_handle: WalletHandle,
+                                                             cred_def_id: *const c_char,
+                                                             cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                                  publications_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_issuer_get_pending_revoc_reg_publications: >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_issuer_get_pending_revoc_reg_publications: entities >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::GetPendingRevocationRegistryPublications(
+                    wallet_handle,
+                    cred_def_id,
+                    Box::new(move |result| {
+                        let (err, publications_json) = prepare_result_1!(result, String::new());
+                        trace!("indy_issuer_get_pending_revoc_reg_publications: publications_json: {:?}", publications_json);
+                        let publications_json = ctypes::string_to_cstring(publications_json);
+                        cb(command_handle, err, publications_json.as_ptr())
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_get_pending_revoc_reg_publications: <<< res: {:?}", res);
+
+    res
+}
+
+/// Removes requests of the revocation registry written to the ledger from pending publications
+/// (see indy_issuer_get_pending_revoc_reg_publications).
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// cred_def_id: id of credential definition with enabled revocation registry rotation
+/// rev_reg_id: id of published revocation registry
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Anoncreds*
+#[no_mangle]
+pub extern fn indy_issuer_confirm_revoc_reg_publication(command_handle: CommandHandle,
+                                                        wallet_handle: WalletHandle,
+                                                        cred_def_id: *const c_char,
+                                                        rev_reg_id: *const c_char,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_issuer_confirm_revoc_reg_publication: >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}",
+           wallet_handle, cred_def_id, rev_reg_id);
+
+    check_useful_c_str!(cred_def_id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rev_reg_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_issuer_confirm_revoc_reg_publication: entities >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}",
+           wallet_handle, cred_def_id, rev_reg_id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(
+            AnoncredsCommand::Issuer(
+                IssuerCommand::ConfirmRevocationRegistryPublication(
+                    wallet_handle,
+                    cred_def_id,
+                    rev_reg_id,
+                    Box::new(move |result| {
+                        let err = prepare_result!(result);
+                        trace!("indy_issuer_confirm_revoc_reg_publication:");
+                        cb(command_handle, err)
+                    })
+                ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_issuer_confirm_revoc_reg_publication: <<< res: {:?}", res);
+
+    res
+}
+
 /// Creates a master secret with a given id and stores it in the wallet.
 /// The id must be unique.
 ///
@@ -1361,6 +1609,9 @@ pub  extern fn indy_prover_close_credentials_search(command_handle: CommandHandl
 /// attr_info: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string], // attribute names, (case insensitive and ignore spaces)
+///                 // NOTE: either "name" or "names" must be specified, but not both.
+///                 // Use "names" to request several attributes that have to be revealed from a single credential.
 ///         "restrictions": Optional<filter_json>, // see above
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1713,6 +1964,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 /// attr_info: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string], // attribute names, (case insensitive and ignore spaces)
+///                 // NOTE: either "name" or "names" must be specified, but not both.
+///                 // Use "names" to request several attributes that have to be revealed from a single credential.
 ///         "restrictions": Optional<wql query>,
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1749,6 +2003,9 @@ pub  extern fn indy_prover_close_credentials_search_for_proof_req(command_handle
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {sub_proof_index: number, values: {"attr_name": {raw: string, encoded: string}}},
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1854,6 +2111,9 @@ pub extern fn indy_prover_create_proof(command_handle: CommandHandle,
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {sub_proof_index: number, values: {"attr_name": {raw: string, encoded: string}}},
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1958,6 +2218,110 @@ pub extern fn indy_verifier_verify_proof(command_handle: CommandHandle,
     res
 }
 
+/// Verifies a proof (of multiple credential) the same way as `indy_verifier_verify_proof`,
+/// but returns the outcome of each performed check instead of a bare boolean.
+/// Failed checks don't cause an error: they are reported with a reason code.
+///
+/// #Params
+/// command_handle: command handle to map callback to user context.
+/// proof_request_json: proof request json (see `indy_verifier_verify_proof`)
+/// proof_json: created for request proof json (see `indy_verifier_verify_proof`)
+/// schemas_json: all schema jsons participating in the proof
+/// credential_defs_json: all credential definitions json participating in the proof
+/// rev_reg_defs_json: all revocation registry definitions json participating in the proof
+/// rev_regs_json: all revocation registries json participating in the proof
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// report_json: verification report json
+///     {
+///         "valid": bool, // true if no check failed, the same as `indy_verifier_verify_proof` returns
+///         "requested_proof": <check>, // received referents match requested ones
+///         "proof": <check>, // cryptographic verification of the whole proof
+///         "requested_attributes": {
+///             "<attr_referent>": <referent_report>,
+///             ...,
+///         },
+///         "requested_predicates": {
+///             "<predicate_referent>": <referent_report>,
+///             ...,
+///         },
+///     }
+/// where
+/// referent_report:
+///     {
+///         "valid": bool,
+///         "sub_proof_index": Optional<int>,
+///         "received": <check>, // referent is received in the proper part of requested proof
+///         "primary_proof": <check>,
+///         "predicate": Optional<<check>>, // for predicates only
+///         "restrictions": <check>, // restrictions of proof request are satisfied
+///         "timestamp": <check>, // timestamp is provided if non-revocation interval is requested
+///         "revocation": <check>, // non-revocation proof
+///     }
+/// check:
+///     {
+///         "status": "passed" | "failed" | "skipped",
+///         "reason": Optional<string>, // reason code of failed check, see below
+///         "message": Optional<string>, // human-readable details of failed check
+///     }
+/// Reason codes: REFERENT_NOT_RECEIVED, UNEXPECTED_REFERENT, INVALID_SUB_PROOF_INDEX, ATTRIBUTE_GROUP_MISMATCH,
+/// ATTRIBUTE_VALUE_MISMATCH, SELF_ATTESTED_NOT_ALLOWED, RESTRICTIONS_NOT_SATISFIED, TIMESTAMP_MISSING,
+/// SCHEMA_NOT_FOUND, CREDENTIAL_DEFINITION_NOT_FOUND, REVOCATION_REGISTRY_DEFINITION_NOT_FOUND,
+/// REVOCATION_REGISTRY_NOT_FOUND, PROOF_INVALID.
+/// Primary, predicate and non-revocation proofs are aggregated, so they can be verified only together:
+/// the result of the whole proof verification is reported for each of them.
+///
+/// #Errors
+/// Anoncreds*
+/// Common*
+#[no_mangle]
+pub extern fn indy_verifier_verify_proof_with_report(command_handle: CommandHandle,
+                                                     proof_request_json: *const c_char,
+                                                     proof_json: *const c_char,
+                                                     schemas_json: *const c_char,
+                                                     credential_defs_json: *const c_char,
+                                                     rev_reg_defs_json: *const c_char,
+                                                     rev_regs_json: *const c_char,
+                                                     cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode,
+                                                                          report_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_verifier_verify_proof_with_report: >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    check_useful_json!(proof_request_json, ErrorCode::CommonInvalidParam2, ProofRequest);
+    check_useful_json!(proof_json, ErrorCode::CommonInvalidParam3, Proof);
+    check_useful_json!(schemas_json, ErrorCode::CommonInvalidParam4, HashMap<String, Schema>);
+    check_useful_json!(credential_defs_json, ErrorCode::CommonInvalidParam5, HashMap<String, CredentialDefinition>);
+    check_useful_json!(rev_reg_defs_json, ErrorCode::CommonInvalidParam6, HashMap<String, RevocationRegistryDefinition>);
+    check_useful_json!(rev_regs_json, ErrorCode::CommonInvalidParam7, HashMap<String, HashMap<u64, RevocationRegistry>>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_verifier_verify_proof_with_report: entities >>> proof_request_json: {:?}, proof_json: {:?}, schemas_json: {:?}, credential_defs_json: {:?}, \
+    rev_reg_defs_json: {:?}, rev_regs_json: {:?}", proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Anoncreds(AnoncredsCommand::Verifier(VerifierCommand::VerifyProofWithReport(
+            proof_request_json,
+            proof_json,
+            schemas_json,
+            credential_defs_json,
+            rev_reg_defs_json,
+            rev_regs_json,
+            Box::new(move |result| {
+                let (err, report_json) = prepare_result_1!(result, String::new());
+                trace!("indy_verifier_verify_proof_with_report: report_json: {:?}", report_json);
+                let report_json = ctypes::string_to_cstring(report_json);
+                cb(command_handle, err, report_json.as_ptr())
+            })
+        ))));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verifier_verify_proof_with_report: <<< res: {:?}", res);
+
+    res
+}
+
 /// Create revocation state for a credential in the particular time moment.
 ///
 /// #Params
@@ -220,3 +220,493 @@ pub extern fn indy_purge_schema_cache(command_handle: CommandHandle,
 
     res
 }
+
+/// Gets Rich Schema object json data for specified object id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger by GET_RICH_SCHEMA_OBJECT_BY_ID request and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// submitter_did: DID of the submitter stored in secured Wallet.
+/// id: identifier of Rich Schema object.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Rich Schema object json (look at `indy_parse_get_rich_schema_object_response`).
+#[no_mangle]
+pub extern fn indy_get_rich_schema_object(command_handle: CommandHandle,
+                                          pool_handle: PoolHandle,
+                                          wallet_handle: WalletHandle,
+                                          submitter_did: *const c_char,
+                                          id: *const c_char,
+                                          options_json: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               object_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_rich_schema_object: >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam7);
+
+    trace!("indy_get_rich_schema_object: entities >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, submitter_did, id, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::GetRichSchemaObject(
+            pool_handle,
+            wallet_handle,
+            submitter_did,
+            id,
+            options_json,
+            Box::new(move |result| {
+                let (err, object_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_rich_schema_object: object_json: {:?}", object_json);
+                let object_json = ctypes::string_to_cstring(object_json);
+                cb(command_handle, err, object_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_rich_schema_object: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge Rich Schema objects cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_purge_rich_schema_cache(command_handle: CommandHandle,
+                                           wallet_handle: WalletHandle,
+                                           options_json: *const c_char,
+                                           cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_rich_schema_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_rich_schema_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::PurgeRichSchemaCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_rich_schema_cache:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_rich_schema_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets reply on read request to the ledger (GET_NYM, GET_ATTRIB, GET_REVOC_REG_DEF, GET_REVOC_REG_DELTA, etc.).
+/// If reply is present inside of cache, cached data is returned.
+/// Otherwise request is sent to the ledger and reply confirmed by state proof is stored inside of cache for future use.
+/// Replies which are accepted by consensus of nodes without state proof are never cached.
+///
+/// Cache entries are identified by the request operation,
+/// so requests which differ only in reqId, submitter or signature share the same entry.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// request_json: read request (built by one of indy_build_get_*_request functions).
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Reply of the ledger on request.
+#[no_mangle]
+pub extern fn indy_get_cached_request(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      wallet_handle: WalletHandle,
+                                      request_json: *const c_char,
+                                      options_json: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           response_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_cached_request: >>> pool_handle: {:?}, wallet_handle: {:?}, request_json: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, request_json, options_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_get_cached_request: entities >>> pool_handle: {:?}, wallet_handle: {:?}, request_json: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, request_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::GetRequest(
+            pool_handle,
+            wallet_handle,
+            request_json,
+            options_json,
+            Box::new(move |result| {
+                let (err, response_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_cached_request: response_json: {:?}", response_json);
+                let response_json = ctypes::string_to_cstring(response_json);
+                cb(command_handle, err, response_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_cached_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge cache of ledger replies.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    txnType: (string, optional) Purge only replies on requests of this transaction type (for example "105" for GET_NYM).
+///             All cached replies are purged by default.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_purge_request_cache(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       options_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_request_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_request_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::PurgeRequestCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_request_cache:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_request_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Resolves did:sov DID into W3C DID Core document.
+/// DID Document is assembled from NYM transaction (verification method) and raw `endpoint` attribute (services) of the DID.
+/// If DID Document is present inside of cache, cached document is returned.
+/// Otherwise it is built from ledger replies and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// did: DID to resolve, either unqualified or qualified with `did:sov:` prefix.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// DID resolution result json:
+/// {
+///     didDocument: {
+///         @context: ["https://www.w3.org/ns/did/v1", "https://w3id.org/security/suites/ed25519-2018/v1"],
+///         id: "did:sov:<did>",
+///         verificationMethod: [{id: "did:sov:<did>#key-1", type: "Ed25519VerificationKey2018", controller: "did:sov:<did>", publicKeyBase58: <verkey>}],
+///         authentication: ["did:sov:<did>#key-1"],
+///         assertionMethod: ["did:sov:<did>#key-1"],
+///         service: [{id: "did:sov:<did>#<type>", type: <type>, serviceEndpoint: <endpoint>, recipientKeys: (optional), routingKeys: (optional)}]
+///     },
+///     didResolutionMetadata: {
+///         contentType: "application/did+ld+json",
+///         nym: {seqNo: int, txnTime: int, stateProof: bool}, - metadata of NYM read
+///         endpoint: {seqNo: int, txnTime: int, stateProof: bool} or null - metadata of endpoint attribute read
+///     }
+/// }
+/// stateProof is true if the reply was confirmed by state proof of a single node, false if by consensus of nodes.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_resolve_did(command_handle: CommandHandle,
+                               pool_handle: PoolHandle,
+                               wallet_handle: WalletHandle,
+                               did: *const c_char,
+                               options_json: *const c_char,
+                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                    err: ErrorCode,
+                                                    resolution_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_resolve_did: >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, did, options_json);
+
+    check_useful_c_str!(did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_resolve_did: entities >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, did, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::ResolveDid(
+            pool_handle,
+            wallet_handle,
+            did,
+            options_json,
+            Box::new(move |result| {
+                let (err, resolution_result_json) = prepare_result_1!(result, String::new());
+                trace!("indy_resolve_did: resolution_result_json: {:?}", resolution_result_json);
+                let resolution_result_json = ctypes::string_to_cstring(resolution_result_json);
+                cb(command_handle, err, resolution_result_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_resolve_did: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge DID Document cache.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_purge_did_doc_cache(command_handle: CommandHandle,
+                                       wallet_handle: WalletHandle,
+                                       options_json: *const c_char,
+                                       cb: Option<extern fn(command_handle_: CommandHandle,
+                                                            err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_did_doc_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_did_doc_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::PurgeDidDocCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_did_doc_cache:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_did_doc_cache: <<< res: {:?}", res);
+
+    res
+}
+
+/// Gets history of revocation registry: indices issued and revoked by each REVOC_REG_ENTRY transaction
+/// written in the given interval along with its ledger timestamp and accumulator value.
+/// History is reconstructed from the states of revocation registry read by GET_REVOC_REG_DELTA requests
+/// from the latest one backwards. States are immutable, so they are stored inside of cache
+/// and only the latest state is read from the ledger next time.
+/// Entries written to the ledger within the same second are reported as one entry.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// rev_reg_def_id: ID of the revocation registry definition.
+/// from: Requested time represented as a total number of seconds from Unix Epoch. Pass 0 to get the whole history.
+/// to: Requested time represented as a total number of seconds from Unix Epoch.
+/// options_json:
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noStore: (bool, optional, false by default) Skip storing fresh data,
+///  }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Revocation history json:
+/// {
+///     revRegDefId: string,
+///     entries: [{
+///         timestamp: int - time of the entry on the ledger,
+///         value: {accum: string} - accumulator value after the entry,
+///         issued: [int] - indices issued by the entry,
+///         revoked: [int] - indices revoked by the entry,
+///     }] - in order of timestamps,
+///     delta: <revocation registry delta json> - all entries merged into one delta, null if there are no entries
+/// }
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_revocation_history(command_handle: CommandHandle,
+                                          pool_handle: PoolHandle,
+                                          wallet_handle: WalletHandle,
+                                          rev_reg_def_id: *const c_char,
+                                          from: i64,
+                                          to: i64,
+                                          options_json: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               history_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_revocation_history: >>> pool_handle: {:?}, wallet_handle: {:?}, rev_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json);
+
+    check_useful_c_str!(rev_reg_def_id, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam7);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    if from < 0 {
+        return ErrorCode::CommonInvalidParam5;
+    }
+
+    if to < from {
+        return ErrorCode::CommonInvalidParam6;
+    }
+
+    trace!("indy_get_revocation_history: entities >>> pool_handle: {:?}, wallet_handle: {:?}, rev_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+           pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::GetRevocationHistory(
+            pool_handle,
+            wallet_handle,
+            rev_reg_def_id,
+            from as u64,
+            to as u64,
+            options_json,
+            Box::new(move |result| {
+                let (err, history_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_revocation_history: history_json: {:?}", history_json);
+                let history_json = ctypes::string_to_cstring(history_json);
+                cb(command_handle, err, history_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_revocation_history: <<< res: {:?}", res);
+
+    res
+}
+
+/// Purge revocation registry state cache used by indy_get_revocation_history.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// wallet_handle: wallet handle (created by open_wallet).
+/// options_json:
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+/// cb: Callback that takes command result as parameter.
+#[no_mangle]
+pub extern fn indy_purge_rev_reg_state_cache(command_handle: CommandHandle,
+                                             wallet_handle: WalletHandle,
+                                             options_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_purge_rev_reg_state_cache: >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    check_useful_c_str!(options_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_purge_rev_reg_state_cache: entities >>> wallet_handle: {:?}, options_json: {:?}",
+           wallet_handle, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Cache(CacheCommand::PurgeRevRegStateCache(
+            wallet_handle,
+            options_json,
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_purge_rev_reg_state_cache:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_purge_rev_reg_state_cache: <<< res: {:?}", res);
+
+    res
+}
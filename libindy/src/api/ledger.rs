@@ -1,4 +1,4 @@
-use api::{ErrorCode, CommandHandle, WalletHandle, PoolHandle};
+use api::{ErrorCode, CommandHandle, WalletHandle, PoolHandle, SearchHandle};
 use errors::prelude::*;
 use commands::{Command, CommandExecutor};
 use commands::ledger::LedgerCommand;
@@ -7,8 +7,10 @@ use domain::anoncreds::schema::Schema;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
 use domain::anoncreds::revocation_registry_delta::RevocationRegistryDelta;
 use domain::ledger::author_agreement::{GetTxnAuthorAgreementData, AcceptanceMechanisms};
+use domain::ledger::genesis::GenesisConfig;
 use domain::ledger::node::NodeOperationData;
 use domain::ledger::auth_rule::AuthRules;
+use domain::ledger::confirm::ConfirmOptions;
 use utils::ctypes;
 
 use serde_json;
@@ -20,6 +22,9 @@ use libc::c_char;
 /// sign key (see wallet_sign), and sends signed request message
 /// to validator pool (see write_request).
 ///
+/// If transaction author agreement acceptance is set for the pool (see indy_set_txn_author_agreement_acceptance)
+/// it is appended to write requests of the domain ledger before signing.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// pool_handle: pool handle (created by open_pool_ledger).
@@ -125,6 +130,124 @@ pub extern fn indy_submit_request(command_handle: CommandHandle,
     res
 }
 
+/// Publishes write request to validator pool and waits until written transaction
+/// can be read back with valid state proof.
+///
+/// The write is submitted as is (see submit_request). Then seqNo is taken from the reply
+/// and the corresponding GET request (GET_NYM for NYM, GET_ATTRIB for ATTRIB, etc.) is sent
+/// periodically until its reply has state proof verified against BLS keys of pool nodes
+/// and refers to transaction not older than the written one.
+///
+/// Supported for NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF and REVOC_REG_ENTRY requests.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: Request data json (signed).
+/// options_json: (optional)
+///     {
+///         "timeout": int (optional, 60 by default), deadline of confirmation in seconds counted from the write reply,
+///         "interval": int (optional, 1000 by default), delay between reads in milliseconds
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Write reply as json.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// PoolLedgerTimeout if transaction isn't confirmed before deadline.
+#[no_mangle]
+pub extern fn indy_submit_request_and_confirm(command_handle: CommandHandle,
+                                              pool_handle: PoolHandle,
+                                              request_json: *const c_char,
+                                              options_json: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   request_result_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_request_and_confirm: >>> pool_handle: {:?}, request_json: {:?}, options_json: {:?}", pool_handle, request_json, options_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_json!(options_json, ErrorCode::CommonInvalidParam4, ConfirmOptions);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_submit_request_and_confirm: entities >>> pool_handle: {:?}, request_json: {:?}, options_json: {:?}", pool_handle, request_json, options_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitRequestAndConfirm(
+            pool_handle,
+            request_json,
+            options_json,
+            Box::new(move |result| {
+                let (err, request_result_json) = prepare_result_1!(result, String::new());
+                trace!("indy_submit_request_and_confirm: request_result_json: {:?}", request_result_json);
+                let request_result_json = ctypes::string_to_cstring(request_result_json);
+                cb(command_handle, err, request_result_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_request_and_confirm: <<< res: {:?}", res);
+
+    res
+}
+
+/// Sends several requests to validator pool concurrently and returns all replies at once.
+///
+/// Requests are sent as is (see submit_request), so it's intended mainly for read requests
+/// like GET_SCHEMA, GET_CRED_DEF or GET_REVOC_REG_DELTA needed to verify a proof.
+/// No more requests than `batch_limit` of the pool config (`conn_limit` by default) are in flight at the same time,
+/// the next one is sent as soon as a reply to a previous one is received.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// requests_json: array of requests. Every request is either json object or string with json.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Array of results in the same order as requests. Every result is either
+///     {"response": string} - reply of the pool
+/// or
+///     {"error": {"code": int, "message": string}} - error code and message the request failed with.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_submit_requests_batch(command_handle: CommandHandle,
+                                         pool_handle: PoolHandle,
+                                         requests_json: *const c_char,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode,
+                                                              results_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_submit_requests_batch: >>> pool_handle: {:?}, requests_json: {:?}", pool_handle, requests_json);
+
+    check_useful_json!(requests_json, ErrorCode::CommonInvalidParam3, Vec<serde_json::Value>);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_submit_requests_batch: entities >>> pool_handle: {:?}, requests_json: {:?}", pool_handle, requests_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::SubmitRequestsBatch(
+            pool_handle,
+            requests_json,
+            Box::new(move |result| {
+                let (err, results_json) = prepare_result_1!(result, String::new());
+                trace!("indy_submit_requests_batch: results_json: {:?}", results_json);
+                let results_json = ctypes::string_to_cstring(results_json);
+                cb(command_handle, err, results_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_submit_requests_batch: <<< res: {:?}", res);
+
+    res
+}
+
 /// Send action to particular nodes of validator pool.
 ///
 /// The list of requests can be send:
@@ -304,6 +427,9 @@ pub extern fn indy_multi_sign_request(command_handle: CommandHandle,
 
 /// Builds a request to get a DDO.
 ///
+/// Deprecated: GET_DDO transaction isn't supported by the ledger, GET_NYM request is built for compatibility.
+/// Use indy_resolve_did to get DID Document of the DID.
+///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
@@ -466,6 +592,58 @@ pub extern fn indy_build_get_nym_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_NYM response to get NYM data in the normalized form
+/// independent of reply and transaction format versions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_nym_response: response of GET_NYM request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// NYM json.
+/// {
+///     did: DID as base58-encoded string,
+///     verkey: (optional) full verkey, abbreviated one is expanded,
+///     role: (optional) role name: TRUSTEE, STEWARD, ENDORSER or NETWORK_MONITOR,
+///     seqNo: (optional) sequence number of NYM transaction in the ledger,
+///     txnTime: (optional) time of NYM transaction in the ledger
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_nym_response(command_handle: CommandHandle,
+                                          get_nym_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               nym_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_nym_response: >>> get_nym_response: {:?}", get_nym_response);
+
+    check_useful_c_str!(get_nym_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_nym_response: entities >>> get_nym_response: {:?}", get_nym_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetNymResponse(
+            get_nym_response,
+            Box::new(move |result| {
+                let (err, nym_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_nym_response: nym_json: {:?}", nym_json);
+                let nym_json = ctypes::string_to_cstring(nym_json);
+                cb(command_handle, err, nym_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_nym_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds an ATTRIB request. Request to add attribute to a NYM record.
 ///
 /// #Params
@@ -589,6 +767,59 @@ pub extern fn indy_build_get_attrib_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_ATTRIB response to get attribute in the normalized form
+/// independent of reply and transaction format versions.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_attrib_response: response of GET_ATTRIB request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Attribute json. Only one of raw, hash and enc is set.
+/// {
+///     did: DID the attribute belongs to,
+///     raw: (optional) raw attribute decoded from json string, e.g. {"endpoint":{"ha":"127.0.0.1:5555"}},
+///     hash: (optional) sha256 hash of attribute data,
+///     enc: (optional) encrypted attribute data,
+///     seqNo: (optional) sequence number of ATTRIB transaction in the ledger,
+///     txnTime: (optional) time of ATTRIB transaction in the ledger
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_attrib_response(command_handle: CommandHandle,
+                                             get_attrib_response: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  attrib_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_attrib_response: >>> get_attrib_response: {:?}", get_attrib_response);
+
+    check_useful_c_str!(get_attrib_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_attrib_response: entities >>> get_attrib_response: {:?}", get_attrib_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetAttribResponse(
+            get_attrib_response,
+            Box::new(move |result| {
+                let (err, attrib_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_attrib_response: attrib_json: {:?}", attrib_json);
+                let attrib_json = ctypes::string_to_cstring(attrib_json);
+                cb(command_handle, err, attrib_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_attrib_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a SCHEMA request. Request to add Credential's schema.
 ///
 /// #Params
@@ -918,6 +1149,8 @@ pub extern fn indy_parse_get_cred_def_response(command_handle: CommandHandle,
 ///     alias: string - Node's alias
 ///     blskey: string - (Optional) BLS multi-signature key as base58-encoded string.
 ///     blskey_pop: string - (Optional) BLS key proof of possession as base58-encoded string.
+///                 Must be specified together with blskey. It is verified against blskey before the request is built
+///                 (look at `indy_generate_bls_keys` to get the keys).
 ///     client_ip: string - (Optional) Node's client listener IP address.
 ///     client_port: string - (Optional) Node's client listener port.
 ///     node_ip: string - (Optional) The IP address other Nodes use to communicate with this Node.
@@ -968,6 +1201,119 @@ pub extern fn indy_build_node_request(command_handle: CommandHandle,
     res
 }
 
+/// Generates BLS keys of a validator node to be used in NODE transaction.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// seed: (optional) seed of the node. Keys are derived from the seed the same way as validator node does it,
+///       so the node must be initialized with the same seed. Random keys are generated if seed is not specified.
+///       It can be either 32 bytes string or base64 string or 64 bytes HEX string.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// blskey: BLS key as base58-encoded string.
+/// blskey_pop: BLS key proof of possession as base58-encoded string.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_generate_bls_keys(command_handle: CommandHandle,
+                                     seed: *const c_char,
+                                     cb: Option<extern fn(command_handle_: CommandHandle,
+                                                          err: ErrorCode,
+                                                          blskey: *const c_char,
+                                                          blskey_pop: *const c_char)>) -> ErrorCode {
+    trace!("indy_generate_bls_keys: >>> seed: {:?}", seed);
+
+    check_useful_opt_c_str!(seed, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_generate_bls_keys: entities >>> seed: {:?}", secret!(&seed));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::GenerateBlsKeys(
+            seed,
+            Box::new(move |result| {
+                let (err, blskey, blskey_pop) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_generate_bls_keys: blskey: {:?}, blskey_pop: {:?}", blskey, blskey_pop);
+                let blskey = ctypes::string_to_cstring(blskey);
+                let blskey_pop = ctypes::string_to_cstring(blskey_pop);
+                cb(command_handle, err, blskey.as_ptr(), blskey_pop.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_generate_bls_keys: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds genesis transactions of a new network: NODE transactions of the pool ledger
+/// and NYM transactions of trustees and node stewards of the domain ledger.
+///
+/// Node verkeys (dest), BLS keys and their proofs of possession are derived from node seeds
+/// the same way as validator nodes do it, so the nodes must be initialized with the same seeds.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// nodes_config: description of the network
+/// {
+///     "trustee_seeds": array<string> - seeds of trustee DIDs. At least one is required.
+///                                      Stewards are written to the domain ledger by the first trustee.
+///     "nodes": [{
+///         "alias": string - unique node name,
+///         "seed": string - seed of node keys,
+///         "steward_seed": string - seed of node steward DID,
+///         "node_ip": string,
+///         "node_port": int,
+///         "client_ip": string,
+///         "client_port": int,
+///         "services": (optional) array<string> - the service of the node.
+///                     VALIDATOR is the only supported one (default).
+///     }]
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// pool_genesis_txns: pool ledger genesis transactions, one json per line.
+/// domain_genesis_txns: domain ledger genesis transactions, one json per line.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_genesis_transactions(command_handle: CommandHandle,
+                                              nodes_config: *const c_char,
+                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                   err: ErrorCode,
+                                                                   pool_genesis_txns: *const c_char,
+                                                                   domain_genesis_txns: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_genesis_transactions: >>> nodes_config: {:?}", nodes_config);
+
+    check_useful_json!(nodes_config, ErrorCode::CommonInvalidParam2, GenesisConfig);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_build_genesis_transactions: entities >>> nodes_config: {:?}", secret!(&nodes_config));
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGenesisTransactions(
+            nodes_config,
+            Box::new(move |result| {
+                let (err, pool_genesis_txns, domain_genesis_txns) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_build_genesis_transactions: pool_genesis_txns: {:?}, domain_genesis_txns: {:?}", pool_genesis_txns, domain_genesis_txns);
+                let pool_genesis_txns = ctypes::string_to_cstring(pool_genesis_txns);
+                let domain_genesis_txns = ctypes::string_to_cstring(domain_genesis_txns);
+                cb(command_handle, err, pool_genesis_txns.as_ptr(), domain_genesis_txns.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_genesis_transactions: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a GET_VALIDATOR_INFO request.
 ///
 /// #Params
@@ -1055,6 +1401,60 @@ pub extern fn indy_build_get_txn_request(command_handle: CommandHandle,
     res
 }
 
+/// Parse a GET_TXN response to get transaction in the normalized form
+/// independent of transaction format version.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_txn_response: response of GET_TXN request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Transaction json.
+/// {
+///     type: transaction type code, e.g. "1" for NYM,
+///     seqNo: sequence number of transaction in the ledger,
+///     txnTime: (optional) time of transaction in the ledger,
+///     txnId: (optional) transaction id,
+///     from: (optional) DID of transaction author,
+///     reqId: (optional) id of request the transaction was written by,
+///     data: transaction specific data, e.g. {"dest": ..., "verkey": ...} for NYM
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                          get_txn_response: *const c_char,
+                                          cb: Option<extern fn(command_handle_: CommandHandle,
+                                                               err: ErrorCode,
+                                                               txn_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_txn_response: >>> get_txn_response: {:?}", get_txn_response);
+
+    check_useful_c_str!(get_txn_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_txn_response: entities >>> get_txn_response: {:?}", get_txn_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetTxnResponse(
+            get_txn_response,
+            Box::new(move |result| {
+                let (err, txn_json) = prepare_result_1!(result, String::new());
+                trace!("indy_parse_get_txn_response: txn_json: {:?}", txn_json);
+                let txn_json = ctypes::string_to_cstring(txn_json);
+                cb(command_handle, err, txn_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_txn_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Builds a POOL_CONFIG request. Request to change Pool's configuration.
 ///
 /// #Params
@@ -1250,28 +1650,15 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
     res
 }
 
-/// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
-/// to an exists credential definition.
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers (sent by Trustee).
+/// Frozen ledgers can't be written anymore, but their transactions are still available for reading.
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
 /// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
 ///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
-/// data: Revocation Registry data:
-///     {
-///         "id": string - ID of the Revocation Registry,
-///         "revocDefType": string - Revocation Registry type (only CL_ACCUM is supported for now),
-///         "tag": string - Unique descriptive ID of the Registry,
-///         "credDefId": string - ID of the corresponding CredentialDefinition,
-///         "value": Registry-specific data {
-///             "issuanceType": string - Type of Issuance(ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND),
-///             "maxCredNum": number - Maximum number of credentials the Registry can serve.
-///             "tailsHash": string - Hash of tails.
-///             "tailsLocation": string - Location of tails file.
-///             "publicKeys": <public_keys> - Registry's public key.
-///         },
-///         "ver": string - version of revocation registry definition json.
-///     }
+/// ledgers_ids: list of ids of ledgers to freeze as json array, for example: [10, 11].
+///              Base ledgers (0 - pool, 1 - domain, 2 - config, 3 - audit) can't be frozen.
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
@@ -1280,31 +1667,149 @@ pub extern fn indy_build_pool_upgrade_request(command_handle: CommandHandle,
 /// #Errors
 /// Common*
 #[no_mangle]
-pub extern fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
-                                               submitter_did: *const c_char,
-                                               data: *const c_char,
-                                               cb: Option<extern fn(command_handle_: CommandHandle,
-                                                                    err: ErrorCode,
-                                                                    rev_reg_def_req: *const c_char)>) -> ErrorCode {
-    trace!("indy_build_revoc_reg_def_request: >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
+pub extern fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
+                                                submitter_did: *const c_char,
+                                                ledgers_ids: *const c_char,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode,
+                                                                     request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_ledgers_freeze_request: >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
     check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
-    check_useful_json!(data, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
+    check_useful_json!(ledgers_ids, ErrorCode::CommonInvalidParam3, Vec<u64>);
     check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
 
-    trace!("indy_build_revoc_reg_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
+    trace!("indy_build_ledgers_freeze_request: entities >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
 
     let result = CommandExecutor::instance()
-        .send(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
-            submitter_did,
-            data,
-            Box::new(move |result| {
-                let (err, rev_reg_def_req) = prepare_result_1!(result, String::new());
-                trace!("indy_build_revoc_reg_def_request: rev_reg_def_req: {:?}", rev_reg_def_req);
-                let rev_reg_def_req = ctypes::string_to_cstring(rev_reg_def_req);
-                cb(command_handle, err, rev_reg_def_req.as_ptr())
-            })
-        )));
+        .send(Command::Ledger(
+            LedgerCommand::BuildLedgersFreezeRequest(
+                submitter_did,
+                ledgers_ids,
+                Box::new(move |result| {
+                    let (err, request_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_build_ledgers_freeze_request: request_json: {:?}", request_json);
+                    let request_json = ctypes::string_to_cstring(request_json);
+                    cb(command_handle, err, request_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_ledgers_freeze_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+/// Frozen ledgers are defined by LEDGERS_FREEZE request.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+/// Reply of the request contains map of frozen ledgers:
+/// {
+///     <ledger_id>: {
+///         "ledger": String - Ledger root hash,
+///         "state": String - State root hash,
+///         "seq_no": u64 - the latest transaction seqNo for particular Node,
+///     },
+///     ...
+/// }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandle,
+                                                    submitter_did: *const c_char,
+                                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                         err: ErrorCode,
+                                                                         request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_frozen_ledgers_request: >>> submitter_did: {:?}", submitter_did);
+
+    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_build_get_frozen_ledgers_request: entities >>> submitter_did: {:?}", submitter_did);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::BuildGetFrozenLedgersRequest(
+                submitter_did,
+                Box::new(move |result| {
+                    let (err, request_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_build_get_frozen_ledgers_request: request_json: {:?}", request_json);
+                    let request_json = ctypes::string_to_cstring(request_json);
+                    cb(command_handle, err, request_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_frozen_ledgers_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
+/// to an exists credential definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// data: Revocation Registry data:
+///     {
+///         "id": string - ID of the Revocation Registry,
+///         "revocDefType": string - Revocation Registry type (only CL_ACCUM is supported for now),
+///         "tag": string - Unique descriptive ID of the Registry,
+///         "credDefId": string - ID of the corresponding CredentialDefinition,
+///         "value": Registry-specific data {
+///             "issuanceType": string - Type of Issuance(ISSUANCE_BY_DEFAULT or ISSUANCE_ON_DEMAND),
+///             "maxCredNum": number - Maximum number of credentials the Registry can serve.
+///             "tailsHash": string - Hash of tails.
+///             "tailsLocation": string - Location of tails file.
+///             "publicKeys": <public_keys> - Registry's public key.
+///         },
+///         "ver": string - version of revocation registry definition json.
+///     }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
+                                               submitter_did: *const c_char,
+                                               data: *const c_char,
+                                               cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                    err: ErrorCode,
+                                                                    rev_reg_def_req: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_revoc_reg_def_request: >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_json!(data, ErrorCode::CommonInvalidParam3, RevocationRegistryDefinition);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_revoc_reg_def_request: entities >>> submitter_did: {:?}, data: {:?}", submitter_did, data);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildRevocRegDefRequest(
+            submitter_did,
+            data,
+            Box::new(move |result| {
+                let (err, rev_reg_def_req) = prepare_result_1!(result, String::new());
+                trace!("indy_build_revoc_reg_def_request: rev_reg_def_req: {:?}", rev_reg_def_req);
+                let rev_reg_def_req = ctypes::string_to_cstring(rev_reg_def_req);
+                cb(command_handle, err, rev_reg_def_req.as_ptr())
+            })
+        )));
 
     let res = prepare_result!(result);
 
@@ -1713,6 +2218,243 @@ pub extern fn indy_parse_get_revoc_reg_delta_response(command_handle: CommandHan
     res
 }
 
+/// Builds a request to add a Rich Schema object: JSON-LD context, rich schema, encoding, mapping,
+/// credential definition or presentation definition.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// id: identifier of the object. Must match `@id` field of content if it is present.
+/// content: JSON-LD content of the object. Content of JSON-LD context must contain `@context` field.
+/// rs_name: name of the object.
+/// rs_version: version of the object.
+/// rs_type: type of the object. One of:
+///     "ctx" - JSON-LD context,
+///     "sch" - rich schema,
+///     "enc" - encoding,
+///     "map" - mapping,
+///     "cdf" - credential definition,
+///     "pdf" - presentation definition
+/// ver: (Optional) version of the object format. "1" is used by default.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_rich_schema_request(command_handle: CommandHandle,
+                                             submitter_did: *const c_char,
+                                             id: *const c_char,
+                                             content: *const c_char,
+                                             rs_name: *const c_char,
+                                             rs_version: *const c_char,
+                                             rs_type: *const c_char,
+                                             ver: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_rich_schema_request: >>> submitter_did: {:?}, id: {:?}, content: {:?}, rs_name: {:?}, rs_version: {:?}, rs_type: {:?}, ver: {:?}",
+           submitter_did, id, content, rs_name, rs_version, rs_type, ver);
+
+    check_useful_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(content, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam6);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam7);
+    check_useful_opt_c_str!(ver, ErrorCode::CommonInvalidParam8);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam9);
+
+    trace!("indy_build_rich_schema_request: entities >>> submitter_did: {:?}, id: {:?}, content: {:?}, rs_name: {:?}, rs_version: {:?}, rs_type: {:?}, ver: {:?}",
+           submitter_did, id, content, rs_name, rs_version, rs_type, ver);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildRichSchemaRequest(
+            submitter_did,
+            id,
+            content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_rich_schema_request: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_rich_schema_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get Rich Schema object by its identifier.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// id: identifier of the object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                                              submitter_did: *const c_char,
+                                                              id: *const c_char,
+                                                              cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                   err: ErrorCode,
+                                                                                   request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_rich_schema_object_by_id_request: >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(id, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_get_rich_schema_object_by_id_request: entities >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRichSchemaObjectByIdRequest(
+            submitter_did,
+            id,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_get_rich_schema_object_by_id_request: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_rich_schema_object_by_id_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request. Request to get Rich Schema object by its type, name and version.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// rs_type: type of the object (look at `indy_build_rich_schema_request`).
+/// rs_name: name of the object.
+/// rs_version: version of the object.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                                    submitter_did: *const c_char,
+                                                                    rs_type: *const c_char,
+                                                                    rs_name: *const c_char,
+                                                                    rs_version: *const c_char,
+                                                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                                         err: ErrorCode,
+                                                                                         request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+           submitter_did, rs_type, rs_name, rs_version);
+
+    check_useful_opt_c_str!(submitter_did, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(rs_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(rs_name, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(rs_version, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: entities >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+           submitter_did, rs_type, rs_name, rs_version);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(
+            submitter_did,
+            rs_type,
+            rs_name,
+            rs_version,
+            Box::new(move |result| {
+                let (err, request_json) = prepare_result_1!(result, String::new());
+                trace!("indy_build_get_rich_schema_object_by_metadata_request: request_json: {:?}", request_json);
+                let request_json = ctypes::string_to_cstring(request_json);
+                cb(command_handle, err, request_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_get_rich_schema_object_by_metadata_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// get_rich_schema_object_response: response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Rich Schema object Id and Rich Schema object json.
+/// {
+///     id: identifier of the object
+///     content: JSON-LD content of the object as string
+///     rsName: name of the object
+///     rsVersion: version of the object
+///     rsType: type of the object
+///     ver: version of the object format
+///     seqNo: (Optional) sequence number of the object transaction on the ledger
+///     txnTime: (Optional) time of the object transaction on the ledger
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                                         get_rich_schema_object_response: *const c_char,
+                                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                              err: ErrorCode,
+                                                                              id: *const c_char,
+                                                                              object_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_parse_get_rich_schema_object_response: >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+    check_useful_c_str!(get_rich_schema_object_response, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_parse_get_rich_schema_object_response: entities >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::ParseGetRichSchemaObjectResponse(
+            get_rich_schema_object_response,
+            Box::new(move |result| {
+                let (err, id, object_json) = prepare_result_2!(result, String::new(), String::new());
+                trace!("indy_parse_get_rich_schema_object_response: id: {:?}, object_json: {:?}", id, object_json);
+                let id = ctypes::string_to_cstring(id);
+                let object_json = ctypes::string_to_cstring(object_json);
+                cb(command_handle, err, id.as_ptr(), object_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_parse_get_rich_schema_object_response: <<< res: {:?}", res);
+
+    res
+}
+
 /// Callback type for parsing Reply from Node to specific StateProof format
 ///
 /// # params
@@ -2076,27 +2818,108 @@ pub extern fn indy_build_get_auth_rule_request(command_handle: CommandHandle,
     res
 }
 
-/// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
+/// Predicts whether the ledger accepts write request from the submitter without sending it.
+///
+/// Auth rules of the pool are read with GET_AUTH_RULE request and cached for the pool handle.
+/// Constraints of the rules applying to the request are evaluated against role info of request signers.
+///
+/// Actions performed by the request are determined in the following way:
+///     NYM - adding of new DID (`role` field) or editing of `role` and `verkey` fields of existing one (current NYM is read from the ledger)
+///     NODE - adding of new node if node and client addresses, blskey and services are specified,
+///            otherwise editing of specified fields (promotion or demotion for `services`)
+///     ATTRIB, SCHEMA, CRED_DEF - adding of new entry
 ///
 /// EXPERIMENTAL
 ///
 /// #Params
 /// command_handle: command handle to map callback to caller context.
-/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
-///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
-/// text: a content of the TTA.
-/// version: a version of the TTA (unique UTF-8 string).
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// request_json: write request.
+/// submitter_role_info: role info of request signers
+///  {
+///     role: (string, optional) role of the submitter (TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR or role code), no role by default,
+///     isOwner: (bool, optional, false by default) the submitter is an owner of changed ledger entry,
+///     offLedger: (bool, optional, false by default) the submitter DID isn't written on the ledger,
+///     additionalSigners: (optional) [{role, isOwner, offLedger}] - other signers of the request (Endorser, multi-signature)
+///  }
 /// cb: Callback that takes command result as parameter.
 ///
 /// #Returns
-/// Request result as json.
+/// Auth check result json
+///  {
+///     authorized: bool - whether all actions of the request are authorized,
+///     actions: [{
+///         auth_type, auth_action, field, old_value, new_value - key of the action,
+///         authorized: bool,
+///         constraint: <constraint> - constraint of the rule (null if the ledger has no rule for the action),
+///         satisfied: <constraint> - part of the constraint satisfied by signers (its metadata contains fees),
+///         missing: <constraint> - part of the constraint which must be satisfied additionally,
+///                                 `sig_count` of ROLE constraints is the number of missing signatures
+///     }]
+///  }
 ///
 /// #Errors
 /// Common*
+/// Ledger*
 #[no_mangle]
-pub extern fn indy_build_txn_author_agreement_request(command_handle: CommandHandle,
-                                                      submitter_did: *const c_char,
-                                                      text: *const c_char,
+pub extern fn indy_check_request_auth(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      request_json: *const c_char,
+                                      submitter_role_info: *const c_char,
+                                      cb: Option<extern fn(command_handle_: CommandHandle,
+                                                           err: ErrorCode,
+                                                           auth_check_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_check_request_auth: >>> pool_handle: {:?}, request_json: {:?}, submitter_role_info: {:?}",
+           pool_handle, request_json, submitter_role_info);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_str!(submitter_role_info, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam5);
+
+    trace!("indy_check_request_auth: entities >>> pool_handle: {:?}, request_json: {:?}, submitter_role_info: {:?}",
+           pool_handle, request_json, submitter_role_info);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(LedgerCommand::CheckRequestAuth(
+            pool_handle,
+            request_json,
+            submitter_role_info,
+            Box::new(move |result| {
+                let (err, auth_check_json) = prepare_result_1!(result, String::new());
+                trace!("indy_check_request_auth: auth_check_json: {:?}", auth_check_json);
+                let auth_check_json = ctypes::string_to_cstring(auth_check_json);
+                cb(command_handle, err, auth_check_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_check_request_auth: <<< res: {:?}", res);
+
+    res
+}
+
+/// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
+///
+/// EXPERIMENTAL
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// submitter_did: Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `indy_append_request_endorser`)
+/// text: a content of the TTA.
+/// version: a version of the TTA (unique UTF-8 string).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Request result as json.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_txn_author_agreement_request(command_handle: CommandHandle,
+                                                      submitter_did: *const c_char,
+                                                      text: *const c_char,
                                                       version: *const c_char,
                                                       cb: Option<extern fn(command_handle_: CommandHandle,
                                                                            err: ErrorCode,
@@ -2394,6 +3217,82 @@ pub extern fn indy_append_txn_author_agreement_acceptance_to_request(command_han
     res
 }
 
+/// Set transaction author agreement acceptance for the pool session.
+/// Once set, acceptance is appended to every write request of the domain ledger
+/// sent with `indy_sign_and_submit_request` through this pool handle
+/// if the request doesn't contain its own acceptance yet.
+///
+/// EXPERIMENTAL
+///
+/// Acceptance is checked against the latest transaction author agreement and
+/// acceptance mechanisms list read from the ledger (GET_TXN_AUTHR_AGRMT and GET_TXN_AUTHR_AGRMT_AML):
+/// digest must match the agreement, mechanism must be in the list and time must be
+/// between ratification of the agreement and current time.
+/// Setting new acceptance replaces the previous one of the pool session.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// text and version - (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// taa_digest - (optional) digest on text and version. This parameter is required if text and version parameters are omitted.
+/// mechanism - mechanism how user has accepted the TAA
+/// time - UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// Error code
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_set_txn_author_agreement_acceptance(command_handle: CommandHandle,
+                                                       pool_handle: PoolHandle,
+                                                       text: *const c_char,
+                                                       version: *const c_char,
+                                                       taa_digest: *const c_char,
+                                                       mechanism: *const c_char,
+                                                       time: u64,
+                                                       cb: Option<extern fn(command_handle_: CommandHandle, err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_set_txn_author_agreement_acceptance: >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, \
+        mechanism: {:?}, time: {:?}",
+           pool_handle, text, version, taa_digest, mechanism, time);
+
+    check_useful_opt_c_str!(text, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(version, ErrorCode::CommonInvalidParam4);
+    check_useful_opt_c_str!(taa_digest, ErrorCode::CommonInvalidParam5);
+    check_useful_c_str!(mechanism, ErrorCode::CommonInvalidParam6);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam8);
+
+    trace!("indy_set_txn_author_agreement_acceptance: entities >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, \
+        mechanism: {:?}, time: {:?}",
+           pool_handle, text, version, taa_digest, mechanism, time);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::SetTxnAuthorAgreementAcceptance(
+                pool_handle,
+                text,
+                version,
+                taa_digest,
+                mechanism,
+                time,
+                Box::new(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_set_txn_author_agreement_acceptance:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_set_txn_author_agreement_acceptance: <<< res: {:?}", res);
+
+    res
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -2447,4 +3346,428 @@ pub extern fn indy_append_request_endorser(command_handle: CommandHandle,
     trace!("indy_append_request_endorser: <<< res: {:?}", res);
 
     res
-}
\ No newline at end of file
+}
+
+/// Wraps request into bundle which Transaction Author hands over to Endorser.
+///
+/// Bundle contains the request, its author and endorser, type of transaction,
+/// human-readable summary of the request operation and optional metadata passed by the author.
+///
+/// Note: Endorser must be appended to the request (see `indy_append_request_endorser`)
+/// and the request must be signed by its author before building of the bundle.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// request_json: request with Endorser appended signed by its author.
+/// metadata_json: (Optional) arbitrary json passed to the Endorser along with the request.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a bundle json:
+///     {
+///         "request": <request json>,
+///         "author": string - DID of transaction author,
+///         "endorser": string - DID of endorser,
+///         "txnType": string - code of transaction type,
+///         "summary": string - human-readable summary of the request operation,
+///         "created": int - time of bundle creation as timestamp,
+///         "metadata": <metadata json> (if set)
+///     }
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_build_endorsement_bundle(command_handle: CommandHandle,
+                                            request_json: *const c_char,
+                                            metadata_json: *const c_char,
+                                            cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                 err: ErrorCode,
+                                                                 bundle_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_build_endorsement_bundle: >>> request_json: {:?}, metadata_json: {:?}", request_json, metadata_json);
+
+    check_useful_c_str!(request_json, ErrorCode::CommonInvalidParam2);
+    check_useful_opt_json!(metadata_json, ErrorCode::CommonInvalidParam3, serde_json::Value);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_build_endorsement_bundle: entities >>> request_json: {:?}, metadata_json: {:?}", request_json, metadata_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::BuildEndorsementBundle(
+                request_json,
+                metadata_json,
+                Box::new(move |result| {
+                    let (err, bundle_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_build_endorsement_bundle: bundle_json: {:?}", bundle_json);
+                    let bundle_json = ctypes::string_to_cstring(bundle_json);
+                    cb(command_handle, err, bundle_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_build_endorsement_bundle: <<< res: {:?}", res);
+
+    res
+}
+
+/// Reviews endorsement bundle built by Transaction Author.
+///
+/// Checks that author, endorser, transaction type and summary of the bundle match the request and verifies
+/// all signatures of the request against verkeys of signers written on the ledger.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// bundle_json: endorsement bundle built by `indy_build_endorsement_bundle`.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a review json:
+///     {
+///         "author": string - DID of transaction author,
+///         "endorser": string - DID of endorser,
+///         "txnType": string - code of transaction type,
+///         "summary": string - human-readable summary of the request operation,
+///         "created": int - time of bundle creation as timestamp,
+///         "metadata": <metadata json> (if set),
+///         "signatures": [{
+///             "did": string - DID of signer,
+///             "verkey": string - verkey of signer on the ledger (null if DID isn't written),
+///             "valid": bool - whether signature is valid
+///         }],
+///         "valid": bool - whether request is signed by its author and all signatures are valid
+///     }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_review_endorsement_bundle(command_handle: CommandHandle,
+                                             pool_handle: PoolHandle,
+                                             bundle_json: *const c_char,
+                                             cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                  err: ErrorCode,
+                                                                  review_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_review_endorsement_bundle: >>> pool_handle: {:?}, bundle_json: {:?}", pool_handle, bundle_json);
+
+    check_useful_c_str!(bundle_json, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_review_endorsement_bundle: entities >>> pool_handle: {:?}, bundle_json: {:?}", pool_handle, bundle_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::ReviewEndorsementBundle(
+                pool_handle,
+                bundle_json,
+                Box::new(move |result| {
+                    let (err, review_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_review_endorsement_bundle: review_json: {:?}", review_json);
+                    let review_json = ctypes::string_to_cstring(review_json);
+                    cb(command_handle, err, review_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_review_endorsement_bundle: <<< res: {:?}", res);
+
+    res
+}
+
+/// Endorses request of endorsement bundle built by Transaction Author.
+///
+/// Checks that Endorser of the request is `endorser_did`, verifies signatures of the request
+/// against verkeys written on the ledger (see `indy_review_endorsement_bundle`)
+/// and adds multi signature of the Endorser to the request.
+/// Result request can be sent to the ledger with `indy_submit_request`.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// wallet_handle: wallet handle (created by open_wallet).
+/// endorser_did: DID of the Endorser stored in the wallet.
+/// bundle_json: endorsement bundle built by `indy_build_endorsement_bundle`.
+/// cb: Callback that takes command result as parameter.
+///     The command result is a request json signed by the Endorser.
+///
+/// #Errors
+/// Common*
+/// Wallet*
+/// Ledger*
+/// Crypto*
+#[no_mangle]
+pub extern fn indy_endorse_request(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   endorser_did: *const c_char,
+                                   bundle_json: *const c_char,
+                                   cb: Option<extern fn(command_handle_: CommandHandle,
+                                                        err: ErrorCode,
+                                                        signed_request_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_endorse_request: >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, bundle_json: {:?}",
+           pool_handle, wallet_handle, endorser_did, bundle_json);
+
+    check_useful_c_str!(endorser_did, ErrorCode::CommonInvalidParam4);
+    check_useful_c_str!(bundle_json, ErrorCode::CommonInvalidParam5);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    trace!("indy_endorse_request: entities >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, bundle_json: {:?}",
+           pool_handle, wallet_handle, endorser_did, bundle_json);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::EndorseRequest(
+                pool_handle,
+                wallet_handle,
+                endorser_did,
+                bundle_json,
+                Box::new(move |result| {
+                    let (err, signed_request_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_endorse_request: signed_request_json: {:?}", signed_request_json);
+                    let signed_request_json = ctypes::string_to_cstring(signed_request_json);
+                    cb(command_handle, err, signed_request_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_endorse_request: <<< res: {:?}", res);
+
+    res
+}
+
+/// Verifies that a transaction is included into the ledger by checking its Merkle audit path
+/// against the ledger root hash (RFC 6962 inclusion proof).
+///
+/// The transaction is identified by `txnMetadata.seqNo` field.
+/// `result` field of a write reply or `data` field of GET_TXN reply can be passed as is:
+/// if `audit_path_json` is omitted `auditPath` field of the transaction is used.
+/// `rootHash` field of the transaction is never used, as it comes with the data being verified.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// root_hash: base58 encoded root hash of the ledger of `ledger_size` size the caller trusts
+///            (e.g. verified by BLS multi-signature of nodes).
+/// txn_json: ledger transaction json.
+/// audit_path_json: (Optional) json array of base58 encoded hashes of the audit path.
+/// ledger_size: size of the ledger the root hash corresponds to.
+///              Pass 0 or negative value to use the sequence number of the transaction.
+/// cb: Callback that takes command result as parameter.
+///     The command result is true if the transaction is included into the ledger.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_verify_txn_inclusion(command_handle: CommandHandle,
+                                        root_hash: *const c_char,
+                                        txn_json: *const c_char,
+                                        audit_path_json: *const c_char,
+                                        ledger_size: i32,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             valid: bool)>) -> ErrorCode {
+    trace!("indy_verify_txn_inclusion: >>> root_hash: {:?}, txn_json: {:?}, audit_path_json: {:?}, ledger_size: {:?}",
+           root_hash, txn_json, audit_path_json, ledger_size);
+
+    check_useful_c_str!(root_hash, ErrorCode::CommonInvalidParam2);
+    check_useful_c_str!(txn_json, ErrorCode::CommonInvalidParam3);
+    check_useful_opt_c_str!(audit_path_json, ErrorCode::CommonInvalidParam4);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    let ledger_size = if ledger_size > 0 { Some(ledger_size as usize) } else { None };
+
+    trace!("indy_verify_txn_inclusion: entities >>> root_hash: {:?}, txn_json: {:?}, audit_path_json: {:?}, ledger_size: {:?}",
+           root_hash, txn_json, audit_path_json, ledger_size);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::VerifyTxnInclusion(
+                root_hash,
+                txn_json,
+                audit_path_json,
+                ledger_size,
+                Box::new(move |result| {
+                    let (err, valid) = prepare_result_1!(result, false);
+                    trace!("indy_verify_txn_inclusion: valid: {:?}", valid);
+                    cb(command_handle, err, valid)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_verify_txn_inclusion: <<< res: {:?}", res);
+
+    res
+}
+
+/// Opens iterator over transactions of the ledger.
+///
+/// Transactions are fetched from nodes by batches with CATCHUP_REQ messages the same way as nodes catch up.
+/// Each batch is verified by consistency proof against the size and merkle root of the ledger
+/// which f+1 nodes agree on at the moment of opening, so transactions written later are not returned.
+///
+/// NOTE: Verification requires all transactions preceding the requested range,
+/// so the ledger is always fetched starting from the first transaction (seq_no 1)
+/// and transactions preceding `from` are fetched too but aren't returned.
+/// Only one iterator over the same ledger of the pool can be open at a time.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// pool_handle: pool handle (created by open_pool_ledger).
+/// ledger_type: (Optional) type of the ledger to iterate over:
+///     DOMAIN - used default,
+///     POOL,
+///     CONFIG
+///     any number
+/// from: sequence number of the first transaction to return (starting from 1).
+/// to: sequence number of the last transaction to return.
+///     Pass -1 to iterate till the end of the ledger.
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// iterator_handle: Ledger iterator handle that can be used later to fetch transactions by batches (with indy_ledger_iterator_next)
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_open_ledger_iterator(command_handle: CommandHandle,
+                                        pool_handle: PoolHandle,
+                                        ledger_type: *const c_char,
+                                        from: i64,
+                                        to: i64,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             iterator_handle: SearchHandle)>) -> ErrorCode {
+    trace!("indy_open_ledger_iterator: >>> pool_handle: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, ledger_type, from, to);
+
+    check_useful_opt_c_str!(ledger_type, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam6);
+
+    if from < 1 {
+        return ErrorCode::CommonInvalidParam4;
+    }
+
+    if to != -1 && to < from {
+        return ErrorCode::CommonInvalidParam5;
+    }
+
+    let to = if to != -1 { Some(to as u64) } else { None };
+
+    trace!("indy_open_ledger_iterator: entities >>> pool_handle: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}",
+           pool_handle, ledger_type, from, to);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::OpenLedgerIterator(
+                pool_handle,
+                ledger_type,
+                from as u64,
+                to,
+                Box::new(move |result| {
+                    let (err, iterator_handle) = prepare_result_1!(result, 0);
+                    trace!("indy_open_ledger_iterator: iterator_handle: {:?}", iterator_handle);
+                    cb(command_handle, err, iterator_handle)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_open_ledger_iterator: <<< res: {:?}", res);
+
+    res
+}
+
+/// Fetches the next batch of verified transactions of the ledger iterator.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// iterator_handle: Ledger iterator handle (created by indy_open_ledger_iterator).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Returns
+/// txns_json: List of transactions ordered by sequence number:
+///     [{
+///         "type": string - transaction type,
+///         "seqNo": int - sequence number of the transaction,
+///         "txnTime": Optional<int> - time of the transaction ordering,
+///         "txnId": Optional<string> - identifier of the transaction,
+///         "from": Optional<string> - DID of the transaction author,
+///         "reqId": Optional<int> - id of the request written the transaction,
+///         "data": object - transaction specific data
+///     }]
+/// NOTE: Empty list means the ledger iterator is completed.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+/// Pool*
+#[no_mangle]
+pub extern fn indy_ledger_iterator_next(command_handle: CommandHandle,
+                                        iterator_handle: SearchHandle,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             txns_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_ledger_iterator_next: >>> iterator_handle: {:?}", iterator_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_ledger_iterator_next: entities >>> iterator_handle: {:?}", iterator_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::LedgerIteratorNext(
+                iterator_handle,
+                Box::new(move |result| {
+                    let (err, txns_json) = prepare_result_1!(result, String::new());
+                    trace!("indy_ledger_iterator_next: txns_json: {:?}", txns_json);
+                    let txns_json = ctypes::string_to_cstring(txns_json);
+                    cb(command_handle, err, txns_json.as_ptr())
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_ledger_iterator_next: <<< res: {:?}", res);
+
+    res
+}
+
+/// Closes ledger iterator (make iterator handle invalid).
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// iterator_handle: Ledger iterator handle (created by indy_open_ledger_iterator).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_close_ledger_iterator(command_handle: CommandHandle,
+                                         iterator_handle: SearchHandle,
+                                         cb: Option<extern fn(command_handle_: CommandHandle,
+                                                              err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_close_ledger_iterator: >>> iterator_handle: {:?}", iterator_handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_close_ledger_iterator: entities >>> iterator_handle: {:?}", iterator_handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Ledger(
+            LedgerCommand::CloseLedgerIterator(
+                iterator_handle,
+                Box::new(move |result| {
+                    let err = prepare_result!(result);
+                    trace!("indy_close_ledger_iterator:");
+                    cb(command_handle, err)
+                })
+            )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_close_ledger_iterator: <<< res: {:?}", res);
+
+    res
+}
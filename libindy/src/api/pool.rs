@@ -72,6 +72,43 @@ pub extern fn indy_create_pool_ledger_config(command_handle: CommandHandle,
 ///     "preordered_nodes": array<string> -  (optional), names of nodes which will have a priority during request sending:
 ///         ["name_of_1st_prior_node",  "name_of_2nd_prior_node", .... ]
 ///         Note: Not specified nodes will be placed in a random way.
+///     "read_strategy": string (optional), order of nodes asked by reads expecting a single state proof verified reply:
+///         "preordered" - (default) nodes from "preordered_nodes" first, the rest in a random way,
+///         "random" - all nodes in a random way, "preordered_nodes" are ignored,
+///         "adaptive" - healthy nodes with the lowest moving-average latency first.
+///                      If reply can't be verified the request is sent to all remaining nodes to collect f+1 consensus.
+///     "networker": string (optional), transport used to reach pool nodes:
+///         "zmq" - (default) connect to validator nodes listed in genesis transactions,
+///         "local" - emulate validator nodes in-process with in-memory pool, domain and config ledgers.
+///                   Ledgers are seeded from genesis transactions and kept until pool config is deleted.
+///                   Note: signatures and permissions of submitted transactions are not verified.
+///         "replay" - replay node replies from traffic capture file set in "replay_traffic" without network access.
+///                    Requests are matched to recorded ones by content, unknown requests get no replies.
+///     "domain_genesis_txn": string (optional), path to domain ledger genesis transactions file
+///         used to seed "local" networker (e.g. trustee NYMs).
+///     "force_rebuild": bool (optional, false by default), drop pool ledger snapshot and cached transactions
+///         and rebuild pool ledger from genesis transactions with full catchup.
+///         By default pool is restored from the snapshot persisted after the last successful catchup
+///         and only transactions after the snapshot height are caught up.
+///         Snapshot beyond genesis transactions is used only if its root is signed by genesis nodes,
+///         otherwise cached transactions are replayed.
+///     "record_traffic": string (optional), path to file where all requests sent to nodes and node replies
+///         are recorded as JSON lines together with timing. Can be used with any networker.
+///     "replay_traffic": string (optional), path to traffic capture file used by "replay" networker.
+///     "write_retry": object (optional), resubmit ledger writes timed out without reply.
+///         Before each retry the pool is asked whether the write was already ordered (GET request for written state
+///         and GET_TXN comparing payload digest or author with reqId). The write is resubmitted only if it wasn't.
+///         Supported for NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF and REVOC_REG_ENTRY, other writes aren't retried.
+///         {
+///             "max_attempts": int (optional, 3 by default), total number of submissions,
+///             "initial_delay": int (optional, 1000 by default), delay before the first check in milliseconds,
+///             "max_delay": int (optional, 30000 by default), upper bound of delay in milliseconds,
+///             "multiplier": float (optional, 2.0 by default), delay grows exponentially with this base,
+///             "jitter": bool (optional, true by default), randomize each delay within [delay / 2, delay]
+///         }
+///     "batch_limit": int (optional, "conn_limit" by default), number of requests of a batch (see indy_submit_requests_batch)
+///         sent to the pool at the same time. Must be greater than 0.
+///         Values greater than "conn_limit" make the pool open extra connections to nodes for a batch.
 /// }
 ///
 /// #Returns
@@ -312,3 +349,232 @@ pub extern fn indy_set_protocol_version(command_handle: CommandHandle,
 
     res
 }
+
+/// Returns health and latency metrics collected for opened pool since it was opened.
+///
+/// #Params
+/// handle: pool handle returned by indy_open_pool_ledger.
+///
+/// #Returns
+/// Metrics json:
+/// {
+///     "catchup_state": string, current state of pool ledger synchronization:
+///         "initialization", "getting_catchup_target", "sync_catchup", "active", "terminated" or "closed",
+///     "catchup_state_since": int, unix timestamp of the last catchup state change,
+///     "consensus_failures": int, number of requests failed because nodes consensus was impossible,
+///     "nodes": {
+///         <node alias>: {
+///             "acks": int, number of received REQACK messages,
+///             "nacks": int, number of received REQNACK messages,
+///             "rejects": int, number of received REJECT messages,
+///             "replies": int, number of received replies (including catchup messages),
+///             "timeouts": int, number of requests the node didn't answer in time,
+///             "latency": {
+///                 "count": int, "min_ms": int, "max_ms": int, "avg_ms": int,
+///                 "buckets": [{"le_ms": int or null for the last one, "count": int}, ...]
+///             } - round-trip latency histogram measured till REPLY, REQNACK or REJECT from node on request (REQACK isn't counted)
+///         },
+///         ...
+///     }
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_get_pool_metrics(command_handle: CommandHandle,
+                                    handle: PoolHandle,
+                                    cb: Option<extern fn(command_handle_: CommandHandle,
+                                                         err: ErrorCode,
+                                                         metrics_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_get_pool_metrics: >>> handle: {:?}", handle);
+
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_get_pool_metrics: entities >>> handle: {:?}", handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::GetMetrics(
+            handle,
+            Box::new(move |result| {
+                let (err, metrics_json) = prepare_result_1!(result, String::new());
+                trace!("indy_get_pool_metrics: metrics_json: {:?}", metrics_json);
+                let metrics_json = ctypes::string_to_cstring(metrics_json);
+                cb(command_handle, err, metrics_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_get_pool_metrics: <<< res: {:?}", res);
+
+    res
+}
+
+/// Persists snapshot of pool ledger built from locally cached transactions:
+/// merkle tree of pool ledger and list of pool nodes.
+/// Next indy_open_pool_ledger call restores pool from the snapshot without replaying of cached transactions
+/// and catches up only transactions after the snapshot height.
+///
+/// Note: snapshot is also updated automatically after each successful catchup of opened pool.
+/// Snapshot beyond genesis transactions is used on open only if it keeps BLS multi-signature of nodes
+/// received during catchup of the same pool ledger state, otherwise cached transactions are replayed.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// config_name: Name of the pool ledger configuration.
+///
+/// #Returns
+/// Snapshot info json:
+/// {
+///     "size": int, number of pool ledger transactions,
+///     "rootHash": string, base58 encoded merkle root of pool ledger,
+///     "timestamp": int, unix timestamp when snapshot has been taken,
+///     "nodes": array<string>, aliases of pool nodes
+/// }
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_create_pool_snapshot(command_handle: CommandHandle,
+                                        config_name: *const c_char,
+                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                             err: ErrorCode,
+                                                             snapshot_json: *const c_char)>) -> ErrorCode {
+    trace!("indy_create_pool_snapshot: >>> config_name: {:?}", config_name);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam3);
+
+    trace!("indy_create_pool_snapshot: entities >>> config_name: {:?}", config_name);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::CreateSnapshot(
+            config_name,
+            Box::new(move |result| {
+                let (err, snapshot_json) = prepare_result_1!(result, String::new());
+                trace!("indy_create_pool_snapshot: snapshot_json: {:?}", snapshot_json);
+                let snapshot_json = ctypes::string_to_cstring(snapshot_json);
+                cb(command_handle, err, snapshot_json.as_ptr())
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_create_pool_snapshot: <<< res: {:?}", res);
+
+    res
+}
+
+/// Registers callback which is called on every state transition of opened pool
+/// (e.g. catchup is started or finished, pool is terminated because nodes are unreachable or pool is closed).
+/// Only one callback can be registered for pool, registering of new one replaces previous.
+/// Callback is unregistered automatically after "closed" event.
+/// Events of pool opening are reported only to callbacks registered by indy_register_pool_event_callback_by_name.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// handle: pool handle returned by indy_open_pool_ledger.
+/// event_cb: callback that takes pool handle and event json:
+/// {
+///     "poolHandle": int, pool handle,
+///     "poolName": string, name of the pool ledger configuration,
+///     "state": string, new state of pool:
+///         "initialization", "getting_catchup_target", "sync_catchup", "active", "terminated" or "closed",
+///     "previousState": string, previous state of pool,
+///     "timestamp": int, unix timestamp of the transition,
+///     "nodes": [string], (only for "active" state) aliases of pool nodes known after catchup
+/// }
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+/// Ledger*
+#[no_mangle]
+pub extern fn indy_register_pool_event_callback(command_handle: CommandHandle,
+                                                handle: PoolHandle,
+                                                event_cb: Option<extern fn(pool_handle: PoolHandle,
+                                                                           event_json: *const c_char)>,
+                                                cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                     err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_pool_event_callback: >>> handle: {:?}", handle);
+
+    check_useful_c_callback!(event_cb, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_pool_event_callback: entities >>> handle: {:?}", handle);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::RegisterEventCallback(
+            handle,
+            Box::new(move |pool_handle, event_json| {
+                trace!("indy_register_pool_event_callback: event_json: {:?}", event_json);
+                let event_json = ctypes::string_to_cstring(event_json);
+                event_cb(pool_handle, event_json.as_ptr())
+            }),
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_pool_event_callback:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_pool_event_callback: <<< res: {:?}", res);
+
+    res
+}
+
+/// Registers callback which is called on every state transition of the pool opened with the given configuration
+/// including transitions during opening (see indy_register_pool_event_callback for the list of events).
+/// Unlike indy_register_pool_event_callback it can be called before indy_open_pool_ledger,
+/// so initial catchup of the pool is reported too.
+/// Only one callback can be registered for pool configuration, registering of new one replaces previous.
+/// Callback is unregistered automatically after "closed" event.
+///
+/// #Params
+/// command_handle: command handle to map callback to caller context.
+/// config_name: name of the pool ledger configuration.
+/// event_cb: callback that takes pool handle and event json (see indy_register_pool_event_callback).
+/// cb: Callback that takes command result as parameter.
+///
+/// #Errors
+/// Common*
+#[no_mangle]
+pub extern fn indy_register_pool_event_callback_by_name(command_handle: CommandHandle,
+                                                        config_name: *const c_char,
+                                                        event_cb: Option<extern fn(pool_handle: PoolHandle,
+                                                                                   event_json: *const c_char)>,
+                                                        cb: Option<extern fn(command_handle_: CommandHandle,
+                                                                             err: ErrorCode)>) -> ErrorCode {
+    trace!("indy_register_pool_event_callback_by_name: >>> config_name: {:?}", config_name);
+
+    check_useful_c_str!(config_name, ErrorCode::CommonInvalidParam2);
+    check_useful_c_callback!(event_cb, ErrorCode::CommonInvalidParam3);
+    check_useful_c_callback!(cb, ErrorCode::CommonInvalidParam4);
+
+    trace!("indy_register_pool_event_callback_by_name: entities >>> config_name: {:?}", config_name);
+
+    let result = CommandExecutor::instance()
+        .send(Command::Pool(PoolCommand::RegisterEventCallbackByName(
+            config_name,
+            Box::new(move |pool_handle, event_json| {
+                trace!("indy_register_pool_event_callback_by_name: event_json: {:?}", event_json);
+                let event_json = ctypes::string_to_cstring(event_json);
+                event_cb(pool_handle, event_json.as_ptr())
+            }),
+            Box::new(move |result| {
+                let err = prepare_result!(result);
+                trace!("indy_register_pool_event_callback_by_name:");
+                cb(command_handle, err)
+            })
+        )));
+
+    let res = prepare_result!(result);
+
+    trace!("indy_register_pool_event_callback_by_name: <<< res: {:?}", res);
+
+    res
+}
@@ -41,6 +41,14 @@ use domain::anoncreds::revocation_registry_delta::{
     RevocationRegistryDelta,
     RevocationRegistryDeltaV1,
 };
+use domain::anoncreds::revocation_registry_rotation::{
+    ActiveRevocationRegistry,
+    REVOC_REG_DEF,
+    REVOC_REG_ENTRY,
+    RevocationRegistryPublication,
+    RevocationRegistryRotation,
+    RevocationRegistryRotationConfig,
+};
 use domain::anoncreds::schema::{AttributeNames, Schema, SchemaV1, MAX_ATTRIBUTES_COUNT};
 use domain::wallet::Tags;
 use errors::prelude::*;
@@ -48,6 +56,7 @@ use services::anoncreds::AnoncredsService;
 use services::anoncreds::helpers::parse_cred_rev_id;
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
+use services::ledger::LedgerService;
 use services::pool::PoolService;
 use services::wallet::{RecordOptions, WalletService};
 
@@ -122,6 +131,25 @@ pub enum IssuerCommand {
         RevocationRegistryDelta, //revocation registry delta
         RevocationRegistryDelta, //other revocation registry delta
         Box<Fn(IndyResult<String>) + Send>),
+    EnableRevocationRegistryRotation(
+        WalletHandle,
+        String, // issuer did
+        String, // credential definition id
+        RevocationRegistryRotationConfig, // config
+        Box<Fn(IndyResult<String>) + Send>),
+    GetActiveRevocationRegistry(
+        WalletHandle,
+        String, // credential definition id
+        Box<Fn(IndyResult<String>) + Send>),
+    GetPendingRevocationRegistryPublications(
+        WalletHandle,
+        String, // credential definition id
+        Box<Fn(IndyResult<String>) + Send>),
+    ConfirmRevocationRegistryPublication(
+        WalletHandle,
+        String, // credential definition id
+        String, // revocation registry id
+        Box<Fn(IndyResult<()>) + Send>),
 }
 
 pub struct IssuerCommandExecutor {
@@ -130,6 +158,7 @@ pub struct IssuerCommandExecutor {
     pub pool_service: Rc<PoolService>,
     pub wallet_service: Rc<WalletService>,
     pub crypto_service: Rc<CryptoService>,
+    pub ledger_service: Rc<LedgerService>,
     pending_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<(String, String)>) + Send>>>,
 }
 
@@ -138,13 +167,15 @@ impl IssuerCommandExecutor {
                pool_service: Rc<PoolService>,
                blob_storage_service: Rc<BlobStorageService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> IssuerCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> IssuerCommandExecutor {
         IssuerCommandExecutor {
             anoncreds_service,
             pool_service,
             blob_storage_service,
             wallet_service,
             crypto_service,
+            ledger_service,
             pending_callbacks: RefCell::new(HashMap::new()),
         }
     }
@@ -199,6 +230,22 @@ impl IssuerCommandExecutor {
                 cb(self.merge_revocation_registry_deltas(&mut RevocationRegistryDeltaV1::from(rev_reg_delta),
                                                          &RevocationRegistryDeltaV1::from(other_rev_reg_delta)));
             }
+            IssuerCommand::EnableRevocationRegistryRotation(wallet_handle, issuer_did, cred_def_id, config, cb) => {
+                info!(target: "issuer_command_executor", "EnableRevocationRegistryRotation command received");
+                cb(self.enable_revocation_registry_rotation(wallet_handle, &issuer_did, &cred_def_id, config));
+            }
+            IssuerCommand::GetActiveRevocationRegistry(wallet_handle, cred_def_id, cb) => {
+                info!(target: "issuer_command_executor", "GetActiveRevocationRegistry command received");
+                cb(self.get_active_revocation_registry(wallet_handle, &cred_def_id));
+            }
+            IssuerCommand::GetPendingRevocationRegistryPublications(wallet_handle, cred_def_id, cb) => {
+                info!(target: "issuer_command_executor", "GetPendingRevocationRegistryPublications command received");
+                cb(self.get_pending_revocation_registry_publications(wallet_handle, &cred_def_id));
+            }
+            IssuerCommand::ConfirmRevocationRegistryPublication(wallet_handle, cred_def_id, rev_reg_id, cb) => {
+                info!(target: "issuer_command_executor", "ConfirmRevocationRegistryPublication command received");
+                cb(self.confirm_revocation_registry_publication(wallet_handle, &cred_def_id, &rev_reg_id));
+            }
         };
     }
 
@@ -531,6 +578,8 @@ impl IssuerCommandExecutor {
                 rev_reg_info.curr_id += 1;
 
                 if rev_reg_info.curr_id > rev_reg_def.value.max_cred_num {
+                    self._rotate_revocation_registry_if_required(wallet_handle, &cred_request.cred_def_id, r_reg_id,
+                                                                 rev_reg_def.value.max_cred_num, rev_reg_def.value.max_cred_num);
                     return Err(err_msg(IndyErrorKind::RevocationRegistryFull, "RevocationRegistryAccumulator is full"));
                 }
 
@@ -607,6 +656,11 @@ impl IssuerCommandExecutor {
             self.wallet_service.update_indy_object(wallet_handle, &r_reg_id, &r_reg_info)?;
         };
 
+        if let (Some(r_reg_id), Some(r_reg_def), Some(r_reg_info)) = (rev_reg_id, rev_reg_def.as_ref(), rev_reg_info.as_ref()) {
+            self._rotate_revocation_registry_if_required(wallet_handle, &cred_request.cred_def_id, r_reg_id,
+                                                         r_reg_info.curr_id, r_reg_def.value.max_cred_num);
+        }
+
         let cred_rev_id = rev_reg_info.map(|r_reg_info| r_reg_info.curr_id.to_string());
 
         debug!("new_credential <<< cred_json: {:?}, cred_rev_id: {:?}, rev_reg_delta_json: {:?}", secret!(&cred_json), secret!(&cred_rev_id), rev_reg_delta_json);
@@ -749,6 +803,263 @@ impl IssuerCommandExecutor {
         Ok(merged_rev_reg_delta_json)
     }
 
+    fn enable_revocation_registry_rotation(&self,
+                                           wallet_handle: WalletHandle,
+                                           issuer_did: &str,
+                                           cred_def_id: &str,
+                                           config: RevocationRegistryRotationConfig) -> IndyResult<String> {
+        debug!("enable_revocation_registry_rotation >>> wallet_handle: {:?}, issuer_did: {:?}, cred_def_id: {:?}, config: {:?}",
+               wallet_handle, issuer_did, cred_def_id, config);
+
+        self.crypto_service.validate_did(issuer_did)?;
+
+        let fill_threshold = config.fill_threshold();
+
+        if !(fill_threshold > 0.0 && fill_threshold <= 1.0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid fill threshold: {}. Must be in (0, 1] interval", fill_threshold)));
+        }
+
+        if self.wallet_service.record_exists::<RevocationRegistryRotation>(wallet_handle, cred_def_id)? {
+            return Err(err_msg(IndyErrorKind::WalletItemAlreadyExists,
+                               format!("Revocation registry rotation is already enabled for credential definition: {}", cred_def_id)));
+        }
+
+        let mut rotation = RevocationRegistryRotation {
+            issuer_did: issuer_did.to_string(),
+            cred_def_id: cred_def_id.to_string(),
+            config,
+            seq_no: 0,
+            active_rev_reg_id: String::new(),
+            next_rev_reg_id: None,
+            pending_publications: Vec::new(),
+        };
+
+        rotation.active_rev_reg_id = self._create_next_revocation_registry(wallet_handle, &mut rotation)?;
+
+        self.wallet_service.add_indy_object(wallet_handle, cred_def_id, &rotation, &HashMap::new())?;
+
+        let res = self._get_active_revocation_registry(wallet_handle, &rotation)?;
+
+        debug!("enable_revocation_registry_rotation <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_active_revocation_registry(&self,
+                                      wallet_handle: WalletHandle,
+                                      cred_def_id: &str) -> IndyResult<String> {
+        debug!("get_active_revocation_registry >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+        let rotation = self._wallet_get_rev_reg_rotation(wallet_handle, cred_def_id)?;
+
+        let res = self._get_active_revocation_registry(wallet_handle, &rotation)?;
+
+        debug!("get_active_revocation_registry <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_pending_revocation_registry_publications(&self,
+                                                    wallet_handle: WalletHandle,
+                                                    cred_def_id: &str) -> IndyResult<String> {
+        debug!("get_pending_revocation_registry_publications >>> wallet_handle: {:?}, cred_def_id: {:?}", wallet_handle, cred_def_id);
+
+        let rotation = self._wallet_get_rev_reg_rotation(wallet_handle, cred_def_id)?;
+
+        let res = serde_json::to_string(&rotation.pending_publications)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RevocationRegistryPublications")?;
+
+        debug!("get_pending_revocation_registry_publications <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn confirm_revocation_registry_publication(&self,
+                                               wallet_handle: WalletHandle,
+                                               cred_def_id: &str,
+                                               rev_reg_id: &str) -> IndyResult<()> {
+        debug!("confirm_revocation_registry_publication >>> wallet_handle: {:?}, cred_def_id: {:?}, rev_reg_id: {:?}",
+               wallet_handle, cred_def_id, rev_reg_id);
+
+        let mut rotation = self._wallet_get_rev_reg_rotation(wallet_handle, cred_def_id)?;
+
+        if rotation.is_published(rev_reg_id) {
+            return Err(err_msg(IndyErrorKind::WalletItemNotFound,
+                               format!("No pending publications found for revocation registry: {}", rev_reg_id)));
+        }
+
+        rotation.pending_publications.retain(|publication| publication.rev_reg_id != rev_reg_id);
+
+        // the active registry got full while the next one was being published
+        if rotation.next_rev_reg_id.as_ref().map(String::as_str) == Some(rev_reg_id) &&
+            self._is_revocation_registry_full(wallet_handle, &rotation.active_rev_reg_id)? {
+            self._activate_next_revocation_registry(&mut rotation);
+        }
+
+        self.wallet_service.update_indy_object(wallet_handle, cred_def_id, &rotation)?;
+
+        debug!("confirm_revocation_registry_publication <<<");
+
+        Ok(())
+    }
+
+    fn _get_active_revocation_registry(&self, wallet_handle: WalletHandle, rotation: &RevocationRegistryRotation) -> IndyResult<String> {
+        let rev_reg_def = self._wallet_get_rev_reg_def(wallet_handle, &rotation.active_rev_reg_id)?;
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, &rotation.active_rev_reg_id)?;
+
+        let max_cred_num = match rev_reg_def {
+            RevocationRegistryDefinition::RevocationRegistryDefinitionV1(ref rev_reg_def) => rev_reg_def.value.max_cred_num
+        };
+
+        let active_rev_reg = ActiveRevocationRegistry {
+            rev_reg_id: rotation.active_rev_reg_id.clone(),
+            rev_reg_def,
+            issued: rev_reg_info.curr_id,
+            max_cred_num,
+            published: rotation.is_published(&rotation.active_rev_reg_id),
+            next_rev_reg_id: rotation.next_rev_reg_id.clone(),
+        };
+
+        serde_json::to_string(&active_rev_reg)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ActiveRevocationRegistry")
+    }
+
+    fn _is_revocation_registry_full(&self, wallet_handle: WalletHandle, rev_reg_id: &str) -> IndyResult<bool> {
+        let rev_reg_def = self._wallet_get_rev_reg_def(wallet_handle, rev_reg_id)?;
+        let rev_reg_info = self._wallet_get_rev_reg_info(wallet_handle, rev_reg_id)?;
+
+        let max_cred_num = match rev_reg_def {
+            RevocationRegistryDefinition::RevocationRegistryDefinitionV1(ref rev_reg_def) => rev_reg_def.value.max_cred_num
+        };
+
+        Ok(rev_reg_info.curr_id >= max_cred_num)
+    }
+
+    fn _is_next_revocation_registry_stored(&self, wallet_handle: WalletHandle, rotation: &RevocationRegistryRotation) -> IndyResult<bool> {
+        let rev_reg_id = RevocationRegistryDefinition::rev_reg_id(&rotation.issuer_did, &rotation.cred_def_id,
+                                                                  &RegistryType::CL_ACCUM, &rotation.next_tag());
+
+        self.wallet_service.record_exists::<RevocationRegistryDefinition>(wallet_handle, &rev_reg_id)
+    }
+
+    /// Creates and stores the next registry of the rotation with its tails and
+    /// adds REVOC_REG_DEF and REVOC_REG_ENTRY requests to the pending publications.
+    fn _create_next_revocation_registry(&self, wallet_handle: WalletHandle, rotation: &mut RevocationRegistryRotation) -> IndyResult<String> {
+        // registry may be left stored by failed rotation which wasn't persisted, so its tag is skipped on retry
+        while self._is_next_revocation_registry_stored(wallet_handle, rotation)? {
+            rotation.seq_no += 1;
+        }
+
+        let tag = rotation.next_tag();
+
+        let tails_writer_handle = self.blob_storage_service.open_writer(&rotation.config.tails_writer_type,
+                                                                        &rotation.config.tails_writer_config.to_string())?;
+
+        let res = self.create_and_store_revocation_registry(wallet_handle,
+                                                            &rotation.issuer_did,
+                                                            None,
+                                                            &tag,
+                                                            &rotation.cred_def_id,
+                                                            &rotation.config.revocation_registry_config(),
+                                                            tails_writer_handle);
+
+        // tails are finalized, writer is opened for every registry of the rotation
+        self.blob_storage_service.close_writer(tails_writer_handle)?;
+
+        let (rev_reg_id, rev_reg_def_json, rev_reg_json) = res?;
+
+        let rev_reg_def: RevocationRegistryDefinition = serde_json::from_str(&rev_reg_def_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDefinition")?;
+
+        let rev_reg_entry: RevocationRegistryDelta = serde_json::from_str(&rev_reg_json)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize RevocationRegistryDelta")?;
+
+        let rev_reg_def = RevocationRegistryDefinitionV1::from(rev_reg_def);
+        let revoc_def_type = rev_reg_def.revoc_def_type.to_str();
+
+        let rev_reg_def_request = self.ledger_service.build_revoc_reg_def_request(&rotation.issuer_did, rev_reg_def)?;
+        let rev_reg_entry_request = self.ledger_service.build_revoc_reg_entry_request(&rotation.issuer_did, &rev_reg_id, revoc_def_type,
+                                                                                      RevocationRegistryDeltaV1::from(rev_reg_entry))?;
+
+        for (txn_type, request) in vec![(REVOC_REG_DEF, rev_reg_def_request), (REVOC_REG_ENTRY, rev_reg_entry_request)] {
+            rotation.pending_publications.push(RevocationRegistryPublication {
+                rev_reg_id: rev_reg_id.clone(),
+                txn_type: txn_type.to_string(),
+                request: serde_json::from_str(&request)
+                    .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize ledger request")?,
+            });
+        }
+
+        rotation.seq_no += 1;
+
+        Ok(rev_reg_id)
+    }
+
+    /// Rotation errors are only logged as they must not affect credential issuance.
+    /// Failed rotation is retried on the next issuance, including the one rejected with `RevocationRegistryFull`.
+    fn _rotate_revocation_registry_if_required(&self,
+                                               wallet_handle: WalletHandle,
+                                               cred_def_id: &str,
+                                               rev_reg_id: &str,
+                                               issued: u32,
+                                               max_cred_num: u32) {
+        if let Err(err) = self._rotate_revocation_registry(wallet_handle, cred_def_id, rev_reg_id, issued, max_cred_num) {
+            warn!("Revocation registry rotation failed for credential definition: {:?}, err: {:?}", cred_def_id, err);
+        }
+    }
+
+    fn _rotate_revocation_registry(&self,
+                                   wallet_handle: WalletHandle,
+                                   cred_def_id: &str,
+                                   rev_reg_id: &str,
+                                   issued: u32,
+                                   max_cred_num: u32) -> IndyResult<()> {
+        if !self.wallet_service.record_exists::<RevocationRegistryRotation>(wallet_handle, cred_def_id)? {
+            return Ok(());
+        }
+
+        let mut rotation = self._wallet_get_rev_reg_rotation(wallet_handle, cred_def_id)?;
+
+        if rotation.active_rev_reg_id != rev_reg_id {
+            return Ok(());
+        }
+
+        if !rotation.is_next_required(issued, max_cred_num) && issued < max_cred_num {
+            return Ok(());
+        }
+
+        if rotation.next_rev_reg_id.is_none() {
+            let next_rev_reg_id = self._create_next_revocation_registry(wallet_handle, &mut rotation)?;
+            rotation.next_rev_reg_id = Some(next_rev_reg_id);
+        }
+
+        let activated = issued < max_cred_num || self._activate_next_revocation_registry(&mut rotation);
+
+        self.wallet_service.update_indy_object(wallet_handle, cred_def_id, &rotation)?;
+
+        if !activated {
+            return Err(err_msg(IndyErrorKind::InvalidState,
+                               format!("Next revocation registry {} can't become active until its publication is confirmed",
+                                       rotation.next_rev_reg_id.unwrap_or_default())));
+        }
+
+        Ok(())
+    }
+
+    /// Makes the next registry active if it is already published, so credentials are never issued in unpublished registry.
+    fn _activate_next_revocation_registry(&self, rotation: &mut RevocationRegistryRotation) -> bool {
+        match rotation.next_rev_reg_id.take() {
+            Some(ref next_rev_reg_id) if rotation.is_published(next_rev_reg_id) => {
+                rotation.active_rev_reg_id = next_rev_reg_id.to_string();
+                true
+            }
+            next_rev_reg_id => {
+                rotation.next_rev_reg_id = next_rev_reg_id;
+                false
+            }
+        }
+    }
+
     // TODO: DELETE IT
     fn _wallet_set_schema_id(&self, wallet_handle: WalletHandle, id: &str, schema_id: &str) -> IndyResult<()> {
         self.wallet_service.add_record(wallet_handle, &self.wallet_service.add_prefix("SchemaId"), id, schema_id, &Tags::new())
@@ -772,4 +1083,8 @@ impl IssuerCommandExecutor {
     fn _wallet_get_rev_reg_info(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<RevocationRegistryInfo> {
         self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
     }
+
+    fn _wallet_get_rev_reg_rotation(&self, wallet_handle: WalletHandle, key: &str) -> IndyResult<RevocationRegistryRotation> {
+        self.wallet_service.get_indy_object(wallet_handle, &key, &RecordOptions::id_value())
+    }
 }
@@ -12,6 +12,7 @@ use services::blob_storage::BlobStorageService;
 use services::pool::PoolService;
 use services::wallet::WalletService;
 use services::crypto::CryptoService;
+use services::ledger::LedgerService;
 
 use std::rc::Rc;
 
@@ -32,11 +33,12 @@ impl AnoncredsCommandExecutor {
                blob_storage_service: Rc<BlobStorageService>,
                pool_service: Rc<PoolService>,
                wallet_service: Rc<WalletService>,
-               crypto_service: Rc<CryptoService>) -> AnoncredsCommandExecutor {
+               crypto_service: Rc<CryptoService>,
+               ledger_service: Rc<LedgerService>) -> AnoncredsCommandExecutor {
         AnoncredsCommandExecutor {
             issuer_command_cxecutor: IssuerCommandExecutor::new(
                 anoncreds_service.clone(), pool_service.clone(),
-                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone()),
+                blob_storage_service.clone(), wallet_service.clone(), crypto_service.clone(), ledger_service.clone()),
             prover_command_cxecutor: ProverCommandExecutor::new(
                 anoncreds_service.clone(), wallet_service.clone(), crypto_service.clone(), blob_storage_service.clone()),
             verifier_command_cxecutor: VerifierCommandExecutor::new(
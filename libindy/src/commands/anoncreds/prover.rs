@@ -21,7 +21,7 @@ use domain::anoncreds::revocation_state::RevocationState;
 use domain::anoncreds::schema::{Schema, schemas_map_to_schemas_v1_map, SchemaV1};
 use errors::prelude::*;
 use services::anoncreds::AnoncredsService;
-use services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval};
+use services::anoncreds::helpers::{parse_cred_rev_id, get_non_revoc_interval, get_requested_attr_names};
 use services::blob_storage::BlobStorageService;
 use services::crypto::CryptoService;
 use services::wallet::{RecordOptions, SearchOptions, WalletRecord, WalletSearch, WalletService};
@@ -526,7 +526,7 @@ impl ProverCommandExecutor {
         let mut credentials_for_proof_request = CredentialsForProofRequest::default();
 
         for (attr_id, requested_attr) in &proof_request.requested_attributes {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.name,
+            let query_json = self.anoncreds_service.prover.build_query(&get_requested_attr_names(requested_attr)?,
                                                                        &attr_id,
                                                                        &requested_attr.restrictions,
                                                                        &None)?;
@@ -539,7 +539,7 @@ impl ProverCommandExecutor {
         }
 
         for (predicate_id, requested_predicate) in &proof_request.requested_predicates {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_predicate.name,
+            let query_json = self.anoncreds_service.prover.build_query(&[requested_predicate.name.as_str()],
                                                                        &predicate_id,
                                                                        &requested_predicate.restrictions,
                                                                        &None)?;
@@ -569,7 +569,7 @@ impl ProverCommandExecutor {
         let mut credentials_for_proof_request_search = HashMap::<String, SearchForProofRequest>::new();
 
         for (attr_id, requested_attr) in &proof_request.requested_attributes {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_attr.name,
+            let query_json = self.anoncreds_service.prover.build_query(&get_requested_attr_names(requested_attr)?,
                                                                        &attr_id,
                                                                        &requested_attr.restrictions,
                                                                        &extra_query)?;
@@ -584,7 +584,7 @@ impl ProverCommandExecutor {
         }
 
         for (predicate_id, requested_predicate) in &proof_request.requested_predicates {
-            let query_json = self.anoncreds_service.prover.build_query(&requested_predicate.name,
+            let query_json = self.anoncreds_service.prover.build_query(&[requested_predicate.name.as_str()],
                                                                        &predicate_id,
                                                                        &requested_predicate.restrictions,
                                                                        &extra_query)?;
@@ -19,6 +19,14 @@ pub enum VerifierCommand {
         HashMap<String, RevocationRegistryDefinition>, // rev reg defs
         HashMap<String, HashMap<u64, RevocationRegistry>>, // rev reg entries
         Box<Fn(IndyResult<bool>) + Send>),
+    VerifyProofWithReport(
+        ProofRequest, // proof request
+        Proof, // proof
+        HashMap<String, Schema>, // credential schemas
+        HashMap<String, CredentialDefinition>, // credential defs
+        HashMap<String, RevocationRegistryDefinition>, // rev reg defs
+        HashMap<String, HashMap<u64, RevocationRegistry>>, // rev reg entries
+        Box<Fn(IndyResult<String>) + Send>),
     GenerateNonce(
         Box<Fn(IndyResult<String>) + Send>)
 }
@@ -44,6 +52,14 @@ impl VerifierCommandExecutor {
                                      &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
                                      &rev_regs_map_to_rev_regs_local_map(rev_regs)));
             }
+            VerifierCommand::VerifyProofWithReport(proof_request, proof, schemas, credential_defs, rev_reg_defs, rev_regs, cb) => {
+                info!(target: "verifier_command_executor", "VerifyProofWithReport command received");
+                cb(self.verify_proof_with_report(proof_request, proof,
+                                                 &schemas_map_to_schemas_v1_map(schemas),
+                                                 &cred_defs_map_to_cred_defs_v1_map(credential_defs),
+                                                 &rev_reg_defs_map_to_rev_reg_defs_v1_map(rev_reg_defs),
+                                                 &rev_regs_map_to_rev_regs_local_map(rev_regs)));
+            }
             VerifierCommand::GenerateNonce(cb) => {
                 info!(target: "verifier_command_executor", "GenerateNonce command received");
                 cb(self.generate_nonce());
@@ -74,6 +90,32 @@ impl VerifierCommandExecutor {
         Ok(result)
     }
 
+    fn verify_proof_with_report(&self,
+                                proof_req: ProofRequest,
+                                proof: Proof,
+                                schemas: &HashMap<String, SchemaV1>,
+                                cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                                rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                                rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<String> {
+        debug!("verify_proof_with_report >>> proof_req: {:?}, proof: {:?}, schemas: {:?}, cred_defs: {:?},  \
+               rev_reg_defs: {:?}, rev_regs: {:?}",
+               proof_req, proof, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let report = self.anoncreds_service.verifier.verify_with_report(&proof,
+                                                                        &proof_req,
+                                                                        schemas,
+                                                                        cred_defs,
+                                                                        rev_reg_defs,
+                                                                        rev_regs)?;
+
+        let res = serde_json::to_string(&report)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ProofVerificationReport")?;
+
+        debug!("verify_proof_with_report <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn generate_nonce(&self) -> IndyResult<String> {
         debug!("generate_nonce >>> ");
 
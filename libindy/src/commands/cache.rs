@@ -1,10 +1,19 @@
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::rc::Rc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use ursa::cl::RevocationRegistryDelta as CryptoRevocationRegistryDelta;
+
+use domain::anoncreds::revocation_history::{RevocationHistory, RevocationHistoryEntry, RevocationRegistryState};
+use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
+use domain::ledger::attrib::ParsedAttrib;
+use domain::ledger::ddo::{DidResolutionResult, unqualify_did};
+use domain::ledger::nym::ParsedNym;
 use domain::wallet::Tags;
 use errors::prelude::*;
+use services::ledger::LedgerService;
+use services::pool::PoolService;
 use services::wallet::WalletService;
 use api::{WalletHandle, PoolHandle};
 use commands::{Command, CommandExecutor};
@@ -12,6 +21,10 @@ use commands::ledger::LedgerCommand;
 
 const CRED_DEF_CACHE: &str = "cred_def_cache";
 const SCHEMA_CACHE: &str = "schema_cache";
+const REQUEST_CACHE: &str = "request_cache";
+const DID_DOC_CACHE: &str = "did_doc_cache";
+const RICH_SCHEMA_CACHE: &str = "rich_schema_cache";
+const REV_REG_STATE_CACHE: &str = "rev_reg_state_cache";
 
 pub enum CacheCommand {
     GetSchema(PoolHandle,
@@ -38,25 +51,115 @@ pub enum CacheCommand {
         GetCacheOptions,              // options
         i32,                          // cb_id
     ),
+    GetRichSchemaObject(PoolHandle,
+                        WalletHandle,
+                        String, // submitter_did
+                        String, // id
+                        String, // options_json
+                        Box<Fn(IndyResult<String>) + Send>),
+    GetRichSchemaObjectContinue(
+        WalletHandle,
+        IndyResult<(String, String)>, // ledger_response
+        GetCacheOptions,              // options
+        i32,                          // cb_id
+    ),
+    GetRequest(PoolHandle,
+               WalletHandle,
+               String, // request_json
+               String, // options_json
+               Box<Fn(IndyResult<String>) + Send>),
+    GetRequestContinue(
+        PoolHandle,
+        WalletHandle,
+        String,               // request_json
+        IndyResult<String>,   // ledger_response
+        GetCacheOptions,      // options
+        i32,                  // cb_id
+    ),
+    ResolveDid(PoolHandle,
+               WalletHandle,
+               String, // did
+               String, // options_json
+               Box<Fn(IndyResult<String>) + Send>),
+    ResolveDidNymContinue(
+        IndyResult<String>, // ledger_response
+        i32,                // cb_id
+    ),
+    ResolveDidEndpointContinue(
+        IndyResult<String>, // ledger_response
+        i32,                // cb_id
+    ),
+    GetRevocationHistory(PoolHandle,
+                         WalletHandle,
+                         String, // rev_reg_def_id
+                         u64, // from
+                         u64, // to
+                         String, // options_json
+                         Box<Fn(IndyResult<String>) + Send>),
+    GetRevocationHistoryContinue(
+        IndyResult<String>, // ledger_response
+        i32,                // cb_id
+    ),
     PurgeSchemaCache(WalletHandle,
                      String, // options json
                      Box<Fn(IndyResult<()>) + Send>),
     PurgeCredDefCache(WalletHandle,
                       String, // options json
                       Box<Fn(IndyResult<()>) + Send>),
+    PurgeRichSchemaCache(WalletHandle,
+                         String, // options json
+                         Box<Fn(IndyResult<()>) + Send>),
+    PurgeRequestCache(WalletHandle,
+                      String, // options json
+                      Box<Fn(IndyResult<()>) + Send>),
+    PurgeDidDocCache(WalletHandle,
+                     String, // options json
+                     Box<Fn(IndyResult<()>) + Send>),
+    PurgeRevRegStateCache(WalletHandle,
+                          String, // options json
+                          Box<Fn(IndyResult<()>) + Send>),
+}
+
+/// State of DID resolution between GET_NYM and GET_ATTRIB replies.
+struct PendingResolve {
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    did: String,
+    options: GetCacheOptions,
+    request_json: String,
+    nym: Option<(ParsedNym, bool)>,
+}
+
+/// State of revocation history reconstruction between GET_REVOC_REG_DELTA replies.
+/// Registry states are collected from the latest one backwards.
+struct PendingRevocationHistory {
+    pool_handle: PoolHandle,
+    wallet_handle: WalletHandle,
+    rev_reg_def_id: String,
+    from: u64,
+    options: GetCacheOptions,
+    states: Vec<RevocationRegistryState>,
 }
 
 pub struct CacheCommandExecutor {
     wallet_service: Rc<WalletService>,
+    pool_service: Rc<PoolService>,
+    ledger_service: Rc<LedgerService>,
 
     pending_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<String>)>>>,
+    pending_resolves: RefCell<HashMap<i32, PendingResolve>>,
+    pending_histories: RefCell<HashMap<i32, PendingRevocationHistory>>,
 }
 
 impl CacheCommandExecutor {
-    pub fn new(wallet_service: Rc<WalletService>) -> CacheCommandExecutor {
+    pub fn new(wallet_service: Rc<WalletService>, pool_service: Rc<PoolService>, ledger_service: Rc<LedgerService>) -> CacheCommandExecutor {
         CacheCommandExecutor {
             wallet_service,
+            pool_service,
+            ledger_service,
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_resolves: RefCell::new(HashMap::new()),
+            pending_histories: RefCell::new(HashMap::new()),
         }
     }
 
@@ -78,6 +181,42 @@ impl CacheCommandExecutor {
                 info!(target: "non_secrets_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(wallet_handle, ledger_response, options, cb_id);
             }
+            CacheCommand::GetRichSchemaObject(pool_handle, wallet_handle, submitter_did, id, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "GetRichSchemaObject command received");
+                self.get_rich_schema_object(pool_handle, wallet_handle, &submitter_did, &id, &options_json, cb);
+            }
+            CacheCommand::GetRichSchemaObjectContinue(wallet_handle, ledger_response, options, cb_id) => {
+                info!(target: "non_secrets_command_executor", "GetRichSchemaObjectContinue command received");
+                self._get_rich_schema_object_continue(wallet_handle, ledger_response, options, cb_id);
+            }
+            CacheCommand::GetRequest(pool_handle, wallet_handle, request_json, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "GetRequest command received");
+                self.get_request(pool_handle, wallet_handle, &request_json, &options_json, cb);
+            }
+            CacheCommand::GetRequestContinue(pool_handle, wallet_handle, request_json, ledger_response, options, cb_id) => {
+                info!(target: "non_secrets_command_executor", "GetRequestContinue command received");
+                self._get_request_continue(pool_handle, wallet_handle, &request_json, ledger_response, options, cb_id);
+            }
+            CacheCommand::ResolveDid(pool_handle, wallet_handle, did, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "ResolveDid command received");
+                self.resolve_did(pool_handle, wallet_handle, &did, &options_json, cb);
+            }
+            CacheCommand::ResolveDidNymContinue(ledger_response, cb_id) => {
+                info!(target: "non_secrets_command_executor", "ResolveDidNymContinue command received");
+                self._resolve_did_nym_continue(ledger_response, cb_id);
+            }
+            CacheCommand::ResolveDidEndpointContinue(ledger_response, cb_id) => {
+                info!(target: "non_secrets_command_executor", "ResolveDidEndpointContinue command received");
+                self._resolve_did_endpoint_continue(ledger_response, cb_id);
+            }
+            CacheCommand::GetRevocationHistory(pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "GetRevocationHistory command received");
+                self.get_revocation_history(pool_handle, wallet_handle, &rev_reg_def_id, from, to, &options_json, cb);
+            }
+            CacheCommand::GetRevocationHistoryContinue(ledger_response, cb_id) => {
+                info!(target: "non_secrets_command_executor", "GetRevocationHistoryContinue command received");
+                self._get_revocation_history_continue(ledger_response, cb_id);
+            }
             CacheCommand::PurgeSchemaCache(wallet_handle, options_json, cb) => {
                 info!(target: "non_secrets_command_executor", "PurgeSchemaCache command received");
                 cb(self.purge_schema_cache(wallet_handle, &options_json));
@@ -86,6 +225,22 @@ impl CacheCommandExecutor {
                 info!(target: "non_secrets_command_executor", "PurgeCredDefCache command received");
                 cb(self.purge_cred_def_cache(wallet_handle, &options_json));
             }
+            CacheCommand::PurgeRichSchemaCache(wallet_handle, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "PurgeRichSchemaCache command received");
+                cb(self.purge_rich_schema_cache(wallet_handle, &options_json));
+            }
+            CacheCommand::PurgeRequestCache(wallet_handle, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "PurgeRequestCache command received");
+                cb(self.purge_request_cache(wallet_handle, &options_json));
+            }
+            CacheCommand::PurgeDidDocCache(wallet_handle, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "PurgeDidDocCache command received");
+                cb(self.purge_did_doc_cache(wallet_handle, &options_json));
+            }
+            CacheCommand::PurgeRevRegStateCache(wallet_handle, options_json, cb) => {
+                info!(target: "non_secrets_command_executor", "PurgeRevRegStateCache command received");
+                cb(self.purge_rev_reg_state_cache(wallet_handle, &options_json));
+            }
         }
     }
 
@@ -101,35 +256,8 @@ impl CacheCommandExecutor {
 
         let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
 
-        let cache = if !options.no_cache.unwrap_or(false) {
-            let options_json = json!({
-                "retrieveType": false,
-                "retrieveValue": true,
-                "retrieveTags": true,
-            }).to_string();
-            match self.wallet_service.get_record(wallet_handle, SCHEMA_CACHE, id, &options_json) {
-                Ok(record) => Ok(Some(record)),
-                Err(err) => if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
-            }
-        } else { Ok(None) };
-        let cache = try_cb!(cache, cb);
-
-        if let Some(cache) = cache {
-            let min_fresh = options.min_fresh.unwrap_or(-1);
-            if min_fresh >= 0 {
-                let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                    Ok(ts) => ts.as_secs() as i32,
-                    Err(err) => {
-                        error!("Cannot get time: {:?}", err);
-                        return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot get time: {:?}", err))))
-                    }
-                };
-                if ts - min_fresh <= cache.get_tags().unwrap_or(&Tags::new()).get("timestamp").unwrap_or(&"-1".to_string()).parse().unwrap_or(-1) {
-                    return cb(Ok(cache.get_value().unwrap_or("").to_string()))
-                }
-            } else {
-                return cb(Ok(cache.get_value().unwrap_or("").to_string()))
-            }
+        if let Some(cached) = try_cb!(self._get_cached(wallet_handle, SCHEMA_CACHE, id, &options), cb) {
+            return cb(Ok(cached));
         }
 
         if options.no_update.unwrap_or(false) {
@@ -168,17 +296,7 @@ impl CacheCommandExecutor {
         let (schema_id, schema_json) = try_cb!(ledger_response, cb);
 
         if !options.no_store.unwrap_or(false) {
-            let mut tags = Tags::new();
-            let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                Ok(ts) => ts.as_secs() as i32,
-                Err(err) => {
-                    warn!("Cannot get time: {:?}", err);
-                    0
-                }
-            };
-            tags.insert("timestamp".to_string(), ts.to_string());
-            let _ = self.wallet_service.delete_record(wallet_handle, SCHEMA_CACHE, &schema_id);
-            let _ = self.wallet_service.add_record(wallet_handle, SCHEMA_CACHE, &schema_id, &schema_json, &tags);
+            self._store(wallet_handle, SCHEMA_CACHE, &schema_id, &schema_json, Tags::new());
         }
 
         cb(Ok(schema_json));
@@ -196,35 +314,8 @@ impl CacheCommandExecutor {
 
         let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
 
-        let cache = if !options.no_cache.unwrap_or(false) {
-            let options_json = json!({
-                "retrieveType": false,
-                "retrieveValue": true,
-                "retrieveTags": true,
-            }).to_string();
-            match self.wallet_service.get_record(wallet_handle, CRED_DEF_CACHE, id, &options_json) {
-                Ok(record) => Ok(Some(record)),
-                Err(err) => if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
-            }
-        } else { Ok(None) };
-        let cache = try_cb!(cache, cb);
-
-        if let Some(cache) = cache {
-            let min_fresh = options.min_fresh.unwrap_or(-1);
-            if min_fresh >= 0 {
-                let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                    Ok(ts) => ts.as_secs() as i32,
-                    Err(err) => {
-                        error!("Cannot get time: {:?}", err);
-                        return cb(Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot get time: {:?}", err))))
-                    }
-                };
-                if ts - min_fresh <= cache.get_tags().unwrap_or(&Tags::new()).get("timestamp").unwrap_or(&"-1".to_string()).parse().unwrap_or(-1) {
-                    return cb(Ok(cache.get_value().unwrap_or("").to_string()))
-                }
-            } else {
-                return cb(Ok(cache.get_value().unwrap_or("").to_string()))
-            }
+        if let Some(cached) = try_cb!(self._get_cached(wallet_handle, CRED_DEF_CACHE, id, &options), cb) {
+            return cb(Ok(cached));
         }
 
         if options.no_update.unwrap_or(false) {
@@ -263,22 +354,70 @@ impl CacheCommandExecutor {
         let (cred_def_id, cred_def_json) = try_cb!(ledger_response, cb);
 
         if !options.no_store.unwrap_or(false) {
-            let mut tags = Tags::new();
-            let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                Ok(ts) => ts.as_secs() as i32,
-                Err(err) => {
-                    warn!("Cannot get time: {:?}", err);
-                    0
-                }
-            };
-            tags.insert("timestamp".to_string(), ts.to_string());
-            let _ = self.wallet_service.delete_record(wallet_handle, CRED_DEF_CACHE, &cred_def_id);
-            let _ = self.wallet_service.add_record(wallet_handle, CRED_DEF_CACHE, &cred_def_id, &cred_def_json, &tags);
+            self._store(wallet_handle, CRED_DEF_CACHE, &cred_def_id, &cred_def_json, Tags::new());
         }
 
         cb(Ok(cred_def_json));
     }
 
+    fn get_rich_schema_object(&self,
+                              pool_handle: PoolHandle,
+                              wallet_handle: WalletHandle,
+                              submitter_did: &str,
+                              id: &str,
+                              options_json: &str,
+                              cb: Box<Fn(IndyResult<String>) + Send>) {
+        trace!("get_rich_schema_object >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, id: {:?}, options_json: {:?}",
+               pool_handle, wallet_handle, submitter_did, id, options_json);
+
+        let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
+
+        if let Some(cached) = try_cb!(self._get_cached(wallet_handle, RICH_SCHEMA_CACHE, id, &options), cb) {
+            return cb(Ok(cached));
+        }
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = ::utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::GetRichSchemaObject(
+                    pool_handle,
+                    Some(submitter_did.to_string()),
+                    id.to_string(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRichSchemaObjectContinue(
+                                    wallet_handle,
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_rich_schema_object_continue(&self, wallet_handle: WalletHandle, ledger_response: IndyResult<(String, String)>, options: GetCacheOptions, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let (object_id, object_json) = try_cb!(ledger_response, cb);
+
+        if !options.no_store.unwrap_or(false) {
+            self._store(wallet_handle, RICH_SCHEMA_CACHE, &object_id, &object_json, Tags::new());
+        }
+
+        cb(Ok(object_json));
+    }
+
     fn purge_schema_cache(&self,
                           wallet_handle: WalletHandle,
                           options_json: &str) -> IndyResult<()> {
@@ -287,66 +426,464 @@ impl CacheCommandExecutor {
         let options = serde_json::from_str::<PurgeOptions>(options_json)
             .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
 
-        let max_age = options.max_age.unwrap_or(-1);
-        let query_json = if max_age >= 0 {
-            let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                Ok(ts) => ts.as_secs() as i32,
-                Err(err) => {
-                    error!("Cannot get time: {:?}", err);
-                    return Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot get time: {:?}", err)))
+        let res = self._purge(wallet_handle, SCHEMA_CACHE, options.max_age, None)?;
+
+        trace!("purge_schema_cache <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn purge_cred_def_cache(&self,
+                            wallet_handle: WalletHandle,
+                            options_json: &str) -> IndyResult<()> {
+        trace!("purge_cred_def_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
+
+        let options = serde_json::from_str::<PurgeOptions>(options_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
+
+        let res = self._purge(wallet_handle, CRED_DEF_CACHE, options.max_age, None)?;
+
+        trace!("purge_cred_def_cache <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn get_request(&self,
+                   pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   request_json: &str,
+                   options_json: &str,
+                   cb: Box<Fn(IndyResult<String>) + Send>) {
+        trace!("get_request >>> pool_handle: {:?}, wallet_handle: {:?}, request_json: {:?}, options_json: {:?}",
+               pool_handle, wallet_handle, request_json, options_json);
+
+        let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
+
+        let (_, id) = try_cb!(_request_cache_key(request_json), cb);
+
+        if let Some(cached) = try_cb!(self._get_cached(wallet_handle, REQUEST_CACHE, &id, &options), cb) {
+            return cb(Ok(cached));
+        }
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let cb_id = ::utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        let request_json = request_json.to_string();
+
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    request_json.clone(),
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(
+                                CacheCommand::GetRequestContinue(
+                                    pool_handle,
+                                    wallet_handle,
+                                    request_json.clone(),
+                                    ledger_response,
+                                    options.clone(),
+                                    cb_id,
+                                )
+                            )
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn _get_request_continue(&self, pool_handle: PoolHandle, wallet_handle: WalletHandle, request_json: &str, ledger_response: IndyResult<String>, options: GetCacheOptions, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let response = try_cb!(ledger_response, cb);
+
+        // Only replies confirmed by state proof are stored, consensus replies are always re-read from the ledger
+        if !options.no_store.unwrap_or(false) {
+            match self.pool_service.check_state_proof(pool_handle, request_json, &response) {
+                Ok(true) => {
+                    let (txn_type, id) = try_cb!(_request_cache_key(request_json), cb);
+                    let mut tags = Tags::new();
+                    tags.insert("txnType".to_string(), txn_type);
+                    self._store(wallet_handle, REQUEST_CACHE, &id, &response, tags);
                 }
-            };
-            json!({"timestamp": {"$lt": ts - max_age}}).to_string()
-        } else {
-            "{}".to_string()
+                Ok(false) => debug!("_get_request_continue: reply isn't confirmed by state proof, skip storing"),
+                Err(err) => warn!("Cannot check state proof of reply: {:?}", err),
+            }
+        }
+
+        cb(Ok(response));
+    }
+
+    fn resolve_did(&self,
+                   pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: &str,
+                   options_json: &str,
+                   cb: Box<Fn(IndyResult<String>) + Send>) {
+        trace!("resolve_did >>> pool_handle: {:?}, wallet_handle: {:?}, did: {:?}, options_json: {:?}",
+               pool_handle, wallet_handle, did, options_json);
+
+        let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
+
+        let did = unqualify_did(did).to_string();
+
+        if let Some(cached) = try_cb!(self._get_cached(wallet_handle, DID_DOC_CACHE, &did, &options), cb) {
+            return cb(Ok(cached));
+        }
+
+        if options.no_update.unwrap_or(false) {
+            return cb(Err(IndyError::from(IndyErrorKind::LedgerItemNotFound)));
+        }
+
+        let request_json = try_cb!(self.ledger_service.build_get_nym_request(None, &did), cb);
+
+        let cb_id = ::utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+        self.pending_resolves.borrow_mut().insert(cb_id, PendingResolve {
+            pool_handle,
+            wallet_handle,
+            did,
+            options,
+            request_json: request_json.clone(),
+            nym: None,
+        });
+
+        CacheCommandExecutor::_submit_request(pool_handle, request_json, cb_id, CacheCommand::ResolveDidNymContinue);
+    }
+
+    fn _resolve_did_nym_continue(&self, ledger_response: IndyResult<String>, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let mut pending = self.pending_resolves.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let response = try_cb!(ledger_response, cb);
+
+        let nym = try_cb!(self.ledger_service.get_parsed_nym(&response), cb);
+        let state_proof = self._check_state_proof(pending.pool_handle, &pending.request_json, &response);
+
+        let request_json = try_cb!(self.ledger_service.build_get_attrib_request(None, &pending.did, Some("endpoint"), None, None), cb);
+
+        let pool_handle = pending.pool_handle;
+        pending.nym = Some((nym, state_proof));
+        pending.request_json = request_json.clone();
+
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+        self.pending_resolves.borrow_mut().insert(cb_id, pending);
+
+        CacheCommandExecutor::_submit_request(pool_handle, request_json, cb_id, CacheCommand::ResolveDidEndpointContinue);
+    }
+
+    fn _resolve_did_endpoint_continue(&self, ledger_response: IndyResult<String>, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pending = self.pending_resolves.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        let response = try_cb!(ledger_response, cb);
+
+        // absence of endpoint is proved by state proof too
+        let endpoint_state_proof = self._check_state_proof(pending.pool_handle, &pending.request_json, &response);
+
+        let endpoint: Option<(ParsedAttrib, bool)> = match self.ledger_service.get_parsed_attrib(&response) {
+            Ok(attrib) => Some((attrib, endpoint_state_proof)),
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => None,
+            Err(err) => return cb(Err(err))
+        };
+
+        let (nym, nym_state_proof) = pending.nym.expect("FIXME INVALID STATE");
+
+        let result = DidResolutionResult::new(&nym, nym_state_proof,
+                                              endpoint.as_ref().map(|&(ref attrib, state_proof)| (attrib, state_proof)));
+
+        let result_json = try_cb!(serde_json::to_string(&result).to_indy(IndyErrorKind::InvalidState, "Cannot serialize DID resolution result"), cb);
+
+        // Only DID documents confirmed by state proofs are stored, as in `_get_request_continue`
+        if !pending.options.no_store.unwrap_or(false) {
+            if nym_state_proof && endpoint_state_proof {
+                self._store(pending.wallet_handle, DID_DOC_CACHE, &pending.did, &result_json, Tags::new());
+            } else {
+                debug!("_resolve_did_endpoint_continue: DID document isn't confirmed by state proof, skip storing");
+            }
+        }
+
+        trace!("resolve_did <<< result_json: {:?}", result_json);
+
+        cb(Ok(result_json));
+    }
+
+    fn _submit_request(pool_handle: PoolHandle, request_json: String, cb_id: i32, continue_command: fn(IndyResult<String>, i32) -> CacheCommand) {
+        CommandExecutor::instance().send(
+            Command::Ledger(
+                LedgerCommand::SubmitRequest(
+                    pool_handle,
+                    request_json,
+                    Box::new(move |ledger_response| {
+                        CommandExecutor::instance().send(
+                            Command::Cache(continue_command(ledger_response, cb_id))
+                        ).unwrap();
+                    })
+                )
+            )
+        ).unwrap();
+    }
+
+    fn get_revocation_history(&self,
+                              pool_handle: PoolHandle,
+                              wallet_handle: WalletHandle,
+                              rev_reg_def_id: &str,
+                              from: u64,
+                              to: u64,
+                              options_json: &str,
+                              cb: Box<Fn(IndyResult<String>) + Send>) {
+        trace!("get_revocation_history >>> pool_handle: {:?}, wallet_handle: {:?}, rev_reg_def_id: {:?}, from: {:?}, to: {:?}, options_json: {:?}",
+               pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json);
+
+        let options = try_cb!(serde_json::from_str::<GetCacheOptions>(options_json).to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options"), cb);
+
+        // the latest state is always read from the ledger as new entries can be written until `to`
+        let request_json = try_cb!(self.ledger_service.build_get_revoc_reg_delta_request(None, rev_reg_def_id, None, to as i64), cb);
+
+        let cb_id = ::utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+        self.pending_histories.borrow_mut().insert(cb_id, PendingRevocationHistory {
+            pool_handle,
+            wallet_handle,
+            rev_reg_def_id: rev_reg_def_id.to_string(),
+            from,
+            options,
+            states: Vec::new(),
+        });
+
+        CacheCommandExecutor::_submit_request(pool_handle, request_json, cb_id, CacheCommand::GetRevocationHistoryContinue);
+    }
+
+    fn _get_revocation_history_continue(&self, ledger_response: IndyResult<String>, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let mut pending = self.pending_histories.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+
+        // LedgerItemNotFound means that there are no registry entries before the requested timestamp
+        let state = match ledger_response.and_then(|response| self.ledger_service.parse_get_revoc_reg_state_response(&response)) {
+            Ok((_, state)) => Some(state),
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => None,
+            Err(err) => return cb(Err(err))
         };
 
+        // the preceding state of the last collected one is known now, so it can be stored
+        if let Some(last) = pending.states.last() {
+            self._store_revoc_reg_state(&pending, last, state.as_ref().map(|state| state.timestamp));
+        }
+
+        if let Some(state) = state {
+            pending.states.push(state);
+
+            if let Some(request_json) = try_cb!(self._next_revoc_reg_state_request(&mut pending), cb) {
+                let pool_handle = pending.pool_handle;
+
+                self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+                self.pending_histories.borrow_mut().insert(cb_id, pending);
+
+                return CacheCommandExecutor::_submit_request(pool_handle, request_json, cb_id, CacheCommand::GetRevocationHistoryContinue);
+            }
+        }
+
+        let history_json = try_cb!(_build_revocation_history(pending), cb);
+
+        trace!("get_revocation_history <<< history_json: {:?}", history_json);
+
+        cb(Ok(history_json));
+    }
+
+    /// Collects preceding registry states from the cache until `from` or the first registry entry is reached.
+    /// Returns the request for the preceding state if it isn't cached.
+    fn _next_revoc_reg_state_request(&self, pending: &mut PendingRevocationHistory) -> IndyResult<Option<String>> {
+        loop {
+            let timestamp = match pending.states.last() {
+                Some(state) if state.timestamp >= pending.from && state.timestamp > 0 => state.timestamp,
+                _ => return Ok(None)
+            };
+
+            match self._get_cached_revoc_reg_state(pending, timestamp)? {
+                Some((_, None)) => return Ok(None),
+                Some((_, Some(prev_timestamp))) => {
+                    if let Some((prev_state, _)) = self._get_cached_revoc_reg_state(pending, prev_timestamp)? {
+                        pending.states.push(prev_state);
+                        continue;
+                    }
+                }
+                None => {}
+            }
+
+            let request_json = self.ledger_service.build_get_revoc_reg_delta_request(None, &pending.rev_reg_def_id, None, timestamp as i64 - 1)?;
+            return Ok(Some(request_json));
+        }
+    }
+
+    fn _get_cached_revoc_reg_state(&self, pending: &PendingRevocationHistory, timestamp: u64) -> IndyResult<Option<(RevocationRegistryState, Option<u64>)>> {
+        if pending.options.no_cache.unwrap_or(false) {
+            return Ok(None);
+        }
+
         let options_json = json!({
             "retrieveType": false,
-            "retrieveValue": false,
-            "retrieveTags": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
         }).to_string();
 
-        let mut search = self.wallet_service.search_records(
-            wallet_handle,
-            SCHEMA_CACHE,
-            &query_json,
-            &options_json,
-        )?;
+        let record = match self.wallet_service.get_record(pending.wallet_handle, REV_REG_STATE_CACHE, &_rev_reg_state_cache_id(&pending.rev_reg_def_id, timestamp), &options_json) {
+            Ok(record) => record,
+            Err(err) => return if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
+        };
 
-        while let Some(record) = search.fetch_next_record()? {
-            self.wallet_service.delete_record(wallet_handle, SCHEMA_CACHE, record.get_id())?;
+        let state: RevocationRegistryState = serde_json::from_str(record.get_value().unwrap_or(""))
+            .to_indy(IndyErrorKind::InvalidState, "Cannot deserialize cached revocation registry state")?;
+
+        let prev_timestamp = record.get_tags()
+            .and_then(|tags| tags.get("prevTimestamp"))
+            .and_then(|prev_timestamp| prev_timestamp.parse().ok());
+
+        Ok(Some((state, prev_timestamp)))
+    }
+
+    /// Stores registry state along with the timestamp of the preceding one, none for the first registry entry.
+    fn _store_revoc_reg_state(&self, pending: &PendingRevocationHistory, state: &RevocationRegistryState, prev_timestamp: Option<u64>) {
+        if pending.options.no_store.unwrap_or(false) {
+            return;
         }
 
-        let res = ();
+        let state_json = match serde_json::to_string(state) {
+            Ok(state_json) => state_json,
+            Err(err) => return warn!("Cannot serialize revocation registry state: {:?}", err)
+        };
 
-        trace!("purge_schema_cache <<< res: {:?}", res);
+        let mut tags = Tags::new();
+        tags.insert("revRegDefId".to_string(), pending.rev_reg_def_id.clone());
+        if let Some(prev_timestamp) = prev_timestamp {
+            tags.insert("prevTimestamp".to_string(), prev_timestamp.to_string());
+        }
+
+        self._store(pending.wallet_handle, REV_REG_STATE_CACHE, &_rev_reg_state_cache_id(&pending.rev_reg_def_id, state.timestamp), &state_json, tags);
+    }
+
+    fn _check_state_proof(&self, pool_handle: PoolHandle, request_json: &str, response: &str) -> bool {
+        self.pool_service.check_state_proof(pool_handle, request_json, response)
+            .unwrap_or_else(|err| {
+                warn!("Cannot check state proof of reply: {:?}", err);
+                false
+            })
+    }
+
+    fn purge_rich_schema_cache(&self,
+                               wallet_handle: WalletHandle,
+                               options_json: &str) -> IndyResult<()> {
+        trace!("purge_rich_schema_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
+
+        let options = serde_json::from_str::<PurgeOptions>(options_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
+
+        let res = self._purge(wallet_handle, RICH_SCHEMA_CACHE, options.max_age, None)?;
+
+        trace!("purge_rich_schema_cache <<< res: {:?}", res);
 
         Ok(res)
     }
 
-    fn purge_cred_def_cache(&self,
-                            wallet_handle: WalletHandle,
-                            options_json: &str) -> IndyResult<()> {
-        trace!("purge_cred_def_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
+    fn purge_did_doc_cache(&self,
+                           wallet_handle: WalletHandle,
+                           options_json: &str) -> IndyResult<()> {
+        trace!("purge_did_doc_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
 
         let options = serde_json::from_str::<PurgeOptions>(options_json)
             .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
 
-        let max_age = options.max_age.unwrap_or(-1);
-        let query_json = if max_age >= 0 {
-            let ts = match SystemTime::now().duration_since(UNIX_EPOCH) {
-                Ok(ts) => ts.as_secs() as i32,
-                Err(err) => {
-                    error!("Cannot get time: {:?}", err);
-                    return Err(IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot get time: {:?}", err)))
-                }
-            };
-            json!({"timestamp": {"$lt": ts - max_age}}).to_string()
-        } else {
-            "{}".to_string()
+        let res = self._purge(wallet_handle, DID_DOC_CACHE, options.max_age, None)?;
+
+        trace!("purge_did_doc_cache <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn purge_rev_reg_state_cache(&self,
+                                 wallet_handle: WalletHandle,
+                                 options_json: &str) -> IndyResult<()> {
+        trace!("purge_rev_reg_state_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
+
+        let options = serde_json::from_str::<PurgeOptions>(options_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
+
+        let res = self._purge(wallet_handle, REV_REG_STATE_CACHE, options.max_age, None)?;
+
+        trace!("purge_rev_reg_state_cache <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn purge_request_cache(&self,
+                           wallet_handle: WalletHandle,
+                           options_json: &str) -> IndyResult<()> {
+        trace!("purge_request_cache >>> wallet_handle: {:?}, options_json: {:?}", wallet_handle, options_json);
+
+        let options = serde_json::from_str::<PurgeRequestOptions>(options_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize options")?;
+
+        let res = self._purge(wallet_handle, REQUEST_CACHE, options.max_age, options.txn_type.as_ref().map(String::as_str))?;
+
+        trace!("purge_request_cache <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _get_cached(&self, wallet_handle: WalletHandle, type_: &str, id: &str, options: &GetCacheOptions) -> IndyResult<Option<String>> {
+        if options.no_cache.unwrap_or(false) {
+            return Ok(None);
+        }
+
+        let options_json = json!({
+            "retrieveType": false,
+            "retrieveValue": true,
+            "retrieveTags": true,
+        }).to_string();
+
+        let cache = match self.wallet_service.get_record(wallet_handle, type_, id, &options_json) {
+            Ok(record) => record,
+            Err(err) => return if err.kind() == IndyErrorKind::WalletItemNotFound { Ok(None) } else { Err(err) }
         };
 
+        let min_fresh = options.min_fresh.unwrap_or(-1);
+        if min_fresh >= 0 {
+            let ts = _now()?;
+            if ts - min_fresh > cache.get_tags().unwrap_or(&Tags::new()).get("timestamp").unwrap_or(&"-1".to_string()).parse().unwrap_or(-1) {
+                return Ok(None);
+            }
+        }
+
+        Ok(Some(cache.get_value().unwrap_or("").to_string()))
+    }
+
+    fn _store(&self, wallet_handle: WalletHandle, type_: &str, id: &str, value: &str, mut tags: Tags) {
+        let ts = _now().unwrap_or_else(|err| {
+            warn!("{:?}", err);
+            0
+        });
+        tags.insert("timestamp".to_string(), ts.to_string());
+        let _ = self.wallet_service.delete_record(wallet_handle, type_, id);
+        let _ = self.wallet_service.add_record(wallet_handle, type_, id, value, &tags);
+    }
+
+    fn _purge(&self, wallet_handle: WalletHandle, type_: &str, max_age: Option<i32>, txn_type: Option<&str>) -> IndyResult<()> {
+        let mut query = json!({});
+
+        let max_age = max_age.unwrap_or(-1);
+        if max_age >= 0 {
+            query["timestamp"] = json!({"$lt": _now()? - max_age});
+        }
+
+        if let Some(txn_type) = txn_type {
+            query["txnType"] = json!(txn_type);
+        }
+
         let options_json = json!({
             "retrieveType": false,
             "retrieveValue": false,
@@ -355,21 +892,87 @@ impl CacheCommandExecutor {
 
         let mut search = self.wallet_service.search_records(
             wallet_handle,
-            CRED_DEF_CACHE,
-            &query_json,
+            type_,
+            &query.to_string(),
             &options_json,
         )?;
 
         while let Some(record) = search.fetch_next_record()? {
-            self.wallet_service.delete_record(wallet_handle, CRED_DEF_CACHE, record.get_id())?;
+            self.wallet_service.delete_record(wallet_handle, type_, record.get_id())?;
         }
 
-        let res = ();
+        Ok(())
+    }
+}
 
-        trace!("purge_cred_def_cache <<< res: {:?}", res);
+fn _now() -> IndyResult<i32> {
+    SystemTime::now().duration_since(UNIX_EPOCH)
+        .map(|ts| ts.as_secs() as i32)
+        .map_err(|err| {
+            error!("Cannot get time: {:?}", err);
+            IndyError::from_msg(IndyErrorKind::InvalidState, format!("Cannot get time: {:?}", err))
+        })
+}
 
-        Ok(res)
+fn _rev_reg_state_cache_id(rev_reg_def_id: &str, timestamp: u64) -> String {
+    format!("{}:{}", rev_reg_def_id, timestamp)
+}
+
+/// Builds history of registry entries not older than `from` out of registry states collected from the latest one backwards.
+fn _build_revocation_history(pending: PendingRevocationHistory) -> IndyResult<String> {
+    let PendingRevocationHistory { rev_reg_def_id, from, mut states, .. } = pending;
+    states.reverse();
+
+    let mut entries = Vec::new();
+    let mut delta: Option<RevocationRegistryDeltaV1> = None;
+
+    for (i, state) in states.iter().enumerate() {
+        if state.timestamp < from {
+            continue;
+        }
+
+        let prev = if i > 0 { states.get(i - 1) } else { None };
+        let (issued, revoked) = state.changes_since(prev);
+
+        let entry_delta = CryptoRevocationRegistryDelta::from_parts(prev.map(|prev| &prev.value),
+                                                                    &state.value,
+                                                                    &issued.iter().cloned().collect::<HashSet<u32>>(),
+                                                                    &revoked.iter().cloned().collect::<HashSet<u32>>());
+
+        match delta {
+            Some(ref mut delta) => delta.value.merge(&entry_delta)?,
+            None => delta = Some(RevocationRegistryDeltaV1 { value: entry_delta })
+        };
+
+        entries.push(RevocationHistoryEntry {
+            timestamp: state.timestamp,
+            value: state.value.clone(),
+            issued,
+            revoked,
+        });
     }
+
+    let history = RevocationHistory {
+        rev_reg_def_id,
+        entries,
+        delta: delta.map(RevocationRegistryDelta::RevocationRegistryDeltaV1),
+    };
+
+    serde_json::to_string(&history)
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize revocation history")
+}
+
+/// Cache key of read request is its operation, so requests which differ only in reqId, submitter or signature share one cache entry.
+fn _request_cache_key(request_json: &str) -> IndyResult<(String, String)> {
+    let request: serde_json::Value = serde_json::from_str(request_json)
+        .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+    let operation = &request["operation"];
+
+    let txn_type = operation["type"].as_str()
+        .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))?;
+
+    Ok((txn_type.to_string(), operation.to_string()))
 }
 
 #[serde(rename_all = "camelCase")]
@@ -378,6 +981,13 @@ struct PurgeOptions {
     pub max_age: Option<i32>,
 }
 
+#[serde(rename_all = "camelCase")]
+#[derive(Debug, Deserialize, Serialize)]
+struct PurgeRequestOptions {
+    pub max_age: Option<i32>,
+    pub txn_type: Option<String>,
+}
+
 #[serde(rename_all = "camelCase")]
 #[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct GetCacheOptions {
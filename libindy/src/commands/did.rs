@@ -546,6 +546,8 @@ impl DidCommandExecutor {
             GetAttrReplyResult::GetAttrReplyResultV1(res) => (res.txn.data.raw, res.txn.data.did)
         };
 
+        let raw = raw.ok_or(err_msg(IndyErrorKind::InvalidState, "Invalid GetAttReply json"))?;
+
         let attrib_data: AttribData = serde_json::from_str(&raw)
             .to_indy(IndyErrorKind::InvalidState, "Invalid GetAttReply json")?;
 
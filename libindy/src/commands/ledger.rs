@@ -1,7 +1,11 @@
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
+use std::thread;
+use std::time::{Duration, Instant};
 
+use rand;
+use rand::Rng;
 use serde_json;
 use serde_json::Value;
 
@@ -10,24 +14,31 @@ use domain::anoncreds::credential_definition::{CredentialDefinition, CredentialD
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::schema::{Schema, SchemaV1};
-use domain::crypto::did::Did;
-use domain::crypto::key::Key;
-use domain::ledger::node::NodeOperationData;
+use domain::crypto::did::{Did, MyDidInfo};
+use domain::crypto::key::{Key, KeyInfo};
+use domain::ledger::genesis::{GenesisConfig, GenesisNode};
+use domain::ledger::node::{NodeOperationData, Services};
 use domain::ledger::author_agreement::{GetTxnAuthorAgreementData, AcceptanceMechanisms};
-use domain::ledger::auth_rule::AuthRules;
-use domain::ledger::request::Request;
+use domain::ledger::auth_rule::{AuthRule, AuthRules, SubmitterRoleInfo};
+use domain::ledger::request::{Request, TxnAuthrAgrmtAcceptanceData};
+use domain::ledger::confirm::{ConfirmOptions, ConfirmStep};
+use domain::ledger::endorsement::{EndorsementBundle, EndorsementReview, SignatureCheck, request_signatures};
+use domain::ledger::nym::ParsedNym;
+use domain::ledger::txn::ParsedTxn;
+use domain::pool::WriteRetryPolicy;
 use errors::prelude::*;
 use services::crypto::CryptoService;
 use services::ledger::LedgerService;
+use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::{
     PoolService,
     parse_response_metadata
 };
 use services::wallet::{RecordOptions, WalletService};
 use utils::crypto::signature_serializer::serialize_signature;
-use api::WalletHandle;
+use api::{ErrorCode, WalletHandle};
 use commands::{Command, CommandExecutor};
-use rust_base58::ToBase58;
+use rust_base58::{FromBase58, ToBase58};
 
 pub enum LedgerCommand {
     SignAndSubmitRequest(
@@ -44,6 +55,31 @@ pub enum LedgerCommand {
         i32, // cmd_id
         IndyResult<String>, // result json or error
     ),
+    SubmitRequestAndConfirm(
+        i32, // pool handle
+        String, // request json
+        Option<ConfirmOptions>,
+        Box<Fn(IndyResult<String>) + Send>),
+    SubmitRequestAndConfirmContinue(
+        IndyResult<String>,
+        i32,
+    ),
+    ConfirmWrite(
+        i32,
+    ),
+    ConfirmWriteContinue(
+        IndyResult<String>,
+        i32,
+    ),
+    SubmitRequestsBatch(
+        i32, // pool handle
+        Vec<Value>, // requests
+        Box<Fn(IndyResult<String>) + Send>),
+    SubmitRequestsBatchContinue(
+        IndyResult<String>, // pool response
+        i32, // batch id
+        usize, // request index
+    ),
     SubmitAction(
         i32, // pool handle
         String, // request json
@@ -85,10 +121,16 @@ pub enum LedgerCommand {
         Option<String>, // hash
         Option<String>, // enc
         Box<Fn(IndyResult<String>) + Send>),
+    ParseGetAttribResponse(
+        String, // get attrib response json
+        Box<Fn(IndyResult<String>) + Send>),
     BuildGetNymRequest(
         Option<String>, // submitter did
         String, // target did
         Box<Fn(IndyResult<String>) + Send>),
+    ParseGetNymResponse(
+        String, // get nym response json
+        Box<Fn(IndyResult<String>) + Send>),
     BuildSchemaRequest(
         String, // submitter did
         Schema, // data
@@ -116,6 +158,12 @@ pub enum LedgerCommand {
         String, // target_did
         NodeOperationData, // data
         Box<Fn(IndyResult<String>) + Send>),
+    GenerateBlsKeys(
+        Option<String>, // seed
+        Box<Fn(IndyResult<(String, String)>) + Send>),
+    BuildGenesisTransactions(
+        GenesisConfig, // nodes config
+        Box<Fn(IndyResult<(String, String)>) + Send>),
     BuildGetValidatorInfoRequest(
         String, // submitter did
         Box<Fn(IndyResult<String>) + Send>),
@@ -124,6 +172,9 @@ pub enum LedgerCommand {
         Option<String>, // ledger type
         i32, // data
         Box<Fn(IndyResult<String>) + Send>),
+    ParseGetTxnResponse(
+        String, // get txn response json
+        Box<Fn(IndyResult<String>) + Send>),
     BuildPoolConfigRequest(
         String, // submitter did
         bool, // writes
@@ -147,6 +198,13 @@ pub enum LedgerCommand {
         bool, // force
         Option<String>, // package
         Box<Fn(IndyResult<String>) + Send>),
+    BuildLedgersFreezeRequest(
+        String, // submitter did
+        Vec<u64>, // ledgers ids
+        Box<Fn(IndyResult<String>) + Send>),
+    BuildGetFrozenLedgersRequest(
+        Option<String>, // submitter did
+        Box<Fn(IndyResult<String>) + Send>),
     BuildRevocRegDefRequest(
         String, // submitter did
         RevocationRegistryDefinition, // data
@@ -210,12 +268,40 @@ pub enum LedgerCommand {
         Option<String>, // old value
         Option<String>, // new value
         Box<Fn(IndyResult<String>) + Send>),
+    CheckRequestAuth(
+        i32, // pool handle
+        String, // request json
+        String, // submitter role info json
+        Box<Fn(IndyResult<String>) + Send>),
+    CheckRequestAuthRulesContinue(
+        IndyResult<String>, // GET_AUTH_RULE response
+        i32, // cb_id
+    ),
+    CheckRequestAuthNymContinue(
+        IndyResult<String>, // GET_NYM response
+        i32, // cb_id
+    ),
     GetSchema(
         i32,
         Option<String>,
         String,
         Box<Fn(IndyResult<(String, String)>) + Send>,
     ),
+    SubmitWriteContinue(
+        IndyResult<String>,
+        i32,
+    ),
+    CheckWriteOrdered(
+        i32,
+    ),
+    CheckWriteOrderedContinue(
+        IndyResult<String>,
+        i32,
+    ),
+    CheckWriteTxnContinue(
+        IndyResult<String>,
+        i32,
+    ),
     GetSchemaContinue(
         IndyResult<String>,
         i32,
@@ -230,6 +316,38 @@ pub enum LedgerCommand {
         IndyResult<String>,
         i32,
     ),
+    BuildRichSchemaRequest(
+        String, // submitter did
+        String, // id
+        String, // content
+        String, // rs name
+        String, // rs version
+        String, // rs type
+        Option<String>, // ver
+        Box<Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByIdRequest(
+        Option<String>, // submitter did
+        String, // id
+        Box<Fn(IndyResult<String>) + Send>),
+    BuildGetRichSchemaObjectByMetadataRequest(
+        Option<String>, // submitter did
+        String, // rs type
+        String, // rs name
+        String, // rs version
+        Box<Fn(IndyResult<String>) + Send>),
+    ParseGetRichSchemaObjectResponse(
+        String, // get rich schema object response json
+        Box<Fn(IndyResult<(String, String)>) + Send>),
+    GetRichSchemaObject(
+        i32,
+        Option<String>,
+        String,
+        Box<Fn(IndyResult<(String, String)>) + Send>,
+    ),
+    GetRichSchemaObjectContinue(
+        IndyResult<String>,
+        i32,
+    ),
     BuildTxnAuthorAgreementRequest(
         String, // submitter did
         String, // text
@@ -258,10 +376,73 @@ pub enum LedgerCommand {
         String, // acceptance mechanism type
         u64, // time of acceptance
         Box<Fn(IndyResult<String>) + Send>),
+    SetTxnAuthorAgreementAcceptance(
+        i32, // pool handle
+        Option<String>, // text
+        Option<String>, // version
+        Option<String>, // hash
+        String, // acceptance mechanism type
+        u64, // time of acceptance
+        Box<Fn(IndyResult<()>) + Send>),
+    SetTxnAuthorAgreementAcceptanceTaaContinue(
+        IndyResult<String>, // GET_TXN_AUTHR_AGRMT response
+        i32, // cb_id
+    ),
+    SetTxnAuthorAgreementAcceptanceAmlContinue(
+        IndyResult<String>, // GET_TXN_AUTHR_AGRMT_AML response
+        i32, // cb_id
+    ),
     AppendRequestEndorser(
         String, // request json
         String, // endorser did
         Box<Fn(IndyResult<String>) + Send>),
+    BuildEndorsementBundle(
+        String, // request json
+        Option<Value>, // metadata
+        Box<Fn(IndyResult<String>) + Send>),
+    ReviewEndorsementBundle(
+        i32, // pool handle
+        String, // bundle json
+        Box<Fn(IndyResult<String>) + Send>),
+    EndorseRequest(
+        i32, // pool handle
+        WalletHandle,
+        String, // endorser did
+        String, // bundle json
+        Box<Fn(IndyResult<String>) + Send>),
+    EndorsementNymContinue(
+        IndyResult<String>, // GET_NYM response
+        i32, // cb_id
+    ),
+    VerifyTxnInclusion(
+        String, // root hash
+        String, // txn json
+        Option<String>, // audit path json
+        Option<usize>, // ledger size
+        Box<Fn(IndyResult<bool>) + Send>),
+    OpenLedgerIterator(
+        i32, // pool handle
+        Option<String>, // ledger type
+        u64, // from
+        Option<u64>, // to
+        Box<Fn(IndyResult<i32>) + Send>),
+    OpenLedgerIteratorContinue(
+        IndyResult<String>, // LEDGER_STATUS reply
+        i32, // iterator handle
+    ),
+    LedgerIteratorNext(
+        i32, // iterator handle
+        Box<Fn(IndyResult<String>) + Send>),
+    LedgerIteratorNextContinue(
+        IndyResult<String>, // CATCHUP_REP reply
+        i32, // iterator handle
+    ),
+    CloseLedgerIterator(
+        i32, // iterator handle
+        Box<Fn(IndyResult<()>) + Send>),
+    PoolClosed(
+        i32, // pool handle
+    ),
 }
 
 pub struct LedgerCommandExecutor {
@@ -272,8 +453,100 @@ pub struct LedgerCommandExecutor {
 
     send_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<String>)>>>,
     pending_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<(String, String)>)>>>,
+    pending_writes: RefCell<HashMap<i32, PendingWrite>>,
+    pending_confirms: RefCell<HashMap<i32, PendingConfirm>>,
+    pending_auth_checks: RefCell<HashMap<i32, PendingAuthCheck>>,
+    auth_rules: RefCell<HashMap<i32, (Instant, Vec<AuthRule>)>>,
+    pending_endorsements: RefCell<HashMap<i32, PendingEndorsement>>,
+    pending_batches: RefCell<HashMap<i32, PendingRequestsBatch>>,
+    pending_taa_acceptances: RefCell<HashMap<i32, PendingTaaAcceptance>>,
+    taa_acceptances: RefCell<HashMap<i32, TxnAuthrAgrmtAcceptanceData>>,
+    pending_iterator_opens: RefCell<HashMap<i32, PendingLedgerIteratorOpen>>,
+    ledger_iterators: RefCell<HashMap<i32, LedgerIterator>>,
+}
+
+struct PendingWrite {
+    pool_handle: i32,
+    request_json: String,
+    check_request_json: String,
+    policy: WriteRetryPolicy,
+    attempt: u32,
+    cb: Box<Fn(IndyResult<String>) + Send>,
+}
+
+struct PendingConfirm {
+    pool_handle: i32,
+    request_json: String,
+    check_request_json: String,
+    options: ConfirmOptions,
+    deadline: Option<Instant>,
+    seq_no: u64,
+    reply: String,
+    cb: Box<Fn(IndyResult<String>) + Send>,
+}
+
+struct PendingAuthCheck {
+    pool_handle: i32,
+    request_json: String,
+    role_info: SubmitterRoleInfo,
+    nym_dest: Option<String>,
+    current_nym: Option<Option<ParsedNym>>,
+    cb: Box<Fn(IndyResult<String>) + Send>,
+}
+
+struct PendingEndorsement {
+    pool_handle: i32,
+    bundle: EndorsementBundle,
+    signatures: Vec<(String, String)>,
+    checks: Vec<SignatureCheck>,
+    // wallet and DID of endorser if request must be signed after review
+    endorse: Option<(WalletHandle, String)>,
+    cb: Box<Fn(IndyResult<String>) + Send>,
+}
+
+struct PendingRequestsBatch {
+    pool_handle: i32,
+    requests: Vec<String>,
+    // index of the next request to send, requests are sent in order
+    next: usize,
+    results: Vec<Option<Value>>,
+    cb: Box<Fn(IndyResult<String>) + Send>,
+}
+
+struct PendingTaaAcceptance {
+    pool_handle: i32,
+    acceptance: TxnAuthrAgrmtAcceptanceData,
+    get_taa_response: Option<String>,
+    cb: Box<Fn(IndyResult<()>) + Send>,
+}
+
+struct PendingLedgerIteratorOpen {
+    pool_handle: i32,
+    ledger_id: usize,
+    from: u64,
+    to: Option<u64>,
+    cb: Box<Fn(IndyResult<i32>) + Send>,
 }
 
+struct LedgerIterator {
+    pool_handle: i32,
+    ledger_id: usize,
+    from: usize,
+    to: usize,
+    // size and root of the ledger agreed by nodes when iterator was opened
+    target_mt_size: usize,
+    target_mt_root: Vec<u8>,
+    // all transactions fetched so far starting from the first one, as they are needed to verify the next batch
+    merkle_tree: MerkleTree,
+    cb: Option<Box<Fn(IndyResult<String>) + Send>>,
+}
+
+/// Auth rules of the pool are re-read from the ledger after this many seconds.
+const AUTH_RULES_CACHE_TTL: u64 = 600;
+
+/// Count of transactions ledger iterator requests from a node at once.
+const LEDGER_ITERATOR_BATCH_SIZE: usize = 100;
+
 impl LedgerCommandExecutor {
     pub fn new(pool_service: Rc<PoolService>,
                crypto_service: Rc<CryptoService>,
@@ -286,6 +559,16 @@ impl LedgerCommandExecutor {
             ledger_service,
             send_callbacks: RefCell::new(HashMap::new()),
             pending_callbacks: RefCell::new(HashMap::new()),
+            pending_writes: RefCell::new(HashMap::new()),
+            pending_confirms: RefCell::new(HashMap::new()),
+            pending_auth_checks: RefCell::new(HashMap::new()),
+            auth_rules: RefCell::new(HashMap::new()),
+            pending_endorsements: RefCell::new(HashMap::new()),
+            pending_batches: RefCell::new(HashMap::new()),
+            pending_taa_acceptances: RefCell::new(HashMap::new()),
+            taa_acceptances: RefCell::new(HashMap::new()),
+            pending_iterator_opens: RefCell::new(HashMap::new()),
+            ledger_iterators: RefCell::new(HashMap::new()),
         }
     }
 
@@ -309,6 +592,30 @@ impl LedgerCommandExecutor {
                     }
                 }
             }
+            LedgerCommand::SubmitRequestAndConfirm(handle, request_json, options, cb) => {
+                info!(target: "ledger_command_executor", "SubmitRequestAndConfirm command received");
+                self.submit_request_and_confirm(handle, &request_json, options.unwrap_or_default(), cb);
+            }
+            LedgerCommand::SubmitRequestAndConfirmContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "SubmitRequestAndConfirmContinue command received");
+                self._submit_request_and_confirm_continue(pool_response, cb_id);
+            }
+            LedgerCommand::ConfirmWrite(cb_id) => {
+                info!(target: "ledger_command_executor", "ConfirmWrite command received");
+                self._confirm_write(cb_id);
+            }
+            LedgerCommand::ConfirmWriteContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "ConfirmWriteContinue command received");
+                self._confirm_write_continue(pool_response, cb_id);
+            }
+            LedgerCommand::SubmitRequestsBatch(handle, requests, cb) => {
+                info!(target: "ledger_command_executor", "SubmitRequestsBatch command received");
+                self.submit_requests_batch(handle, requests, cb);
+            }
+            LedgerCommand::SubmitRequestsBatchContinue(pool_response, batch_id, index) => {
+                info!(target: "ledger_command_executor", "SubmitRequestsBatchContinue command received");
+                self._submit_requests_batch_continue(pool_response, batch_id, index);
+            }
             LedgerCommand::SubmitAction(handle, request_json, nodes, timeout, cb) => {
                 info!(target: "ledger_command_executor", "SubmitRequest command received");
                 self.submit_action(handle, &request_json, nodes.as_ref().map(String::as_str), timeout, cb);
@@ -350,10 +657,18 @@ impl LedgerCommandExecutor {
                                                  hash.as_ref().map(String::as_str),
                                                  enc.as_ref().map(String::as_str)));
             }
+            LedgerCommand::ParseGetAttribResponse(get_attrib_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetAttribResponse command received");
+                cb(self.parse_get_attrib_response(&get_attrib_response));
+            }
             LedgerCommand::BuildGetNymRequest(submitter_did, target_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetNymRequest command received");
                 cb(self.build_get_nym_request(submitter_did.as_ref().map(String::as_str), &target_did));
             }
+            LedgerCommand::ParseGetNymResponse(get_nym_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetNymResponse command received");
+                cb(self.parse_get_nym_response(&get_nym_response));
+            }
             LedgerCommand::BuildSchemaRequest(submitter_did, data, cb) => {
                 info!(target: "ledger_command_executor", "BuildSchemaRequest command received");
                 cb(self.build_schema_request(&submitter_did, SchemaV1::from(data)));
@@ -382,6 +697,14 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "BuildNodeRequest command received");
                 cb(self.build_node_request(&submitter_did, &target_did, data));
             }
+            LedgerCommand::GenerateBlsKeys(seed, cb) => {
+                info!(target: "ledger_command_executor", "GenerateBlsKeys command received");
+                cb(self.generate_bls_keys(seed.as_ref().map(String::as_str)));
+            }
+            LedgerCommand::BuildGenesisTransactions(nodes_config, cb) => {
+                info!(target: "ledger_command_executor", "BuildGenesisTransactions command received");
+                cb(self.build_genesis_transactions(nodes_config));
+            }
             LedgerCommand::BuildGetValidatorInfoRequest(submitter_did, cb) => {
                 info!(target: "ledger_command_executor", "BuildGetValidatorInfoRequest command received");
                 cb(self.build_get_validator_info_request(&submitter_did));
@@ -390,6 +713,10 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "BuildGetTxnRequest command received");
                 cb(self.build_get_txn_request(submitter_did.as_ref().map(String::as_str), ledger_type.as_ref().map(String::as_str), seq_no));
             }
+            LedgerCommand::ParseGetTxnResponse(get_txn_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetTxnResponse command received");
+                cb(self.parse_get_txn_response(&get_txn_response));
+            }
             LedgerCommand::BuildPoolConfigRequest(submitter_did, writes, force, cb) => {
                 info!(target: "ledger_command_executor", "BuildPoolConfigRequest command received");
                 cb(self.build_pool_config_request(&submitter_did, writes, force));
@@ -405,6 +732,14 @@ impl LedgerCommandExecutor {
                                                    justification.as_ref().map(String::as_str),
                                                    reinstall, force, package.as_ref().map(String::as_str)));
             }
+            LedgerCommand::BuildLedgersFreezeRequest(submitter_did, ledgers_ids, cb) => {
+                info!(target: "ledger_command_executor", "BuildLedgersFreezeRequest command received");
+                cb(self.build_ledgers_freeze_request(&submitter_did, ledgers_ids));
+            }
+            LedgerCommand::BuildGetFrozenLedgersRequest(submitter_did, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetFrozenLedgersRequest command received");
+                cb(self.build_get_frozen_ledgers_request(submitter_did.as_ref().map(String::as_str)));
+            }
             LedgerCommand::BuildRevocRegDefRequest(submitter_did, data, cb) => {
                 info!(target: "ledger_command_executor", "BuildRevocRegDefRequest command received");
                 cb(self.build_revoc_reg_def_request(&submitter_did, RevocationRegistryDefinitionV1::from(data)));
@@ -458,10 +793,38 @@ impl LedgerCommandExecutor {
                                                     old_value.as_ref().map(String::as_str),
                                                     new_value.as_ref().map(String::as_str)));
             }
+            LedgerCommand::CheckRequestAuth(pool_handle, request_json, submitter_role_info, cb) => {
+                info!(target: "ledger_command_executor", "CheckRequestAuth command received");
+                self.check_request_auth(pool_handle, &request_json, &submitter_role_info, cb);
+            }
+            LedgerCommand::CheckRequestAuthRulesContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "CheckRequestAuthRulesContinue command received");
+                self._check_request_auth_rules_continue(pool_response, cb_id);
+            }
+            LedgerCommand::CheckRequestAuthNymContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "CheckRequestAuthNymContinue command received");
+                self._check_request_auth_nym_continue(pool_response, cb_id);
+            }
             LedgerCommand::GetSchema(pool_handle, submitter_did, id, cb) => {
                 info!(target: "ledger_command_executor", "GetSchema command received");
                 self.get_schema(pool_handle, submitter_did.as_ref().map(String::as_str), &id, cb);
             }
+            LedgerCommand::SubmitWriteContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "SubmitWriteContinue command received");
+                self._submit_write_continue(pool_response, cb_id);
+            }
+            LedgerCommand::CheckWriteOrdered(cb_id) => {
+                info!(target: "ledger_command_executor", "CheckWriteOrdered command received");
+                self._check_write_ordered(cb_id);
+            }
+            LedgerCommand::CheckWriteOrderedContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "CheckWriteOrderedContinue command received");
+                self._check_write_ordered_continue(pool_response, cb_id);
+            }
+            LedgerCommand::CheckWriteTxnContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "CheckWriteTxnContinue command received");
+                self._check_write_txn_continue(pool_response, cb_id);
+            }
             LedgerCommand::GetSchemaContinue(pool_response, cb_id) => {
                 info!(target: "ledger_command_executor", "GetSchemaContinue command received");
                 self._get_schema_continue(pool_response, cb_id);
@@ -474,6 +837,31 @@ impl LedgerCommandExecutor {
                 info!(target: "ledger_command_executor", "GetCredDefContinue command received");
                 self._get_cred_def_continue(pool_response, cb_id);
             }
+            LedgerCommand::BuildRichSchemaRequest(submitter_did, id, content, rs_name, rs_version, rs_type, ver, cb) => {
+                info!(target: "ledger_command_executor", "BuildRichSchemaRequest command received");
+                cb(self.build_rich_schema_request(&submitter_did, &id, &content, &rs_name, &rs_version, &rs_type,
+                                                  ver.as_ref().map(String::as_str)));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByIdRequest(submitter_did, id, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByIdRequest command received");
+                cb(self.build_get_rich_schema_object_by_id_request(submitter_did.as_ref().map(String::as_str), &id));
+            }
+            LedgerCommand::BuildGetRichSchemaObjectByMetadataRequest(submitter_did, rs_type, rs_name, rs_version, cb) => {
+                info!(target: "ledger_command_executor", "BuildGetRichSchemaObjectByMetadataRequest command received");
+                cb(self.build_get_rich_schema_object_by_metadata_request(submitter_did.as_ref().map(String::as_str), &rs_type, &rs_name, &rs_version));
+            }
+            LedgerCommand::ParseGetRichSchemaObjectResponse(get_rich_schema_object_response, cb) => {
+                info!(target: "ledger_command_executor", "ParseGetRichSchemaObjectResponse command received");
+                cb(self.parse_get_rich_schema_object_response(&get_rich_schema_object_response));
+            }
+            LedgerCommand::GetRichSchemaObject(pool_handle, submitter_did, id, cb) => {
+                info!(target: "ledger_command_executor", "GetRichSchemaObject command received");
+                self.get_rich_schema_object(pool_handle, submitter_did.as_ref().map(String::as_str), &id, cb);
+            }
+            LedgerCommand::GetRichSchemaObjectContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "GetRichSchemaObjectContinue command received");
+                self._get_rich_schema_object_continue(pool_response, cb_id);
+            }
             LedgerCommand::BuildTxnAuthorAgreementRequest(submitter_did, text, version, cb) => {
                 info!(target: "ledger_command_executor", "BuildTxnAuthorAgreementRequest command received");
                 cb(self.build_txn_author_agreement_request(&submitter_did, &text, &version));
@@ -501,11 +889,76 @@ impl LedgerCommandExecutor {
                                                                           &acc_mech_type,
                                                                           time_of_acceptance));
             }
+            LedgerCommand::SetTxnAuthorAgreementAcceptance(pool_handle, text, version, hash, acc_mech_type, time_of_acceptance, cb) => {
+                info!(target: "ledger_command_executor", "SetTxnAuthorAgreementAcceptance command received");
+                self.set_txn_author_agreement_acceptance(pool_handle,
+                                                         text.as_ref().map(String::as_str),
+                                                         version.as_ref().map(String::as_str),
+                                                         hash.as_ref().map(String::as_str),
+                                                         &acc_mech_type,
+                                                         time_of_acceptance,
+                                                         cb);
+            }
+            LedgerCommand::SetTxnAuthorAgreementAcceptanceTaaContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "SetTxnAuthorAgreementAcceptanceTaaContinue command received");
+                self._set_txn_author_agreement_acceptance_taa_continue(pool_response, cb_id);
+            }
+            LedgerCommand::SetTxnAuthorAgreementAcceptanceAmlContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "SetTxnAuthorAgreementAcceptanceAmlContinue command received");
+                self._set_txn_author_agreement_acceptance_aml_continue(pool_response, cb_id);
+            }
             LedgerCommand::AppendRequestEndorser(request_json, endorser_did, cb) => {
                 info!(target: "ledger_command_executor", "AppendRequestEndorser command received");
                 cb(self.append_request_endorser(&request_json,
                                                 &endorser_did));
             }
+            LedgerCommand::BuildEndorsementBundle(request_json, metadata, cb) => {
+                info!(target: "ledger_command_executor", "BuildEndorsementBundle command received");
+                cb(self.build_endorsement_bundle(&request_json, metadata));
+            }
+            LedgerCommand::ReviewEndorsementBundle(pool_handle, bundle_json, cb) => {
+                info!(target: "ledger_command_executor", "ReviewEndorsementBundle command received");
+                self.review_endorsement_bundle(pool_handle, &bundle_json, None, cb);
+            }
+            LedgerCommand::EndorseRequest(pool_handle, wallet_handle, endorser_did, bundle_json, cb) => {
+                info!(target: "ledger_command_executor", "EndorseRequest command received");
+                self.endorse_request(pool_handle, wallet_handle, &endorser_did, &bundle_json, cb);
+            }
+            LedgerCommand::EndorsementNymContinue(pool_response, cb_id) => {
+                info!(target: "ledger_command_executor", "EndorsementNymContinue command received");
+                self._endorsement_nym_continue(pool_response, cb_id);
+            }
+            LedgerCommand::VerifyTxnInclusion(root_hash, txn_json, audit_path, ledger_size, cb) => {
+                info!(target: "ledger_command_executor", "VerifyTxnInclusion command received");
+                cb(self.verify_txn_inclusion(&root_hash,
+                                             &txn_json,
+                                             audit_path.as_ref().map(String::as_str),
+                                             ledger_size));
+            }
+            LedgerCommand::OpenLedgerIterator(pool_handle, ledger_type, from, to, cb) => {
+                info!(target: "ledger_command_executor", "OpenLedgerIterator command received");
+                self.open_ledger_iterator(pool_handle, ledger_type.as_ref().map(String::as_str), from, to, cb);
+            }
+            LedgerCommand::OpenLedgerIteratorContinue(pool_response, iterator_handle) => {
+                info!(target: "ledger_command_executor", "OpenLedgerIteratorContinue command received");
+                self._open_ledger_iterator_continue(pool_response, iterator_handle);
+            }
+            LedgerCommand::LedgerIteratorNext(iterator_handle, cb) => {
+                info!(target: "ledger_command_executor", "LedgerIteratorNext command received");
+                self.ledger_iterator_next(iterator_handle, cb);
+            }
+            LedgerCommand::LedgerIteratorNextContinue(pool_response, iterator_handle) => {
+                info!(target: "ledger_command_executor", "LedgerIteratorNextContinue command received");
+                self._ledger_iterator_next_continue(pool_response, iterator_handle);
+            }
+            LedgerCommand::CloseLedgerIterator(iterator_handle, cb) => {
+                info!(target: "ledger_command_executor", "CloseLedgerIterator command received");
+                cb(self.close_ledger_iterator(iterator_handle));
+            }
+            LedgerCommand::PoolClosed(pool_handle) => {
+                info!(target: "ledger_command_executor", "PoolClosed command received");
+                self.pool_closed(pool_handle);
+            }
         };
     }
 
@@ -527,7 +980,12 @@ impl LedgerCommandExecutor {
         debug!("sign_and_submit_request >>> pool_handle: {:?}, wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}",
                pool_handle, wallet_handle, submitter_did, request_json);
 
-        match self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single) {
+        let request_json = match self.taa_acceptances.borrow().get(&pool_handle) {
+            Some(acceptance) => try_cb!(self.ledger_service.append_acceptance_data_if_required(request_json, acceptance), cb),
+            None => request_json.to_string()
+        };
+
+        match self._sign_request(wallet_handle, submitter_did, &request_json, SignatureType::Single) {
             Ok(signed_request) => self.submit_request(pool_handle, signed_request.as_str(), cb),
             Err(err) => cb(Err(err))
         }
@@ -586,6 +1044,32 @@ impl LedgerCommandExecutor {
                       cb: Box<Fn(IndyResult<String>) + Send>) {
         debug!("submit_request >>> handle: {:?}, request_json: {:?}", handle, request_json);
 
+        let policy = try_cb!(self.pool_service.get_write_retry_policy(handle), cb);
+
+        if let Some(policy) = policy.filter(|policy| policy.max_attempts > 1) {
+            if let Some(check_request_json) = self.ledger_service.build_write_check_request(request_json) {
+                let cb_id = ::utils::sequence::get_next_id();
+
+                self.pending_writes.borrow_mut().insert(cb_id, PendingWrite {
+                    pool_handle: handle,
+                    request_json: request_json.to_string(),
+                    check_request_json,
+                    policy,
+                    attempt: 0,
+                    cb,
+                });
+
+                return self._submit_write(cb_id);
+            }
+        }
+
+        self._send_tx(handle, request_json, cb);
+    }
+
+    fn _send_tx(&self,
+                handle: i32,
+                request_json: &str,
+                cb: Box<Fn(IndyResult<String>) + Send>) {
         let x: IndyResult<i32> = self.pool_service.send_tx(handle, request_json);
         match x {
             Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
@@ -593,96 +1077,452 @@ impl LedgerCommandExecutor {
         };
     }
 
-    fn submit_action(&self,
-                     handle: i32,
-                     request_json: &str,
-                     nodes: Option<&str>,
-                     timeout: Option<i32>,
-                     cb: Box<Fn(IndyResult<String>) + Send>) {
-        debug!("submit_action >>> handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", handle, request_json, nodes, timeout);
+    fn _submit_write(&self, cb_id: i32) {
+        let (pool_handle, request_json) = match self.pending_writes.borrow_mut().get_mut(&cb_id) {
+            Some(write) => {
+                write.attempt += 1;
+                (write.pool_handle, write.request_json.clone())
+            }
+            None => return
+        };
 
-        if let Err(err) = self.ledger_service.validate_action(request_json) {
-            return cb(Err(err));
-        }
+        debug!("_submit_write >>> cb_id: {:?}, request_json: {:?}", cb_id, request_json);
 
-        let x: IndyResult<i32> = self.pool_service.send_action(handle, request_json, nodes, timeout);
-        match x {
-            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
-            Err(err) => { cb(Err(err)); }
+        self._send_tx(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SubmitWriteContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _submit_write_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let timed_out = match pool_response {
+            Err(ref err) => err.kind() == IndyErrorKind::PoolTimeout,
+            Ok(_) => false
         };
+
+        if timed_out {
+            self._schedule_write_check(cb_id, pool_response)
+        } else {
+            self._complete_write(cb_id, pool_response)
+        }
     }
 
-    fn sign_request(&self,
-                    wallet_handle: WalletHandle,
-                    submitter_did: &str,
-                    request_json: &str) -> IndyResult<String> {
-        debug!("sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+    /// Waits backoff delay and checks whether timed out write was ordered.
+    /// Completes write with the last error if no attempts left.
+    fn _schedule_write_check(&self, cb_id: i32, last_result: IndyResult<String>) {
+        let delay = match self.pending_writes.borrow().get(&cb_id) {
+            Some(write) if write.attempt < write.policy.max_attempts => Some(_jittered(write.policy.delay(write.attempt), write.policy.jitter)),
+            Some(_) => None,
+            None => return
+        };
 
-        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single)?;
+        let delay = match delay {
+            Some(delay) => delay,
+            None => return self._complete_write(cb_id, last_result)
+        };
 
-        debug!("sign_request <<< res: {:?}", res);
+        debug!("_schedule_write_check >>> cb_id: {:?}, delay: {:?}", cb_id, delay);
 
-        Ok(res)
+        _send_delayed(delay, LedgerCommand::CheckWriteOrdered(cb_id));
     }
 
-    fn multi_sign_request(&self,
-                          wallet_handle: WalletHandle,
-                          submitter_did: &str,
-                          request_json: &str) -> IndyResult<String> {
-        debug!("multi_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+    fn _check_write_ordered(&self, cb_id: i32) {
+        let (pool_handle, check_request_json) = match self.pending_writes.borrow().get(&cb_id) {
+            Some(write) => (write.pool_handle, write.check_request_json.clone()),
+            None => return
+        };
 
-        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Multi)?;
+        self._send_tx(pool_handle, &check_request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::CheckWriteOrderedContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
 
-        debug!("multi_sign_request <<< res: {:?}", res);
+    fn _check_write_ordered_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let pool_handle = match self.pending_writes.borrow().get(&cb_id) {
+            Some(write) => write.pool_handle,
+            None => return
+        };
 
-        Ok(res)
-    }
-    fn build_get_ddo_request(&self,
-                             submitter_did: Option<&str>,
-                             target_did: &str) -> IndyResult<String> {
-        debug!("build_get_ddo_request >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+        let seq_no = match pool_response.and_then(|response| self.ledger_service.parse_write_check_response(&response)) {
+            Ok(seq_no) => seq_no,
+            Err(err) => return self._write_check_failed(cb_id, err)
+        };
 
-        let res = self.ledger_service.build_get_ddo_request(submitter_did, target_did)?;
+        let seq_no = match seq_no {
+            Some(seq_no) => seq_no,
+            None => return self._submit_write(cb_id)
+        };
 
-        debug!("build_get_ddo_request <<< res: {:?}", res);
+        let get_txn_request = match self.ledger_service.build_get_txn_request(None, None, seq_no) {
+            Ok(get_txn_request) => get_txn_request,
+            Err(err) => return self._complete_write(cb_id, Err(err))
+        };
 
-        Ok(res)
+        self._send_tx(pool_handle, &get_txn_request, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::CheckWriteTxnContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
     }
 
-    fn build_nym_request(&self,
-                         submitter_did: &str,
-                         target_did: &str,
-                         verkey: Option<&str>,
-                         alias: Option<&str>,
-                         role: Option<&str>) -> IndyResult<String> {
-        debug!("build_nym_request >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
-               submitter_did, target_did, verkey, alias, role);
+    fn _check_write_txn_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let request_json = match self.pending_writes.borrow().get(&cb_id) {
+            Some(write) => write.request_json.clone(),
+            None => return
+        };
 
-        self.crypto_service.validate_did(submitter_did)?;
-        self.crypto_service.validate_did(target_did)?;
-        if let Some(vk) = verkey {
-            self.crypto_service.validate_key(vk)?;
+        match pool_response.and_then(|response| self.ledger_service.parse_ordered_write(&request_json, &response)) {
+            Ok(Some(reply)) => self._complete_write(cb_id, Ok(reply)),
+            // state was last changed by another transaction, so the write was not ordered
+            Ok(None) => self._submit_write(cb_id),
+            Err(err) => self._write_check_failed(cb_id, err)
         }
+    }
 
-        let res = self.ledger_service.build_nym_request(submitter_did,
-                                                        target_did,
-                                                        verkey,
-                                                        alias,
-                                                        role)?;
+    /// Check itself consumes an attempt, so unreachable pool doesn't make us poll it forever.
+    fn _write_check_failed(&self, cb_id: i32, err: IndyError) {
+        debug!("_write_check_failed >>> cb_id: {:?}, err: {:?}", cb_id, err);
 
-        debug!("build_nym_request <<< res: {:?}", res);
+        if let Some(write) = self.pending_writes.borrow_mut().get_mut(&cb_id) {
+            write.attempt += 1;
+        }
 
-        Ok(res)
+        self._schedule_write_check(cb_id, Err(err_msg(IndyErrorKind::PoolTimeout, "Write is timed out and can't be checked")));
     }
 
-    fn build_attrib_request(&self,
-                            submitter_did: &str,
-                            target_did: &str,
-                            hash: Option<&str>,
-                            raw: Option<&str>,
-                            enc: Option<&str>) -> IndyResult<String> {
-        debug!("build_attrib_request >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}",
-               submitter_did, target_did, hash, raw, enc);
+    fn _complete_write(&self, cb_id: i32, result: IndyResult<String>) {
+        let write = self.pending_writes.borrow_mut().remove(&cb_id);
+
+        if let Some(write) = write {
+            (write.cb)(result);
+        }
+    }
+
+    fn submit_request_and_confirm(&self,
+                                  handle: i32,
+                                  request_json: &str,
+                                  options: ConfirmOptions,
+                                  cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("submit_request_and_confirm >>> handle: {:?}, request_json: {:?}, options: {:?}", handle, request_json, options);
+
+        let check_request_json = match self.ledger_service.build_write_check_request(request_json) {
+            Some(check_request_json) => check_request_json,
+            None => return cb(Err(err_msg(IndyErrorKind::InvalidStructure, "Confirmation isn't supported for the request")))
+        };
+
+        let cb_id = ::utils::sequence::get_next_id();
+
+        self.pending_confirms.borrow_mut().insert(cb_id, PendingConfirm {
+            pool_handle: handle,
+            request_json: request_json.to_string(),
+            check_request_json,
+            options,
+            deadline: None,
+            seq_no: 0,
+            reply: String::new(),
+            cb,
+        });
+
+        self.submit_request(handle, request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SubmitRequestAndConfirmContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _submit_request_and_confirm_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let reply = match pool_response {
+            Ok(reply) => reply,
+            Err(err) => return self._complete_confirm(cb_id, Err(err))
+        };
+
+        let seq_no = parse_response_metadata(&reply)
+            .and_then(|metadata| metadata.seq_no
+                .ok_or(err_msg(IndyErrorKind::InvalidTransaction, "Write reply doesn't contain seqNo")));
+
+        let seq_no = match seq_no {
+            Ok(seq_no) => seq_no,
+            Err(err) => return self._complete_confirm(cb_id, Err(err))
+        };
+
+        debug!("_submit_request_and_confirm_continue >>> cb_id: {:?}, seq_no: {:?}", cb_id, seq_no);
+
+        if let Some(confirm) = self.pending_confirms.borrow_mut().get_mut(&cb_id) {
+            confirm.seq_no = seq_no;
+            confirm.reply = reply;
+            confirm.deadline = Some(confirm.options.deadline(Instant::now()));
+        }
+
+        self._confirm_write(cb_id);
+    }
+
+    fn _confirm_write(&self, cb_id: i32) {
+        let (pool_handle, check_request_json) = match self.pending_confirms.borrow_mut().get_mut(&cb_id) {
+            Some(confirm) => {
+                // rebuilt to get fresh reqId and timestamp for revocation registry
+                if let Some(check_request_json) = self.ledger_service.build_write_check_request(&confirm.request_json) {
+                    confirm.check_request_json = check_request_json;
+                }
+                (confirm.pool_handle, confirm.check_request_json.clone())
+            }
+            None => return
+        };
+
+        self._send_tx(pool_handle, &check_request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::ConfirmWriteContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _confirm_write_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let (pool_handle, check_request_json, seq_no, options, deadline) = match self.pending_confirms.borrow().get(&cb_id) {
+            Some(&PendingConfirm { pool_handle, ref check_request_json, seq_no, ref options, deadline: Some(deadline), .. }) =>
+                (pool_handle, check_request_json.clone(), seq_no, options.clone(), deadline),
+            _ => return
+        };
+
+        let confirmed = pool_response.and_then(|response| {
+            let read_seq_no = self.ledger_service.parse_write_check_response(&response)?;
+            let proved = self.pool_service.check_state_proof(pool_handle, &check_request_json, &response)?;
+            Ok(proved && read_seq_no.map(|read_seq_no| read_seq_no as u64 >= seq_no).unwrap_or(false))
+        });
+
+        debug!("_confirm_write_continue >>> cb_id: {:?}, confirmed: {:?}", cb_id, confirmed);
+
+        let confirmed = match confirmed {
+            Err(ref err) if err.kind() != IndyErrorKind::PoolTimeout => {
+                let err = err_msg(err.kind(), format!("Can't read written transaction: {}", err));
+                return self._complete_confirm(cb_id, Err(err));
+            }
+            confirmed => confirmed.unwrap_or(false)
+        };
+
+        match options.next_step(confirmed, Instant::now(), deadline) {
+            ConfirmStep::Confirmed => {
+                let reply = self.pending_confirms.borrow().get(&cb_id).map(|confirm| confirm.reply.clone()).unwrap_or_default();
+                self._complete_confirm(cb_id, Ok(reply))
+            }
+            ConfirmStep::Retry(interval) => _send_delayed(interval, LedgerCommand::ConfirmWrite(cb_id)),
+            ConfirmStep::TimedOut => {
+                let err = err_msg(IndyErrorKind::PoolTimeout, "Written transaction isn't confirmed by state proof before deadline");
+                self._complete_confirm(cb_id, Err(err))
+            }
+        }
+    }
+
+    fn _complete_confirm(&self, cb_id: i32, result: IndyResult<String>) {
+        let confirm = self.pending_confirms.borrow_mut().remove(&cb_id);
+
+        if let Some(confirm) = confirm {
+            (confirm.cb)(result);
+        }
+    }
+
+    fn submit_requests_batch(&self,
+                             handle: i32,
+                             requests: Vec<Value>,
+                             cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("submit_requests_batch >>> handle: {:?}, requests: {:?}", handle, requests);
+
+        let batch_limit = try_cb!(self.pool_service.get_batch_limit(handle), cb);
+
+        let requests: Vec<String> = requests
+            .into_iter()
+            .map(|request| match request {
+                Value::String(request) => request,
+                request => request.to_string()
+            })
+            .collect();
+
+        if requests.is_empty() {
+            return cb(Ok(json!([]).to_string()));
+        }
+
+        let batch_id = ::utils::sequence::get_next_id();
+        let count = requests.len();
+
+        self.pending_batches.borrow_mut().insert(batch_id, PendingRequestsBatch {
+            pool_handle: handle,
+            requests,
+            next: 0,
+            results: vec![None; count],
+            cb,
+        });
+
+        for _ in 0..::std::cmp::min(batch_limit, count) {
+            self._submit_next_batch_request(batch_id);
+        }
+    }
+
+    fn _submit_next_batch_request(&self, batch_id: i32) {
+        let (pool_handle, request_json, index) = match self.pending_batches.borrow_mut().get_mut(&batch_id) {
+            Some(batch) if batch.next < batch.requests.len() => {
+                batch.next += 1;
+                (batch.pool_handle, batch.requests[batch.next - 1].clone(), batch.next - 1)
+            }
+            _ => return
+        };
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SubmitRequestsBatchContinue(
+                        response,
+                        batch_id,
+                        index
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _submit_requests_batch_continue(&self, pool_response: IndyResult<String>, batch_id: i32, index: usize) {
+        debug!("_submit_requests_batch_continue >>> batch_id: {:?}, index: {:?}, pool_response: {:?}", batch_id, index, pool_response);
+
+        let result = match pool_response {
+            Ok(response) => json!({"response": response}),
+            Err(err) => json!({"error": {"code": ErrorCode::from(err.kind()) as i32, "message": err.to_string()}})
+        };
+
+        let completed = match self.pending_batches.borrow_mut().get_mut(&batch_id) {
+            Some(batch) => {
+                batch.results[index] = Some(result);
+                batch.results.iter().all(Option::is_some)
+            }
+            None => return
+        };
+
+        if !completed {
+            return self._submit_next_batch_request(batch_id);
+        }
+
+        let batch = self.pending_batches.borrow_mut().remove(&batch_id);
+
+        if let Some(batch) = batch {
+            let results: Vec<Value> = batch.results.into_iter().filter_map(|result| result).collect();
+            (batch.cb)(Ok(Value::Array(results).to_string()));
+        }
+    }
+
+    fn submit_action(&self,
+                     handle: i32,
+                     request_json: &str,
+                     nodes: Option<&str>,
+                     timeout: Option<i32>,
+                     cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("submit_action >>> handle: {:?}, request_json: {:?}, nodes: {:?}, timeout: {:?}", handle, request_json, nodes, timeout);
+
+        if let Err(err) = self.ledger_service.validate_action(request_json) {
+            return cb(Err(err));
+        }
+
+        let x: IndyResult<i32> = self.pool_service.send_action(handle, request_json, nodes, timeout);
+        match x {
+            Ok(cmd_id) => { self.send_callbacks.borrow_mut().insert(cmd_id, cb); }
+            Err(err) => { cb(Err(err)); }
+        };
+    }
+
+    fn sign_request(&self,
+                    wallet_handle: WalletHandle,
+                    submitter_did: &str,
+                    request_json: &str) -> IndyResult<String> {
+        debug!("sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Single)?;
+
+        debug!("sign_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn multi_sign_request(&self,
+                          wallet_handle: WalletHandle,
+                          submitter_did: &str,
+                          request_json: &str) -> IndyResult<String> {
+        debug!("multi_sign_request >>> wallet_handle: {:?}, submitter_did: {:?}, request_json: {:?}", wallet_handle, submitter_did, request_json);
+
+        let res = self._sign_request(wallet_handle, submitter_did, request_json, SignatureType::Multi)?;
+
+        debug!("multi_sign_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+    fn build_get_ddo_request(&self,
+                             submitter_did: Option<&str>,
+                             target_did: &str) -> IndyResult<String> {
+        debug!("build_get_ddo_request >>> submitter_did: {:?}, target_did: {:?}", submitter_did, target_did);
+
+        let res = self.ledger_service.build_get_ddo_request(submitter_did, target_did)?;
+
+        debug!("build_get_ddo_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_nym_request(&self,
+                         submitter_did: &str,
+                         target_did: &str,
+                         verkey: Option<&str>,
+                         alias: Option<&str>,
+                         role: Option<&str>) -> IndyResult<String> {
+        debug!("build_nym_request >>> submitter_did: {:?}, target_did: {:?}, verkey: {:?}, alias: {:?}, role: {:?}",
+               submitter_did, target_did, verkey, alias, role);
+
+        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(target_did)?;
+        if let Some(vk) = verkey {
+            self.crypto_service.validate_key(vk)?;
+        }
+
+        let res = self.ledger_service.build_nym_request(submitter_did,
+                                                        target_did,
+                                                        verkey,
+                                                        alias,
+                                                        role)?;
+
+        debug!("build_nym_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_attrib_request(&self,
+                            submitter_did: &str,
+                            target_did: &str,
+                            hash: Option<&str>,
+                            raw: Option<&str>,
+                            enc: Option<&str>) -> IndyResult<String> {
+        debug!("build_attrib_request >>> submitter_did: {:?}, target_did: {:?}, hash: {:?}, raw: {:?}, enc: {:?}",
+               submitter_did, target_did, hash, raw, enc);
 
         self.crypto_service.validate_did(submitter_did)?;
         self.crypto_service.validate_did(target_did)?;
@@ -721,6 +1561,17 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_attrib_response(&self,
+                                 get_attrib_response: &str) -> IndyResult<String> {
+        debug!("parse_get_attrib_response >>> get_attrib_response: {:?}", get_attrib_response);
+
+        let res = self.ledger_service.parse_get_attrib_response(get_attrib_response)?;
+
+        debug!("parse_get_attrib_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_get_nym_request(&self,
                              submitter_did: Option<&str>,
                              target_did: &str) -> IndyResult<String> {
@@ -737,6 +1588,17 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_nym_response(&self,
+                              get_nym_response: &str) -> IndyResult<String> {
+        debug!("parse_get_nym_response >>> get_nym_response: {:?}", get_nym_response);
+
+        let res = self.ledger_service.parse_get_nym_response(get_nym_response)?;
+
+        debug!("parse_get_nym_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_schema_request(&self,
                             submitter_did: &str,
                             schema: SchemaV1) -> IndyResult<String> {
@@ -776,6 +1638,68 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_rich_schema_request(&self,
+                                 submitter_did: &str,
+                                 id: &str,
+                                 content: &str,
+                                 rs_name: &str,
+                                 rs_version: &str,
+                                 rs_type: &str,
+                                 ver: Option<&str>) -> IndyResult<String> {
+        debug!("build_rich_schema_request >>> submitter_did: {:?}, id: {:?}, content: {:?}, rs_name: {:?}, rs_version: {:?}, rs_type: {:?}, ver: {:?}",
+               submitter_did, id, content, rs_name, rs_version, rs_type, ver);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_rich_schema_request(submitter_did, id, content, rs_name, rs_version, rs_type, ver)?;
+
+        debug!("build_rich_schema_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_id_request(&self,
+                                                  submitter_did: Option<&str>,
+                                                  id: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_id_request >>> submitter_did: {:?}, id: {:?}", submitter_did, id);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_id_request(submitter_did, id)?;
+
+        debug!("build_get_rich_schema_object_by_id_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_rich_schema_object_by_metadata_request(&self,
+                                                        submitter_did: Option<&str>,
+                                                        rs_type: &str,
+                                                        rs_name: &str,
+                                                        rs_version: &str) -> IndyResult<String> {
+        debug!("build_get_rich_schema_object_by_metadata_request >>> submitter_did: {:?}, rs_type: {:?}, rs_name: {:?}, rs_version: {:?}",
+               submitter_did, rs_type, rs_name, rs_version);
+
+        self.validate_opt_did(submitter_did)?;
+
+        let res = self.ledger_service.build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version)?;
+
+        debug!("build_get_rich_schema_object_by_metadata_request <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn parse_get_rich_schema_object_response(&self,
+                                             get_rich_schema_object_response: &str) -> IndyResult<(String, String)> {
+        debug!("parse_get_rich_schema_object_response >>> get_rich_schema_object_response: {:?}", get_rich_schema_object_response);
+
+        let res = self.ledger_service.parse_get_rich_schema_object_response(get_rich_schema_object_response)?;
+
+        debug!("parse_get_rich_schema_object_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_cred_def_request(&self,
                               submitter_did: &str,
                               cred_def: CredentialDefinitionV1) -> IndyResult<String> {
@@ -832,6 +1756,61 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn generate_bls_keys(&self,
+                         seed: Option<&str>) -> IndyResult<(String, String)> {
+        debug!("generate_bls_keys >>> seed: {:?}", secret!(seed));
+
+        let res = self.crypto_service.create_bls_key(seed)?;
+
+        debug!("generate_bls_keys <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_genesis_transactions(&self,
+                                  nodes_config: GenesisConfig) -> IndyResult<(String, String)> {
+        debug!("build_genesis_transactions >>> nodes_config: {:?}", secret!(&nodes_config));
+
+        let trustees = nodes_config.trustee_seeds
+            .iter()
+            .map(|seed| self._create_genesis_did(seed))
+            .collect::<IndyResult<Vec<(String, String)>>>()?;
+
+        let mut nodes = Vec::new();
+
+        for node in nodes_config.nodes {
+            let (steward_did, steward_verkey) = self._create_genesis_did(&node.steward_seed)?;
+
+            let dest = self.crypto_service.create_key(&KeyInfo { seed: Some(node.seed.clone()), crypto_type: None })?.verkey.clone();
+            let (blskey, blskey_pop) = self.crypto_service.create_bls_key(Some(&node.seed))?;
+
+            let data = NodeOperationData {
+                node_ip: Some(node.node_ip),
+                node_port: Some(node.node_port),
+                client_ip: Some(node.client_ip),
+                client_port: Some(node.client_port),
+                alias: node.alias,
+                services: Some(node.services.unwrap_or_else(|| vec![Services::VALIDATOR])),
+                blskey: Some(blskey),
+                blskey_pop: Some(blskey_pop),
+            };
+
+            nodes.push(GenesisNode { steward_did, steward_verkey, dest, data });
+        }
+
+        let res = self.ledger_service.build_genesis_transactions(&trustees, nodes)?;
+
+        debug!("build_genesis_transactions <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _create_genesis_did(&self, seed: &str) -> IndyResult<(String, String)> {
+        let did_info = MyDidInfo { did: None, seed: Some(seed.to_string()), crypto_type: None, cid: None };
+        let (did, _) = self.crypto_service.create_my_did(&did_info)?;
+        Ok((did.did, did.verkey))
+    }
+
     fn build_get_validator_info_request(&self,
                                         submitter_did: &str) -> IndyResult<String> {
         info!("build_get_validator_info_request >>> submitter_did: {:?}", submitter_did);
@@ -861,6 +1840,17 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn parse_get_txn_response(&self,
+                              get_txn_response: &str) -> IndyResult<String> {
+        debug!("parse_get_txn_response >>> get_txn_response: {:?}", get_txn_response);
+
+        let res = self.ledger_service.parse_get_txn_response(get_txn_response)?;
+
+        debug!("parse_get_txn_response <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     fn build_pool_config_request(&self,
                                  submitter_did: &str,
                                  writes: bool,
@@ -906,12 +1896,39 @@ impl LedgerCommandExecutor {
          timeout: {:?}, schedule: {:?}, justification: {:?}, reinstall: {:?}, force: {:?}, package: {:?}",
                submitter_did, name, version, action, sha256, timeout, schedule, justification, reinstall, force, package);
 
-        self.crypto_service.validate_did(submitter_did)?;
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_pool_upgrade(submitter_did, name, version, action, sha256,
+                                                         timeout, schedule, justification, reinstall, force, package)?;
+
+        debug!("build_pool_upgrade_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_ledgers_freeze_request(&self,
+                                    submitter_did: &str,
+                                    ledgers_ids: Vec<u64>) -> IndyResult<String> {
+        debug!("build_ledgers_freeze_request >>> submitter_did: {:?}, ledgers_ids: {:?}", submitter_did, ledgers_ids);
+
+        self.crypto_service.validate_did(submitter_did)?;
+
+        let res = self.ledger_service.build_ledgers_freeze_request(submitter_did, ledgers_ids)?;
+
+        debug!("build_ledgers_freeze_request  <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn build_get_frozen_ledgers_request(&self,
+                                        submitter_did: Option<&str>) -> IndyResult<String> {
+        debug!("build_get_frozen_ledgers_request >>> submitter_did: {:?}", submitter_did);
+
+        self.validate_opt_did(submitter_did)?;
 
-        let res = self.ledger_service.build_pool_upgrade(submitter_did, name, version, action, sha256,
-                                                         timeout, schedule, justification, reinstall, force, package)?;
+        let res = self.ledger_service.build_get_frozen_ledgers_request(submitter_did)?;
 
-        debug!("build_pool_upgrade_request  <<< res: {:?}", res);
+        debug!("build_get_frozen_ledgers_request  <<< res: {:?}", res);
 
         Ok(res)
     }
@@ -1092,6 +2109,143 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn check_request_auth(&self,
+                          pool_handle: i32,
+                          request_json: &str,
+                          submitter_role_info: &str,
+                          cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("check_request_auth >>> pool_handle: {:?}, request_json: {:?}, submitter_role_info: {:?}",
+               pool_handle, request_json, submitter_role_info);
+
+        let role_info = try_cb!(self.ledger_service.parse_submitter_role_info(submitter_role_info), cb);
+
+        // validates request before reading of the ledger, actions of NYM are determined by its current state later
+        try_cb!(self.ledger_service.get_request_auth_actions(request_json, None), cb);
+
+        let nym_dest = try_cb!(self.ledger_service.get_auth_nym_dest(request_json), cb);
+
+        let cb_id = ::utils::sequence::get_next_id();
+
+        self.pending_auth_checks.borrow_mut().insert(cb_id, PendingAuthCheck {
+            pool_handle,
+            request_json: request_json.to_string(),
+            role_info,
+            nym_dest,
+            current_nym: None,
+            cb,
+        });
+
+        self._check_request_auth_step(cb_id);
+    }
+
+    /// Reads auth rules (if not cached) and current NYM (for NYM request) before evaluation of the request.
+    fn _check_request_auth_step(&self, cb_id: i32) {
+        let (pool_handle, nym_dest) = match self.pending_auth_checks.borrow().get(&cb_id) {
+            Some(check) => (check.pool_handle,
+                            if check.current_nym.is_none() { check.nym_dest.clone() } else { None }),
+            None => return
+        };
+
+        let rules_are_fresh = self.auth_rules.borrow().get(&pool_handle)
+            .map(|&(ref read_at, _)| read_at.elapsed() < Duration::from_secs(AUTH_RULES_CACHE_TTL))
+            .unwrap_or(false);
+
+        if !rules_are_fresh {
+            let request_json = match self.ledger_service.build_get_auth_rule_request(None, None, None, None, None, None) {
+                Ok(request_json) => request_json,
+                Err(err) => return self._complete_auth_check(cb_id, Err(err))
+            };
+
+            return self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::CheckRequestAuthRulesContinue(
+                            response,
+                            cb_id
+                        )
+                    )
+                ).unwrap();
+            }));
+        }
+
+        if let Some(nym_dest) = nym_dest {
+            let request_json = match self.ledger_service.build_get_nym_request(None, &nym_dest) {
+                Ok(request_json) => request_json,
+                Err(err) => return self._complete_auth_check(cb_id, Err(err))
+            };
+
+            return self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::CheckRequestAuthNymContinue(
+                            response,
+                            cb_id
+                        )
+                    )
+                ).unwrap();
+            }));
+        }
+
+        let res = match self.pending_auth_checks.borrow().get(&cb_id) {
+            Some(check) => {
+                let auth_rules = self.auth_rules.borrow();
+                let rules = auth_rules.get(&pool_handle).map(|&(_, ref rules)| rules.as_slice()).unwrap_or(&[]);
+
+                self.ledger_service.get_request_auth_actions(&check.request_json, check.current_nym.as_ref().and_then(Option::as_ref))
+                    .and_then(|actions| self.ledger_service.check_request_auth(actions, rules, &check.role_info))
+            }
+            None => return
+        };
+
+        debug!("check_request_auth <<< res: {:?}", res);
+
+        self._complete_auth_check(cb_id, res);
+    }
+
+    fn _check_request_auth_rules_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let rules = pool_response
+            .and_then(|response| self.ledger_service.parse_get_auth_rule_response(&response));
+
+        let rules = match rules {
+            Ok(rules) => rules,
+            Err(err) => return self._complete_auth_check(cb_id, Err(err))
+        };
+
+        let pool_handle = match self.pending_auth_checks.borrow().get(&cb_id) {
+            Some(check) => check.pool_handle,
+            None => return
+        };
+
+        self.auth_rules.borrow_mut().insert(pool_handle, (Instant::now(), rules));
+
+        self._check_request_auth_step(cb_id);
+    }
+
+    fn _check_request_auth_nym_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let nym = pool_response
+            .and_then(|response| self.ledger_service.get_parsed_nym(&response));
+
+        let nym = match nym {
+            Ok(nym) => Some(nym),
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => None,
+            Err(err) => return self._complete_auth_check(cb_id, Err(err))
+        };
+
+        if let Some(check) = self.pending_auth_checks.borrow_mut().get_mut(&cb_id) {
+            check.current_nym = Some(nym);
+        }
+
+        self._check_request_auth_step(cb_id);
+    }
+
+    fn _complete_auth_check(&self, cb_id: i32, result: IndyResult<String>) {
+        let check = self.pending_auth_checks.borrow_mut().remove(&cb_id);
+
+        if let Some(check) = check {
+            (check.cb)(result);
+        }
+    }
+
     fn build_txn_author_agreement_request(&self,
                                           submitter_did: &str,
                                           text: &str,
@@ -1175,6 +2329,102 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn set_txn_author_agreement_acceptance(&self,
+                                           pool_handle: i32,
+                                           text: Option<&str>,
+                                           version: Option<&str>,
+                                           taa_digest: Option<&str>,
+                                           acc_mech_type: &str,
+                                           time: u64,
+                                           cb: Box<Fn(IndyResult<()>) + Send>) {
+        debug!("set_txn_author_agreement_acceptance >>> pool_handle: {:?}, text: {:?}, version: {:?}, taa_digest: {:?}, acc_mech_type: {:?}, time: {:?}",
+               pool_handle, text, version, taa_digest, acc_mech_type, time);
+
+        let acceptance = try_cb!(self.ledger_service.prepare_acceptance_data(text, version, taa_digest, acc_mech_type, time), cb);
+
+        let request_json = try_cb!(self.ledger_service.build_get_txn_author_agreement_request(None, None), cb);
+
+        let cb_id = ::utils::sequence::get_next_id();
+
+        self.pending_taa_acceptances.borrow_mut().insert(cb_id, PendingTaaAcceptance {
+            pool_handle,
+            acceptance,
+            get_taa_response: None,
+            cb,
+        });
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SetTxnAuthorAgreementAcceptanceTaaContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _set_txn_author_agreement_acceptance_taa_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let response = match pool_response {
+            Ok(response) => response,
+            Err(err) => return self._complete_taa_acceptance(cb_id, Err(err))
+        };
+
+        let pool_handle = match self.pending_taa_acceptances.borrow_mut().get_mut(&cb_id) {
+            Some(pending) => {
+                pending.get_taa_response = Some(response);
+                pending.pool_handle
+            }
+            None => return
+        };
+
+        let request_json = match self.ledger_service.build_get_acceptance_mechanisms_request(None, None, None) {
+            Ok(request_json) => request_json,
+            Err(err) => return self._complete_taa_acceptance(cb_id, Err(err))
+        };
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::SetTxnAuthorAgreementAcceptanceAmlContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _set_txn_author_agreement_acceptance_aml_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let res = match self.pending_taa_acceptances.borrow().get(&cb_id) {
+            Some(pending) => {
+                let get_taa_response = pending.get_taa_response.as_ref().map(String::as_str).unwrap_or_default();
+
+                pool_response
+                    .and_then(|get_aml_response| self.ledger_service.check_acceptance_data(&pending.acceptance, get_taa_response, &get_aml_response))
+                    .map(|_| (pending.pool_handle, pending.acceptance.clone()))
+            }
+            None => return
+        };
+
+        let res = res.map(|(pool_handle, acceptance)| {
+            self.taa_acceptances.borrow_mut().insert(pool_handle, acceptance);
+        });
+
+        debug!("set_txn_author_agreement_acceptance <<< res: {:?}", res);
+
+        self._complete_taa_acceptance(cb_id, res);
+    }
+
+    fn _complete_taa_acceptance(&self, cb_id: i32, result: IndyResult<()>) {
+        let pending = self.pending_taa_acceptances.borrow_mut().remove(&cb_id);
+
+        if let Some(pending) = pending {
+            (pending.cb)(result);
+        }
+    }
+
     fn append_request_endorser(&self,
                                request_json: &str,
                                endorser_did: &str) -> IndyResult<String> {
@@ -1195,6 +2445,406 @@ impl LedgerCommandExecutor {
         Ok(res)
     }
 
+    fn build_endorsement_bundle(&self,
+                                request_json: &str,
+                                metadata: Option<Value>) -> IndyResult<String> {
+        debug!("build_endorsement_bundle >>> request_json: {:?}, metadata: {:?}", request_json, metadata);
+
+        let res = self.ledger_service.build_endorsement_bundle(request_json, metadata)?;
+
+        debug!("build_endorsement_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn review_endorsement_bundle(&self,
+                                 pool_handle: i32,
+                                 bundle_json: &str,
+                                 endorse: Option<(WalletHandle, String)>,
+                                 cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("review_endorsement_bundle >>> pool_handle: {:?}, bundle_json: {:?}, endorse: {:?}", pool_handle, bundle_json, endorse);
+
+        let bundle = try_cb!(self.ledger_service.parse_endorsement_bundle(bundle_json), cb);
+
+        if let Some((_, ref endorser_did)) = endorse {
+            if endorser_did != &bundle.endorser {
+                return cb(Err(err_msg(IndyErrorKind::InvalidStructure,
+                                      format!("Request is intended to be endorsed by {} but not {}", bundle.endorser, endorser_did))));
+            }
+        }
+
+        let signatures = request_signatures(&bundle.request);
+
+        let cb_id = ::utils::sequence::get_next_id();
+
+        self.pending_endorsements.borrow_mut().insert(cb_id, PendingEndorsement {
+            pool_handle,
+            bundle,
+            signatures,
+            checks: Vec::new(),
+            endorse,
+            cb,
+        });
+
+        self._endorsement_step(cb_id);
+    }
+
+    fn endorse_request(&self,
+                       pool_handle: i32,
+                       wallet_handle: WalletHandle,
+                       endorser_did: &str,
+                       bundle_json: &str,
+                       cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("endorse_request >>> pool_handle: {:?}, wallet_handle: {:?}, endorser_did: {:?}, bundle_json: {:?}",
+               pool_handle, wallet_handle, endorser_did, bundle_json);
+
+        try_cb!(self.crypto_service.validate_did(endorser_did), cb);
+
+        self.review_endorsement_bundle(pool_handle, bundle_json, Some((wallet_handle, endorser_did.to_string())), cb);
+    }
+
+    /// Reads verkey of the next signer from the ledger or completes review if all signatures are checked.
+    fn _endorsement_step(&self, cb_id: i32) {
+        let (pool_handle, signer) = match self.pending_endorsements.borrow().get(&cb_id) {
+            Some(endorsement) => (endorsement.pool_handle,
+                                  endorsement.signatures.get(endorsement.checks.len()).map(|&(ref did, _)| did.clone())),
+            None => return
+        };
+
+        if let Some(signer) = signer {
+            let request_json = match self.ledger_service.build_get_nym_request(None, &signer) {
+                Ok(request_json) => request_json,
+                Err(err) => return self._complete_endorsement(cb_id, Err(err))
+            };
+
+            return self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+                CommandExecutor::instance().send(
+                    Command::Ledger(
+                        LedgerCommand::EndorsementNymContinue(
+                            response,
+                            cb_id
+                        )
+                    )
+                ).unwrap();
+            }));
+        }
+
+        let endorsement = match self.pending_endorsements.borrow_mut().remove(&cb_id) {
+            Some(endorsement) => endorsement,
+            None => return
+        };
+
+        let request_json = endorsement.bundle.request.to_string();
+        let review = EndorsementReview::new(endorsement.bundle, endorsement.checks);
+
+        let res = match endorsement.endorse {
+            Some((wallet_handle, endorser_did)) => {
+                if review.valid {
+                    self._sign_request(wallet_handle, &endorser_did, &request_json, SignatureType::Multi)
+                } else {
+                    Err(err_msg(IndyErrorKind::InvalidStructure, "Request isn't signed by its author or contains invalid signatures"))
+                }
+            }
+            None => serde_json::to_string(&review)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize endorsement review")
+        };
+
+        debug!("review_endorsement_bundle <<< res: {:?}", res);
+
+        (endorsement.cb)(res);
+    }
+
+    fn _endorsement_nym_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let nym = pool_response
+            .and_then(|response| self.ledger_service.get_parsed_nym(&response));
+
+        let verkey = match nym {
+            Ok(nym) => nym.verkey,
+            Err(ref err) if err.kind() == IndyErrorKind::LedgerItemNotFound => None,
+            Err(err) => return self._complete_endorsement(cb_id, Err(err))
+        };
+
+        if let Some(endorsement) = self.pending_endorsements.borrow_mut().get_mut(&cb_id) {
+            let (did, signature) = endorsement.signatures[endorsement.checks.len()].clone();
+
+            let valid = match verkey {
+                Some(ref verkey) => self._verify_request_signature(&endorsement.bundle.request, verkey, &signature),
+                None => false
+            };
+
+            endorsement.checks.push(SignatureCheck { did, verkey, valid });
+        }
+
+        self._endorsement_step(cb_id);
+    }
+
+    fn _verify_request_signature(&self, request: &Value, verkey: &str, signature: &str) -> bool {
+        let signature = match signature.from_base58() {
+            Ok(signature) => signature,
+            Err(_) => return false
+        };
+
+        serialize_signature(request.clone())
+            .and_then(|serialized_request| self.crypto_service.verify(verkey, serialized_request.as_bytes(), &signature))
+            .unwrap_or(false)
+    }
+
+    fn _complete_endorsement(&self, cb_id: i32, result: IndyResult<String>) {
+        let endorsement = self.pending_endorsements.borrow_mut().remove(&cb_id);
+
+        if let Some(endorsement) = endorsement {
+            (endorsement.cb)(result);
+        }
+    }
+
+    fn verify_txn_inclusion(&self,
+                            root_hash: &str,
+                            txn_json: &str,
+                            audit_path: Option<&str>,
+                            ledger_size: Option<usize>) -> IndyResult<bool> {
+        debug!("verify_txn_inclusion >>> root_hash: {:?}, txn_json: {:?}, audit_path: {:?}, ledger_size: {:?}", root_hash, txn_json, audit_path, ledger_size);
+
+        let res = self.ledger_service.verify_txn_inclusion(root_hash, txn_json, audit_path, ledger_size)?;
+
+        debug!("verify_txn_inclusion <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn open_ledger_iterator(&self,
+                            pool_handle: i32,
+                            ledger_type: Option<&str>,
+                            from: u64,
+                            to: Option<u64>,
+                            cb: Box<Fn(IndyResult<i32>) + Send>) {
+        debug!("open_ledger_iterator >>> pool_handle: {:?}, ledger_type: {:?}, from: {:?}, to: {:?}", pool_handle, ledger_type, from, to);
+
+        if from == 0 || to.map(|to| to < from).unwrap_or(false) {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid range of transactions: from {} to {:?}", from, to))));
+        }
+
+        let ledger_id = try_cb!(self.ledger_service.parse_ledger_id(ledger_type), cb);
+
+        if ledger_id < 0 {
+            return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid Ledger id: {}", ledger_id))));
+        }
+
+        let ledger_id = ledger_id as usize;
+
+        // replies to LEDGER_STATUS and CATCHUP_REQ are matched with requests by ledger only,
+        // so concurrent iterators over the same ledger would steal replies of each other
+        if self._is_ledger_iterator_open(pool_handle, ledger_id) {
+            return cb(Err(err_msg(IndyErrorKind::InvalidState, format!("Ledger iterator over ledger {} is already open for the pool", ledger_id))));
+        }
+
+        let cmd_id = try_cb!(self.pool_service.get_ledger_status(pool_handle, ledger_id), cb);
+
+        let iterator_handle = ::utils::sequence::get_next_id();
+
+        self.pending_iterator_opens.borrow_mut().insert(iterator_handle, PendingLedgerIteratorOpen {
+            pool_handle,
+            ledger_id,
+            from,
+            to,
+            cb,
+        });
+
+        self.send_callbacks.borrow_mut().insert(cmd_id, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::OpenLedgerIteratorContinue(
+                        response,
+                        iterator_handle
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _is_ledger_iterator_open(&self, pool_handle: i32, ledger_id: usize) -> bool {
+        self.pending_iterator_opens.borrow().values()
+            .any(|open| open.pool_handle == pool_handle && open.ledger_id == ledger_id) ||
+            self.ledger_iterators.borrow().values()
+                .any(|iterator| iterator.pool_handle == pool_handle && iterator.ledger_id == ledger_id)
+    }
+
+    fn _open_ledger_iterator_continue(&self, pool_response: IndyResult<String>, iterator_handle: i32) {
+        let PendingLedgerIteratorOpen { pool_handle, ledger_id, from, to, cb } =
+            match self.pending_iterator_opens.borrow_mut().remove(&iterator_handle) {
+                Some(open) => open,
+                None => return
+            };
+
+        let ledger_status = try_cb!(pool_response, cb);
+        let (target_mt_root, target_mt_size) = try_cb!(self.ledger_service.parse_ledger_status(&ledger_status), cb);
+        let merkle_tree = try_cb!(MerkleTree::from_vec(Vec::new()), cb);
+
+        debug!("_open_ledger_iterator_continue >>> iterator_handle: {:?}, target_mt_size: {:?}", iterator_handle, target_mt_size);
+
+        let to = to.map(|to| ::std::cmp::min(to as usize, target_mt_size)).unwrap_or(target_mt_size);
+
+        self.ledger_iterators.borrow_mut().insert(iterator_handle, LedgerIterator {
+            pool_handle,
+            ledger_id,
+            from: from as usize,
+            to,
+            target_mt_size,
+            target_mt_root,
+            merkle_tree,
+            cb: None,
+        });
+
+        cb(Ok(iterator_handle));
+    }
+
+    fn ledger_iterator_next(&self, iterator_handle: i32, cb: Box<Fn(IndyResult<String>) + Send>) {
+        debug!("ledger_iterator_next >>> iterator_handle: {:?}", iterator_handle);
+
+        match self.ledger_iterators.borrow_mut().get_mut(&iterator_handle) {
+            Some(ref iterator) if iterator.cb.is_some() =>
+                return cb(Err(err_msg(IndyErrorKind::InvalidState, "Previous batch of ledger iterator is still being fetched"))),
+            Some(iterator) => iterator.cb = Some(cb),
+            None => return cb(Err(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger iterator handle: {}", iterator_handle))))
+        };
+
+        self._fetch_ledger_txns(iterator_handle);
+    }
+
+    fn _fetch_ledger_txns(&self, iterator_handle: i32) {
+        let batch = match self.ledger_iterators.borrow().get(&iterator_handle) {
+            Some(iterator) => {
+                let seq_no_start = iterator.merkle_tree.count() + 1;
+                let seq_no_end = ::std::cmp::min(seq_no_start + LEDGER_ITERATOR_BATCH_SIZE - 1, iterator.to);
+
+                if iterator.from <= iterator.to && seq_no_start <= seq_no_end {
+                    Some((iterator.pool_handle, iterator.ledger_id, seq_no_start, seq_no_end, iterator.target_mt_size))
+                } else {
+                    None
+                }
+            }
+            None => return
+        };
+
+        let (pool_handle, ledger_id, seq_no_start, seq_no_end, target_mt_size) = match batch {
+            Some(batch) => batch,
+            None => return self._complete_ledger_iterator_next(iterator_handle, Ok("[]".to_string()))
+        };
+
+        debug!("_fetch_ledger_txns >>> iterator_handle: {:?}, seq_no_start: {:?}, seq_no_end: {:?}", iterator_handle, seq_no_start, seq_no_end);
+
+        match self.pool_service.get_ledger_txns(pool_handle, ledger_id, seq_no_start, seq_no_end, target_mt_size) {
+            Ok(cmd_id) => {
+                self.send_callbacks.borrow_mut().insert(cmd_id, Box::new(move |response| {
+                    CommandExecutor::instance().send(
+                        Command::Ledger(
+                            LedgerCommand::LedgerIteratorNextContinue(
+                                response,
+                                iterator_handle
+                            )
+                        )
+                    ).unwrap();
+                }));
+            }
+            Err(err) => self._complete_ledger_iterator_next(iterator_handle, Err(err))
+        }
+    }
+
+    fn _ledger_iterator_next_continue(&self, pool_response: IndyResult<String>, iterator_handle: i32) {
+        match pool_response.and_then(|catchup_rep| self._apply_ledger_txns(iterator_handle, &catchup_rep)) {
+            Ok(Some(txns)) => self._complete_ledger_iterator_next(iterator_handle, Ok(txns)),
+            // the whole batch precedes requested range and was fetched only to verify the next ones
+            Ok(None) => self._fetch_ledger_txns(iterator_handle),
+            Err(err) => self._complete_ledger_iterator_next(iterator_handle, Err(err))
+        }
+    }
+
+    fn _apply_ledger_txns(&self, iterator_handle: i32, catchup_rep: &str) -> IndyResult<Option<String>> {
+        let mut iterators = self.ledger_iterators.borrow_mut();
+
+        let iterator = iterators.get_mut(&iterator_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidState, "Ledger iterator is closed"))?;
+
+        let (merkle_tree, txns) = self.ledger_service.verify_catchup_reply(catchup_rep, &iterator.merkle_tree,
+                                                                          &iterator.target_mt_root, iterator.target_mt_size)?;
+        iterator.merkle_tree = merkle_tree;
+
+        let from = iterator.from as u64;
+        let txns: Vec<ParsedTxn> = txns.into_iter().filter(|txn| txn.seq_no >= from).collect();
+
+        if txns.is_empty() {
+            return Ok(None);
+        }
+
+        serde_json::to_string(&txns)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize ledger txns")
+            .map(Some)
+    }
+
+    fn _complete_ledger_iterator_next(&self, iterator_handle: i32, result: IndyResult<String>) {
+        let cb = self.ledger_iterators.borrow_mut().get_mut(&iterator_handle)
+            .and_then(|iterator| iterator.cb.take());
+
+        if let Some(cb) = cb {
+            cb(result);
+        }
+    }
+
+    fn close_ledger_iterator(&self, iterator_handle: i32) -> IndyResult<()> {
+        debug!("close_ledger_iterator >>> iterator_handle: {:?}", iterator_handle);
+
+        let iterator = self.ledger_iterators.borrow_mut().remove(&iterator_handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger iterator handle: {}", iterator_handle)))?;
+
+        if let Some(cb) = iterator.cb {
+            cb(Err(err_msg(IndyErrorKind::InvalidState, "Ledger iterator is closed")));
+        }
+
+        debug!("close_ledger_iterator <<<");
+
+        Ok(())
+    }
+
+    /// Drops state kept for the closed pool.
+    fn pool_closed(&self, pool_handle: i32) {
+        debug!("pool_closed >>> pool_handle: {:?}", pool_handle);
+
+        self.auth_rules.borrow_mut().remove(&pool_handle);
+        self.taa_acceptances.borrow_mut().remove(&pool_handle);
+
+        let pending_taa_acceptances: Vec<i32> = self.pending_taa_acceptances.borrow().iter()
+            .filter(|&(_, pending)| pending.pool_handle == pool_handle)
+            .map(|(cb_id, _)| *cb_id)
+            .collect();
+
+        for cb_id in pending_taa_acceptances {
+            self._complete_taa_acceptance(cb_id, Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("Pool {} is closed", pool_handle))));
+        }
+
+        let pending_iterator_opens: Vec<i32> = self.pending_iterator_opens.borrow().iter()
+            .filter(|&(_, open)| open.pool_handle == pool_handle)
+            .map(|(iterator_handle, _)| *iterator_handle)
+            .collect();
+
+        for iterator_handle in pending_iterator_opens {
+            let open = self.pending_iterator_opens.borrow_mut().remove(&iterator_handle);
+
+            if let Some(open) = open {
+                (open.cb)(Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("Pool {} is closed", pool_handle))));
+            }
+        }
+
+        let ledger_iterators: Vec<i32> = self.ledger_iterators.borrow().iter()
+            .filter(|&(_, iterator)| iterator.pool_handle == pool_handle)
+            .map(|(iterator_handle, _)| *iterator_handle)
+            .collect();
+
+        for iterator_handle in ledger_iterators {
+            self.close_ledger_iterator(iterator_handle).ok();
+        }
+
+        debug!("pool_closed <<<");
+    }
+
     fn validate_opt_did(&self, did: Option<&str>) -> IndyResult<()> {
         match did {
             Some(did) => Ok(self.crypto_service.validate_did(did)?),
@@ -1249,6 +2899,45 @@ impl LedgerCommandExecutor {
         let pool_response = try_cb!(pool_response, cb);
         cb(self.parse_get_cred_def_response(&pool_response));
     }
+
+    fn get_rich_schema_object(&self, pool_handle: i32, submitter_did: Option<&str>, id: &str, cb: Box<Fn(IndyResult<(String, String)>) + Send>) {
+        let request_json = try_cb!(self.build_get_rich_schema_object_by_id_request(submitter_did, id), cb);
+
+        let cb_id = ::utils::sequence::get_next_id();
+        self.pending_callbacks.borrow_mut().insert(cb_id, cb);
+
+        self.submit_request(pool_handle, &request_json, Box::new(move |response| {
+            CommandExecutor::instance().send(
+                Command::Ledger(
+                    LedgerCommand::GetRichSchemaObjectContinue(
+                        response,
+                        cb_id
+                    )
+                )
+            ).unwrap();
+        }));
+    }
+
+    fn _get_rich_schema_object_continue(&self, pool_response: IndyResult<String>, cb_id: i32) {
+        let cb = self.pending_callbacks.borrow_mut().remove(&cb_id).expect("FIXME INVALID STATE");
+        let pool_response = try_cb!(pool_response, cb);
+        cb(self.parse_get_rich_schema_object_response(&pool_response));
+    }
+}
+
+fn _send_delayed(delay: u64, command: LedgerCommand) {
+    thread::spawn(move || {
+        thread::sleep(Duration::from_millis(delay));
+        CommandExecutor::instance().send(Command::Ledger(command)).unwrap();
+    });
+}
+
+fn _jittered(delay: u64, jitter: bool) -> u64 {
+    if jitter && delay > 1 {
+        rand::thread_rng().gen_range(delay / 2, delay + 1)
+    } else {
+        delay
+    }
 }
 
 enum SignatureType {
@@ -2,6 +2,8 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use commands::{Command, CommandExecutor};
+use commands::ledger::LedgerCommand;
 use domain::ledger::request::ProtocolVersion;
 use domain::pool::{PoolConfig, PoolOpenConfig};
 use errors::prelude::*;
@@ -37,6 +39,23 @@ pub enum PoolCommand {
     SetProtocolVersion(
         usize, // protocol version
         Box<Fn(IndyResult<()>) + Send>),
+    GetMetrics(
+        i32, // pool handle
+        Box<Fn(IndyResult<String>) + Send>),
+    CreateSnapshot(
+        String, // name
+        Box<Fn(IndyResult<String>) + Send>),
+    RegisterEventCallback(
+        i32, // pool handle
+        Box<Fn(i32, String) + Send>, // event cb
+        Box<Fn(IndyResult<()>) + Send>),
+    RegisterEventCallbackByName(
+        String, // name
+        Box<Fn(i32, String) + Send>, // event cb
+        Box<Fn(IndyResult<()>) + Send>),
+    StateChanged(
+        i32, // pool handle
+        String), // event json
 }
 
 pub struct PoolCommandExecutor {
@@ -44,6 +63,8 @@ pub struct PoolCommandExecutor {
     close_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<()>)>>>,
     refresh_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<()>)>>>,
     open_callbacks: RefCell<HashMap<i32, Box<Fn(IndyResult<i32>)>>>,
+    event_callbacks: RefCell<HashMap<i32, Box<Fn(i32, String)>>>,
+    name_event_callbacks: RefCell<HashMap<String, Box<Fn(i32, String)>>>,
 }
 
 impl PoolCommandExecutor {
@@ -53,6 +74,8 @@ impl PoolCommandExecutor {
             close_callbacks: RefCell::new(HashMap::new()),
             refresh_callbacks: RefCell::new(HashMap::new()),
             open_callbacks: RefCell::new(HashMap::new()),
+            event_callbacks: RefCell::new(HashMap::new()),
+            name_event_callbacks: RefCell::new(HashMap::new()),
         }
     }
 
@@ -131,6 +154,26 @@ impl PoolCommandExecutor {
                 info!(target: "pool_command_executor", "SetProtocolVersion command received");
                 cb(self.set_protocol_version(protocol_version));
             }
+            PoolCommand::GetMetrics(handle, cb) => {
+                info!(target: "pool_command_executor", "GetMetrics command received");
+                cb(self.get_metrics(handle));
+            }
+            PoolCommand::CreateSnapshot(name, cb) => {
+                info!(target: "pool_command_executor", "CreateSnapshot command received");
+                cb(self.create_snapshot(&name));
+            }
+            PoolCommand::RegisterEventCallback(handle, event_cb, cb) => {
+                info!(target: "pool_command_executor", "RegisterEventCallback command received");
+                cb(self.register_event_callback(handle, event_cb));
+            }
+            PoolCommand::RegisterEventCallbackByName(name, event_cb, cb) => {
+                info!(target: "pool_command_executor", "RegisterEventCallbackByName command received");
+                cb(self.register_event_callback_by_name(&name, event_cb));
+            }
+            PoolCommand::StateChanged(handle, event_json) => {
+                info!(target: "pool_command_executor", "StateChanged command received");
+                self._state_changed(handle, &event_json);
+            }
         };
     }
 
@@ -188,6 +231,10 @@ impl PoolCommandExecutor {
         debug!("close >>> handle: {:?}", handle);
 
         let result = self.pool_service.close(handle)
+            .map(|cmd_id| {
+                CommandExecutor::instance().send(Command::Ledger(LedgerCommand::PoolClosed(handle))).unwrap();
+                cmd_id
+            })
             .and_then(|handle| {
                 match self.close_callbacks.try_borrow_mut() {
                     Ok(cbs) => Ok((cbs, handle)),
@@ -233,4 +280,84 @@ impl PoolCommandExecutor {
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    fn get_metrics(&self, handle: i32) -> IndyResult<String> {
+        debug!("get_metrics >>> handle: {:?}", handle);
+
+        let res = self.pool_service.get_metrics(handle)?;
+
+        debug!("get_metrics << res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn create_snapshot(&self, name: &str) -> IndyResult<String> {
+        debug!("create_snapshot >>> name: {:?}", name);
+
+        let res = self.pool_service.create_snapshot(name)?;
+
+        debug!("create_snapshot << res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn register_event_callback(&self, handle: i32, event_cb: Box<Fn(i32, String) + Send>) -> IndyResult<()> {
+        debug!("register_event_callback >>> handle: {:?}", handle);
+
+        self.pool_service.check_open(handle)?;
+
+        self.event_callbacks.try_borrow_mut()?.insert(handle, event_cb);
+
+        debug!("register_event_callback <<<");
+
+        Ok(())
+    }
+
+    /// Pool may be not opened yet, so events of its opening are passed to the callback as well.
+    fn register_event_callback_by_name(&self, name: &str, event_cb: Box<Fn(i32, String) + Send>) -> IndyResult<()> {
+        debug!("register_event_callback_by_name >>> name: {:?}", name);
+
+        self.name_event_callbacks.try_borrow_mut()?.insert(name.to_string(), event_cb);
+
+        debug!("register_event_callback_by_name <<<");
+
+        Ok(())
+    }
+
+    fn _state_changed(&self, handle: i32, event_json: &str) {
+        debug!("_state_changed >>> handle: {:?}, event_json: {:?}", handle, event_json);
+
+        let event = ::serde_json::from_str::<::serde_json::Value>(event_json).unwrap_or(::serde_json::Value::Null);
+        let closed = event["state"] == "closed";
+
+        match self.event_callbacks.try_borrow_mut() {
+            Ok(mut cbs) => {
+                if let Some(cb) = cbs.get(&handle) {
+                    cb(handle, event_json.to_string());
+                }
+
+                if closed {
+                    cbs.remove(&handle);
+                }
+            }
+            Err(err) => { error!("{:?}", err); }
+        }
+
+        if let Some(name) = event["poolName"].as_str() {
+            match self.name_event_callbacks.try_borrow_mut() {
+                Ok(mut cbs) => {
+                    if let Some(cb) = cbs.get(name) {
+                        cb(handle, event_json.to_string());
+                    }
+
+                    if closed {
+                        cbs.remove(name);
+                    }
+                }
+                Err(err) => { error!("{:?}", err); }
+            }
+        }
+
+        debug!("_state_changed <<<");
+    }
+}
@@ -6,9 +6,12 @@ pub mod credential_offer;
 pub mod credential_request;
 pub mod proof;
 pub mod proof_request;
+pub mod proof_verification_report;
 pub mod requested_credential;
 pub mod revocation_registry_definition;
+pub mod revocation_history;
 pub mod revocation_registry_delta;
+pub mod revocation_registry_rotation;
 pub mod revocation_registry;
 pub mod revocation_state;
 pub mod schema;
@@ -12,6 +12,8 @@ pub struct Proof {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RequestedProof {
     pub revealed_attrs: HashMap<String, RevealedAttributeInfo>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub revealed_attr_groups: HashMap<String, RevealedAttributeGroupInfo>,
     pub self_attested_attrs: HashMap<String, String>,
     pub unrevealed_attrs: HashMap<String, SubProofReferent>,
     pub predicates: HashMap<String, SubProofReferent>
@@ -21,6 +23,7 @@ impl Default for RequestedProof {
     fn default() -> Self {
         RequestedProof {
             revealed_attrs: HashMap::new(),
+            revealed_attr_groups: HashMap::new(),
             self_attested_attrs: HashMap::new(),
             unrevealed_attrs: HashMap::new(),
             predicates: HashMap::new(),
@@ -40,6 +43,17 @@ pub struct RevealedAttributeInfo {
     pub encoded: String
 }
 
+#[derive(Debug, Deserialize, Serialize)]
+pub struct RevealedAttributeGroupInfo {
+    pub sub_proof_index: i32,
+    pub values: HashMap<String, AttributeValue>
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AttributeValue {
+    pub raw: String,
+    pub encoded: String
+}
 
 #[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash)]
 pub struct Identifier {
@@ -23,7 +23,10 @@ pub struct NonRevocedInterval {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct AttributeInfo {
-    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
     pub restrictions: Option<serde_json::Value>,
     pub non_revoked: Option<NonRevocedInterval>
 }
@@ -0,0 +1,118 @@
+use std::collections::HashMap;
+
+/// Result of proof verification with the outcome of each check performed for every requested referent.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ProofVerificationReport {
+    /// `true` if no check failed, the same value `indy_verifier_verify_proof` returns for a valid proof.
+    pub valid: bool,
+    /// Received referents compared with the requested ones.
+    pub requested_proof: VerificationCheck,
+    /// Cryptographic verification of the whole proof: primary and non-revocation proofs are aggregated,
+    /// so they can be verified only together.
+    pub proof: VerificationCheck,
+    pub requested_attributes: HashMap<String, ReferentVerificationReport>,
+    pub requested_predicates: HashMap<String, ReferentVerificationReport>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReferentVerificationReport {
+    pub valid: bool,
+    pub sub_proof_index: Option<i32>,
+    /// Referent is received in the proper part of requested proof with the expected values.
+    pub received: VerificationCheck,
+    pub primary_proof: VerificationCheck,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<VerificationCheck>,
+    pub restrictions: VerificationCheck,
+    pub timestamp: VerificationCheck,
+    pub revocation: VerificationCheck,
+}
+
+impl ReferentVerificationReport {
+    pub fn new(sub_proof_index: Option<i32>,
+               received: VerificationCheck,
+               primary_proof: VerificationCheck,
+               predicate: Option<VerificationCheck>,
+               restrictions: VerificationCheck,
+               timestamp: VerificationCheck,
+               revocation: VerificationCheck) -> ReferentVerificationReport {
+        let valid = ![&received, &primary_proof, &restrictions, &timestamp, &revocation].iter().any(|check| check.is_failed()) &&
+            !predicate.as_ref().map(VerificationCheck::is_failed).unwrap_or(false);
+
+        ReferentVerificationReport { valid, sub_proof_index, received, primary_proof, predicate, restrictions, timestamp, revocation }
+    }
+
+    /// Report for referent that can't be checked further as it isn't received properly.
+    pub fn rejected(sub_proof_index: Option<i32>, received: VerificationCheck, is_predicate: bool) -> ReferentVerificationReport {
+        ReferentVerificationReport::new(sub_proof_index,
+                                        received,
+                                        VerificationCheck::skipped(),
+                                        if is_predicate { Some(VerificationCheck::skipped()) } else { None },
+                                        VerificationCheck::skipped(),
+                                        VerificationCheck::skipped(),
+                                        VerificationCheck::skipped())
+    }
+
+    pub fn self_attested() -> ReferentVerificationReport {
+        ReferentVerificationReport::new(None,
+                                        VerificationCheck::passed(),
+                                        VerificationCheck::skipped(),
+                                        None,
+                                        VerificationCheck::skipped(),
+                                        VerificationCheck::skipped(),
+                                        VerificationCheck::skipped())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct VerificationCheck {
+    pub status: VerificationStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<VerificationFailureReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+impl VerificationCheck {
+    pub fn passed() -> VerificationCheck {
+        VerificationCheck { status: VerificationStatus::Passed, reason: None, message: None }
+    }
+
+    pub fn skipped() -> VerificationCheck {
+        VerificationCheck { status: VerificationStatus::Skipped, reason: None, message: None }
+    }
+
+    pub fn failed(reason: VerificationFailureReason, message: String) -> VerificationCheck {
+        VerificationCheck { status: VerificationStatus::Failed, reason: Some(reason), message: Some(message) }
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.status == VerificationStatus::Failed
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum VerificationStatus {
+    Passed,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VerificationFailureReason {
+    ReferentNotReceived,
+    UnexpectedReferent,
+    InvalidSubProofIndex,
+    AttributeGroupMismatch,
+    AttributeValueMismatch,
+    SelfAttestedNotAllowed,
+    RestrictionsNotSatisfied,
+    TimestampMissing,
+    SchemaNotFound,
+    CredentialDefinitionNotFound,
+    RevocationRegistryDefinitionNotFound,
+    RevocationRegistryNotFound,
+    ProofInvalid,
+}
@@ -0,0 +1,101 @@
+use ursa::cl::RevocationRegistry;
+
+use super::revocation_registry_delta::RevocationRegistryDelta;
+
+use std::collections::BTreeSet;
+
+/// State of revocation registry written by REVOC_REG_ENTRY transaction
+/// as returned by GET_REVOC_REG_DELTA request without `from`: issued and revoked indices are cumulative.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationRegistryState {
+    pub timestamp: u64,
+    pub value: RevocationRegistry,
+    pub issued: BTreeSet<u32>,
+    pub revoked: BTreeSet<u32>,
+}
+
+impl RevocationRegistryState {
+    /// Returns indices issued and revoked since the previous state.
+    /// Index re-issued after revocation is reported as issued and vice versa,
+    /// so the same result is returned for both ISSUANCE_BY_DEFAULT and ISSUANCE_ON_DEMAND registries.
+    pub fn changes_since(&self, prev: Option<&RevocationRegistryState>) -> (BTreeSet<u32>, BTreeSet<u32>) {
+        let empty = BTreeSet::new();
+        let (prev_issued, prev_revoked) = prev.map(|prev| (&prev.issued, &prev.revoked)).unwrap_or((&empty, &empty));
+
+        let issued = self.issued.difference(prev_issued)
+            .chain(prev_revoked.difference(&self.revoked))
+            .cloned()
+            .collect();
+
+        let revoked = self.revoked.difference(prev_revoked)
+            .chain(prev_issued.difference(&self.issued))
+            .cloned()
+            .collect();
+
+        (issued, revoked)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationHistoryEntry {
+    pub timestamp: u64,
+    pub value: RevocationRegistry,
+    pub issued: BTreeSet<u32>,
+    pub revoked: BTreeSet<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RevocationHistory {
+    pub rev_reg_def_id: String,
+    pub entries: Vec<RevocationHistoryEntry>,
+    /// All entries merged into one delta, `None` if there are no entries in the requested interval.
+    pub delta: Option<RevocationRegistryDelta>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json;
+
+    const ACCUM: &str = "1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000";
+
+    fn _state(issued: &[u32], revoked: &[u32]) -> RevocationRegistryState {
+        RevocationRegistryState {
+            timestamp: 100,
+            value: serde_json::from_value(json!({"accum": ACCUM})).unwrap(),
+            issued: issued.iter().cloned().collect(),
+            revoked: revoked.iter().cloned().collect(),
+        }
+    }
+
+    fn _set(indices: &[u32]) -> BTreeSet<u32> {
+        indices.iter().cloned().collect()
+    }
+
+    #[test]
+    fn changes_since_works_for_first_state() {
+        let (issued, revoked) = _state(&[1, 2], &[]).changes_since(None);
+        assert_eq!(_set(&[1, 2]), issued);
+        assert_eq!(_set(&[]), revoked);
+    }
+
+    #[test]
+    fn changes_since_works_for_issuance_on_demand() {
+        let prev = _state(&[1, 2, 3], &[]);
+        let (issued, revoked) = _state(&[1, 3, 4], &[]).changes_since(Some(&prev));
+        assert_eq!(_set(&[4]), issued);
+        assert_eq!(_set(&[2]), revoked);
+    }
+
+    #[test]
+    fn changes_since_works_for_issuance_by_default() {
+        let prev = _state(&[], &[1, 2]);
+        let (issued, revoked) = _state(&[], &[2, 3]).changes_since(Some(&prev));
+        assert_eq!(_set(&[1]), issued);
+        assert_eq!(_set(&[3]), revoked);
+    }
+}
@@ -0,0 +1,147 @@
+use super::revocation_registry_definition::{RevocationRegistryConfig, RevocationRegistryDefinition};
+
+use named_type::NamedType;
+
+pub const DEFAULT_FILL_THRESHOLD: f64 = 0.8;
+
+pub const REVOC_REG_DEF: &str = "REVOC_REG_DEF";
+pub const REVOC_REG_ENTRY: &str = "REVOC_REG_ENTRY";
+
+#[derive(Deserialize, Debug, Serialize, Clone)]
+pub struct RevocationRegistryRotationConfig {
+    pub tag: Option<String>,
+    pub issuance_type: Option<String>,
+    pub max_cred_num: Option<u32>,
+    pub fill_threshold: Option<f64>,
+    pub tails_writer_type: String,
+    pub tails_writer_config: serde_json::Value,
+}
+
+impl RevocationRegistryRotationConfig {
+    pub fn fill_threshold(&self) -> f64 {
+        self.fill_threshold.unwrap_or(DEFAULT_FILL_THRESHOLD)
+    }
+
+    pub fn revocation_registry_config(&self) -> RevocationRegistryConfig {
+        RevocationRegistryConfig {
+            issuance_type: self.issuance_type.clone(),
+            max_cred_num: self.max_cred_num,
+        }
+    }
+}
+
+/// Issuer-side state of revocation registries rotated automatically for a credential definition.
+#[derive(Debug, Deserialize, Serialize, NamedType)]
+pub struct RevocationRegistryRotation {
+    pub issuer_did: String,
+    pub cred_def_id: String,
+    pub config: RevocationRegistryRotationConfig,
+    /// Number of registries created for the credential definition, used to build unique registry tags.
+    pub seq_no: u32,
+    pub active_rev_reg_id: String,
+    pub next_rev_reg_id: Option<String>,
+    pub pending_publications: Vec<RevocationRegistryPublication>,
+}
+
+impl RevocationRegistryRotation {
+    pub fn next_tag(&self) -> String {
+        let seq_no = self.seq_no + 1;
+
+        match self.config.tag {
+            Some(ref tag) => format!("{}_{}", tag, seq_no),
+            None => seq_no.to_string()
+        }
+    }
+
+    pub fn is_next_required(&self, issued: u32, max_cred_num: u32) -> bool {
+        self.next_rev_reg_id.is_none() && issued as f64 / max_cred_num as f64 >= self.config.fill_threshold()
+    }
+
+    pub fn is_published(&self, rev_reg_id: &str) -> bool {
+        !self.pending_publications.iter().any(|publication| publication.rev_reg_id == rev_reg_id)
+    }
+}
+
+/// Ledger request that must be sent by the issuer to make the registry usable by provers and verifiers.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct RevocationRegistryPublication {
+    pub rev_reg_id: String,
+    pub txn_type: String,
+    pub request: serde_json::Value,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ActiveRevocationRegistry {
+    pub rev_reg_id: String,
+    pub rev_reg_def: RevocationRegistryDefinition,
+    pub issued: u32,
+    pub max_cred_num: u32,
+    pub published: bool,
+    pub next_rev_reg_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _rotation(tag: Option<&str>, fill_threshold: Option<f64>, next_rev_reg_id: Option<&str>) -> RevocationRegistryRotation {
+        RevocationRegistryRotation {
+            issuer_did: "NcYxiDXkpYi6ov5FcYDi1e".to_string(),
+            cred_def_id: "NcYxiDXkpYi6ov5FcYDi1e:3:CL:1:tag".to_string(),
+            config: RevocationRegistryRotationConfig {
+                tag: tag.map(String::from),
+                issuance_type: None,
+                max_cred_num: Some(10),
+                fill_threshold,
+                tails_writer_type: "default".to_string(),
+                tails_writer_config: json!({}),
+            },
+            seq_no: 1,
+            active_rev_reg_id: "rev_reg_1".to_string(),
+            next_rev_reg_id: next_rev_reg_id.map(String::from),
+            pending_publications: vec![
+                RevocationRegistryPublication {
+                    rev_reg_id: "rev_reg_1".to_string(),
+                    txn_type: REVOC_REG_DEF.to_string(),
+                    request: json!({}),
+                }
+            ],
+        }
+    }
+
+    #[test]
+    fn next_tag_works() {
+        assert_eq!("2", _rotation(None, None, None).next_tag());
+    }
+
+    #[test]
+    fn next_tag_works_for_tag_prefix() {
+        assert_eq!("prefix_2", _rotation(Some("prefix"), None, None).next_tag());
+    }
+
+    #[test]
+    fn is_next_required_works_for_default_threshold() {
+        let rotation = _rotation(None, None, None);
+        assert!(!rotation.is_next_required(7, 10));
+        assert!(rotation.is_next_required(8, 10));
+    }
+
+    #[test]
+    fn is_next_required_works_for_custom_threshold() {
+        let rotation = _rotation(None, Some(0.7), None);
+        assert!(!rotation.is_next_required(6, 10));
+        assert!(rotation.is_next_required(7, 10));
+    }
+
+    #[test]
+    fn is_next_required_works_for_existing_next() {
+        assert!(!_rotation(None, None, Some("rev_reg_2")).is_next_required(9, 10));
+    }
+
+    #[test]
+    fn is_published_works() {
+        let rotation = _rotation(None, None, None);
+        assert!(!rotation.is_published("rev_reg_1"));
+        assert!(rotation.is_published("rev_reg_2"));
+    }
+}
@@ -1,5 +1,7 @@
 use super::constants::{ATTRIB, GET_ATTR};
-use super::response::GetReplyResultV1;
+use super::response::{GetReplyResultV1, ReplyType};
+
+use serde_json::Value;
 
 use named_type::NamedType;
 
@@ -61,13 +63,23 @@ pub enum GetAttrReplyResult {
     GetAttrReplyResultV1(GetReplyResultV1<GetAttResultDataV1>)
 }
 
+impl ReplyType for GetAttrReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_ATTR
+    }
+}
+
 #[derive(Deserialize, Eq, PartialEq, Debug)]
 #[serde(rename_all = "camelCase")]
 pub struct GetAttResultV0 {
-    pub  identifier: String,
-    pub  data: String,
+    pub  identifier: Option<String>,
+    pub  data: Option<String>,
     pub  dest: String,
-    pub  raw: String
+    pub  raw: Option<String>,
+    pub  hash: Option<String>,
+    pub  enc: Option<String>,
+    pub  seq_no: Option<u64>,
+    pub  txn_time: Option<u64>
 }
 
 #[derive(Deserialize, Eq, PartialEq, Debug)]
@@ -75,7 +87,23 @@ pub struct GetAttResultDataV1 {
     pub ver: String,
     pub id: String,
     pub did: String,
-    pub raw: String,
+    pub raw: Option<String>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+}
+
+/// Attribute returned by GET_ATTRIB reply regardless of reply version.
+/// Only one of raw, hash and enc is set.
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedAttrib {
+    pub did: String,
+    /// Raw attribute decoded from json string.
+    pub raw: Option<Value>,
+    pub hash: Option<String>,
+    pub enc: Option<String>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>
 }
 
 #[derive(Deserialize, Debug)]
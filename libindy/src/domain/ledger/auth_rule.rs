@@ -247,4 +247,201 @@ impl AuthRulesOperation {
     pub fn new(rules: AuthRules) -> AuthRulesOperation {
         AuthRulesOperation { _type: AUTH_RULES.to_string(), rules }
     }
-}
\ No newline at end of file
+}
+
+/// Key of auth rule which applies to an action performed by write request.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct AuthActionKey {
+    pub auth_type: String,
+    pub auth_action: String,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+}
+
+impl AuthActionKey {
+    pub fn add(auth_type: &str, field: &str, new_value: &str) -> AuthActionKey {
+        AuthActionKey {
+            auth_type: auth_type.to_string(),
+            auth_action: "ADD".to_string(),
+            field: field.to_string(),
+            old_value: None,
+            new_value: Some(new_value.to_string()),
+        }
+    }
+
+    pub fn edit(auth_type: &str, field: &str, old_value: &str, new_value: &str) -> AuthActionKey {
+        AuthActionKey {
+            auth_type: auth_type.to_string(),
+            auth_action: "EDIT".to_string(),
+            field: field.to_string(),
+            old_value: Some(old_value.to_string()),
+            new_value: Some(new_value.to_string()),
+        }
+    }
+}
+
+impl AuthRule {
+    /// Returns number of wildcards used by the rule to match the action or None if the rule doesn't match it.
+    pub fn matches(&self, key: &AuthActionKey) -> Option<usize> {
+        fn _match(rule_value: Option<&String>, value: Option<&String>) -> Option<usize> {
+            match rule_value.map(String::as_str) {
+                Some("*") => Some(1),
+                rule_value => if rule_value.unwrap_or("") == value.map(String::as_str).unwrap_or("") { Some(0) } else { None }
+            }
+        }
+
+        if self.auth_type != key.auth_type || self.auth_action != key.auth_action {
+            return None;
+        }
+
+        let field = _match(Some(&self.field), Some(&key.field))?;
+        let new_value = _match(self.new_value.as_ref(), key.new_value.as_ref())?;
+        let old_value = if key.auth_action == "ADD" { 0 } else { _match(self.old_value.as_ref(), key.old_value.as_ref())? };
+
+        Some(field + new_value + old_value)
+    }
+}
+
+/**
+   Role information of the DID which signs a request
+    # parameters
+   role - Role code of the signer, None for the signer without role.
+   is_owner - Whether the signer is an owner of the changed ledger entry.
+   off_ledger - Whether the signer DID isn't written on the ledger.
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SignerRoleInfo {
+    pub role: Option<String>,
+    #[serde(default)]
+    pub is_owner: bool,
+    #[serde(default)]
+    pub off_ledger: bool,
+}
+
+/**
+   Role information of the request submitter and other signers of the request (endorser, multi-signature)
+*/
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitterRoleInfo {
+    #[serde(flatten)]
+    pub submitter: SignerRoleInfo,
+    #[serde(default)]
+    pub additional_signers: Vec<SignerRoleInfo>,
+}
+
+impl SubmitterRoleInfo {
+    pub fn signers(&self) -> Vec<&SignerRoleInfo> {
+        let mut signers = vec![&self.submitter];
+        signers.extend(self.additional_signers.iter());
+        signers
+    }
+}
+
+/**
+   Result of constraint evaluation
+    # parameters
+   Satisfied - The part of the constraint satisfied by signers
+   Missing - The part of the constraint which isn't satisfied yet (sig_count is decreased by matching signatures)
+*/
+#[derive(PartialEq, Debug)]
+pub enum ConstraintCheck {
+    Satisfied(Constraint),
+    Missing(Constraint),
+}
+
+impl Constraint {
+    pub fn check(&self, signers: &[&SignerRoleInfo]) -> ConstraintCheck {
+        match *self {
+            Constraint::RoleConstraint(ref constraint) => {
+                let count = signers.iter().filter(|signer| constraint.is_matched_by(signer)).count() as u32;
+
+                if count >= constraint.sig_count {
+                    ConstraintCheck::Satisfied(self.clone())
+                } else {
+                    ConstraintCheck::Missing(Constraint::RoleConstraint(RoleConstraint {
+                        sig_count: constraint.sig_count - count,
+                        ..constraint.clone()
+                    }))
+                }
+            }
+            Constraint::AndConstraint(ref constraint) => {
+                let missing: Vec<Constraint> = constraint.auth_constraints.iter()
+                    .filter_map(|constraint| match constraint.check(signers) {
+                        ConstraintCheck::Satisfied(_) => None,
+                        ConstraintCheck::Missing(missing) => Some(missing)
+                    })
+                    .collect();
+
+                if missing.is_empty() {
+                    ConstraintCheck::Satisfied(self.clone())
+                } else {
+                    ConstraintCheck::Missing(Constraint::_combine(missing, Constraint::AndConstraint))
+                }
+            }
+            Constraint::OrConstraint(ref constraint) => {
+                let mut missing = Vec::new();
+
+                for constraint in constraint.auth_constraints.iter() {
+                    match constraint.check(signers) {
+                        satisfied @ ConstraintCheck::Satisfied(_) => return satisfied,
+                        ConstraintCheck::Missing(constraint) => missing.push(constraint)
+                    }
+                }
+
+                ConstraintCheck::Missing(Constraint::_combine(missing, Constraint::OrConstraint))
+            }
+            Constraint::ForbiddenConstraint(_) => ConstraintCheck::Missing(self.clone())
+        }
+    }
+
+    fn _combine(mut constraints: Vec<Constraint>, combination: fn(CombinationConstraint) -> Constraint) -> Constraint {
+        if constraints.len() == 1 {
+            constraints.remove(0)
+        } else {
+            combination(CombinationConstraint { auth_constraints: constraints })
+        }
+    }
+}
+
+impl RoleConstraint {
+    fn is_matched_by(&self, signer: &SignerRoleInfo) -> bool {
+        if signer.off_ledger && !self.off_ledger_signature {
+            return false;
+        }
+
+        if self.need_to_be_owner && !signer.is_owner {
+            return false;
+        }
+
+        match self.role.as_ref().map(String::as_str) {
+            Some("*") => true,
+            role => role.unwrap_or("") == signer.role.as_ref().map(String::as_str).unwrap_or("")
+        }
+    }
+}
+
+/**
+   Evaluation of auth rule for single action of request
+    # parameters
+   constraint - Constraint of the rule, None if the ledger has no rule for the action.
+   satisfied - The part of the constraint satisfied by signers.
+   missing - The part of the constraint which must be satisfied additionally.
+*/
+#[derive(Serialize, PartialEq, Debug)]
+pub struct AuthCheck {
+    #[serde(flatten)]
+    pub action: AuthActionKey,
+    pub authorized: bool,
+    pub constraint: Option<Constraint>,
+    pub satisfied: Option<Constraint>,
+    pub missing: Option<Constraint>,
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct AuthCheckResult {
+    pub authorized: bool,
+    pub actions: Vec<AuthCheck>,
+}
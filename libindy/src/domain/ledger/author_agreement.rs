@@ -1,4 +1,5 @@
 use super::constants::{TXN_AUTHR_AGRMT, GET_TXN_AUTHR_AGRMT, TXN_AUTHR_AGRMT_AML, GET_TXN_AUTHR_AGRMT_AML};
+use super::response::ReplyType;
 
 use std::collections::HashMap;
 
@@ -50,6 +51,27 @@ impl GetTxnAuthorAgreementOperation {
     }
 }
 
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnAuthorAgreementReplyResult {
+    pub data: Option<TxnAuthorAgreementData>,
+    pub txn_time: Option<u64>,
+}
+
+impl ReplyType for GetTxnAuthorAgreementReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TxnAuthorAgreementData {
+    pub text: String,
+    pub version: String,
+    pub digest: Option<String>,
+    pub ratification_ts: Option<u64>,
+}
+
 pub type AcceptanceMechanisms = HashMap<String, ::serde_json::Value>;
 
 #[derive(Serialize, PartialEq, Debug)]
@@ -92,4 +114,21 @@ impl GetAcceptanceMechanismOperation {
             version,
         }
     }
-}
\ No newline at end of file
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetAcceptanceMechanismReplyResult {
+    pub data: Option<AcceptanceMechanismData>,
+}
+
+impl ReplyType for GetAcceptanceMechanismReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN_AUTHR_AGRMT_AML
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct AcceptanceMechanismData {
+    pub aml: AcceptanceMechanisms,
+    pub version: String,
+}
@@ -0,0 +1,110 @@
+use std::time::{Duration, Instant};
+
+pub const CONFIRM_TIMEOUT: u64 = 60;
+pub const CONFIRM_INTERVAL: u64 = 1000;
+
+/// Options of waiting until written transaction is readable with valid state proof.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ConfirmOptions {
+    /// Deadline in seconds counted from the write reply.
+    #[serde(default = "ConfirmOptions::default_timeout")]
+    pub timeout: u64,
+    /// Delay between reads in milliseconds.
+    #[serde(default = "ConfirmOptions::default_interval")]
+    pub interval: u64,
+}
+
+impl Default for ConfirmOptions {
+    fn default() -> Self {
+        ConfirmOptions {
+            timeout: ConfirmOptions::default_timeout(),
+            interval: ConfirmOptions::default_interval(),
+        }
+    }
+}
+
+/// What to do after a read of the written transaction.
+#[derive(Debug, PartialEq)]
+pub enum ConfirmStep {
+    Confirmed,
+    /// Read again in the given number of milliseconds.
+    Retry(u64),
+    TimedOut,
+}
+
+impl ConfirmOptions {
+    /// Deadline of confirmation of transaction replied at `replied`.
+    pub fn deadline(&self, replied: Instant) -> Instant {
+        replied + Duration::from_secs(self.timeout)
+    }
+
+    /// Unconfirmed transaction is read again only if the next read starts before deadline.
+    pub fn next_step(&self, confirmed: bool, now: Instant, deadline: Instant) -> ConfirmStep {
+        if confirmed {
+            ConfirmStep::Confirmed
+        } else if now + Duration::from_millis(self.interval) >= deadline {
+            ConfirmStep::TimedOut
+        } else {
+            ConfirmStep::Retry(self.interval)
+        }
+    }
+
+    fn default_timeout() -> u64 {
+        CONFIRM_TIMEOUT
+    }
+
+    fn default_interval() -> u64 {
+        CONFIRM_INTERVAL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn _options() -> ConfirmOptions {
+        ConfirmOptions { timeout: 10, interval: 1000 }
+    }
+
+    #[test]
+    fn deadline_works() {
+        let replied = Instant::now();
+        assert_eq!(replied + Duration::from_secs(10), _options().deadline(replied));
+    }
+
+    #[test]
+    fn next_step_works_for_confirmed() {
+        let now = Instant::now();
+        let options = _options();
+        assert_eq!(ConfirmStep::Confirmed, options.next_step(true, now, options.deadline(now)));
+    }
+
+    #[test]
+    fn next_step_works_for_confirmed_after_deadline() {
+        let now = Instant::now();
+        assert_eq!(ConfirmStep::Confirmed, _options().next_step(true, now + Duration::from_secs(20), now));
+    }
+
+    #[test]
+    fn next_step_works_for_not_confirmed_before_deadline() {
+        let now = Instant::now();
+        let options = _options();
+        assert_eq!(ConfirmStep::Retry(1000), options.next_step(false, now, options.deadline(now)));
+    }
+
+    #[test]
+    fn next_step_works_for_timeout() {
+        let now = Instant::now();
+        let options = _options();
+        let deadline = options.deadline(now);
+        assert_eq!(ConfirmStep::TimedOut, options.next_step(false, now + Duration::from_secs(9), deadline));
+        assert_eq!(ConfirmStep::TimedOut, options.next_step(false, now + Duration::from_secs(11), deadline));
+    }
+
+    #[test]
+    fn deserialize_works_for_defaults() {
+        let options: ConfirmOptions = serde_json::from_str("{}").unwrap();
+        assert_eq!(CONFIRM_TIMEOUT, options.timeout);
+        assert_eq!(CONFIRM_INTERVAL, options.interval);
+    }
+}
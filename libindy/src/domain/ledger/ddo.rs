@@ -1,17 +1,296 @@
-use super::constants::GET_DDO;
+use serde_json::Value;
 
-#[derive(Serialize, PartialEq, Debug)]
-pub struct GetDdoOperation {
+use super::attrib::ParsedAttrib;
+use super::nym::ParsedNym;
+
+pub const DID_SOV_PREFIX: &str = "did:sov:";
+pub const DID_DOCUMENT_CONTENT_TYPE: &str = "application/did+ld+json";
+
+const DID_CONTEXT: &str = "https://www.w3.org/ns/did/v1";
+const ED25519_2018_CONTEXT: &str = "https://w3id.org/security/suites/ed25519-2018/v1";
+const ED25519_2018_KEY_TYPE: &str = "Ed25519VerificationKey2018";
+const ENDPOINT_SERVICE_TYPE: &str = "endpoint";
+const DID_COMMUNICATION_SERVICE_TYPE: &str = "did-communication";
+
+/// Fields of endpoint attribute which describe the main endpoint and aren't services on their own.
+const ENDPOINT_FIELDS: [&str; 5] = ["endpoint", "ha", "types", "routingKeys", "verkey"];
+
+/// W3C DID Core document of did:sov DID.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidDocument {
+    #[serde(rename = "@context")]
+    pub context: Vec<String>,
+    pub id: String,
+    pub verification_method: Vec<VerificationMethod>,
+    pub authentication: Vec<String>,
+    pub assertion_method: Vec<String>,
+    pub service: Vec<Service>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct VerificationMethod {
+    pub id: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub controller: String,
+    pub public_key_base58: String,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Service {
+    pub id: String,
     #[serde(rename = "type")]
-    pub _type: String,
-    pub dest: String
+    pub type_: String,
+    pub service_endpoint: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_keys: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routing_keys: Option<Vec<String>>,
+}
+
+/// Result of did:sov DID resolution.
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionResult {
+    pub did_document: DidDocument,
+    pub did_resolution_metadata: DidResolutionMetadata,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DidResolutionMetadata {
+    pub content_type: String,
+    /// Metadata of GET_NYM reply.
+    pub nym: LedgerReadMetadata,
+    /// Metadata of GET_ATTRIB reply for endpoint attribute, None if DID has no endpoint.
+    pub endpoint: Option<LedgerReadMetadata>,
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct LedgerReadMetadata {
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+    /// Whether the reply was confirmed by state proof of a single node.
+    pub state_proof: bool,
+}
+
+/// Returns unqualified DID for either `did:sov:` qualified or unqualified DID.
+pub fn unqualify_did(did: &str) -> &str {
+    if did.starts_with(DID_SOV_PREFIX) {
+        &did[DID_SOV_PREFIX.len()..]
+    } else {
+        did
+    }
 }
 
-impl GetDdoOperation {
-    pub fn new(dest: String) -> GetDdoOperation {
-        GetDdoOperation {
-            _type: GET_DDO.to_string(),
-            dest
+impl DidDocument {
+    /// Builds DID Document from NYM and value of raw `endpoint` attribute.
+    ///
+    /// Endpoint may be either a string or an object containing `endpoint` (with optional `types` and `routingKeys`)
+    /// or legacy `ha` field. Other string fields of endpoint object are treated as named service endpoints.
+    pub fn new(nym: &ParsedNym, endpoint: Option<&Value>) -> DidDocument {
+        let id = format!("{}{}", DID_SOV_PREFIX, nym.did);
+        let key_id = format!("{}#key-1", id);
+
+        let verification_method: Vec<VerificationMethod> = nym.verkey.iter()
+            .map(|verkey| VerificationMethod {
+                id: key_id.clone(),
+                type_: ED25519_2018_KEY_TYPE.to_string(),
+                controller: id.clone(),
+                public_key_base58: verkey.clone(),
+            })
+            .collect();
+
+        let key_refs: Vec<String> = verification_method.iter().map(|method| method.id.clone()).collect();
+
+        let service = endpoint
+            .map(|endpoint| DidDocument::_services(&id, &key_refs, endpoint))
+            .unwrap_or_else(Vec::new);
+
+        DidDocument {
+            context: vec![DID_CONTEXT.to_string(), ED25519_2018_CONTEXT.to_string()],
+            id,
+            verification_method,
+            authentication: key_refs.clone(),
+            assertion_method: key_refs,
+            service,
         }
     }
-}
\ No newline at end of file
+
+    fn _services(id: &str, key_refs: &[String], endpoint: &Value) -> Vec<Service> {
+        let mut services = Vec::new();
+
+        let main_endpoint = endpoint.as_str()
+            .or_else(|| endpoint["endpoint"].as_str())
+            .or_else(|| endpoint["ha"].as_str());
+
+        if let Some(main_endpoint) = main_endpoint {
+            let types: Vec<&str> = match endpoint["types"].as_array() {
+                Some(types) => types.iter().filter_map(Value::as_str).collect(),
+                None => vec![ENDPOINT_SERVICE_TYPE, DID_COMMUNICATION_SERVICE_TYPE]
+            };
+
+            if types.contains(&ENDPOINT_SERVICE_TYPE) {
+                services.push(Service {
+                    id: format!("{}#{}", id, ENDPOINT_SERVICE_TYPE),
+                    type_: ENDPOINT_SERVICE_TYPE.to_string(),
+                    service_endpoint: main_endpoint.to_string(),
+                    recipient_keys: None,
+                    routing_keys: None,
+                });
+            }
+
+            // DIDComm service requires key of DID to encrypt messages for
+            if types.contains(&DID_COMMUNICATION_SERVICE_TYPE) && !key_refs.is_empty() {
+                let routing_keys = endpoint["routingKeys"].as_array()
+                    .map(|keys| keys.iter().filter_map(Value::as_str).map(String::from).collect())
+                    .unwrap_or_else(Vec::new);
+
+                services.push(Service {
+                    id: format!("{}#{}", id, DID_COMMUNICATION_SERVICE_TYPE),
+                    type_: DID_COMMUNICATION_SERVICE_TYPE.to_string(),
+                    service_endpoint: main_endpoint.to_string(),
+                    recipient_keys: Some(key_refs.to_vec()),
+                    routing_keys: Some(routing_keys),
+                });
+            }
+        }
+
+        if let Some(fields) = endpoint.as_object() {
+            for (name, value) in fields {
+                if ENDPOINT_FIELDS.contains(&name.as_str()) {
+                    continue;
+                }
+
+                if let Some(value) = value.as_str() {
+                    services.push(Service {
+                        id: format!("{}#{}", id, name),
+                        type_: name.clone(),
+                        service_endpoint: value.to_string(),
+                        recipient_keys: None,
+                        routing_keys: None,
+                    });
+                }
+            }
+        }
+
+        services
+    }
+}
+
+impl DidResolutionResult {
+    pub fn new(nym: &ParsedNym, nym_state_proof: bool, endpoint: Option<(&ParsedAttrib, bool)>) -> DidResolutionResult {
+        let endpoint_value = endpoint
+            .and_then(|(attrib, _)| attrib.raw.as_ref())
+            .map(|raw| &raw["endpoint"]);
+
+        DidResolutionResult {
+            did_document: DidDocument::new(nym, endpoint_value),
+            did_resolution_metadata: DidResolutionMetadata {
+                content_type: DID_DOCUMENT_CONTENT_TYPE.to_string(),
+                nym: LedgerReadMetadata {
+                    seq_no: nym.seq_no,
+                    txn_time: nym.txn_time,
+                    state_proof: nym_state_proof,
+                },
+                endpoint: endpoint.map(|(attrib, state_proof)| LedgerReadMetadata {
+                    seq_no: attrib.seq_no,
+                    txn_time: attrib.txn_time,
+                    state_proof,
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DID: &str = "VsKV7grR1BUE29mG2Fm2kX";
+    const VERKEY: &str = "GjZWsBLgZCR18aL468JAT7w9CZRiBnpxUPPgyQxh4voa";
+
+    fn _nym() -> ParsedNym {
+        ParsedNym {
+            did: DID.to_string(),
+            verkey: Some(VERKEY.to_string()),
+            role: None,
+            seq_no: Some(10),
+            txn_time: Some(1_500_000_000),
+        }
+    }
+
+    #[test]
+    fn unqualify_did_works() {
+        assert_eq!(DID, unqualify_did(DID));
+        assert_eq!(DID, unqualify_did(&format!("did:sov:{}", DID)));
+    }
+
+    #[test]
+    fn did_document_new_works_without_endpoint() {
+        let doc = DidDocument::new(&_nym(), None);
+
+        assert_eq!("did:sov:VsKV7grR1BUE29mG2Fm2kX", doc.id);
+        assert_eq!(1, doc.verification_method.len());
+        assert_eq!(VERKEY, doc.verification_method[0].public_key_base58);
+        assert_eq!(vec!["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1".to_string()], doc.authentication);
+        assert!(doc.service.is_empty());
+    }
+
+    #[test]
+    fn did_document_new_works_without_verkey() {
+        let mut nym = _nym();
+        nym.verkey = None;
+
+        let doc = DidDocument::new(&nym, Some(&json!("https://agent.example.com")));
+
+        assert!(doc.verification_method.is_empty());
+        assert!(doc.authentication.is_empty());
+        assert_eq!(1, doc.service.len());
+        assert_eq!("endpoint", doc.service[0].type_);
+    }
+
+    #[test]
+    fn did_document_new_works_for_endpoint_with_default_types() {
+        let endpoint = json!({"endpoint": "https://agent.example.com", "routingKeys": ["routing"]});
+
+        let doc = DidDocument::new(&_nym(), Some(&endpoint));
+
+        assert_eq!(2, doc.service.len());
+        assert_eq!("did:sov:VsKV7grR1BUE29mG2Fm2kX#endpoint", doc.service[0].id);
+        assert_eq!("https://agent.example.com", doc.service[0].service_endpoint);
+        assert_eq!("did-communication", doc.service[1].type_);
+        assert_eq!(Some(vec!["did:sov:VsKV7grR1BUE29mG2Fm2kX#key-1".to_string()]), doc.service[1].recipient_keys);
+        assert_eq!(Some(vec!["routing".to_string()]), doc.service[1].routing_keys);
+    }
+
+    #[test]
+    fn did_document_new_works_for_endpoint_with_types_and_named_services() {
+        let endpoint = json!({
+            "endpoint": "https://agent.example.com",
+            "types": ["endpoint"],
+            "profile": "https://profile.example.com"
+        });
+
+        let doc = DidDocument::new(&_nym(), Some(&endpoint));
+
+        assert_eq!(2, doc.service.len());
+        assert_eq!("endpoint", doc.service[0].type_);
+        assert_eq!("did:sov:VsKV7grR1BUE29mG2Fm2kX#profile", doc.service[1].id);
+        assert_eq!("https://profile.example.com", doc.service[1].service_endpoint);
+    }
+
+    #[test]
+    fn did_document_new_works_for_legacy_ha_endpoint() {
+        let endpoint = json!({"ha": "127.0.0.1:5555", "verkey": VERKEY});
+
+        let doc = DidDocument::new(&_nym(), Some(&endpoint));
+
+        assert_eq!(2, doc.service.len());
+        assert_eq!("127.0.0.1:5555", doc.service[0].service_endpoint);
+    }
+}
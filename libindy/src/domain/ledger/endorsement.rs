@@ -0,0 +1,79 @@
+use serde_json::Value;
+
+/// Write request prepared and signed by its author which is handed over to endorser.
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EndorsementBundle {
+    pub request: Value,
+    pub author: String,
+    pub endorser: String,
+    pub txn_type: String,
+    /// Human-readable summary of request operation.
+    pub summary: String,
+    /// Time of bundle creation in seconds since Unix epoch.
+    pub created: i64,
+    /// Arbitrary data author passes to endorser along with the request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+}
+
+/// Result of verification of a request signature against verkey written on the ledger.
+#[derive(Serialize, Deserialize, Debug)]
+pub struct SignatureCheck {
+    pub did: String,
+    /// Verkey of the DID on the ledger, None if the DID isn't written.
+    pub verkey: Option<String>,
+    pub valid: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EndorsementReview {
+    pub author: String,
+    pub endorser: String,
+    pub txn_type: String,
+    pub summary: String,
+    pub created: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<Value>,
+    pub signatures: Vec<SignatureCheck>,
+    /// Whether request is signed by its author and all signatures are valid.
+    pub valid: bool,
+}
+
+impl EndorsementReview {
+    pub fn new(bundle: EndorsementBundle, signatures: Vec<SignatureCheck>) -> EndorsementReview {
+        let valid = signatures.iter().any(|check| check.did == bundle.author) &&
+            signatures.iter().all(|check| check.valid);
+
+        EndorsementReview {
+            author: bundle.author,
+            endorser: bundle.endorser,
+            txn_type: bundle.txn_type,
+            summary: bundle.summary,
+            created: bundle.created,
+            metadata: bundle.metadata,
+            signatures,
+            valid,
+        }
+    }
+}
+
+/// Returns pairs of DID and base58 signature for both single and multi signatures of the request.
+pub fn request_signatures(request: &Value) -> Vec<(String, String)> {
+    let mut signatures = Vec::new();
+
+    if let (Some(identifier), Some(signature)) = (request["identifier"].as_str(), request["signature"].as_str()) {
+        signatures.push((identifier.to_string(), signature.to_string()));
+    }
+
+    if let Some(multi_signatures) = request["signatures"].as_object() {
+        for (did, signature) in multi_signatures {
+            if let Some(signature) = signature.as_str() {
+                signatures.push((did.to_string(), signature.to_string()));
+            }
+        }
+    }
+
+    signatures
+}
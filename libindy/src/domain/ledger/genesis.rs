@@ -0,0 +1,108 @@
+use super::constants::{NODE, NYM};
+use super::node::{NodeOperationData, Services};
+
+pub const GENESIS_TXN_VERSION: &str = "1";
+
+/// Description of a new network passed to `indy_build_genesis_transactions`.
+#[derive(Deserialize, Debug)]
+pub struct GenesisConfig {
+    pub trustee_seeds: Vec<String>,
+    pub nodes: Vec<GenesisNodeConfig>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GenesisNodeConfig {
+    pub alias: String,
+    pub seed: String,
+    pub steward_seed: String,
+    pub node_ip: String,
+    pub node_port: i32,
+    pub client_ip: String,
+    pub client_port: i32,
+    pub services: Option<Vec<Services>>,
+}
+
+/// Keys of a validator node and its steward derived from `GenesisNodeConfig` seeds.
+#[derive(Debug)]
+pub struct GenesisNode {
+    pub steward_did: String,
+    pub steward_verkey: String,
+    pub dest: String,
+    pub data: NodeOperationData,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisTxn<T> {
+    pub req_signature: GenesisReqSignature,
+    pub txn: GenesisTxnBody<T>,
+    pub txn_metadata: GenesisTxnMetadata,
+    pub ver: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GenesisReqSignature {}
+
+#[derive(Serialize, Debug)]
+pub struct GenesisTxnBody<T> {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub data: T,
+    pub metadata: GenesisTxnAuthor,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GenesisTxnAuthor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GenesisTxnMetadata {
+    pub seq_no: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub txn_id: Option<String>,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GenesisNodeData {
+    pub data: NodeOperationData,
+    pub dest: String,
+}
+
+#[derive(Serialize, Debug)]
+pub struct GenesisNymData {
+    pub dest: String,
+    pub role: String,
+    pub verkey: String,
+}
+
+impl<T> GenesisTxn<T> {
+    fn new(seq_no: u64, _type: &str, data: T, from: Option<&str>, txn_id: Option<String>) -> GenesisTxn<T> {
+        GenesisTxn {
+            req_signature: GenesisReqSignature {},
+            txn: GenesisTxnBody {
+                _type: _type.to_string(),
+                data,
+                metadata: GenesisTxnAuthor { from: from.map(String::from) },
+            },
+            txn_metadata: GenesisTxnMetadata { seq_no, txn_id },
+            ver: GENESIS_TXN_VERSION.to_string(),
+        }
+    }
+}
+
+impl GenesisTxn<GenesisNodeData> {
+    pub fn node(seq_no: u64, txn_id: String, node: GenesisNode) -> GenesisTxn<GenesisNodeData> {
+        let data = GenesisNodeData { data: node.data, dest: node.dest };
+        GenesisTxn::new(seq_no, NODE, data, Some(&node.steward_did), Some(txn_id))
+    }
+}
+
+impl GenesisTxn<GenesisNymData> {
+    pub fn nym(seq_no: u64, from: Option<&str>, dest: &str, verkey: &str, role: &str) -> GenesisTxn<GenesisNymData> {
+        let data = GenesisNymData { dest: dest.to_string(), role: role.to_string(), verkey: verkey.to_string() };
+        GenesisTxn::new(seq_no, NYM, data, from, None)
+    }
+}
@@ -0,0 +1,34 @@
+use super::constants::{LEDGERS_FREEZE, GET_FROZEN_LEDGERS};
+
+/// Identifiers of base ledgers (pool, domain, config and audit) which can't be frozen.
+pub const BASE_LEDGERS_IDS: [u64; 4] = [0, 1, 2, 3];
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct LedgersFreezeOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub ledgers_ids: Vec<u64>,
+}
+
+impl LedgersFreezeOperation {
+    pub fn new(ledgers_ids: Vec<u64>) -> LedgersFreezeOperation {
+        LedgersFreezeOperation {
+            _type: LEDGERS_FREEZE.to_string(),
+            ledgers_ids,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetFrozenLedgersOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+}
+
+impl GetFrozenLedgersOperation {
+    pub fn new() -> GetFrozenLedgersOperation {
+        GetFrozenLedgersOperation {
+            _type: GET_FROZEN_LEDGERS.to_string(),
+        }
+    }
+}
@@ -2,11 +2,14 @@ pub mod request;
 pub mod nym;
 pub mod attrib;
 pub mod schema;
+pub mod rich_schema;
 pub mod cred_def;
 pub mod node;
+pub mod genesis;
 pub mod ddo;
 pub mod txn;
 pub mod pool;
+pub mod ledgers_freeze;
 pub mod rev_reg_def;
 pub mod rev_reg;
 pub mod response;
@@ -14,3 +17,5 @@ pub mod validator_info;
 pub mod constants;
 pub mod auth_rule;
 pub mod author_agreement;
+pub mod confirm;
+pub mod endorsement;
@@ -1,5 +1,5 @@
 use super::constants::GET_NYM;
-use super::response::{GetReplyResultV0, GetReplyResultV1};
+use super::response::{GetReplyResultV1, ReplyType};
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetNymOperation {
@@ -20,8 +20,23 @@ impl GetNymOperation {
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 pub enum GetNymReplyResult {
-    GetNymReplyResultV0(GetReplyResultV0<String>),
-    GetNymReplyResultV1(GetReplyResultV1<GetNymResultDataV1>)
+    // V1 goes first as all fields of V0 are optional
+    GetNymReplyResultV1(GetReplyResultV1<GetNymResultDataV1>),
+    GetNymReplyResultV0(GetNymResultV0)
+}
+
+impl ReplyType for GetNymReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_NYM
+    }
+}
+
+#[derive(Deserialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetNymResultV0 {
+    pub data: Option<String>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>
 }
 
 #[derive(Deserialize, Eq, PartialEq, Debug)]
@@ -40,3 +55,16 @@ pub struct GetNymResultDataV1 {
     pub verkey: Option<String>,
     pub role: Option<String>
 }
+
+/// NYM data returned by GET_NYM reply regardless of reply version.
+#[derive(Serialize, Eq, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedNym {
+    pub did: String,
+    /// Full verkey, abbreviated one is expanded.
+    pub verkey: Option<String>,
+    /// Role name (TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR).
+    pub role: Option<String>,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>
+}
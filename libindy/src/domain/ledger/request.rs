@@ -26,7 +26,7 @@ impl ProtocolVersion {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Debug)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct TxnAuthrAgrmtAcceptanceData {
     pub mechanism: String,
@@ -0,0 +1,178 @@
+use super::constants::{JSON_LD_CONTEXT, RICH_SCHEMA, RICH_SCHEMA_ENCODING, RICH_SCHEMA_MAPPING, RICH_SCHEMA_CRED_DEF,
+                       RICH_SCHEMA_PRES_DEF, GET_RICH_SCHEMA_OBJECT_BY_ID, GET_RICH_SCHEMA_OBJECT_BY_METADATA};
+use super::response::ReplyType;
+
+pub const RICH_SCHEMA_OBJECT_VERSION: &str = "1";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub enum RichSchemaType {
+    #[serde(rename = "ctx")]
+    JsonLdContext,
+    #[serde(rename = "sch")]
+    Schema,
+    #[serde(rename = "enc")]
+    Encoding,
+    #[serde(rename = "map")]
+    Mapping,
+    #[serde(rename = "cdf")]
+    CredDef,
+    #[serde(rename = "pdf")]
+    PresDef,
+}
+
+impl RichSchemaType {
+    /// Code of the write transaction for objects of this type.
+    pub fn txn_type(&self) -> &'static str {
+        match *self {
+            RichSchemaType::JsonLdContext => JSON_LD_CONTEXT,
+            RichSchemaType::Schema => RICH_SCHEMA,
+            RichSchemaType::Encoding => RICH_SCHEMA_ENCODING,
+            RichSchemaType::Mapping => RICH_SCHEMA_MAPPING,
+            RichSchemaType::CredDef => RICH_SCHEMA_CRED_DEF,
+            RichSchemaType::PresDef => RICH_SCHEMA_PRES_DEF,
+        }
+    }
+
+    pub fn from_txn_type(txn_type: &str) -> Option<RichSchemaType> {
+        match txn_type {
+            JSON_LD_CONTEXT => Some(RichSchemaType::JsonLdContext),
+            RICH_SCHEMA => Some(RichSchemaType::Schema),
+            RICH_SCHEMA_ENCODING => Some(RichSchemaType::Encoding),
+            RICH_SCHEMA_MAPPING => Some(RichSchemaType::Mapping),
+            RICH_SCHEMA_CRED_DEF => Some(RichSchemaType::CredDef),
+            RICH_SCHEMA_PRES_DEF => Some(RichSchemaType::PresDef),
+            _ => None
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+    /// JSON-LD content serialized to string as it is stored on the ledger.
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+impl RichSchemaOperation {
+    pub fn new(id: String, content: String, rs_name: String, rs_version: String, rs_type: RichSchemaType, ver: String) -> RichSchemaOperation {
+        RichSchemaOperation {
+            _type: rs_type.txn_type().to_string(),
+            id,
+            content,
+            rs_name,
+            rs_version,
+            rs_type,
+            ver,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+pub struct GetRichSchemaObjectByIdOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub id: String,
+}
+
+impl GetRichSchemaObjectByIdOperation {
+    pub fn new(id: String) -> GetRichSchemaObjectByIdOperation {
+        GetRichSchemaObjectByIdOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_ID.to_string(),
+            id,
+        }
+    }
+}
+
+#[derive(Serialize, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectByMetadataOperation {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub rs_type: RichSchemaType,
+    pub rs_name: String,
+    pub rs_version: String,
+}
+
+impl GetRichSchemaObjectByMetadataOperation {
+    pub fn new(rs_type: RichSchemaType, rs_name: String, rs_version: String) -> GetRichSchemaObjectByMetadataOperation {
+        GetRichSchemaObjectByMetadataOperation {
+            _type: GET_RICH_SCHEMA_OBJECT_BY_METADATA.to_string(),
+            rs_type,
+            rs_name,
+            rs_version,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRichSchemaObjectReplyResult {
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+    pub data: Option<RichSchemaObjectData>,
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetRichSchemaObjectByIdReplyResult(pub GetRichSchemaObjectReplyResult);
+
+impl ReplyType for GetRichSchemaObjectByIdReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_ID
+    }
+}
+
+#[derive(Deserialize, Debug)]
+pub struct GetRichSchemaObjectByMetadataReplyResult(pub GetRichSchemaObjectReplyResult);
+
+impl ReplyType for GetRichSchemaObjectByMetadataReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_RICH_SCHEMA_OBJECT_BY_METADATA
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaObjectData {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+}
+
+/// Rich schema object read from the ledger.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RichSchemaObject {
+    pub id: String,
+    pub content: String,
+    pub rs_name: String,
+    pub rs_version: String,
+    pub rs_type: RichSchemaType,
+    pub ver: String,
+    pub seq_no: Option<u64>,
+    pub txn_time: Option<u64>,
+}
+
+impl RichSchemaObject {
+    pub fn new(data: RichSchemaObjectData, seq_no: Option<u64>, txn_time: Option<u64>) -> RichSchemaObject {
+        RichSchemaObject {
+            id: data.id,
+            content: data.content,
+            rs_name: data.rs_name,
+            rs_version: data.rs_version,
+            rs_type: data.rs_type,
+            ver: data.ver,
+            seq_no,
+            txn_time,
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use super::constants::GET_TXN;
+use super::response::ReplyType;
+
+use serde_json::Value;
 
 #[derive(Serialize, PartialEq, Debug)]
 pub struct GetTxnOperation {
@@ -35,3 +38,108 @@ impl LedgerType {
         }
     }
 }
+
+#[derive(Deserialize, Debug)]
+pub struct GetTxnReplyResult {
+    pub data: Option<GetTxnResultData>
+}
+
+impl ReplyType for GetTxnReplyResult {
+    fn get_type<'a>() -> &'a str {
+        GET_TXN
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+pub enum GetTxnResultData {
+    GetTxnResultDataV1(GetTxnResultDataV1),
+    GetTxnResultDataV0(GetTxnResultDataV0)
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnResultDataV1 {
+    pub txn: TxnV1,
+    pub txn_metadata: TxnMetadataV1,
+    pub ver: String
+}
+
+#[derive(Deserialize, Debug)]
+pub struct TxnV1 {
+    #[serde(rename = "type")]
+    pub _type: String,
+    #[serde(default)]
+    pub data: Value,
+    #[serde(default)]
+    pub metadata: TxnMetadataFromV1
+}
+
+#[derive(Deserialize, Default, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnMetadataFromV1 {
+    pub from: Option<String>,
+    pub req_id: Option<u64>
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct TxnMetadataV1 {
+    pub seq_no: u64,
+    pub txn_time: Option<u64>,
+    pub txn_id: Option<String>
+}
+
+/// Legacy flat transaction format.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct GetTxnResultDataV0 {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub seq_no: u64,
+    pub txn_time: Option<u64>,
+    pub identifier: Option<String>,
+    pub req_id: Option<u64>,
+    #[serde(flatten)]
+    pub data: ::serde_json::Map<String, Value>
+}
+
+/// Transaction returned by GET_TXN reply regardless of transaction format version.
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ParsedTxn {
+    #[serde(rename = "type")]
+    pub _type: String,
+    pub seq_no: u64,
+    pub txn_time: Option<u64>,
+    pub txn_id: Option<String>,
+    pub from: Option<String>,
+    pub req_id: Option<u64>,
+    /// Transaction specific data (e.g. dest and verkey of NYM).
+    pub data: Value
+}
+
+impl From<GetTxnResultData> for ParsedTxn {
+    fn from(data: GetTxnResultData) -> ParsedTxn {
+        match data {
+            GetTxnResultData::GetTxnResultDataV1(data) => ParsedTxn {
+                _type: data.txn._type,
+                seq_no: data.txn_metadata.seq_no,
+                txn_time: data.txn_metadata.txn_time,
+                txn_id: data.txn_metadata.txn_id,
+                from: data.txn.metadata.from,
+                req_id: data.txn.metadata.req_id,
+                data: data.txn.data,
+            },
+            GetTxnResultData::GetTxnResultDataV0(data) => ParsedTxn {
+                _type: data._type,
+                seq_no: data.seq_no,
+                txn_time: data.txn_time,
+                txn_id: None,
+                from: data.identifier,
+                req_id: data.req_id,
+                data: Value::Object(data.data),
+            }
+        }
+    }
+}
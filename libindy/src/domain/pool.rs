@@ -26,8 +26,103 @@ pub struct PoolOpenConfig {
     pub conn_limit: usize,
     #[serde(default="PoolOpenConfig::default_conn_active_timeout")]
     pub conn_active_timeout: i64,
+    /// Number of requests of a batch (see submit_requests_batch) sent to the pool at the same time, `conn_limit` if omitted.
+    pub batch_limit: Option<usize>,
     #[serde(default="PoolOpenConfig::default_preordered_nodes")]
     pub preordered_nodes: Vec<String>,
+    #[serde(default="PoolOpenConfig::default_read_strategy")]
+    pub read_strategy: ReadStrategy,
+    #[serde(default="PoolOpenConfig::default_networker")]
+    pub networker: NetworkerType,
+    pub domain_genesis_txn: Option<String>,
+    #[serde(default)]
+    pub force_rebuild: bool,
+    pub record_traffic: Option<String>,
+    pub replay_traffic: Option<String>,
+    pub write_retry: Option<WriteRetryPolicy>,
+    #[cfg(feature = "local_faults_tests")]
+    #[serde(default)]
+    pub local_faults: LocalFaults,
+}
+
+/// Transport used by opened pool to reach validator nodes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkerType {
+    /// Real validator nodes reached through ZeroMQ sockets.
+    Zmq,
+    /// In-process emulation of validator pool with in-memory ledgers.
+    Local,
+    /// Node replies are replayed from traffic capture file.
+    Replay,
+}
+
+/// Faults of emulated nodes of "local" networker, used to test handling of lost messages.
+/// Accepted by open config only if libindy is built with "local_faults_tests" feature.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LocalFaults {
+    /// Number of the first ledger writes that are ordered but neither acknowledged nor replied by nodes.
+    #[serde(default)]
+    pub lost_write_replies: usize,
+}
+
+/// Order in which nodes are asked by requests expecting a single verifiable reply.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReadStrategy {
+    /// Nodes are shuffled, `preordered_nodes` are ignored.
+    Random,
+    /// Nodes from `preordered_nodes` are asked first, the rest are shuffled.
+    Preordered,
+    /// Healthy nodes with the lowest moving-average latency are asked first.
+    Adaptive,
+}
+
+/// Resubmission of ledger writes timed out without reply.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct WriteRetryPolicy {
+    /// Total number of attempts including the first submission.
+    #[serde(default="WriteRetryPolicy::default_max_attempts")]
+    pub max_attempts: u32,
+    /// Delay before the first retry in milliseconds.
+    #[serde(default="WriteRetryPolicy::default_initial_delay")]
+    pub initial_delay: u64,
+    /// Upper bound of delay between retries in milliseconds.
+    #[serde(default="WriteRetryPolicy::default_max_delay")]
+    pub max_delay: u64,
+    #[serde(default="WriteRetryPolicy::default_multiplier")]
+    pub multiplier: f64,
+    /// Randomize each delay within [delay / 2, delay] to avoid bursts of retries.
+    #[serde(default="WriteRetryPolicy::default_jitter")]
+    pub jitter: bool,
+}
+
+impl WriteRetryPolicy {
+    /// Delay in milliseconds before retry following the given attempt (starting from 1) without jitter.
+    pub fn delay(&self, attempt: u32) -> u64 {
+        let delay = self.initial_delay as f64 * self.multiplier.powi(attempt.saturating_sub(1) as i32);
+        delay.min(self.max_delay as f64) as u64
+    }
+
+    fn default_max_attempts() -> u32 {
+        3
+    }
+
+    fn default_initial_delay() -> u64 {
+        1000
+    }
+
+    fn default_max_delay() -> u64 {
+        30000
+    }
+
+    fn default_multiplier() -> f64 {
+        2.0
+    }
+
+    fn default_jitter() -> bool {
+        true
+    }
 }
 
 impl Default for PoolOpenConfig {
@@ -37,12 +132,32 @@ impl Default for PoolOpenConfig {
             extended_timeout: PoolOpenConfig::default_extended_timeout(),
             conn_limit: PoolOpenConfig::default_conn_limit(),
             conn_active_timeout: PoolOpenConfig::default_conn_active_timeout(),
-            preordered_nodes: PoolOpenConfig::default_preordered_nodes()
+            batch_limit: None,
+            preordered_nodes: PoolOpenConfig::default_preordered_nodes(),
+            read_strategy: PoolOpenConfig::default_read_strategy(),
+            networker: PoolOpenConfig::default_networker(),
+            domain_genesis_txn: None,
+            force_rebuild: false,
+            record_traffic: None,
+            replay_traffic: None,
+            write_retry: None,
+            #[cfg(feature = "local_faults_tests")]
+            local_faults: LocalFaults::default(),
         }
     }
 }
 
 impl PoolOpenConfig {
+    #[cfg(feature = "local_faults_tests")]
+    pub fn local_faults(&self) -> LocalFaults {
+        self.local_faults.clone()
+    }
+
+    #[cfg(not(feature = "local_faults_tests"))]
+    pub fn local_faults(&self) -> LocalFaults {
+        LocalFaults::default()
+    }
+
     fn default_timeout() -> i64 {
         POOL_ACK_TIMEOUT
     }
@@ -62,4 +177,12 @@ impl PoolOpenConfig {
     fn default_preordered_nodes() -> Vec<String> {
         Vec::new()
     }
+
+    fn default_read_strategy() -> ReadStrategy {
+        ReadStrategy::Preordered
+    }
+
+    fn default_networker() -> NetworkerType {
+        NetworkerType::Zmq
+    }
 }
@@ -63,7 +63,9 @@ pub fn build_sub_proof_request(attrs_for_credential: &Vec<AttributeInfo>,
     let mut sub_proof_request_builder = verifier::Verifier::new_sub_proof_request_builder()?;
 
     for attr in attrs_for_credential {
-        sub_proof_request_builder.add_revealed_attr(&attr_common_view(&attr.name))?
+        for name in get_requested_attr_names(attr)? {
+            sub_proof_request_builder.add_revealed_attr(&attr_common_view(name))?
+        }
     }
 
     for predicate in predicates_for_credential {
@@ -90,15 +92,26 @@ pub fn parse_cred_rev_id(cred_rev_id: &str) -> IndyResult<u32> {
     Ok(res)
 }
 
-pub fn build_wql_query(name: &str,
+pub fn get_requested_attr_names(attr_info: &AttributeInfo) -> IndyResult<Vec<&str>> {
+    match (&attr_info.name, &attr_info.names) {
+        (&Some(ref name), &None) => Ok(vec![name.as_str()]),
+        (&None, &Some(ref names)) if !names.is_empty() => Ok(names.iter().map(String::as_str).collect()),
+        (&None, &Some(_)) =>
+            Err(err_msg(IndyErrorKind::InvalidStructure, "Requested attribute group \"names\" must not be empty")),
+        _ =>
+            Err(err_msg(IndyErrorKind::InvalidStructure, "Requested attribute must contain either \"name\" or \"names\" field"))
+    }
+}
+
+pub fn build_wql_query(names: &[&str],
                        referent: &str,
                        restrictions: &Option<serde_json::Value>,
                        extra_query: &Option<&ProofRequestExtraQuery>) -> IndyResult<String> {
 
-    trace!("build_wql_query >>> name: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}",
-           name, referent, restrictions, extra_query);
+    trace!("build_wql_query >>> names: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}",
+           names, referent, restrictions, extra_query);
 
-    Prover::new().build_query(name, referent, restrictions, extra_query)
+    Prover::new().build_query(names, referent, restrictions, extra_query)
 }
 
 
@@ -137,4 +150,43 @@ mod tests{
         let res = get_non_revoc_interval(&None, &None);
         assert_eq!(None, res);
     }
+
+    fn _attr_info(name: Option<&str>, names: Option<Vec<&str>>) -> AttributeInfo {
+        AttributeInfo {
+            name: name.map(String::from),
+            names: names.map(|names| names.into_iter().map(String::from).collect()),
+            restrictions: None,
+            non_revoked: None,
+        }
+    }
+
+    #[test]
+    fn get_requested_attr_names_for_name(){
+        let attr_info = _attr_info(Some("name"), None);
+        assert_eq!(vec!["name"], get_requested_attr_names(&attr_info).unwrap());
+    }
+
+    #[test]
+    fn get_requested_attr_names_for_names(){
+        let attr_info = _attr_info(None, Some(vec!["name", "age"]));
+        assert_eq!(vec!["name", "age"], get_requested_attr_names(&attr_info).unwrap());
+    }
+
+    #[test]
+    fn get_requested_attr_names_for_both_name_and_names(){
+        let attr_info = _attr_info(Some("name"), Some(vec!["age"]));
+        assert_kind!(IndyErrorKind::InvalidStructure, get_requested_attr_names(&attr_info));
+    }
+
+    #[test]
+    fn get_requested_attr_names_for_empty_names(){
+        let attr_info = _attr_info(None, Some(vec![]));
+        assert_kind!(IndyErrorKind::InvalidStructure, get_requested_attr_names(&attr_info));
+    }
+
+    #[test]
+    fn get_requested_attr_names_for_none(){
+        let attr_info = _attr_info(None, None);
+        assert_kind!(IndyErrorKind::InvalidStructure, get_requested_attr_names(&attr_info));
+    }
 }
\ No newline at end of file
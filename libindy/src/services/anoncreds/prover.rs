@@ -18,7 +18,7 @@ use domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use domain::anoncreds::credential_definition::CredentialDefinitionV1 as CredentialDefinition;
 use domain::anoncreds::credential_offer::CredentialOffer;
 use domain::anoncreds::credential_request::CredentialRequestMetadata;
-use domain::anoncreds::proof::{Identifier, Proof, RequestedProof, RevealedAttributeInfo, SubProofReferent};
+use domain::anoncreds::proof::{AttributeValue, Identifier, Proof, RequestedProof, RevealedAttributeGroupInfo, RevealedAttributeInfo, SubProofReferent};
 use domain::anoncreds::proof_request::{PredicateInfo, PredicateTypes, ProofRequest, ProofRequestExtraQuery, RequestedAttributeInfo, RequestedPredicateInfo};
 use domain::anoncreds::requested_credential::ProvingCredentialKey;
 use domain::anoncreds::requested_credential::RequestedCredentials;
@@ -212,6 +212,8 @@ impl Prover {
                 .get(attr_referent.as_str())
                 .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("AttributeInfo not found in ProofRequest for referent \"{}\"", attr_referent.as_str())))?;
 
+            get_requested_attr_names(attr_info)?;
+
             let req_attr_info = RequestedAttributeInfo {
                 attr_referent: attr_referent.clone(),
                 attr_info: attr_info.clone(),
@@ -296,17 +298,19 @@ impl Prover {
     }
 
     pub fn build_query(&self,
-                       name: &str,
+                       names: &[&str],
                        referent: &str,
                        restrictions: &Option<serde_json::Value>,
                        extra_query: &Option<&ProofRequestExtraQuery>) -> IndyResult<String> {
-        trace!("build_query >>> name: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}", name, referent, restrictions, extra_query);
+        trace!("build_query >>> names: {:?}, referent: {:?}, restrictions: {:?}, extra_query: {:?}", names, referent, restrictions, extra_query);
 
         let mut sub_queries: Vec<serde_json::Value> = vec![];
 
-        sub_queries.push(serde_json::Value::Object(serde_map!(
-            format!("attr::{}::marker", &attr_common_view(name)) => serde_json::Value::String(ATTRIBUTE_EXISTENCE_MARKER.to_string())
-        )));
+        for name in names {
+            sub_queries.push(serde_json::Value::Object(serde_map!(
+                format!("attr::{}::marker", &attr_common_view(name)) => serde_json::Value::String(ATTRIBUTE_EXISTENCE_MARKER.to_string())
+            )));
+        }
 
         match restrictions.as_ref() {
             // Convert old restrictions format to valid wql
@@ -398,16 +402,39 @@ impl Prover {
         for attr_info in req_attrs_for_credential {
             if attr_info.revealed {
                 let attribute = &proof_req.requested_attributes[&attr_info.attr_referent];
-                let attribute_values =
-                    self.get_credential_values_for_attribute(&credential.values, &attribute.name)
-                        .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", attribute.name)))?;
-
-                requested_proof.revealed_attrs.insert(attr_info.attr_referent,
-                                                      RevealedAttributeInfo {
-                                                          sub_proof_index,
-                                                          raw: attribute_values.raw,
-                                                          encoded: attribute_values.encoded,
-                                                      });
+
+                if attribute.names.is_some() {
+                    let mut values: HashMap<String, AttributeValue> = HashMap::new();
+
+                    for name in get_requested_attr_names(attribute)? {
+                        let attribute_values =
+                            self.get_credential_values_for_attribute(&credential.values, name)
+                                .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", name)))?;
+
+                        values.insert(name.to_string(), AttributeValue {
+                            raw: attribute_values.raw,
+                            encoded: attribute_values.encoded,
+                        });
+                    }
+
+                    requested_proof.revealed_attr_groups.insert(attr_info.attr_referent,
+                                                                RevealedAttributeGroupInfo {
+                                                                    sub_proof_index,
+                                                                    values,
+                                                                });
+                } else {
+                    let name = get_requested_attr_names(attribute)?[0];
+                    let attribute_values =
+                        self.get_credential_values_for_attribute(&credential.values, name)
+                            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Credential value not found for attribute {:?}", name)))?;
+
+                    requested_proof.revealed_attrs.insert(attr_info.attr_referent,
+                                                          RevealedAttributeInfo {
+                                                              sub_proof_index,
+                                                              raw: attribute_values.raw,
+                                                              encoded: attribute_values.encoded,
+                                                          });
+                }
             } else {
                 requested_proof.unrevealed_attrs.insert(attr_info.attr_referent, SubProofReferent { sub_proof_index });
             }
@@ -431,7 +458,9 @@ impl Prover {
 
         for attr in req_attrs_for_credential {
             if attr.revealed {
-                sub_proof_request_builder.add_revealed_attr(&attr_common_view(&attr.attr_info.name))?
+                for name in get_requested_attr_names(&attr.attr_info)? {
+                    sub_proof_request_builder.add_revealed_attr(&attr_common_view(name))?
+                }
             }
         }
 
@@ -579,7 +608,7 @@ mod tests {
         #[test]
         fn build_query_works() {
             let ps = Prover::new();
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &None, &None).unwrap();
             let expected_query = json!({
                 "$and": vec![
                     json!({
@@ -590,12 +619,29 @@ mod tests {
             assert_eq!(expected_query, _value(&query));
         }
 
+        #[test]
+        fn build_query_works_for_names() {
+            let ps = Prover::new();
+            let query = ps.build_query(&[ATTR_NAME, "age"], ATTR_REFERENT, &None, &None).unwrap();
+            let expected_query = json!({
+                "$and": vec![
+                    json!({
+                        "attr::name::marker": ATTRIBUTE_EXISTENCE_MARKER
+                    }),
+                    json!({
+                        "attr::age::marker": ATTRIBUTE_EXISTENCE_MARKER
+                    })
+                ]
+            });
+            assert_eq!(expected_query, _value(&query));
+        }
+
         #[test]
         fn build_query_works_for_restriction() {
             let ps = Prover::new();
 
             let restriction = json!({"schema_id": SCHEMA_ID, "cred_def_id": CRED_DEF_ID});
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(restriction), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -615,7 +661,7 @@ mod tests {
         #[test]
         fn build_query_works_for_empty_restrictions() {
             let ps = Prover::new();
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(json!([])), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(json!([])), &None).unwrap();
             let expected_query = json!({
                 "$and": vec![
                     json!({
@@ -637,7 +683,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -666,7 +712,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -694,7 +740,7 @@ mod tests {
             let restriction_2 = json!({"cred_def_id": CRED_DEF_ID});
             let restirctions = serde_json::Value::Array(vec![restriction_1, restriction_2]);
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restirctions), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(restirctions), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -726,7 +772,7 @@ mod tests {
             let restriction_2 = json!({"schema_id":  serde_json::Value::Null, "cred_def_id": CRED_DEF_ID});
             let restirctions = serde_json::Value::Array(vec![restriction_1, restriction_2]);
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restirctions), &None).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(restirctions), &None).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -760,7 +806,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &None, &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": vec![
@@ -795,7 +841,7 @@ mod tests {
                     )
             );
 
-            let query = ps.build_query(ATTR_NAME, ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
+            let query = ps.build_query(&[ATTR_NAME], ATTR_REFERENT, &Some(restriction), &Some(&extra_query)).unwrap();
 
             let expected_query = json!({
                 "$and": [
@@ -868,7 +914,8 @@ mod tests {
 
         fn _attr_info() -> AttributeInfo {
             AttributeInfo {
-                name: "name".to_string(),
+                name: Some("name".to_string()),
+                names: None,
                 restrictions: None,
                 non_revoked: None,
             }
@@ -943,7 +990,8 @@ mod tests {
             });
 
             proof_req.requested_attributes.insert("attribute_referent_2".to_string(), AttributeInfo {
-                name: "last_name".to_string(),
+                name: Some("last_name".to_string()),
+                names: None,
                 restrictions: None,
                 non_revoked: None,
             });
@@ -958,6 +1006,43 @@ mod tests {
             assert_eq!(1, req_pred_info.len());
         }
 
+        #[test]
+        fn prepare_credentials_for_proving_works_for_attribute_group() {
+            let req_cred = _req_cred();
+            let mut proof_req = _proof_req();
+
+            proof_req.requested_attributes.insert(ATTRIBUTE_REFERENT.to_string(), AttributeInfo {
+                name: None,
+                names: Some(vec!["name".to_string(), "last_name".to_string()]),
+                restrictions: None,
+                non_revoked: None,
+            });
+
+            let res = Prover::_prepare_credentials_for_proving(&req_cred, &proof_req).unwrap();
+
+            assert_eq!(1, res.len());
+
+            let (req_attr_info, _) = res.get(&ProvingCredentialKey { cred_id: CRED_ID.to_string(), timestamp: None }).unwrap();
+            assert_eq!(1, req_attr_info.len());
+            assert_eq!(Some(vec!["name".to_string(), "last_name".to_string()]), req_attr_info[0].attr_info.names);
+        }
+
+        #[test]
+        fn prepare_credentials_for_proving_works_for_invalid_attribute_group() {
+            let req_cred = _req_cred();
+            let mut proof_req = _proof_req();
+
+            proof_req.requested_attributes.insert(ATTRIBUTE_REFERENT.to_string(), AttributeInfo {
+                name: Some("name".to_string()),
+                names: Some(vec!["last_name".to_string()]),
+                restrictions: None,
+                non_revoked: None,
+            });
+
+            let res = Prover::_prepare_credentials_for_proving(&req_cred, &proof_req);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
         #[test]
         fn prepare_credentials_for_proving_works_for_missed_attribute() {
             let req_cred = _req_cred();
@@ -1,8 +1,9 @@
 use std::collections::{HashMap, HashSet};
 
 use domain::anoncreds::credential_definition::{CredentialDefinitionV1, CredentialDefinition};
-use domain::anoncreds::proof::{Proof, RequestedProof, Identifier};
+use domain::anoncreds::proof::{Proof, RequestedProof, Identifier, RevealedAttributeGroupInfo};
 use domain::anoncreds::proof_request::{AttributeInfo, PredicateInfo, ProofRequest, NonRevocedInterval};
+use domain::anoncreds::proof_verification_report::{ProofVerificationReport, ReferentVerificationReport, VerificationCheck, VerificationFailureReason};
 use domain::anoncreds::revocation_registry::RevocationRegistryV1;
 use domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinitionV1;
 use domain::anoncreds::schema::{SchemaV1, Schema};
@@ -41,30 +42,312 @@ impl Verifier {
                full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
 
         let received_revealed_attrs: HashMap<String, Identifier> = Verifier::_received_revealed_attrs(&full_proof)?;
+        let received_revealed_attr_groups: HashMap<String, Identifier> = Verifier::_received_revealed_attr_groups(&full_proof)?;
         let received_unrevealed_attrs: HashMap<String, Identifier> = Verifier::_received_unrevealed_attrs(&full_proof)?;
         let received_predicates: HashMap<String, Identifier> = Verifier::_received_predicates(&full_proof)?;
         let received_self_attested_attrs: HashSet<String> = Verifier::_received_self_attested_attrs(&full_proof);
 
         Verifier::_compare_attr_from_proof_and_request(proof_req,
                                                        &received_revealed_attrs,
+                                                       &received_revealed_attr_groups,
                                                        &received_unrevealed_attrs,
                                                        &received_self_attested_attrs,
                                                        &received_predicates)?;
 
+        Verifier::_verify_revealed_attr_groups(proof_req, &full_proof)?;
+
         Verifier::_verify_requested_restrictions(&proof_req,
                                                  schemas,
                                                  cred_defs,
                                                  &received_revealed_attrs,
+                                                 &received_revealed_attr_groups,
                                                  &received_unrevealed_attrs,
                                                  &received_predicates,
                                                  &received_self_attested_attrs)?;
 
         Verifier::_compare_timestamps_from_proof_and_request(proof_req,
                                                              &received_revealed_attrs,
+                                                             &received_revealed_attr_groups,
                                                              &received_unrevealed_attrs,
                                                              &received_self_attested_attrs,
                                                              &received_predicates)?;
 
+        let valid = Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs)?;
+
+        trace!("verify <<< valid: {:?}", valid);
+
+        Ok(valid)
+    }
+
+    pub fn verify_with_report(&self,
+                              full_proof: &Proof,
+                              proof_req: &ProofRequest,
+                              schemas: &HashMap<String, SchemaV1>,
+                              cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                              rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                              rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<ProofVerificationReport> {
+        trace!("verify_with_report >>> full_proof: {:?}, proof_req: {:?}, schemas: {:?}, cred_defs: {:?}, rev_reg_defs: {:?} rev_regs: {:?}",
+               full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs);
+
+        let requested_proof_check = Verifier::_check_unexpected_referents(proof_req, &full_proof.requested_proof);
+
+        let proof_data_check = full_proof.identifiers
+            .iter()
+            .flat_map(|identifier| vec![Verifier::_check_credential_data(identifier, schemas, cred_defs),
+                                        Verifier::_check_revocation_data(identifier, rev_reg_defs, rev_regs)])
+            .find(VerificationCheck::is_failed);
+
+        // Crypto proof can be verified only if all the data it refers to is provided
+        let crypto_check = match proof_data_check {
+            Some(_) => None,
+            None => Some(
+                match Verifier::_verify_crypto_proof(full_proof, proof_req, schemas, cred_defs, rev_reg_defs, rev_regs) {
+                    Ok(true) => VerificationCheck::passed(),
+                    Ok(false) => VerificationCheck::failed(VerificationFailureReason::ProofInvalid, "Proof verification failed".to_string()),
+                    Err(err) => VerificationCheck::failed(VerificationFailureReason::ProofInvalid, err.to_string())
+                })
+        };
+
+        let proof_check = crypto_check.clone().or(proof_data_check).unwrap_or_else(VerificationCheck::passed);
+
+        let crypto_proof = serde_json::to_value(&full_proof.proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        let mut requested_attributes: HashMap<String, ReferentVerificationReport> = HashMap::new();
+        for (referent, info) in proof_req.requested_attributes.iter() {
+            let report = Verifier::_attribute_report(referent, info, full_proof, proof_req, &crypto_proof,
+                                                     schemas, cred_defs, rev_reg_defs, rev_regs, &crypto_check)?;
+            requested_attributes.insert(referent.to_string(), report);
+        }
+
+        let mut requested_predicates: HashMap<String, ReferentVerificationReport> = HashMap::new();
+        for (referent, info) in proof_req.requested_predicates.iter() {
+            let report = match full_proof.requested_proof.predicates.get(referent) {
+                Some(sub_proof_referent) =>
+                    Verifier::_sub_proof_referent_report(referent, sub_proof_referent.sub_proof_index, &[info.name.as_str()], &info.restrictions,
+                                                         get_non_revoc_interval(&proof_req.non_revoked, &info.non_revoked), true,
+                                                         full_proof, schemas, cred_defs, rev_reg_defs, rev_regs, &crypto_check)?,
+                None =>
+                    ReferentVerificationReport::rejected(None,
+                                                         VerificationCheck::failed(VerificationFailureReason::ReferentNotReceived,
+                                                                                   format!("Requested predicate \"{}\" not found in proof", referent)),
+                                                         true)
+            };
+            requested_predicates.insert(referent.to_string(), report);
+        }
+
+        let valid = !requested_proof_check.is_failed() && !proof_check.is_failed() &&
+            requested_attributes.values().chain(requested_predicates.values()).all(|report| report.valid);
+
+        let report = ProofVerificationReport {
+            valid,
+            requested_proof: requested_proof_check,
+            proof: proof_check,
+            requested_attributes,
+            requested_predicates,
+        };
+
+        trace!("verify_with_report <<< report: {:?}", report);
+
+        Ok(report)
+    }
+
+    fn _check_unexpected_referents(proof_req: &ProofRequest, requested_proof: &RequestedProof) -> VerificationCheck {
+        let unexpected_attrs = requested_proof.revealed_attrs.keys()
+            .chain(requested_proof.revealed_attr_groups.keys())
+            .chain(requested_proof.unrevealed_attrs.keys())
+            .chain(requested_proof.self_attested_attrs.keys())
+            .filter(|referent| !proof_req.requested_attributes.contains_key(*referent));
+
+        let unexpected_predicates = requested_proof.predicates.keys()
+            .filter(|referent| !proof_req.requested_predicates.contains_key(*referent));
+
+        let unexpected_referents: Vec<&String> = unexpected_attrs.chain(unexpected_predicates).collect();
+
+        if unexpected_referents.is_empty() {
+            VerificationCheck::passed()
+        } else {
+            VerificationCheck::failed(VerificationFailureReason::UnexpectedReferent,
+                                      format!("Received referents {:?} are not requested", unexpected_referents))
+        }
+    }
+
+    fn _check_credential_data(identifier: &Identifier,
+                              schemas: &HashMap<String, SchemaV1>,
+                              cred_defs: &HashMap<String, CredentialDefinitionV1>) -> VerificationCheck {
+        if !schemas.contains_key(&identifier.schema_id) {
+            VerificationCheck::failed(VerificationFailureReason::SchemaNotFound,
+                                      format!("Schema not found for id: {:?}", identifier.schema_id))
+        } else if !cred_defs.contains_key(&identifier.cred_def_id) {
+            VerificationCheck::failed(VerificationFailureReason::CredentialDefinitionNotFound,
+                                      format!("CredentialDefinition not found for id: {:?}", identifier.cred_def_id))
+        } else {
+            VerificationCheck::passed()
+        }
+    }
+
+    fn _check_revocation_data(identifier: &Identifier,
+                              rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                              rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> VerificationCheck {
+        let timestamp = match identifier.timestamp {
+            Some(timestamp) => timestamp,
+            None => return VerificationCheck::skipped()
+        };
+
+        let rev_reg_id = match identifier.rev_reg_id {
+            Some(ref rev_reg_id) => rev_reg_id,
+            None => return VerificationCheck::failed(VerificationFailureReason::RevocationRegistryDefinitionNotFound,
+                                                     "Revocation Registry Id not found".to_string())
+        };
+
+        if !rev_reg_defs.contains_key(rev_reg_id) {
+            VerificationCheck::failed(VerificationFailureReason::RevocationRegistryDefinitionNotFound,
+                                      format!("RevocationRegistryDefinition not found for id: {:?}", rev_reg_id))
+        } else if rev_regs.get(rev_reg_id).and_then(|rev_regs_for_cred| rev_regs_for_cred.get(&timestamp)).is_none() {
+            VerificationCheck::failed(VerificationFailureReason::RevocationRegistryNotFound,
+                                      format!("RevocationRegistry not found for id: {:?} and timestamp: {:?}", rev_reg_id, timestamp))
+        } else {
+            VerificationCheck::passed()
+        }
+    }
+
+    fn _attribute_report(referent: &str,
+                         info: &AttributeInfo,
+                         full_proof: &Proof,
+                         proof_req: &ProofRequest,
+                         crypto_proof: &serde_json::Value,
+                         schemas: &HashMap<String, SchemaV1>,
+                         cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                         rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                         rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>,
+                         crypto_check: &Option<VerificationCheck>) -> IndyResult<ReferentVerificationReport> {
+        let names = get_requested_attr_names(info)?;
+        let is_group = info.names.is_some();
+        let requested_proof = &full_proof.requested_proof;
+
+        let sub_proof_index = if let Some(revealed_attr_info) = requested_proof.revealed_attrs.get(referent) {
+            if is_group {
+                return Ok(ReferentVerificationReport::rejected(
+                    Some(revealed_attr_info.sub_proof_index),
+                    VerificationCheck::failed(VerificationFailureReason::AttributeGroupMismatch,
+                                              format!("Requested attribute group \"{}\" is received as a single attribute", referent)),
+                    false));
+            }
+            revealed_attr_info.sub_proof_index
+        } else if let Some(group_info) = requested_proof.revealed_attr_groups.get(referent) {
+            let res = if is_group {
+                Verifier::_verify_revealed_attr_group(referent, &names, group_info, crypto_proof)
+            } else {
+                Err(err_msg(IndyErrorKind::InvalidStructure, format!("Requested attribute \"{}\" is received as a group", referent)))
+            };
+
+            if let Err(err) = res {
+                let reason = match err.kind() {
+                    IndyErrorKind::ProofRejected => VerificationFailureReason::AttributeValueMismatch,
+                    _ => VerificationFailureReason::AttributeGroupMismatch
+                };
+                return Ok(ReferentVerificationReport::rejected(Some(group_info.sub_proof_index),
+                                                               VerificationCheck::failed(reason, err.to_string()),
+                                                               false));
+            }
+            group_info.sub_proof_index
+        } else if let Some(sub_proof_referent) = requested_proof.unrevealed_attrs.get(referent) {
+            sub_proof_referent.sub_proof_index
+        } else if requested_proof.self_attested_attrs.contains_key(referent) {
+            return Ok(if !is_group && Verifier::_allows_self_attested(info) {
+                ReferentVerificationReport::self_attested()
+            } else {
+                ReferentVerificationReport::rejected(
+                    None,
+                    VerificationCheck::failed(VerificationFailureReason::SelfAttestedNotAllowed,
+                                              format!("Requested attribute \"{}\" can't be self attested", referent)),
+                    false)
+            });
+        } else {
+            return Ok(ReferentVerificationReport::rejected(
+                None,
+                VerificationCheck::failed(VerificationFailureReason::ReferentNotReceived,
+                                          format!("Requested attribute \"{}\" not found in proof", referent)),
+                false));
+        };
+
+        Verifier::_sub_proof_referent_report(referent, sub_proof_index, &names, &info.restrictions,
+                                             get_non_revoc_interval(&proof_req.non_revoked, &info.non_revoked), false,
+                                             full_proof, schemas, cred_defs, rev_reg_defs, rev_regs, crypto_check)
+    }
+
+    fn _sub_proof_referent_report(referent: &str,
+                                  sub_proof_index: i32,
+                                  names: &[&str],
+                                  restrictions: &Option<serde_json::Value>,
+                                  interval: Option<NonRevocedInterval>,
+                                  is_predicate: bool,
+                                  full_proof: &Proof,
+                                  schemas: &HashMap<String, SchemaV1>,
+                                  cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                                  rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                                  rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>,
+                                  crypto_check: &Option<VerificationCheck>) -> IndyResult<ReferentVerificationReport> {
+        let identifier = match Verifier::_get_proof_identifier(full_proof, sub_proof_index) {
+            Ok(identifier) => identifier,
+            Err(err) => return Ok(ReferentVerificationReport::rejected(
+                Some(sub_proof_index),
+                VerificationCheck::failed(VerificationFailureReason::InvalidSubProofIndex, err.to_string()),
+                is_predicate))
+        };
+
+        let credential_data_check = Verifier::_check_credential_data(&identifier, schemas, cred_defs);
+        let revocation_data_check = Verifier::_check_revocation_data(&identifier, rev_reg_defs, rev_regs);
+
+        let restrictions_check = if credential_data_check.is_failed() {
+            VerificationCheck::skipped()
+        } else {
+            let op = parse_from_json(&build_wql_query(names, referent, restrictions, &None)?)?;
+
+            match Verifier::_filter_for_identifier(&identifier, schemas, cred_defs)
+                .and_then(|filter| Verifier::_process_operator(&names.join(", "), &op, &filter)) {
+                Ok(()) => VerificationCheck::passed(),
+                Err(err) => VerificationCheck::failed(VerificationFailureReason::RestrictionsNotSatisfied, err.to_string())
+            }
+        };
+
+        let timestamp_check = match (interval, identifier.timestamp) {
+            (None, _) => VerificationCheck::skipped(),
+            (Some(_), Some(_)) => VerificationCheck::passed(),
+            (Some(interval), None) =>
+                VerificationCheck::failed(VerificationFailureReason::TimestampMissing,
+                                          format!("Non-revocation proof for interval {:?} is requested but sub proof {} has no timestamp", interval, sub_proof_index))
+        };
+
+        // Primary, predicate and non-revocation proofs are verified together as a part of the whole crypto proof
+        let crypto_check_for = |data_check: VerificationCheck|
+            if data_check.is_failed() { data_check } else { crypto_check.clone().unwrap_or_else(VerificationCheck::skipped) };
+
+        let primary_proof_check = crypto_check_for(credential_data_check);
+
+        let predicate_check = if is_predicate { Some(primary_proof_check.clone()) } else { None };
+
+        let revocation_check = if identifier.timestamp.is_none() {
+            VerificationCheck::skipped()
+        } else {
+            crypto_check_for(revocation_data_check)
+        };
+
+        Ok(ReferentVerificationReport::new(Some(sub_proof_index),
+                                           VerificationCheck::passed(),
+                                           primary_proof_check,
+                                           predicate_check,
+                                           restrictions_check,
+                                           timestamp_check,
+                                           revocation_check))
+    }
+
+    fn _verify_crypto_proof(full_proof: &Proof,
+                            proof_req: &ProofRequest,
+                            schemas: &HashMap<String, SchemaV1>,
+                            cred_defs: &HashMap<String, CredentialDefinitionV1>,
+                            rev_reg_defs: &HashMap<String, RevocationRegistryDefinitionV1>,
+                            rev_regs: &HashMap<String, HashMap<u64, RevocationRegistryV1>>) -> IndyResult<bool> {
         let mut proof_verifier = CryptoVerifier::new_proof_verifier()?;
         let non_credential_schema = build_non_credential_schema()?;
 
@@ -116,8 +399,6 @@ impl Verifier {
 
         let valid = proof_verifier.verify(&full_proof.proof, &proof_req.nonce)?;
 
-        trace!("verify <<< valid: {:?}", valid);
-
         Ok(valid)
     }
 
@@ -139,8 +420,12 @@ impl Verifier {
 
         let revealed_attrs_for_credential = requested_proof.revealed_attrs
             .iter()
-            .filter(|&(attr_referent, ref revealed_attr_info)|
-                sub_proof_index == revealed_attr_info.sub_proof_index as usize && proof_req.requested_attributes.contains_key(attr_referent))
+            .map(|(attr_referent, revealed_attr_info)| (attr_referent, revealed_attr_info.sub_proof_index))
+            .chain(requested_proof.revealed_attr_groups
+                .iter()
+                .map(|(attr_referent, revealed_attr_group_info)| (attr_referent, revealed_attr_group_info.sub_proof_index)))
+            .filter(|&(attr_referent, index)|
+                sub_proof_index == index as usize && proof_req.requested_attributes.contains_key(attr_referent))
             .map(|(attr_referent, _)|
                 proof_req.requested_attributes[attr_referent].clone())
             .collect::<Vec<AttributeInfo>>();
@@ -171,6 +456,7 @@ impl Verifier {
 
     fn _compare_attr_from_proof_and_request(proof_req: &ProofRequest,
                                             received_revealed_attrs: &HashMap<String, Identifier>,
+                                            received_revealed_attr_groups: &HashMap<String, Identifier>,
                                             received_unrevealed_attrs: &HashMap<String, Identifier>,
                                             received_self_attested_attrs: &HashSet<String>,
                                             received_predicates: &HashMap<String, Identifier>) -> IndyResult<()> {
@@ -181,6 +467,7 @@ impl Verifier {
 
         let received_attrs: HashSet<String> = received_revealed_attrs
             .iter()
+            .chain(received_revealed_attr_groups)
             .chain(received_unrevealed_attrs)
             .map(|(r, _)| r.to_string())
             .collect::<HashSet<String>>()
@@ -209,8 +496,69 @@ impl Verifier {
         Ok(())
     }
 
+    fn _verify_revealed_attr_groups(proof_req: &ProofRequest,
+                                    proof: &Proof) -> IndyResult<()> {
+        let requested_proof = &proof.requested_proof;
+
+        // Values revealed by the crypto proof are not accessible directly, so take them from its serialized form
+        let crypto_proof = serde_json::to_value(&proof.proof)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Proof")?;
+
+        for (referent, info) in proof_req.requested_attributes.iter() {
+            let requested_names = get_requested_attr_names(info)?;
+
+            if info.names.is_none() {
+                if requested_proof.revealed_attr_groups.contains_key(referent) {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                       format!("Requested attribute \"{}\" must not be received as a group", referent)));
+                }
+                continue;
+            }
+
+            if requested_proof.revealed_attrs.contains_key(referent) || requested_proof.self_attested_attrs.contains_key(referent) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure,
+                                   format!("Requested attribute group \"{}\" must be received either as a revealed group or unrevealed", referent)));
+            }
+
+            if let Some(group_info) = requested_proof.revealed_attr_groups.get(referent) {
+                Verifier::_verify_revealed_attr_group(referent, &requested_names, group_info, &crypto_proof)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn _verify_revealed_attr_group(referent: &str,
+                                   requested_names: &[&str],
+                                   group_info: &RevealedAttributeGroupInfo,
+                                   crypto_proof: &serde_json::Value) -> IndyResult<()> {
+        let requested_names: HashSet<&str> = requested_names.iter().cloned().collect();
+        let received_names: HashSet<&str> = group_info.values.keys().map(String::as_str).collect();
+
+        if requested_names != received_names {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Requested attributes {:?} of group \"{}\" do not correspond to received {:?}", requested_names, referent, received_names)));
+        }
+
+        let proved_values = &crypto_proof["proofs"][group_info.sub_proof_index as usize]["primary_proof"]["eq_proof"]["revealed_attrs"];
+
+        for (name, value) in group_info.values.iter() {
+            let proved_value = proved_values[attr_common_view(name)].as_str()
+                .ok_or(err_msg(IndyErrorKind::ProofRejected,
+                               format!("Attribute \"{}\" of group \"{}\" is not revealed by sub proof {}", name, referent, group_info.sub_proof_index)))?;
+
+            if proved_value.trim_start_matches('0') != value.encoded.trim_start_matches('0') {
+                return Err(err_msg(IndyErrorKind::ProofRejected,
+                                   format!("Encoded value of attribute \"{}\" of group \"{}\" does not correspond to proof", name, referent)));
+            }
+        }
+
+        Ok(())
+    }
+
     fn _compare_timestamps_from_proof_and_request(proof_req: &ProofRequest,
                                                   received_revealed_attrs: &HashMap<String, Identifier>,
+                                                  received_revealed_attr_groups: &HashMap<String, Identifier>,
                                                   received_unrevealed_attrs: &HashMap<String, Identifier>,
                                                   received_self_attested_attrs: &HashSet<String>,
                                                   received_predicates: &HashMap<String, Identifier>) -> IndyResult<()> {
@@ -218,6 +566,7 @@ impl Verifier {
             .iter()
             .map(|(referent, info)|
                 Verifier::_validate_timestamp(&received_revealed_attrs, referent, &proof_req.non_revoked, &info.non_revoked)
+                    .or(Verifier::_validate_timestamp(&received_revealed_attr_groups, referent, &proof_req.non_revoked, &info.non_revoked))
                     .or(Verifier::_validate_timestamp(&received_unrevealed_attrs, referent, &proof_req.non_revoked, &info.non_revoked))
                     .or(received_self_attested_attrs.get(referent).map(|_| ()).ok_or(IndyError::from(IndyErrorKind::InvalidStructure)))
             )
@@ -259,6 +608,17 @@ impl Verifier {
         Ok(revealed_identifiers)
     }
 
+    fn _received_revealed_attr_groups(proof: &Proof) -> IndyResult<HashMap<String, Identifier>> {
+        let mut revealed_identifiers: HashMap<String, Identifier> = HashMap::new();
+        for (referent, info) in proof.requested_proof.revealed_attr_groups.iter() {
+            revealed_identifiers.insert(
+                referent.to_string(),
+                Verifier::_get_proof_identifier(proof, info.sub_proof_index)?
+            );
+        }
+        Ok(revealed_identifiers)
+    }
+
     fn _received_unrevealed_attrs(proof: &Proof) -> IndyResult<HashMap<String, Identifier>> {
         let mut unrevealed_identifiers: HashMap<String, Identifier> = HashMap::new();
         for (referent, info) in proof.requested_proof.unrevealed_attrs.iter() {
@@ -302,11 +662,13 @@ impl Verifier {
                                       schemas: &HashMap<String, SchemaV1>,
                                       cred_defs: &HashMap<String, CredentialDefinitionV1>,
                                       received_revealed_attrs: &HashMap<String, Identifier>,
+                                      received_revealed_attr_groups: &HashMap<String, Identifier>,
                                       received_unrevealed_attrs: &HashMap<String, Identifier>,
                                       received_predicates: &HashMap<String, Identifier>,
                                       self_attested_attrs: &HashSet<String>) -> IndyResult<()> {
         let proof_attr_identifiers: HashMap<String, Identifier> = received_revealed_attrs
             .into_iter()
+            .chain(received_revealed_attr_groups)
             .chain(received_unrevealed_attrs)
             .map(|(r, id)| (r.to_string(), id.clone()))
             .collect();
@@ -318,19 +680,22 @@ impl Verifier {
             .collect();
 
         for (referent, info) in requested_attrs {
+            let names = get_requested_attr_names(&info)?;
+            let attr_name = names.join(", ");
+
             let op = parse_from_json(
-                &build_wql_query(&info.name, &referent, &info.restrictions, &None)?
+                &build_wql_query(&names, &referent, &info.restrictions, &None)?
             )?;
 
             let filter = Verifier::_gather_filter_info(&referent, &proof_attr_identifiers, schemas, cred_defs)?;
 
-            Verifier::_process_operator(&info.name, &op, &filter)
-                .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" attribute", &info.name)))?;
+            Verifier::_process_operator(&attr_name, &op, &filter)
+                .map_err(|err| err.extend(format!("Requested restriction validation failed for \"{}\" attribute", &attr_name)))?;
         }
 
         for (referent, info) in proof_req.requested_predicates.iter() {
             let op = parse_from_json(
-                &build_wql_query(&info.name, &referent, &info.restrictions, &None)?
+                &build_wql_query(&[info.name.as_str()], &referent, &info.restrictions, &None)?
             )?;
 
             let filter = Verifier::_gather_filter_info(&referent, received_predicates, schemas, cred_defs)?;
@@ -343,10 +708,13 @@ impl Verifier {
     }
 
     fn _is_self_attested(referent: &str, info: &AttributeInfo, self_attested_attrs: &HashSet<String>) -> bool {
+        Verifier::_allows_self_attested(info) && self_attested_attrs.contains(referent)
+    }
+
+    fn _allows_self_attested(info: &AttributeInfo) -> bool {
         match info.restrictions.as_ref() {
-            Some(&serde_json::Value::Array(ref array)) if array.is_empty() =>
-                self_attested_attrs.contains(referent),
-            None => self_attested_attrs.contains(referent),
+            Some(&serde_json::Value::Array(ref array)) => array.is_empty(),
+            None => true,
             Some(_) => false
         }
     }
@@ -362,6 +730,12 @@ impl Verifier {
                 format!("Identifier not found for referent: {}", referent))
             )?;
 
+        Verifier::_filter_for_identifier(identifier, schemas, cred_defs)
+    }
+
+    fn _filter_for_identifier(identifier: &Identifier,
+                              schemas: &HashMap<String, SchemaV1>,
+                              cred_defs: &HashMap<String, CredentialDefinitionV1>) -> IndyResult<Filter> {
         let schema: &SchemaV1 = schemas
             .get(&identifier.schema_id)
             .ok_or(err_msg(
@@ -768,4 +1142,31 @@ mod tests {
         Verifier::_validate_timestamp(&_received(), "referent_2", &None, &Some(_interval())).unwrap_err();
         Verifier::_validate_timestamp(&_received(), "referent_3", &None, &Some(_interval())).unwrap_err();
     }
+
+    #[test]
+    fn check_credential_data_works_for_missed_schema() {
+        let check = Verifier::_check_credential_data(&_received()["referent_1"], &HashMap::new(), &HashMap::new());
+        assert_eq!(Some(VerificationFailureReason::SchemaNotFound), check.reason);
+    }
+
+    #[test]
+    fn check_revocation_data_works_for_no_timestamp() {
+        let check = Verifier::_check_revocation_data(&_received()["referent_2"], &HashMap::new(), &HashMap::new());
+        assert_eq!(VerificationCheck::skipped(), check);
+    }
+
+    #[test]
+    fn check_revocation_data_works_for_missed_rev_reg_def() {
+        let check = Verifier::_check_revocation_data(&_received()["referent_1"], &HashMap::new(), &HashMap::new());
+        assert_eq!(Some(VerificationFailureReason::RevocationRegistryDefinitionNotFound), check.reason);
+    }
+
+    #[test]
+    fn check_revocation_data_works_for_no_rev_reg_id() {
+        let mut identifier = _received()["referent_1"].clone();
+        identifier.rev_reg_id = None;
+
+        let check = Verifier::_check_revocation_data(&identifier, &HashMap::new(), &HashMap::new());
+        assert_eq!(Some(VerificationFailureReason::RevocationRegistryDefinitionNotFound), check.reason);
+    }
 }
@@ -109,6 +109,13 @@ impl BlobStorageService {
         writer.finalize(hash.as_slice())
             .map(|location| (location, hash))
     }
+
+    pub fn close_writer(&self, config_handle: i32) -> IndyResult<()> {
+        self.writer_configs.try_borrow_mut()?
+            .remove(&config_handle).ok_or(err_msg(IndyErrorKind::InvalidStructure, "Invalid BlobStorage config handle"))?; // FIXME: Review error kind
+
+        Ok(())
+    }
 }
 
 /* Reader */
@@ -7,6 +7,7 @@ use domain::crypto::combo_box::ComboBox;
 use domain::crypto::did::{Did, MyDidInfo, TheirDid, TheirDidInfo};
 use domain::crypto::key::{Key, KeyInfo};
 use errors::prelude::*;
+use services::pool::DEFAULT_GENERATOR;
 use utils::crypto::base64;
 use utils::crypto::ed25519_box;
 use utils::crypto::chacha20poly1305_ietf;
@@ -17,6 +18,7 @@ use utils::crypto::verkey_builder::build_full_verkey;
 use self::ed25519::ED25519CryptoType;
 use self::hex::FromHex;
 use rust_base58::{FromBase58, ToBase58};
+use ursa::bls::{Generator, ProofOfPossession, SignKey as BlsSignKey, VerKey as BlsVerKey};
 
 mod ed25519;
 
@@ -121,6 +123,25 @@ impl CryptoService {
         Ok(did)
     }
 
+    /// Creates BLS key pair used by validator nodes for state proofs.
+    /// Returns base58 encoded BLS verkey and its proof of possession.
+    pub fn create_bls_key(&self, seed: Option<&str>) -> IndyResult<(String, String)> {
+        trace!("create_bls_key >>> seed: {:?}", secret!(seed));
+
+        let seed = self.convert_seed(seed)?;
+
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58()?)?;
+        let sign_key = BlsSignKey::new(seed.as_ref().map(|seed| &seed[..]))?;
+        let ver_key = BlsVerKey::new(&generator, &sign_key)?;
+        let pop = ProofOfPossession::new(&ver_key, &sign_key)?;
+
+        let res = (ver_key.as_bytes().to_base58(), pop.as_bytes().to_base58());
+
+        trace!("create_bls_key <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
     pub fn create_their_did(&self, their_did_info: &TheirDidInfo) -> IndyResult<TheirDid> {
         trace!("create_their_did >>> their_did_info: {:?}", their_did_info);
 
@@ -875,4 +896,28 @@ mod tests {
             .decrypt_ciphertext(&expected_ciphertext, &iv_encoded, &tag, bad_aad, &cek);
         assert!(expected_error.is_err());
     }
+
+    #[test]
+    fn create_bls_key_works() {
+        use ursa::bls::Bls;
+
+        let service = CryptoService::new();
+
+        let (blskey, blskey_pop) = service.create_bls_key(None).unwrap();
+
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+        let blskey = BlsVerKey::from_bytes(&blskey.from_base58().unwrap()).unwrap();
+        let blskey_pop = ProofOfPossession::from_bytes(&blskey_pop.from_base58().unwrap()).unwrap();
+        assert!(Bls::verify_proof_of_posession(&blskey_pop, &blskey, &generator).unwrap());
+    }
+
+    #[test]
+    fn create_bls_key_works_for_seed() {
+        let service = CryptoService::new();
+
+        let seed = Some("000000000000000000000000000Node1");
+        let (blskey, blskey_pop) = service.create_bls_key(seed).unwrap();
+
+        assert_eq!((blskey, blskey_pop), service.create_bls_key(seed).unwrap());
+    }
 }
\ No newline at end of file
@@ -126,6 +126,39 @@ impl MerkleTree {
         Ok(true)
     }
 
+    /// Checks RFC 6962 audit path of leaf with `leaf_index` in the tree of `tree_size` leaves.
+    pub fn check_inclusion_proof(leaf: &TreeLeafData, leaf_index: usize, tree_size: usize,
+                                 root_hash: &Vec<u8>, audit_path: &Vec<Vec<u8>>) -> IndyResult<bool> {
+        if leaf_index >= tree_size {
+            return Ok(false);
+        }
+
+        let mut node = leaf_index;
+        let mut last_node = tree_size - 1;
+        let mut hash = Hash::hash_leaf(leaf)?.to_vec();
+
+        for proof in audit_path {
+            if last_node == 0 {
+                // path is longer than tree height
+                return Ok(false);
+            }
+
+            if node % 2 != 0 || node == last_node {
+                hash = Hash::hash_nodes(proof, &hash)?.to_vec();
+                while node % 2 == 0 && node != 0 {
+                    node /= 2;
+                    last_node /= 2;
+                }
+            } else {
+                hash = Hash::hash_nodes(&hash, proof)?.to_vec();
+            }
+            node /= 2;
+            last_node /= 2;
+        }
+
+        Ok(last_node == 0 && hash == *root_hash)
+    }
+
     pub fn append(&mut self, node: TreeLeafData) -> IndyResult<()> {
         if self.count == 0 {
             // empty tree
@@ -315,6 +348,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn check_inclusion_proof_works() {
+        let values = vec!["1", "2", "3", "4", "5"].iter().map(|x| x.as_bytes().to_vec()).collect::<Vec<_>>();
+        let root_hash = MerkleTree::from_vec(values.clone()).unwrap().root_hash().clone();
+
+        let leaf_hash = |i: usize| Hash::hash_leaf(&values[i]).unwrap().to_vec();
+        let subtree_hash = |from: usize, to: usize| MerkleTree::from_vec(values[from..to].to_vec()).unwrap().root_hash().clone();
+
+        let path_for_2 = vec![leaf_hash(0), subtree_hash(2, 4), leaf_hash(4)];
+        assert!(MerkleTree::check_inclusion_proof(&values[1], 1, 5, &root_hash, &path_for_2).unwrap());
+
+        let path_for_5 = vec![subtree_hash(0, 4)];
+        assert!(MerkleTree::check_inclusion_proof(&values[4], 4, 5, &root_hash, &path_for_5).unwrap());
+    }
+
+    #[test]
+    fn check_inclusion_proof_works_for_invalid_proof() {
+        let values = vec!["1", "2", "3", "4", "5"].iter().map(|x| x.as_bytes().to_vec()).collect::<Vec<_>>();
+        let root_hash = MerkleTree::from_vec(values.clone()).unwrap().root_hash().clone();
+
+        let path_for_5 = vec![MerkleTree::from_vec(values[0..4].to_vec()).unwrap().root_hash().clone()];
+
+        assert!(!MerkleTree::check_inclusion_proof(&values[3], 4, 5, &root_hash, &path_for_5).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(&values[4], 3, 5, &root_hash, &path_for_5).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(&values[4], 4, 6, &root_hash, &path_for_5).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(&values[4], 5, 5, &root_hash, &path_for_5).unwrap());
+        assert!(!MerkleTree::check_inclusion_proof(&values[4], 4, 5, &root_hash, &vec![]).unwrap());
+    }
+
     #[test]
     fn serialize_works() {
         let strvals = vec!["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"];
@@ -1,36 +1,52 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
+use hex;
 use hex::FromHex;
+use ursa::bls::{Bls, Generator, ProofOfPossession, VerKey as BlsVerKey};
 use ursa::cl::RevocationRegistryDelta as CryproRevocationRegistryDelta;
 use serde::de::DeserializeOwned;
 use serde_json;
 use serde_json::Value;
 use log_derive::logfn;
+use rmp_serde;
+use rust_base58::{FromBase58, ToBase58};
+use time;
 
 use domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionV1};
 use domain::anoncreds::DELIMITER;
+use domain::anoncreds::revocation_history::RevocationRegistryState;
 use domain::anoncreds::revocation_registry::RevocationRegistry;
 use domain::anoncreds::revocation_registry_definition::{RevocationRegistryDefinition, RevocationRegistryDefinitionV1};
 use domain::anoncreds::revocation_registry_delta::{RevocationRegistryDelta, RevocationRegistryDeltaV1};
 use domain::anoncreds::schema::{Schema, SchemaV1, MAX_ATTRIBUTES_COUNT};
-use domain::ledger::attrib::{AttribOperation, GetAttribOperation};
-use domain::ledger::constants::{GET_VALIDATOR_INFO, NYM, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, txn_name_to_code};
+use domain::ledger::attrib::{AttribOperation, GetAttribOperation, GetAttrReplyResult, ParsedAttrib};
+use domain::ledger::constants::{GET_VALIDATOR_INFO, NODE, NYM, ATTRIB, SCHEMA, CRED_DEF, REVOC_REG_DEF, REVOC_REG_ENTRY, POOL_RESTART, ROLE_REMOVE, STEWARD, ENDORSER, TRUSTEE, NETWORK_MONITOR, ROLES, GET_RICH_SCHEMA_OBJECT_BY_METADATA, TXN_AUTHR_AGRMT_ACCEPTANCE_REQUESTS, txn_name_to_code};
 use domain::ledger::cred_def::{CredDefOperation, GetCredDefOperation, GetCredDefReplyResult};
-use domain::ledger::ddo::GetDdoOperation;
+use domain::ledger::genesis::{GenesisNode, GenesisTxn};
 use domain::ledger::node::{NodeOperation, NodeOperationData};
-use domain::ledger::nym::GetNymOperation;
+use domain::ledger::nym::{GetNymOperation, GetNymReplyResult, GetNymResultDataV0, ParsedNym};
 use domain::ledger::pool::{PoolConfigOperation, PoolRestartOperation, PoolUpgradeOperation};
+use domain::ledger::ledgers_freeze::{BASE_LEDGERS_IDS, GetFrozenLedgersOperation, LedgersFreezeOperation};
 use domain::ledger::request::{TxnAuthrAgrmtAcceptanceData, Request};
 use domain::ledger::response::{Message, Reply, ReplyType};
-use domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation};
+use domain::ledger::rev_reg::{GetRevocRegDeltaReplyResult, GetRevocRegReplyResult, GetRevRegDeltaOperation, GetRevRegOperation, RevRegEntryOperation, RevocationRegistryDeltaData};
 use domain::ledger::rev_reg_def::{GetRevocRegDefReplyResult, GetRevRegDefOperation, RevRegDefOperation};
+use domain::ledger::rich_schema::{GetRichSchemaObjectByIdOperation, GetRichSchemaObjectByIdReplyResult, GetRichSchemaObjectByMetadataOperation,
+                                  GetRichSchemaObjectByMetadataReplyResult, RichSchemaObject, RichSchemaOperation, RichSchemaType,
+                                  RICH_SCHEMA_OBJECT_VERSION};
 use domain::ledger::schema::{GetSchemaOperation, GetSchemaOperationData, GetSchemaReplyResult, SchemaOperation, SchemaOperationData};
-use domain::ledger::txn::{GetTxnOperation, LedgerType};
+use domain::ledger::txn::{GetTxnOperation, GetTxnReplyResult, GetTxnResultData, LedgerType, ParsedTxn};
 use domain::ledger::validator_info::GetValidatorInfoOperation;
 use domain::ledger::auth_rule::*;
 use domain::ledger::author_agreement::*;
+use domain::ledger::endorsement::{EndorsementBundle, request_signatures};
 use errors::prelude::*;
+use services::pool::DEFAULT_GENERATOR;
 use utils::crypto::hash::hash as openssl_hash;
+use utils::crypto::signature_serializer::serialize_signature;
+use utils::crypto::verkey_builder::build_full_verkey;
+
+use self::merkletree::merkletree::MerkleTree;
 
 pub mod merkletree;
 
@@ -69,14 +85,7 @@ impl LedgerService {
             if r == ROLE_REMOVE {
                 operation["role"] = Value::Null
             } else {
-                operation["role"] = Value::String(match r {
-                    "STEWARD" => STEWARD,
-                    "TRUSTEE" => TRUSTEE,
-                    "TRUST_ANCHOR" | "ENDORSER" => ENDORSER,
-                    "NETWORK_MONITOR" => NETWORK_MONITOR,
-                    role if ROLES.contains(&role) => role,
-                    role @ _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
-                }.to_string())
+                operation["role"] = Value::String(_role_code(r)?)
             }
         }
 
@@ -92,8 +101,9 @@ impl LedgerService {
     }
 
     #[logfn(Info)]
+    /// Ledger doesn't support DDO transaction, so GET_NYM request is built instead.
     pub fn build_get_ddo_request(&self, identifier: Option<&str>, dest: &str) -> IndyResult<String> {
-        build_result!(GetDdoOperation, identifier, dest.to_string())
+        build_result!(GetNymOperation, identifier, dest.to_string())
     }
 
     #[logfn(Info)]
@@ -193,9 +203,116 @@ impl LedgerService {
             return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: Fields node_ip, node_port, client_ip, client_port must be specified together"));
         }
 
+        match (data.blskey.as_ref(), data.blskey_pop.as_ref()) {
+            (Some(blskey), Some(blskey_pop)) => LedgerService::_check_blskey_pop(blskey, blskey_pop)?,
+            (Some(_), None) => return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: Field blskey_pop must be specified together with blskey")),
+            (None, Some(_)) => return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: Field blskey_pop can't be specified without blskey")),
+            (None, None) => {}
+        }
+
         build_result!(NodeOperation, Some(identifier), dest.to_string(), data)
     }
 
+    fn _check_blskey_pop(blskey: &str, blskey_pop: &str) -> IndyResult<()> {
+        let generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58()?)?;
+
+        let blskey = blskey.from_base58().ok()
+            .and_then(|blskey| BlsVerKey::from_bytes(&blskey).ok())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: blskey is not a valid BLS key"))?;
+
+        let blskey_pop = blskey_pop.from_base58().ok()
+            .and_then(|blskey_pop| ProofOfPossession::from_bytes(&blskey_pop).ok())
+            .ok_or_else(|| err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: blskey_pop is not a valid BLS proof of possession"))?;
+
+        if !Bls::verify_proof_of_posession(&blskey_pop, &blskey, &generator)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid data json: blskey_pop doesn't correspond to blskey"));
+        }
+
+        Ok(())
+    }
+
+    /// Builds pool and domain genesis transactions of a new network.
+    /// Domain genesis contains NYMs of trustees followed by NYMs of node stewards written by the first trustee.
+    pub fn build_genesis_transactions(&self, trustees: &[(String, String)], nodes: Vec<GenesisNode>) -> IndyResult<(String, String)> {
+        trace!("build_genesis_transactions >>> trustees: {:?}, nodes: {:?}", trustees, nodes);
+
+        let first_trustee_did = match trustees.first() {
+            Some(&(ref did, _)) => did.clone(),
+            None => return Err(err_msg(IndyErrorKind::InvalidStructure, "At least one trustee must be specified"))
+        };
+
+        if nodes.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "At least one node must be specified"));
+        }
+
+        {
+            let mut aliases = HashSet::new();
+            let mut dests = HashSet::new();
+            let mut stewards = HashSet::new();
+
+            for node in nodes.iter() {
+                if !aliases.insert(&node.data.alias) {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Node alias {} is used more than once", node.data.alias)));
+                }
+
+                if !dests.insert(&node.dest) {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Node {} uses the same seed as another node", node.data.alias)));
+                }
+
+                if !stewards.insert(&node.steward_did) || trustees.iter().any(|&(ref did, _)| did == &node.steward_did) {
+                    return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Steward of node {} must have its own seed", node.data.alias)));
+                }
+            }
+        }
+
+        let mut domain_txns = Vec::new();
+
+        for &(ref did, ref verkey) in trustees.iter() {
+            let seq_no = domain_txns.len() as u64 + 1;
+            let verkey = LedgerService::_abbreviate_verkey(did, verkey)?;
+            let txn = GenesisTxn::nym(seq_no, None, did, &verkey, TRUSTEE);
+            domain_txns.push(serde_json::to_string(&txn)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize trustee genesis transaction")?);
+        }
+
+        for node in nodes.iter() {
+            let seq_no = domain_txns.len() as u64 + 1;
+            let verkey = LedgerService::_abbreviate_verkey(&node.steward_did, &node.steward_verkey)?;
+            let txn = GenesisTxn::nym(seq_no, Some(&first_trustee_did), &node.steward_did, &verkey, STEWARD);
+            domain_txns.push(serde_json::to_string(&txn)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize steward genesis transaction")?);
+        }
+
+        let mut pool_txns = Vec::new();
+
+        for node in nodes {
+            let seq_no = pool_txns.len() as u64 + 1;
+            let txn_id = hex::encode(openssl_hash(node.data.alias.as_bytes())?);
+            let txn = GenesisTxn::node(seq_no, txn_id, node);
+            pool_txns.push(serde_json::to_string(&txn)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize node genesis transaction")?);
+        }
+
+        let res = (pool_txns.join("\n"), domain_txns.join("\n"));
+
+        trace!("build_genesis_transactions <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    fn _abbreviate_verkey(did: &str, verkey: &str) -> IndyResult<String> {
+        let did = did.from_base58()?;
+        let dverkey = verkey.from_base58()?;
+
+        let (first_part, second_part) = dverkey.split_at(16);
+
+        if first_part.eq(did.as_slice()) {
+            Ok(format!("~{}", second_part.to_base58()))
+        } else {
+            Ok(verkey.to_string())
+        }
+    }
+
     #[logfn(Info)]
     pub fn build_get_validator_info_request(&self, identifier: &str) -> IndyResult<String> {
         let operation = GetValidatorInfoOperation::new();
@@ -206,16 +323,142 @@ impl LedgerService {
 
     #[logfn(Info)]
     pub fn build_get_txn_request(&self, identifier: Option<&str>, ledger_type: Option<&str>, seq_no: i32) -> IndyResult<String> {
-        let ledger_id = match ledger_type {
+        let ledger_id = self.parse_ledger_id(ledger_type)?;
+
+        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+    }
+
+    /// Returns id of the ledger passed either as predefined name (POOL, DOMAIN, CONFIG) or as number.
+    pub fn parse_ledger_id(&self, ledger_type: Option<&str>) -> IndyResult<i32> {
+        match ledger_type {
             Some(type_) =>
                 serde_json::from_str::<LedgerType>(&format!(r#""{}""#, type_))
                     .map(|type_| type_.to_id())
                     .or_else(|_| type_.parse::<i32>())
-                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", type_))?,
-            None => LedgerType::DOMAIN.to_id()
+                    .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid Ledger type: {}", type_)),
+            None => Ok(LedgerType::DOMAIN.to_id())
+        }
+    }
+
+    /// Checks that ledger txn is included into ledger with specified merkle root.
+    /// Audit path is taken from txn json if not passed explicitly, so result of write reply
+    /// or data of GET_TXN reply can be passed as is. Root hash inside of txn json is never used.
+    #[logfn(Info)]
+    pub fn verify_txn_inclusion(&self, root_hash: &str, txn_json: &str, audit_path: Option<&str>, ledger_size: Option<usize>) -> IndyResult<bool> {
+        let mut txn: Value = serde_json::from_str(txn_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize ledger txn")?;
+
+        // root hash of reply isn't a part of txn
+        let txn_audit_path = match txn.as_object_mut() {
+            Some(txn) => {
+                txn.remove("rootHash");
+                txn.remove("auditPath")
+            }
+            None => return Err(err_msg(IndyErrorKind::InvalidStructure, "Ledger txn must be an object"))
         };
 
-        build_result!(GetTxnOperation, identifier, seq_no, ledger_id)
+        let seq_no = txn["txnMetadata"]["seqNo"].as_u64()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Ledger txn doesn't contain txnMetadata.seqNo"))? as usize;
+
+        if seq_no == 0 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Invalid txn seqNo: 0"));
+        }
+
+        let root_hash = root_hash.from_base58()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid root hash: {:?}", err)))?;
+
+        let audit_path: Vec<String> = match audit_path {
+            Some(audit_path) => serde_json::from_str(audit_path)
+                .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize audit path")?,
+            None => txn_audit_path.map(serde_json::from_value)
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Audit path isn't passed and ledger txn doesn't contain auditPath"))?
+                .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize audit path")?
+        };
+
+        let audit_path = audit_path.iter()
+            .map(|hash| hash.from_base58()
+                .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid audit path hash: {:?}", err))))
+            .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+        let leaf = rmp_serde::to_vec_named(&txn)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot encode ledger txn as message pack")?;
+
+        // nodes build proofs for the ledger of txn seqNo size
+        let ledger_size = ledger_size.unwrap_or(seq_no);
+
+        MerkleTree::check_inclusion_proof(&leaf, seq_no - 1, ledger_size, &root_hash, &audit_path)
+    }
+
+    /// Parses root hash and size of the ledger from LEDGER_STATUS message.
+    pub fn parse_ledger_status(&self, ledger_status: &str) -> IndyResult<(Vec<u8>, usize)> {
+        let ledger_status: Value = serde_json::from_str(ledger_status)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize LedgerStatus")?;
+
+        let size = ledger_status["txnSeqNo"].as_u64()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "LedgerStatus doesn't contain txnSeqNo"))? as usize;
+
+        let root_hash = ledger_status["merkleRoot"].as_str()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "LedgerStatus doesn't contain merkleRoot"))?
+            .from_base58()
+            .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid merkle root: {:?}", err)))?;
+
+        Ok((root_hash, size))
+    }
+
+    /// Appends transactions of CATCHUP_REP message to the merkle tree of preceding ledger transactions
+    /// and checks consistency proof of the result against root hash and size of the whole ledger.
+    /// Returns extended merkle tree and transactions in order of seqNo.
+    pub fn verify_catchup_reply(&self, catchup_rep: &str, merkle_tree: &MerkleTree,
+                                target_mt_root: &Vec<u8>, target_mt_size: usize) -> IndyResult<(MerkleTree, Vec<ParsedTxn>)> {
+        let catchup_rep: Value = serde_json::from_str(catchup_rep)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize CatchupRep")?;
+
+        let txns = catchup_rep["txns"].as_object()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "CatchupRep doesn't contain txns"))?;
+
+        let seq_no_start = merkle_tree.count() + 1;
+        let seq_no_end = merkle_tree.count() + txns.len();
+
+        if seq_no_end > target_mt_size {
+            return Err(err_msg(IndyErrorKind::InvalidState, "CatchupRep contains transactions beyond the ledger size"));
+        }
+
+        let mut merkle_tree = merkle_tree.clone();
+        let mut parsed_txns = Vec::new();
+
+        for seq_no in seq_no_start..seq_no_end + 1 {
+            let txn = txns.get(&seq_no.to_string())
+                .ok_or(err_msg(IndyErrorKind::InvalidState, format!("CatchupRep doesn't contain txn {}", seq_no)))?;
+
+            let leaf = rmp_serde::to_vec_named(txn)
+                .to_indy(IndyErrorKind::InvalidState, "Cannot encode ledger txn as message pack")?;
+
+            merkle_tree.append(leaf)?;
+
+            let parsed_txn = serde_json::from_value::<GetTxnResultData>(txn.clone())
+                .map(ParsedTxn::from)
+                .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize ledger txn")?;
+
+            if parsed_txn.seq_no != seq_no as u64 {
+                return Err(err_msg(IndyErrorKind::InvalidState, format!("Ledger txn {} has unexpected seqNo {}", seq_no, parsed_txn.seq_no)));
+            }
+
+            parsed_txns.push(parsed_txn);
+        }
+
+        let cons_proof: Vec<String> = serde_json::from_value(catchup_rep["consProof"].clone())
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize consistency proof")?;
+
+        let cons_proof = cons_proof.iter()
+            .map(|hash| hash.from_base58()
+                .map_err(|err| err_msg(IndyErrorKind::InvalidStructure, format!("Invalid consistency proof hash: {:?}", err))))
+            .collect::<IndyResult<Vec<Vec<u8>>>>()?;
+
+        if !merkle_tree.consistency_proof(target_mt_root, target_mt_size, &cons_proof)? {
+            return Err(err_msg(IndyErrorKind::InvalidState, "Consistency proof verification failed"));
+        }
+
+        Ok((merkle_tree, parsed_txns))
     }
 
     #[logfn(Info)]
@@ -253,6 +496,27 @@ impl LedgerService {
         build_result!(PoolUpgradeOperation, Some(identifier), name, version, action, sha256, timeout, schedule, justification, reinstall, force, package)
     }
 
+    #[logfn(Info)]
+    pub fn build_ledgers_freeze_request(&self, identifier: &str, ledgers_ids: Vec<u64>) -> IndyResult<String> {
+        if ledgers_ids.is_empty() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "At least one ledger id must be specified"));
+        }
+
+        if let Some(ledger_id) = ledgers_ids.iter().find(|ledger_id| BASE_LEDGERS_IDS.contains(ledger_id)) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Base ledger {} can't be frozen", ledger_id)));
+        }
+
+        build_result!(LedgersFreezeOperation, Some(identifier), ledgers_ids)
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_frozen_ledgers_request(&self, identifier: Option<&str>) -> IndyResult<String> {
+        let operation = GetFrozenLedgersOperation::new();
+
+        Request::build_request(identifier, operation)
+            .to_indy(IndyErrorKind::InvalidState, "GET_FROZEN_LEDGERS request json is invalid")
+    }
+
     #[logfn(Info)]
     pub fn build_revoc_reg_def_request(&self, identifier: &str, rev_reg_def: RevocationRegistryDefinitionV1) -> IndyResult<String> {
         build_result!(RevRegDefOperation, Some(identifier), rev_reg_def)
@@ -279,6 +543,44 @@ impl LedgerService {
         build_result!(GetRevRegDeltaOperation, identifier, revoc_reg_def_id, from, to)
     }
 
+    #[logfn(Info)]
+    pub fn build_rich_schema_request(&self, identifier: &str, id: &str, content: &str, rs_name: &str, rs_version: &str,
+                                     rs_type: &str, ver: Option<&str>) -> IndyResult<String> {
+        let rs_type = _rich_schema_type(rs_type)?;
+
+        let content_json: Value = serde_json::from_str(content)
+            .to_indy(IndyErrorKind::InvalidStructure, "Rich schema object content is invalid json")?;
+
+        if !content_json.is_object() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Rich schema object content must be a JSON object"));
+        }
+
+        if rs_type == RichSchemaType::JsonLdContext && content_json["@context"].is_null() {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "JSON-LD context content must contain `@context` field"));
+        }
+
+        if rs_type != RichSchemaType::JsonLdContext && content_json["@id"].as_str().map(|content_id| content_id != id).unwrap_or(false) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Rich schema object id {} doesn't match `@id` of its content", id)));
+        }
+
+        build_result!(RichSchemaOperation, Some(identifier), id.to_string(), content.to_string(), rs_name.to_string(),
+                      rs_version.to_string(), rs_type, ver.unwrap_or(RICH_SCHEMA_OBJECT_VERSION).to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_id_request(&self, identifier: Option<&str>, id: &str) -> IndyResult<String> {
+        build_result!(GetRichSchemaObjectByIdOperation, identifier, id.to_string())
+    }
+
+    #[logfn(Info)]
+    pub fn build_get_rich_schema_object_by_metadata_request(&self, identifier: Option<&str>, rs_type: &str, rs_name: &str,
+                                                            rs_version: &str) -> IndyResult<String> {
+        let rs_type = _rich_schema_type(rs_type)?;
+
+        build_result!(GetRichSchemaObjectByMetadataOperation, identifier, rs_type, rs_name.to_string(), rs_version.to_string())
+    }
+
     #[logfn(Info)]
     pub fn parse_get_schema_response(&self, get_schema_response: &str) -> IndyResult<(String, String)> {
         let reply: Reply<GetSchemaReplyResult> = LedgerService::parse_response(get_schema_response)?;
@@ -370,12 +672,7 @@ impl LedgerService {
 
     #[logfn(Info)]
     pub fn parse_get_revoc_reg_delta_response(&self, get_revoc_reg_delta_response: &str) -> IndyResult<(String, String, u64)> {
-        let reply: Reply<GetRevocRegDeltaReplyResult> = LedgerService::parse_response(get_revoc_reg_delta_response)?;
-
-        let (revoc_reg_def_id, revoc_reg) = match reply.result() {
-            GetRevocRegDeltaReplyResult::GetRevocRegDeltaReplyResultV0(res) => (res.revoc_reg_def_id, res.data),
-            GetRevocRegDeltaReplyResult::GetRevocRegDeltaReplyResultV1(res) => (res.txn.data.revoc_reg_def_id, res.txn.data.value),
-        };
+        let (revoc_reg_def_id, revoc_reg) = LedgerService::_parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response)?;
 
         let res = (revoc_reg_def_id.clone(),
                    serde_json::to_string(&RevocationRegistryDelta::RevocationRegistryDeltaV1(
@@ -391,6 +688,248 @@ impl LedgerService {
         Ok(res)
     }
 
+    /// Parses reply to GET_REVOC_REG_DELTA request sent without `from` into the state of revocation registry at `to`.
+    #[logfn(Info)]
+    pub fn parse_get_revoc_reg_state_response(&self, get_revoc_reg_delta_response: &str) -> IndyResult<(String, RevocationRegistryState)> {
+        let (revoc_reg_def_id, revoc_reg) = LedgerService::_parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response)?;
+
+        if revoc_reg.value.accum_from.is_some() {
+            return Err(err_msg(IndyErrorKind::InvalidTransaction, "GET_REVOC_REG_DELTA reply contains delta instead of registry state"));
+        }
+
+        let state = RevocationRegistryState {
+            timestamp: revoc_reg.value.accum_to.txn_time,
+            value: revoc_reg.value.accum_to.value,
+            issued: revoc_reg.value.issued.into_iter().collect(),
+            revoked: revoc_reg.value.revoked.into_iter().collect(),
+        };
+
+        Ok((revoc_reg_def_id, state))
+    }
+
+    fn _parse_get_revoc_reg_delta_response(get_revoc_reg_delta_response: &str) -> IndyResult<(String, RevocationRegistryDeltaData)> {
+        let reply: Reply<GetRevocRegDeltaReplyResult> = LedgerService::parse_response(get_revoc_reg_delta_response)?;
+
+        let res = match reply.result() {
+            GetRevocRegDeltaReplyResult::GetRevocRegDeltaReplyResultV0(res) => (res.revoc_reg_def_id, res.data),
+            GetRevocRegDeltaReplyResult::GetRevocRegDeltaReplyResultV1(res) => (res.txn.data.revoc_reg_def_id, res.txn.data.value),
+        };
+
+        Ok(res)
+    }
+
+    /// Parses reply to either GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+    #[logfn(Info)]
+    pub fn parse_get_rich_schema_object_response(&self, get_rich_schema_object_response: &str) -> IndyResult<(String, String)> {
+        let response: Value = serde_json::from_str(get_rich_schema_object_response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        let result = match response["result"]["type"].as_str() {
+            Some(GET_RICH_SCHEMA_OBJECT_BY_METADATA) => {
+                let reply: Reply<GetRichSchemaObjectByMetadataReplyResult> = LedgerService::parse_response(get_rich_schema_object_response)?;
+                reply.result().0
+            }
+            _ => {
+                let reply: Reply<GetRichSchemaObjectByIdReplyResult> = LedgerService::parse_response(get_rich_schema_object_response)?;
+                reply.result().0
+            }
+        };
+
+        let data = result.data
+            .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Rich schema object not found"))?;
+
+        let object = RichSchemaObject::new(data, result.seq_no, result.txn_time);
+
+        let res = (object.id.clone(),
+                   serde_json::to_string(&object)
+                       .to_indy(IndyErrorKind::InvalidState, "Cannot serialize RichSchemaObject")?);
+
+        Ok(res)
+    }
+
+    /// Builds read request for the state changed by write request.
+    /// Used to check whether timed out write was already ordered before resubmitting it.
+    /// Returns None for write requests that can't be checked this way.
+    pub fn build_write_check_request(&self, request_json: &str) -> Option<String> {
+        let request: Value = serde_json::from_str(request_json).ok()?;
+
+        let identifier = request["identifier"].as_str()?;
+        let op = &request["operation"];
+
+        let res = match op["type"].as_str()? {
+            NYM => self.build_get_nym_request(Some(identifier), op["dest"].as_str()?),
+            ATTRIB => {
+                let raw = op["raw"].as_str()
+                    .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+                    .and_then(|raw| raw.as_object().and_then(|raw| raw.keys().next().cloned()));
+                self.build_get_attrib_request(Some(identifier), op["dest"].as_str()?,
+                                              raw.as_ref().map(String::as_str), op["hash"].as_str(), op["enc"].as_str())
+            }
+            SCHEMA => {
+                let id = format!("{}:2:{}:{}", identifier, op["data"]["name"].as_str()?, op["data"]["version"].as_str()?);
+                self.build_get_schema_request(Some(identifier), &id)
+            }
+            CRED_DEF => {
+                let mut id = format!("{}:3:{}:{}", identifier, op["signature_type"].as_str()?, op["ref"].as_u64()?);
+                if let Some(tag) = op["tag"].as_str() {
+                    id = format!("{}:{}", id, tag);
+                }
+                self.build_get_cred_def_request(Some(identifier), &id)
+            }
+            REVOC_REG_DEF => self.build_get_revoc_reg_def_request(Some(identifier), op["id"].as_str()?),
+            REVOC_REG_ENTRY => self.build_get_revoc_reg_request(Some(identifier), op["revocRegDefId"].as_str()?, time::get_time().sec),
+            _ => return None
+        };
+
+        res.ok()
+    }
+
+    /// Returns seqNo of the transaction that last changed the state requested by write check request.
+    pub fn parse_write_check_response(&self, response: &str) -> IndyResult<Option<i32>> {
+        let result = LedgerService::_reply_result(response)?;
+        Ok(result["seqNo"].as_i64().map(|seq_no| seq_no as i32))
+    }
+
+    /// Checks whether transaction returned in GET_TXN reply was written by the request
+    /// comparing payload digest if ledger provides it and author with reqId otherwise.
+    /// Returns transaction in the form of write reply if so.
+    pub fn parse_ordered_write(&self, request_json: &str, get_txn_response: &str) -> IndyResult<Option<String>> {
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let txn = LedgerService::_reply_result(get_txn_response)?["data"].take();
+        let metadata = &txn["txn"]["metadata"];
+
+        if metadata.is_null() {
+            return Ok(None);
+        }
+
+        let ordered = match metadata["payloadDigest"].as_str() {
+            Some(payload_digest) => {
+                let digest = openssl_hash(serialize_signature(request.clone())?.as_bytes())?;
+                hex::encode(digest) == payload_digest
+            }
+            None => metadata["from"] == request["identifier"] && metadata["reqId"] == request["reqId"]
+        };
+
+        if !ordered {
+            return Ok(None);
+        }
+
+        let reply = json!({"op": "REPLY", "result": txn});
+        Ok(Some(serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize write reply")?))
+    }
+
+    fn _reply_result(response: &str) -> IndyResult<Value> {
+        let mut response: Value = serde_json::from_str(response)
+            .to_indy(IndyErrorKind::InvalidTransaction, "Response is invalid json")?;
+
+        match response["op"].as_str() {
+            Some("REPLY") => Ok(response["result"].take()),
+            _ => Err(err_msg(IndyErrorKind::InvalidTransaction, format!("Transaction has been failed: {:?}", response["reason"])))
+        }
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_nym_response(&self, get_nym_response: &str) -> IndyResult<String> {
+        let nym = self.get_parsed_nym(get_nym_response)?;
+
+        serde_json::to_string(&nym)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize NYM")
+    }
+
+    pub fn get_parsed_nym(&self, get_nym_response: &str) -> IndyResult<ParsedNym> {
+        let reply: Reply<GetNymReplyResult> = LedgerService::parse_response(get_nym_response)?;
+
+        let (did, verkey, role, seq_no, txn_time) = match reply.result() {
+            GetNymReplyResult::GetNymReplyResultV0(res) => {
+                let data = res.data
+                    .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "NYM not found"))?;
+
+                let data: GetNymResultDataV0 = serde_json::from_str(&data)
+                    .to_indy(IndyErrorKind::InvalidTransaction, "Invalid GET_NYM reply data")?;
+
+                (data.dest, data.verkey, data.role, res.seq_no, res.txn_time)
+            }
+            GetNymReplyResult::GetNymReplyResultV1(res) =>
+                (res.txn.data.did, res.txn.data.verkey, res.txn.data.role,
+                 Some(res.txn_metadata.seq_no as u64), Some(res.txn_metadata.creation_time))
+        };
+
+        let verkey = match verkey {
+            Some(verkey) => Some(build_full_verkey(&did, Some(&verkey))?),
+            None => None
+        };
+
+        Ok(ParsedNym {
+            did,
+            verkey,
+            role: role.map(|role| _role_name(&role)),
+            seq_no,
+            txn_time,
+        })
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_attrib_response(&self, get_attrib_response: &str) -> IndyResult<String> {
+        let attrib = self.get_parsed_attrib(get_attrib_response)?;
+
+        serde_json::to_string(&attrib)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Attribute")
+    }
+
+    pub fn get_parsed_attrib(&self, get_attrib_response: &str) -> IndyResult<ParsedAttrib> {
+        let reply: Reply<GetAttrReplyResult> = LedgerService::parse_response(get_attrib_response)?;
+
+        let (did, raw, hash, enc, seq_no, txn_time) = match reply.result() {
+            GetAttrReplyResult::GetAttrReplyResultV0(res) => {
+                let data = res.data
+                    .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Attribute not found"))?;
+
+                // data contains value of the requested kind
+                if res.raw.is_some() {
+                    (res.dest, Some(data), None, None, res.seq_no, res.txn_time)
+                } else if res.hash.is_some() {
+                    (res.dest, None, Some(data), None, res.seq_no, res.txn_time)
+                } else {
+                    (res.dest, None, None, Some(data), res.seq_no, res.txn_time)
+                }
+            }
+            GetAttrReplyResult::GetAttrReplyResultV1(res) =>
+                (res.txn.data.did, res.txn.data.raw, res.txn.data.hash, res.txn.data.enc,
+                 Some(res.txn_metadata.seq_no as u64), Some(res.txn_metadata.creation_time))
+        };
+
+        let raw = match raw {
+            Some(raw) => Some(serde_json::from_str::<Value>(&raw)
+                .to_indy(IndyErrorKind::InvalidTransaction, "Raw attribute is invalid json")?),
+            None => None
+        };
+
+        Ok(ParsedAttrib {
+            did,
+            raw,
+            hash,
+            enc,
+            seq_no,
+            txn_time,
+        })
+    }
+
+    #[logfn(Info)]
+    pub fn parse_get_txn_response(&self, get_txn_response: &str) -> IndyResult<String> {
+        let reply: Reply<GetTxnReplyResult> = LedgerService::parse_response(get_txn_response)?;
+
+        let data = reply.result().data
+            .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction not found"))?;
+
+        let txn = ParsedTxn::from(data);
+
+        serde_json::to_string(&txn)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize Transaction")
+    }
+
     #[logfn(Info)]
     pub fn build_auth_rule_request(&self, submitter_did: &str, txn_type: &str, action: &str, field: &str,
                                    old_value: Option<&str>, new_value: Option<&str>, constraint: &str) -> IndyResult<String> {
@@ -549,6 +1088,69 @@ impl LedgerService {
         Ok(())
     }
 
+    /// Checks that acceptance corresponds to the latest transaction author agreement and
+    /// acceptance mechanisms list read from the ledger.
+    #[logfn(Info)]
+    pub fn check_acceptance_data(&self, acceptance: &TxnAuthrAgrmtAcceptanceData, get_taa_response: &str, get_aml_response: &str) -> IndyResult<()> {
+        let taa = LedgerService::parse_response::<GetTxnAuthorAgreementReplyResult>(get_taa_response)?.result();
+        let txn_time = taa.txn_time;
+
+        // agreement with empty text disables transaction author agreement on the ledger
+        let taa = taa.data
+            .filter(|taa| !taa.text.is_empty())
+            .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Transaction author agreement isn't set on the ledger"))?;
+
+        let digest = match taa.digest {
+            Some(digest) => digest,
+            None => hex::encode(self._calculate_hash(&taa.text, &taa.version)?)
+        };
+
+        if digest != acceptance.taa_digest {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Accepted transaction author agreement doesn't match the latest one on the ledger of version {}", taa.version)));
+        }
+
+        if let Some(ratification_time) = taa.ratification_ts.or(txn_time) {
+            if acceptance.time < LedgerService::datetime_to_date_timestamp(ratification_time) {
+                return Err(err_msg(IndyErrorKind::InvalidStructure, "Time of acceptance is earlier than ratification of transaction author agreement"));
+            }
+        }
+
+        if acceptance.time > time::get_time().sec as u64 {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Time of acceptance is in the future"));
+        }
+
+        let aml = LedgerService::parse_response::<GetAcceptanceMechanismReplyResult>(get_aml_response)?.result().data
+            .ok_or(err_msg(IndyErrorKind::LedgerItemNotFound, "Acceptance mechanisms list isn't set on the ledger"))?;
+
+        if !aml.aml.contains_key(&acceptance.mechanism) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure,
+                               format!("Acceptance mechanism {} isn't in the latest acceptance mechanisms list of version {}", acceptance.mechanism, aml.version)));
+        }
+
+        Ok(())
+    }
+
+    /// Appends acceptance of transaction author agreement to write request of the domain ledger
+    /// unless request already contains one. Other requests are returned as is.
+    pub fn append_acceptance_data_if_required(&self, request_json: &str, acceptance: &TxnAuthrAgrmtAcceptanceData) -> IndyResult<String> {
+        let mut request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Request is invalid json")?;
+
+        let required = request["operation"]["type"].as_str()
+            .map(|type_| TXN_AUTHR_AGRMT_ACCEPTANCE_REQUESTS.contains(&type_))
+            .unwrap_or(false);
+
+        if !required || !request["taaAcceptance"].is_null() {
+            return Ok(request_json.to_string());
+        }
+
+        request["taaAcceptance"] = serde_json::to_value(acceptance)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize author agreement acceptance data")?;
+
+        Ok(request.to_string())
+    }
+
     pub fn parse_get_auth_rule_response(&self, response: &str) -> IndyResult<Vec<AuthRule>> {
         trace!("parse_get_auth_rule_response >>> response: {:?}", response);
 
@@ -561,23 +1163,355 @@ impl LedgerService {
 
         Ok(res)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use domain::anoncreds::schema::AttributeNames;
-    use domain::ledger::constants::*;
-    use domain::ledger::node::Services;
-    use domain::ledger::request::ProtocolVersion;
+    /// Returns DID which current NYM is needed to determine auth actions of the request.
+    pub fn get_auth_nym_dest(&self, request_json: &str) -> IndyResult<Option<String>> {
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
 
-    use super::*;
+        match request["operation"]["type"].as_str() {
+            Some(NYM) => request["operation"]["dest"].as_str()
+                .map(|dest| Some(dest.to_string()))
+                .ok_or(err_msg(IndyErrorKind::InvalidStructure, "NYM request doesn't contain dest")),
+            _ => Ok(None)
+        }
+    }
 
-    const IDENTIFIER: &str = "NcYxiDXkpYi6ov5FcYDi1e";
-    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
-    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+    /// Returns keys of auth rules which apply to actions performed by write request.
+    /// NYM request is compared with current NYM of its dest (None if DID isn't written on the ledger).
+    /// NODE request containing node and client addresses, blskey and services is treated as adding of new node.
+    /// ATTRIB, SCHEMA and CRED_DEF requests are treated as adding of new entry.
+    pub fn get_request_auth_actions(&self, request_json: &str, current_nym: Option<&ParsedNym>) -> IndyResult<Vec<AuthActionKey>> {
+        trace!("get_request_auth_actions >>> request_json: {:?}, current_nym: {:?}", request_json, current_nym);
 
-    #[test]
-    fn build_nym_request_works_for_only_required_fields() {
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        let operation = &request["operation"];
+
+        let txn_type = operation["type"].as_str()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))?;
+
+        let actions = match txn_type {
+            NYM => {
+                let new_role = match operation["role"].as_str() {
+                    Some(role) if role != ROLE_REMOVE => _role_code(role)?,
+                    _ => ROLE_REMOVE.to_string()
+                };
+
+                match current_nym {
+                    None => vec![AuthActionKey::add(NYM, "role", &new_role)],
+                    Some(nym) => {
+                        let mut actions = Vec::new();
+
+                        if operation.get("role").is_some() {
+                            let old_role = match nym.role {
+                                Some(ref role) => _role_code(role)?,
+                                None => ROLE_REMOVE.to_string()
+                            };
+
+                            if old_role != new_role {
+                                actions.push(AuthActionKey::edit(NYM, "role", &old_role, &new_role));
+                            }
+                        }
+
+                        if let Some(verkey) = operation["verkey"].as_str() {
+                            let verkey = build_full_verkey(&nym.did, Some(verkey))?;
+
+                            if nym.verkey.as_ref() != Some(&verkey) {
+                                actions.push(AuthActionKey::edit(NYM, "verkey", "*", "*"));
+                            }
+                        }
+
+                        actions
+                    }
+                }
+            }
+            NODE => {
+                const NODE_FIELDS: [&str; 5] = ["node_ip", "node_port", "client_ip", "client_port", "blskey"];
+
+                let data = &operation["data"];
+                let services = data["services"].as_array().map(|services| _node_services_value(services));
+
+                match services {
+                    Some(ref services) if NODE_FIELDS.iter().all(|field| !data[*field].is_null()) =>
+                        vec![AuthActionKey::add(NODE, "services", services)],
+                    _ => {
+                        let mut actions: Vec<AuthActionKey> = NODE_FIELDS.iter()
+                            .filter(|field| !data[**field].is_null())
+                            .map(|field| AuthActionKey::edit(NODE, field, "*", "*"))
+                            .collect();
+
+                        match services {
+                            Some(ref services) if services == "[]" =>
+                                actions.push(AuthActionKey::edit(NODE, "services", "['VALIDATOR']", services)),
+                            Some(ref services) =>
+                                actions.push(AuthActionKey::edit(NODE, "services", "[]", services)),
+                            None => {}
+                        }
+
+                        actions
+                    }
+                }
+            }
+            ATTRIB | SCHEMA | CRED_DEF => vec![AuthActionKey::add(txn_type, "*", "*")],
+            txn_type => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Auth check isn't supported for transaction type: {}", txn_type)))
+        };
+
+        trace!("get_request_auth_actions <<< actions: {:?}", actions);
+
+        Ok(actions)
+    }
+
+    /// Parses role info of request signers, role names are replaced with role codes.
+    pub fn parse_submitter_role_info(&self, submitter_role_info: &str) -> IndyResult<SubmitterRoleInfo> {
+        let mut role_info: SubmitterRoleInfo = serde_json::from_str(submitter_role_info)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize submitter role info")?;
+
+        for signer in ::std::iter::once(&mut role_info.submitter).chain(role_info.additional_signers.iter_mut()) {
+            signer.role = match signer.role.take() {
+                Some(ref role) if role != ROLE_REMOVE => Some(_role_code(role)?),
+                _ => None
+            };
+        }
+
+        Ok(role_info)
+    }
+
+    /// Evaluates constraints of auth rules for request actions against role info of request signers.
+    pub fn check_request_auth(&self, actions: Vec<AuthActionKey>, rules: &[AuthRule], role_info: &SubmitterRoleInfo) -> IndyResult<String> {
+        trace!("check_request_auth >>> actions: {:?}, rules: {:?}, role_info: {:?}", actions, rules, role_info);
+
+        let signers = role_info.signers();
+
+        let actions: Vec<AuthCheck> = actions.into_iter()
+            .map(|action| {
+                let constraint = rules.iter()
+                    .filter_map(|rule| rule.matches(&action).map(|wildcards| (wildcards, rule)))
+                    .min_by_key(|&(wildcards, _)| wildcards)
+                    .map(|(_, rule)| rule.constraint.clone());
+
+                let (satisfied, missing) = match constraint.as_ref().map(|constraint| constraint.check(&signers)) {
+                    Some(ConstraintCheck::Satisfied(satisfied)) => (Some(satisfied), None),
+                    Some(ConstraintCheck::Missing(missing)) => (None, Some(missing)),
+                    None => (None, None)
+                };
+
+                AuthCheck {
+                    action,
+                    authorized: satisfied.is_some(),
+                    constraint,
+                    satisfied,
+                    missing,
+                }
+            })
+            .collect();
+
+        let result = AuthCheckResult {
+            authorized: actions.iter().all(|action| action.authorized),
+            actions,
+        };
+
+        let res = serde_json::to_string(&result)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize auth check result")?;
+
+        trace!("check_request_auth <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Wraps request into bundle handed over to endorser.
+    /// Request must contain endorser field and be signed by its author.
+    pub fn build_endorsement_bundle(&self, request_json: &str, metadata: Option<Value>) -> IndyResult<String> {
+        trace!("build_endorsement_bundle >>> request_json: {:?}, metadata: {:?}", request_json, metadata);
+
+        let request: Value = serde_json::from_str(request_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        let author = request["identifier"].as_str()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain identifier"))?
+            .to_string();
+
+        let endorser = request["endorser"].as_str()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain endorser. Use indy_append_request_endorser to set it"))?
+            .to_string();
+
+        if !request_signatures(&request).iter().any(|&(ref did, _)| did == &author) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Request must be signed by its author before handing over to endorser"));
+        }
+
+        let txn_type = _request_txn_type(&request)?;
+
+        let bundle = EndorsementBundle {
+            summary: _request_summary(&request),
+            request,
+            author,
+            endorser,
+            txn_type,
+            created: time::get_time().sec,
+            metadata,
+        };
+
+        let res = serde_json::to_string(&bundle)
+            .to_indy(IndyErrorKind::InvalidState, "Cannot serialize endorsement bundle")?;
+
+        trace!("build_endorsement_bundle <<< res: {:?}", res);
+
+        Ok(res)
+    }
+
+    /// Parses endorsement bundle and checks that author, endorser, transaction type and summary of the bundle match its request,
+    /// so endorser reviews exactly the operation it signs.
+    pub fn parse_endorsement_bundle(&self, bundle_json: &str) -> IndyResult<EndorsementBundle> {
+        let bundle: EndorsementBundle = serde_json::from_str(bundle_json)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize endorsement bundle")?;
+
+        if bundle.request["identifier"].as_str() != Some(&bundle.author) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Author of endorsement bundle doesn't match identifier of its request"));
+        }
+
+        if bundle.request["endorser"].as_str() != Some(&bundle.endorser) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Endorser of endorsement bundle doesn't match endorser of its request"));
+        }
+
+        if bundle.txn_type != _request_txn_type(&bundle.request)? {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Transaction type of endorsement bundle doesn't match operation of its request"));
+        }
+
+        if bundle.summary != _request_summary(&bundle.request) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "Summary of endorsement bundle doesn't match operation of its request"));
+        }
+
+        Ok(bundle)
+    }
+}
+
+fn _request_txn_type(request: &Value) -> IndyResult<String> {
+    request["operation"]["type"].as_str()
+        .map(String::from)
+        .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))
+}
+
+/// Human-readable summary of request operation shown to endorser.
+fn _request_summary(request: &Value) -> String {
+    let operation = &request["operation"];
+    let field = |name: &str| operation[name].as_str().unwrap_or("?").to_string();
+
+    match operation["type"].as_str() {
+        Some(NYM) => {
+            let mut summary = format!("NYM: write DID {}", field("dest"));
+
+            if let Some(role) = operation["role"].as_str() {
+                if role == ROLE_REMOVE {
+                    summary += ", remove role";
+                } else {
+                    summary += &format!(", set role {}", _role_name(role));
+                }
+            }
+            if let Some(verkey) = operation["verkey"].as_str() {
+                summary += &format!(", set verkey {}", verkey);
+            }
+            if let Some(alias) = operation["alias"].as_str() {
+                summary += &format!(", set alias {}", alias);
+            }
+
+            summary
+        }
+        Some(ATTRIB) => {
+            let kind = if operation["raw"].is_string() {
+                let names = operation["raw"].as_str()
+                    .and_then(|raw| serde_json::from_str::<Value>(raw).ok())
+                    .and_then(|raw| raw.as_object().map(|raw| raw.keys().cloned().collect::<Vec<String>>().join(", ")))
+                    .unwrap_or_default();
+                format!("raw attribute ({})", names)
+            } else if operation["hash"].is_string() {
+                "hashed attribute".to_string()
+            } else {
+                "encrypted attribute".to_string()
+            };
+
+            format!("ATTRIB: write {} of DID {}", kind, field("dest"))
+        }
+        Some(SCHEMA) => {
+            let data = &operation["data"];
+            let attr_names: Vec<&str> = data["attr_names"].as_array()
+                .map(|names| names.iter().filter_map(Value::as_str).collect())
+                .unwrap_or_default();
+
+            format!("SCHEMA: write schema {} version {} with attributes: {}",
+                    data["name"].as_str().unwrap_or("?"), data["version"].as_str().unwrap_or("?"), attr_names.join(", "))
+        }
+        Some(CRED_DEF) =>
+            format!("CRED_DEF: write {} credential definition with tag {} for schema with seqNo {}",
+                    field("signature_type"), field("tag"), operation["ref"]),
+        Some(REVOC_REG_DEF) =>
+            format!("REVOC_REG_DEF: write {} revocation registry {} for credential definition {}",
+                    field("revocDefType"), field("id"), field("credDefId")),
+        Some(REVOC_REG_ENTRY) =>
+            format!("REVOC_REG_ENTRY: update revocation registry {}", field("revocRegDefId")),
+        Some(NODE) =>
+            format!("NODE: update node {} ({})", operation["data"]["alias"].as_str().unwrap_or("?"), field("dest")),
+        Some(txn_type) if RichSchemaType::from_txn_type(txn_type).is_some() =>
+            format!("Rich schema: write {} object {} version {} with id {}",
+                    field("rsType"), field("rsName"), field("rsVersion"), field("id")),
+        Some(txn_type) => format!("Transaction of type {}", txn_type),
+        None => "Unknown transaction".to_string()
+    }
+}
+
+fn _role_code(role: &str) -> IndyResult<String> {
+    Ok(match role {
+        "STEWARD" => STEWARD,
+        "TRUSTEE" => TRUSTEE,
+        "TRUST_ANCHOR" | "ENDORSER" => ENDORSER,
+        "NETWORK_MONITOR" => NETWORK_MONITOR,
+        role if ROLES.contains(&role) => role,
+        role @ _ => return Err(err_msg(IndyErrorKind::InvalidStructure, format!("Invalid role: {}", role)))
+    }.to_string())
+}
+
+/// Node services in the form used by auth rules of NODE transaction, e.g. `['VALIDATOR']`.
+fn _node_services_value(services: &[Value]) -> String {
+    let services: Vec<String> = services.iter()
+        .filter_map(Value::as_str)
+        .map(|service| format!("'{}'", service))
+        .collect();
+
+    format!("[{}]", services.join(", "))
+}
+
+fn _rich_schema_type(rs_type: &str) -> IndyResult<RichSchemaType> {
+    serde_json::from_value(Value::String(rs_type.to_string()))
+        .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid rich schema object type: {}. Expected one of: ctx, sch, enc, map, cdf, pdf", rs_type))
+}
+
+fn _role_name(role: &str) -> String {
+    match role {
+        TRUSTEE => "TRUSTEE",
+        STEWARD => "STEWARD",
+        ENDORSER => "ENDORSER",
+        NETWORK_MONITOR => "NETWORK_MONITOR",
+        role => role
+    }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::anoncreds::schema::AttributeNames;
+    use domain::ledger::constants::*;
+    use domain::ledger::node::Services;
+    use domain::ledger::request::ProtocolVersion;
+
+    use super::*;
+
+    const IDENTIFIER: &str = "NcYxiDXkpYi6ov5FcYDi1e";
+    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
+    const VERKEY: &str = "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW";
+    const BLSKEY: &str = "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba";
+    const BLSKEY_POP: &str = "RahHYiCvoNCtPTrVtP7nMC5eTYrsUA8WjXbdhNc8debh1agE9bGiJxWBXYNFbnJXoXhWFMvyqhqhRoq737YQemH5ik9oL7R4NTTCz2LEZhkgLJzB3QRQqJyBNyv7acbdHrAT8nQ9UkLbaVL9NBpnWXBTw4LEMePaSHEw66RzPNdAX1";
+
+    #[test]
+    fn build_nym_request_works_for_only_required_fields() {
         let ledger_service = LedgerService::new();
 
         let expected_result = json!({
@@ -637,7 +1571,7 @@ mod tests {
         let ledger_service = LedgerService::new();
 
         let expected_result = json!({
-            "type": GET_DDO,
+            "type": GET_NYM,
             "dest": DEST
         });
 
@@ -813,8 +1747,8 @@ mod tests {
             client_port: Some(1),
             alias: "some".to_string(),
             services: Some(vec![Services::VALIDATOR]),
-            blskey: Some("blskey".to_string()),
-            blskey_pop: Some("pop".to_string()),
+            blskey: Some(BLSKEY.to_string()),
+            blskey_pop: Some(BLSKEY_POP.to_string()),
         };
 
         let expected_result = json!({
@@ -827,8 +1761,8 @@ mod tests {
                 "client_port": 1,
                 "alias": "some",
                 "services": ["VALIDATOR"],
-                "blskey": "blskey",
-                "blskey_pop": "pop"
+                "blskey": BLSKEY,
+                "blskey_pop": BLSKEY_POP
             }
         });
 
@@ -836,6 +1770,190 @@ mod tests {
         check_request(&request, expected_result);
     }
 
+    fn _node_data(blskey: Option<&str>, blskey_pop: Option<&str>) -> NodeOperationData {
+        NodeOperationData {
+            node_ip: None,
+            node_port: None,
+            client_ip: None,
+            client_port: None,
+            alias: "some".to_string(),
+            services: None,
+            blskey: blskey.map(String::from),
+            blskey_pop: blskey_pop.map(String::from),
+        }
+    }
+
+    #[test]
+    fn build_node_request_works_for_blskey_without_pop() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_node_request(IDENTIFIER, DEST, _node_data(Some(BLSKEY), None));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_node_request_works_for_pop_without_blskey() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_node_request(IDENTIFIER, DEST, _node_data(None, Some(BLSKEY_POP)));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_node_request_works_for_pop_not_corresponding_blskey() {
+        let ledger_service = LedgerService::new();
+
+        let other_blskey = "2zN3bHM1m4rLz54MJHYSwvqzPchYp8jkHswveCLAEJVcX6Mm1wHQD1SkPYMzUDTZvWvhuE6VNAkK3KxVeEmsanSmvjVkReDeBEMxeDaayjcZjFGPydyey1qxBHmTvAnBKoPydvuTAqx5f7YNNRAdeLmUi99gERUU7TD8KfAa6MpQ9bw";
+
+        let res = ledger_service.build_node_request(IDENTIFIER, DEST, _node_data(Some(other_blskey), Some(BLSKEY_POP)));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn build_node_request_works_for_invalid_blskey() {
+        let ledger_service = LedgerService::new();
+
+        let res = ledger_service.build_node_request(IDENTIFIER, DEST, _node_data(Some(VERKEY), Some(BLSKEY_POP)));
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    mod build_genesis_transactions {
+        use super::*;
+
+        const TRUSTEE_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+        const TRUSTEE_VERKEY: &str = "GJ1SzoWzavQYfNL9XkaJdrQejfztN4XqdsiV4ct3LXKL";
+        const STEWARD_DID: &str = "Th7MpTaRZVRYnPiabds81Y";
+        const STEWARD_VERKEY: &str = "FYmoFw55GeQH7SRFa37dkx1d2dZ3zUF8ckg7wmL7ofN4";
+        const NODE_DEST: &str = "Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv";
+
+        fn _trustees() -> Vec<(String, String)> {
+            vec![(TRUSTEE_DID.to_string(), TRUSTEE_VERKEY.to_string())]
+        }
+
+        fn _node(alias: &str, steward_did: &str) -> GenesisNode {
+            GenesisNode {
+                steward_did: steward_did.to_string(),
+                steward_verkey: STEWARD_VERKEY.to_string(),
+                dest: NODE_DEST.to_string(),
+                data: NodeOperationData {
+                    node_ip: Some("10.0.0.2".to_string()),
+                    node_port: Some(9701),
+                    client_ip: Some("10.0.0.2".to_string()),
+                    client_port: Some(9702),
+                    alias: alias.to_string(),
+                    services: Some(vec![Services::VALIDATOR]),
+                    blskey: Some("blskey".to_string()),
+                    blskey_pop: Some("pop".to_string()),
+                },
+            }
+        }
+
+        #[test]
+        fn build_genesis_transactions_works() {
+            let ledger_service = LedgerService::new();
+
+            let (pool_txns, domain_txns) = ledger_service.build_genesis_transactions(&_trustees(), vec![_node("Node1", STEWARD_DID)]).unwrap();
+
+            let expected_pool_txns = vec![json!({
+                "reqSignature": {},
+                "txn": {
+                    "data": {
+                        "data": {
+                            "alias": "Node1",
+                            "blskey": "blskey",
+                            "blskey_pop": "pop",
+                            "client_ip": "10.0.0.2",
+                            "client_port": 9702,
+                            "node_ip": "10.0.0.2",
+                            "node_port": 9701,
+                            "services": ["VALIDATOR"]
+                        },
+                        "dest": NODE_DEST
+                    },
+                    "metadata": {"from": STEWARD_DID},
+                    "type": NODE
+                },
+                "txnMetadata": {"seqNo": 1, "txnId": "fea82e10e894419fe2bea7d96296a6d46f50f93f9eeda954ec461b2ed2950b62"},
+                "ver": "1"
+            })];
+
+            let expected_domain_txns = vec![
+                json!({
+                    "reqSignature": {},
+                    "txn": {
+                        "data": {"dest": TRUSTEE_DID, "role": TRUSTEE, "verkey": "~CoRER63DVYnWZtK8uAzNbx"},
+                        "metadata": {},
+                        "type": NYM
+                    },
+                    "txnMetadata": {"seqNo": 1},
+                    "ver": "1"
+                }),
+                json!({
+                    "reqSignature": {},
+                    "txn": {
+                        "data": {"dest": STEWARD_DID, "role": STEWARD, "verkey": "~7TYfekw4GUagBnBVCqPjiC"},
+                        "metadata": {"from": TRUSTEE_DID},
+                        "type": NYM
+                    },
+                    "txnMetadata": {"seqNo": 2},
+                    "ver": "1"
+                })
+            ];
+
+            let pool_txns: Vec<Value> = pool_txns.lines().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+            let domain_txns: Vec<Value> = domain_txns.lines().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+
+            assert_eq!(expected_pool_txns, pool_txns);
+            assert_eq!(expected_domain_txns, domain_txns);
+        }
+
+        #[test]
+        fn build_genesis_transactions_works_for_empty_trustees() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_genesis_transactions(&[], vec![_node("Node1", STEWARD_DID)]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_genesis_transactions_works_for_empty_nodes() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_genesis_transactions(&_trustees(), vec![]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_genesis_transactions_works_for_duplicated_alias() {
+            let ledger_service = LedgerService::new();
+
+            let mut node2 = _node("Node1", DEST);
+            node2.dest = VERKEY.to_string();
+
+            let res = ledger_service.build_genesis_transactions(&_trustees(), vec![_node("Node1", STEWARD_DID), node2]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_genesis_transactions_works_for_shared_steward() {
+            let ledger_service = LedgerService::new();
+
+            let mut node2 = _node("Node2", STEWARD_DID);
+            node2.dest = VERKEY.to_string();
+
+            let res = ledger_service.build_genesis_transactions(&_trustees(), vec![_node("Node1", STEWARD_DID), node2]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_genesis_transactions_works_for_trustee_as_steward() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_genesis_transactions(&_trustees(), vec![_node("Node1", TRUSTEE_DID)]);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
     #[test]
     fn build_get_txn_request_works() {
         let ledger_service = LedgerService::new();
@@ -888,78 +2006,338 @@ mod tests {
     }
 
     #[test]
-    fn validate_action_works_for_pool_restart() {
+    fn build_ledgers_freeze_request_works() {
         let ledger_service = LedgerService::new();
-        let request = ledger_service.build_pool_restart(IDENTIFIER, "start", None).unwrap();
-        ledger_service.validate_action(&request).unwrap();
+
+        let expected_result = json!({
+            "type": LEDGERS_FREEZE,
+            "ledgers_ids": [10, 11]
+        });
+
+        let request = ledger_service.build_ledgers_freeze_request(IDENTIFIER, vec![10, 11]).unwrap();
+        check_request(&request, expected_result);
     }
 
     #[test]
-    fn validate_action_works_for_get_validator_info() {
+    fn build_ledgers_freeze_request_works_for_base_ledger() {
         let ledger_service = LedgerService::new();
-        let request = ledger_service.build_get_validator_info_request(IDENTIFIER).unwrap();
-        ledger_service.validate_action(&request).unwrap();
-    }
 
-    mod auth_rule {
-        use super::*;
+        let res = ledger_service.build_ledgers_freeze_request(IDENTIFIER, vec![10, 1]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
 
-        const ADD_AUTH_ACTION: &str = "ADD";
-        const EDIT_AUTH_ACTION: &str = "EDIT";
-        const FIELD: &str = "role";
-        const OLD_VALUE: &str = "0";
-        const NEW_VALUE: &str = "101";
+    #[test]
+    fn build_ledgers_freeze_request_works_for_empty_ledgers_ids() {
+        let ledger_service = LedgerService::new();
 
-        fn _role_constraint() -> Constraint {
-            Constraint::RoleConstraint(RoleConstraint {
-                sig_count: 0,
-                metadata: None,
-                role: Some(String::new()),
-                need_to_be_owner: false,
-                off_ledger_signature: false,
-            })
-        }
+        let res = ledger_service.build_ledgers_freeze_request(IDENTIFIER, vec![]);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
 
-        fn _role_constraint_json() -> String {
-            serde_json::to_string(&_role_constraint()).unwrap()
-        }
+    #[test]
+    fn build_get_frozen_ledgers_request_works() {
+        let ledger_service = LedgerService::new();
 
-        #[test]
-        fn build_auth_rule_request_works_for_role_constraint() {
-            let ledger_service = LedgerService::new();
+        let expected_result = json!({
+            "type": GET_FROZEN_LEDGERS
+        });
 
-            let expected_result = json!({
-                "type": AUTH_RULE,
-                "auth_type": NYM,
-                "field": FIELD,
-                "new_value": NEW_VALUE,
-                "auth_action": AuthAction::ADD,
-                "constraint": _role_constraint(),
-            });
+        let request = ledger_service.build_get_frozen_ledgers_request(Some(IDENTIFIER)).unwrap();
+        check_request(&request, expected_result);
+    }
 
-            let request = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, ADD_AUTH_ACTION, FIELD,
-                                                                 None, Some(NEW_VALUE),
-                                                                 &_role_constraint_json()).unwrap();
-            check_request(&request, expected_result);
-        }
+    fn _ledger_txn(seq_no: u64) -> Value {
+        json!({
+            "txn": {"type": NYM, "data": {"dest": DEST}, "metadata": {"from": IDENTIFIER}},
+            "txnMetadata": {"seqNo": seq_no, "txnTime": 1552995178},
+            "reqSignature": {},
+            "ver": "1"
+        })
+    }
 
-        #[test]
-        fn build_auth_rule_request_works_for_combination_constraints() {
-            let ledger_service = LedgerService::new();
+    fn _root_hash(txns: &[Value]) -> String {
+        use rust_base58::ToBase58;
 
-            let constraint = Constraint::AndConstraint(
-                CombinationConstraint {
-                    auth_constraints: vec![
-                        _role_constraint(),
-                        Constraint::OrConstraint(
-                            CombinationConstraint {
-                                auth_constraints: vec![
-                                    _role_constraint(), _role_constraint(), ],
-                            }
-                        )
-                    ],
-                });
-            let constraint_json = serde_json::to_string(&constraint).unwrap();
+        let leaves = txns.iter().map(|txn| rmp_serde::to_vec_named(txn).unwrap()).collect();
+        MerkleTree::from_vec(leaves).unwrap().root_hash().to_base58()
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns);
+        let audit_path = json!([_root_hash(&txns[0..1])]).to_string();
+
+        assert!(ledger_service.verify_txn_inclusion(&root_hash, &txns[1].to_string(), Some(&audit_path), None).unwrap());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_audit_path_inside_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns);
+
+        let mut txn = txns[1].clone();
+        txn["rootHash"] = json!(root_hash);
+        txn["auditPath"] = json!([_root_hash(&txns[0..1])]);
+
+        assert!(ledger_service.verify_txn_inclusion(&root_hash, &txn.to_string(), None, None).unwrap());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_forged_root_hash_inside_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns);
+
+        // proof of forged txn matches root hash of forged ledger, but not the trusted one
+        let mut forged_txn = txns[1].clone();
+        forged_txn["txn"]["data"]["dest"] = json!(IDENTIFIER);
+        let forged_txns = vec![txns[0].clone(), forged_txn.clone()];
+        forged_txn["rootHash"] = json!(_root_hash(&forged_txns));
+        forged_txn["auditPath"] = json!([_root_hash(&txns[0..1])]);
+
+        assert!(!ledger_service.verify_txn_inclusion(&root_hash, &forged_txn.to_string(), None, None).unwrap());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_changed_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns);
+        let audit_path = json!([_root_hash(&txns[0..1])]).to_string();
+
+        let mut txn = txns[1].clone();
+        txn["txn"]["data"]["dest"] = json!(IDENTIFIER);
+
+        assert!(!ledger_service.verify_txn_inclusion(&root_hash, &txn.to_string(), Some(&audit_path), None).unwrap());
+        assert!(!ledger_service.verify_txn_inclusion(&root_hash, &txns[1].to_string(), Some(&audit_path), Some(3)).unwrap());
+    }
+
+    #[test]
+    fn verify_txn_inclusion_works_for_missed_seq_no() {
+        let ledger_service = LedgerService::new();
+
+        let mut txn = _ledger_txn(1);
+        txn["txnMetadata"] = json!({});
+
+        let res = ledger_service.verify_txn_inclusion(&_root_hash(&[_ledger_txn(1)]), &txn.to_string(), Some("[]"), None);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    fn _catchup_rep(txns: &[Value]) -> String {
+        let txns: serde_json::Map<String, Value> = txns.iter()
+            .map(|txn| (txn["txnMetadata"]["seqNo"].to_string(), txn.clone()))
+            .collect();
+
+        json!({"op": "CATCHUP_REP", "ledgerId": 1, "txns": txns, "consProof": []}).to_string()
+    }
+
+    #[test]
+    fn parse_ledger_status_works() {
+        let ledger_service = LedgerService::new();
+
+        let root_hash = _root_hash(&[_ledger_txn(1)]);
+        let ledger_status = json!({"op": "LEDGER_STATUS", "ledgerId": 1, "txnSeqNo": 1, "merkleRoot": root_hash}).to_string();
+
+        let (parsed_root_hash, size) = ledger_service.parse_ledger_status(&ledger_status).unwrap();
+        assert_eq!(root_hash.from_base58().unwrap(), parsed_root_hash);
+        assert_eq!(1, size);
+    }
+
+    #[test]
+    fn parse_ledger_status_works_for_invalid_root_hash() {
+        let ledger_service = LedgerService::new();
+
+        let ledger_status = json!({"op": "LEDGER_STATUS", "ledgerId": 1, "txnSeqNo": 1, "merkleRoot": "0OIl"}).to_string();
+
+        let res = ledger_service.parse_ledger_status(&ledger_status);
+        assert_kind!(IndyErrorKind::InvalidStructure, res);
+    }
+
+    #[test]
+    fn verify_catchup_reply_works() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns).from_base58().unwrap();
+
+        let (merkle_tree, parsed_txns) = ledger_service.verify_catchup_reply(&_catchup_rep(&txns),
+                                                                             &MerkleTree::from_vec(Vec::new()).unwrap(),
+                                                                             &root_hash, 2).unwrap();
+        assert_eq!(2, merkle_tree.count());
+        assert_eq!(vec![1, 2], parsed_txns.iter().map(|txn| txn.seq_no).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn verify_catchup_reply_works_for_preceding_txns() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns).from_base58().unwrap();
+        let merkle_tree = MerkleTree::from_vec(vec![rmp_serde::to_vec_named(&txns[0]).unwrap()]).unwrap();
+
+        let (merkle_tree, parsed_txns) = ledger_service.verify_catchup_reply(&_catchup_rep(&txns[1..]), &merkle_tree, &root_hash, 2).unwrap();
+        assert_eq!(2, merkle_tree.count());
+        assert_eq!(vec![2], parsed_txns.iter().map(|txn| txn.seq_no).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn verify_catchup_reply_works_for_changed_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns).from_base58().unwrap();
+
+        let mut changed_txns = txns.clone();
+        changed_txns[1]["txn"]["data"]["dest"] = json!(IDENTIFIER);
+
+        let res = ledger_service.verify_catchup_reply(&_catchup_rep(&changed_txns), &MerkleTree::from_vec(Vec::new()).unwrap(), &root_hash, 2);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+    }
+
+    #[test]
+    fn verify_catchup_reply_works_for_txns_beyond_ledger_size() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(2)];
+        let root_hash = _root_hash(&txns[0..1]).from_base58().unwrap();
+
+        let res = ledger_service.verify_catchup_reply(&_catchup_rep(&txns), &MerkleTree::from_vec(Vec::new()).unwrap(), &root_hash, 1);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+    }
+
+    #[test]
+    fn verify_catchup_reply_works_for_missed_txn() {
+        let ledger_service = LedgerService::new();
+
+        let txns = vec![_ledger_txn(1), _ledger_txn(3)];
+        let root_hash = _root_hash(&txns).from_base58().unwrap();
+
+        let res = ledger_service.verify_catchup_reply(&_catchup_rep(&txns), &MerkleTree::from_vec(Vec::new()).unwrap(), &root_hash, 2);
+        assert_kind!(IndyErrorKind::InvalidState, res);
+    }
+
+    #[test]
+    fn parse_get_revoc_reg_state_response_works() {
+        let ledger_service = LedgerService::new();
+
+        let accum = json!({"accum": "1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000 1 0000000000000000000000000000000000000000000000000000000000000000"});
+
+        let response = json!({
+            "op": "REPLY",
+            "result": {
+                "type": GET_REVOC_REG_DELTA,
+                "seqNo": 10,
+                "revocRegDefId": "RevRegId",
+                "data": {
+                    "value": {
+                        "accum_to": {"value": accum, "txnTime": 100},
+                        "issued": [],
+                        "revoked": [2, 1]
+                    }
+                }
+            }
+        }).to_string();
+
+        let (rev_reg_def_id, state) = ledger_service.parse_get_revoc_reg_state_response(&response).unwrap();
+        assert_eq!("RevRegId", rev_reg_def_id);
+        assert_eq!(100, state.timestamp);
+        assert!(state.issued.is_empty());
+        assert_eq!(vec![1, 2], state.revoked.into_iter().collect::<Vec<u32>>());
+    }
+
+    #[test]
+    fn parse_get_revoc_reg_state_response_works_for_not_found() {
+        let ledger_service = LedgerService::new();
+
+        let response = json!({
+            "op": "REPLY",
+            "result": {"type": GET_REVOC_REG_DELTA, "seqNo": null, "revocRegDefId": "RevRegId", "data": null}
+        }).to_string();
+
+        let res = ledger_service.parse_get_revoc_reg_state_response(&response);
+        assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+    }
+
+    #[test]
+    fn validate_action_works_for_pool_restart() {
+        let ledger_service = LedgerService::new();
+        let request = ledger_service.build_pool_restart(IDENTIFIER, "start", None).unwrap();
+        ledger_service.validate_action(&request).unwrap();
+    }
+
+    #[test]
+    fn validate_action_works_for_get_validator_info() {
+        let ledger_service = LedgerService::new();
+        let request = ledger_service.build_get_validator_info_request(IDENTIFIER).unwrap();
+        ledger_service.validate_action(&request).unwrap();
+    }
+
+    mod auth_rule {
+        use super::*;
+
+        const ADD_AUTH_ACTION: &str = "ADD";
+        const EDIT_AUTH_ACTION: &str = "EDIT";
+        const FIELD: &str = "role";
+        const OLD_VALUE: &str = "0";
+        const NEW_VALUE: &str = "101";
+
+        fn _role_constraint() -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count: 0,
+                metadata: None,
+                role: Some(String::new()),
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _role_constraint_json() -> String {
+            serde_json::to_string(&_role_constraint()).unwrap()
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_role_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": AUTH_RULE,
+                "auth_type": NYM,
+                "field": FIELD,
+                "new_value": NEW_VALUE,
+                "auth_action": AuthAction::ADD,
+                "constraint": _role_constraint(),
+            });
+
+            let request = ledger_service.build_auth_rule_request(IDENTIFIER, NYM, ADD_AUTH_ACTION, FIELD,
+                                                                 None, Some(NEW_VALUE),
+                                                                 &_role_constraint_json()).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_auth_rule_request_works_for_combination_constraints() {
+            let ledger_service = LedgerService::new();
+
+            let constraint = Constraint::AndConstraint(
+                CombinationConstraint {
+                    auth_constraints: vec![
+                        _role_constraint(),
+                        Constraint::OrConstraint(
+                            CombinationConstraint {
+                                auth_constraints: vec![
+                                    _role_constraint(), _role_constraint(), ],
+                            }
+                        )
+                    ],
+                });
+            let constraint_json = serde_json::to_string(&constraint).unwrap();
 
             let expected_result = json!({
                 "type": AUTH_RULE,
@@ -1162,6 +2540,122 @@ mod tests {
             let request = ledger_service.build_get_txn_author_agreement_request(Some(IDENTIFIER), Some(&data)).unwrap();
             check_request(&request, expected_result);
         }
+
+        const MECHANISM: &str = "acceptance type 1";
+        const RATIFICATION_TIME: u64 = 1_500_000_000;
+
+        fn _digest() -> String {
+            hex::encode(LedgerService::new()._calculate_hash(TEXT, VERSION).unwrap())
+        }
+
+        fn _acceptance(taa_digest: &str, mechanism: &str, time: u64) -> TxnAuthrAgrmtAcceptanceData {
+            LedgerService::new().prepare_acceptance_data(None, None, Some(taa_digest), mechanism, time).unwrap()
+        }
+
+        fn _get_taa_response(text: &str) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN_AUTHR_AGRMT,
+                    "data": {"text": text, "version": VERSION, "digest": _digest(), "ratification_ts": RATIFICATION_TIME},
+                    "txnTime": RATIFICATION_TIME,
+                }
+            }).to_string()
+        }
+
+        fn _get_aml_response() -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN_AUTHR_AGRMT_AML,
+                    "data": {"aml": {MECHANISM: "description"}, "version": "1"},
+                }
+            }).to_string()
+        }
+
+        #[test]
+        fn check_acceptance_data_works() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME + 100);
+            ledger_service.check_acceptance_data(&acceptance, &_get_taa_response(TEXT), &_get_aml_response()).unwrap();
+        }
+
+        #[test]
+        fn check_acceptance_data_works_for_other_digest() {
+            let ledger_service = LedgerService::new();
+
+            let digest = hex::encode(ledger_service._calculate_hash(TEXT, "2.0.0").unwrap());
+            let acceptance = _acceptance(&digest, MECHANISM, RATIFICATION_TIME + 100);
+
+            let res = ledger_service.check_acceptance_data(&acceptance, &_get_taa_response(TEXT), &_get_aml_response());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn check_acceptance_data_works_for_unknown_mechanism() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), "other type", RATIFICATION_TIME + 100);
+
+            let res = ledger_service.check_acceptance_data(&acceptance, &_get_taa_response(TEXT), &_get_aml_response());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn check_acceptance_data_works_for_time_before_ratification() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME - 2 * 86400);
+
+            let res = ledger_service.check_acceptance_data(&acceptance, &_get_taa_response(TEXT), &_get_aml_response());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn check_acceptance_data_works_for_disabled_agreement() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME + 100);
+
+            let res = ledger_service.check_acceptance_data(&acceptance, &_get_taa_response(""), &_get_aml_response());
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn append_acceptance_data_if_required_works_for_nym() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME);
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap();
+
+            let request = ledger_service.append_acceptance_data_if_required(&request, &acceptance).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!(json!(acceptance), request["taaAcceptance"]);
+        }
+
+        #[test]
+        fn append_acceptance_data_if_required_works_for_read_request() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME);
+            let request = ledger_service.build_get_nym_request(Some(IDENTIFIER), DEST).unwrap();
+
+            assert_eq!(request, ledger_service.append_acceptance_data_if_required(&request, &acceptance).unwrap());
+        }
+
+        #[test]
+        fn append_acceptance_data_if_required_works_for_request_with_acceptance() {
+            let ledger_service = LedgerService::new();
+
+            let acceptance = _acceptance(&_digest(), MECHANISM, RATIFICATION_TIME);
+            let mut request: serde_json::Value = serde_json::from_str(&ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap()).unwrap();
+            request["taaAcceptance"] = json!({"mechanism": "other type", "taaDigest": _digest(), "time": RATIFICATION_TIME});
+            let request = request.to_string();
+
+            assert_eq!(request, ledger_service.append_acceptance_data_if_required(&request, &acceptance).unwrap());
+        }
     }
 
     mod acceptance_mechanism {
@@ -1254,6 +2748,654 @@ mod tests {
         }
     }
 
+    mod write_check {
+        use super::*;
+
+        #[test]
+        fn build_write_check_request_works_for_nym() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), None, None).unwrap();
+            let write_check_request = ledger_service.build_write_check_request(&request).unwrap();
+
+            let expected_result = json!({
+                "type": GET_NYM,
+                "dest": DEST
+            });
+
+            check_request(&write_check_request, expected_result);
+        }
+
+        #[test]
+        fn build_write_check_request_works_for_raw_attrib() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_attrib_request(IDENTIFIER, DEST, None, Some(r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#), None).unwrap();
+            let write_check_request = ledger_service.build_write_check_request(&request).unwrap();
+
+            let expected_result = json!({
+                "type": GET_ATTR,
+                "dest": DEST,
+                "raw": "endpoint"
+            });
+
+            check_request(&write_check_request, expected_result);
+        }
+
+        #[test]
+        fn build_write_check_request_works_for_not_supported_write() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_pool_config(IDENTIFIER, true, false).unwrap();
+            assert!(ledger_service.build_write_check_request(&request).is_none());
+        }
+
+        #[test]
+        fn parse_write_check_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = r#"{"op":"REPLY","result":{"type":"105","seqNo":12,"data":null}}"#;
+            assert_eq!(Some(12), ledger_service.parse_write_check_response(response).unwrap());
+
+            let response = r#"{"op":"REPLY","result":{"type":"105","seqNo":null,"data":null}}"#;
+            assert_eq!(None, ledger_service.parse_write_check_response(response).unwrap());
+
+            let response = r#"{"op":"REQNACK","reqId":1,"reason":"some reason"}"#;
+            assert_kind!(IndyErrorKind::InvalidTransaction, ledger_service.parse_write_check_response(response));
+        }
+
+        #[test]
+        fn parse_ordered_write_works_for_req_id() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap();
+            let req_id = serde_json::from_str::<serde_json::Value>(&request).unwrap()["reqId"].clone();
+
+            let response = _get_txn_response(json!({"from": IDENTIFIER, "reqId": req_id}));
+            let reply = ledger_service.parse_ordered_write(&request, &response).unwrap().unwrap();
+            let reply: serde_json::Value = serde_json::from_str(&reply).unwrap();
+            assert_eq!("REPLY", reply["op"]);
+            assert_eq!(5, reply["result"]["txnMetadata"]["seqNo"]);
+
+            let response = _get_txn_response(json!({"from": DEST, "reqId": req_id}));
+            assert!(ledger_service.parse_ordered_write(&request, &response).unwrap().is_none());
+        }
+
+        #[test]
+        fn parse_ordered_write_works_for_payload_digest() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap();
+            let request_value = serde_json::from_str::<serde_json::Value>(&request).unwrap();
+            let payload_digest = hex::encode(openssl_hash(serialize_signature(request_value.clone()).unwrap().as_bytes()).unwrap());
+
+            let response = _get_txn_response(json!({"from": IDENTIFIER, "reqId": request_value["reqId"], "payloadDigest": payload_digest}));
+            assert!(ledger_service.parse_ordered_write(&request, &response).unwrap().is_some());
+
+            let response = _get_txn_response(json!({"from": IDENTIFIER, "reqId": request_value["reqId"], "payloadDigest": "other"}));
+            assert!(ledger_service.parse_ordered_write(&request, &response).unwrap().is_none());
+        }
+
+        #[test]
+        fn parse_ordered_write_works_for_txn_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, None, None, None).unwrap();
+            let response = r#"{"op":"REPLY","result":{"type":"3","seqNo":5,"data":null}}"#;
+            assert!(ledger_service.parse_ordered_write(&request, response).unwrap().is_none());
+        }
+
+        fn _get_txn_response(metadata: serde_json::Value) -> String {
+            json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN,
+                    "seqNo": 5,
+                    "data": {
+                        "txn": {"type": NYM, "data": {"dest": DEST}, "metadata": metadata},
+                        "txnMetadata": {"seqNo": 5, "txnTime": 1562367600},
+                        "ver": "1"
+                    }
+                }
+            }).to_string()
+        }
+    }
+
+    mod parse_get_responses {
+        use super::*;
+
+        #[test]
+        fn parse_get_nym_response_works_for_reply_v1() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_NYM,
+                    "ver": "1",
+                    "txn": {"data": {"ver": "1", "id": "id", "did": DEST, "verkey": "~NcYxiDXkpYi6ov5FcYDi1e", "role": TRUSTEE}},
+                    "txnMetadata": {"seqNo": 10, "creationTime": 1562367600}
+                }
+            }).to_string();
+
+            let nym: serde_json::Value = serde_json::from_str(&ledger_service.parse_get_nym_response(&response).unwrap()).unwrap();
+
+            assert_eq!(DEST, nym["did"]);
+            assert_eq!(build_full_verkey(DEST, Some("~NcYxiDXkpYi6ov5FcYDi1e")).unwrap(), nym["verkey"].as_str().unwrap());
+            assert_eq!("TRUSTEE", nym["role"]);
+            assert_eq!(10, nym["seqNo"]);
+            assert_eq!(1562367600, nym["txnTime"]);
+        }
+
+        #[test]
+        fn parse_get_attrib_response_works_for_hash() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_ATTR, "dest": DEST, "hash": "abc", "data": "abc", "seqNo": 3, "txnTime": 1562367600}
+            }).to_string();
+
+            let attrib: serde_json::Value = serde_json::from_str(&ledger_service.parse_get_attrib_response(&response).unwrap()).unwrap();
+
+            assert_eq!(DEST, attrib["did"]);
+            assert_eq!("abc", attrib["hash"]);
+            assert!(attrib["raw"].is_null());
+            assert_eq!(3, attrib["seqNo"]);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_legacy_txn() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_TXN,
+                    "data": {"type": NYM, "seqNo": 7, "txnTime": 1562367600, "identifier": IDENTIFIER, "reqId": 1, "dest": DEST}
+                }
+            }).to_string();
+
+            let txn: serde_json::Value = serde_json::from_str(&ledger_service.parse_get_txn_response(&response).unwrap()).unwrap();
+
+            assert_eq!(NYM, txn["type"]);
+            assert_eq!(7, txn["seqNo"]);
+            assert_eq!(IDENTIFIER, txn["from"]);
+            assert_eq!(DEST, txn["data"]["dest"]);
+        }
+
+        #[test]
+        fn parse_get_txn_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REPLY", "result": {"type": GET_TXN, "seqNo": null, "data": null}}).to_string();
+
+            let res = ledger_service.parse_get_txn_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+    }
+
+    mod check_request_auth {
+        use super::*;
+
+        fn _role(role: &str, sig_count: u32) -> Constraint {
+            Constraint::RoleConstraint(RoleConstraint {
+                sig_count,
+                role: Some(role.to_string()),
+                metadata: None,
+                need_to_be_owner: false,
+                off_ledger_signature: false,
+            })
+        }
+
+        fn _rule(key: AuthActionKey, constraint: Constraint) -> AuthRule {
+            AuthRule {
+                auth_type: key.auth_type,
+                auth_action: key.auth_action,
+                field: key.field,
+                old_value: key.old_value,
+                new_value: key.new_value,
+                constraint,
+            }
+        }
+
+        fn _check(ledger_service: &LedgerService, actions: Vec<AuthActionKey>, rules: &[AuthRule], role_info: &str) -> serde_json::Value {
+            let role_info = ledger_service.parse_submitter_role_info(role_info).unwrap();
+            let res = ledger_service.check_request_auth(actions, rules, &role_info).unwrap();
+            serde_json::from_str(&res).unwrap()
+        }
+
+        #[test]
+        fn get_request_auth_actions_works_for_new_nym() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), None, Some("ENDORSER")).unwrap();
+
+            let actions = ledger_service.get_request_auth_actions(&request, None).unwrap();
+            assert_eq!(vec![AuthActionKey::add(NYM, "role", ENDORSER)], actions);
+        }
+
+        #[test]
+        fn get_request_auth_actions_works_for_existing_nym() {
+            let ledger_service = LedgerService::new();
+
+            let nym = ParsedNym {
+                did: DEST.to_string(),
+                verkey: Some(VERKEY.to_string()),
+                role: Some("ENDORSER".to_string()),
+                seq_no: Some(1),
+                txn_time: None,
+            };
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), None, Some("")).unwrap();
+            let actions = ledger_service.get_request_auth_actions(&request, Some(&nym)).unwrap();
+            assert_eq!(vec![AuthActionKey::edit(NYM, "role", ENDORSER, "")], actions);
+
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(IDENTIFIER), None, None).unwrap();
+            let actions = ledger_service.get_request_auth_actions(&request, Some(&nym)).unwrap();
+            assert_eq!(vec![AuthActionKey::edit(NYM, "verkey", "*", "*")], actions);
+        }
+
+        #[test]
+        fn get_request_auth_actions_works_for_node() {
+            let ledger_service = LedgerService::new();
+
+            let data = NodeOperationData {
+                node_ip: Some("ip".to_string()),
+                node_port: Some(1),
+                client_ip: Some("ip".to_string()),
+                client_port: Some(1),
+                alias: "some".to_string(),
+                services: Some(vec![Services::VALIDATOR]),
+                blskey: Some(BLSKEY.to_string()),
+                blskey_pop: Some(BLSKEY_POP.to_string()),
+            };
+            let request = ledger_service.build_node_request(IDENTIFIER, DEST, data).unwrap();
+            let actions = ledger_service.get_request_auth_actions(&request, None).unwrap();
+            assert_eq!(vec![AuthActionKey::add(NODE, "services", "['VALIDATOR']")], actions);
+
+            let data = NodeOperationData {
+                node_ip: None,
+                node_port: None,
+                client_ip: None,
+                client_port: None,
+                alias: "some".to_string(),
+                services: Some(vec![]),
+                blskey: None,
+                blskey_pop: None,
+            };
+            let request = ledger_service.build_node_request(IDENTIFIER, DEST, data).unwrap();
+            let actions = ledger_service.get_request_auth_actions(&request, None).unwrap();
+            assert_eq!(vec![AuthActionKey::edit(NODE, "services", "['VALIDATOR']", "[]")], actions);
+        }
+
+        #[test]
+        fn get_request_auth_actions_works_for_not_supported_type() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_get_nym_request(None, DEST).unwrap();
+
+            let res = ledger_service.get_request_auth_actions(&request, None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn check_request_auth_works_for_or_constraint() {
+            let ledger_service = LedgerService::new();
+
+            let action = AuthActionKey::add(SCHEMA, "*", "*");
+            let rules = vec![_rule(action.clone(), Constraint::OrConstraint(CombinationConstraint {
+                auth_constraints: vec![_role(TRUSTEE, 1), _role(ENDORSER, 1)]
+            }))];
+
+            let res = _check(&ledger_service, vec![action.clone()], &rules, r#"{"role": "ENDORSER"}"#);
+            assert_eq!(true, res["authorized"]);
+            assert_eq!(ENDORSER, res["actions"][0]["satisfied"]["role"]);
+
+            let res = _check(&ledger_service, vec![action], &rules, "{}");
+            assert_eq!(false, res["authorized"]);
+            assert_eq!("OR", res["actions"][0]["missing"]["constraint_id"]);
+        }
+
+        #[test]
+        fn check_request_auth_works_for_missing_signatures() {
+            let ledger_service = LedgerService::new();
+
+            let action = AuthActionKey::add(NYM, "role", TRUSTEE);
+            let rules = vec![_rule(action.clone(), Constraint::AndConstraint(CombinationConstraint {
+                auth_constraints: vec![_role(TRUSTEE, 3), _role(STEWARD, 1)]
+            }))];
+
+            let res = _check(&ledger_service, vec![action], &rules,
+                             r#"{"role": "TRUSTEE", "additionalSigners": [{"role": "STEWARD"}, {"role": "TRUSTEE", "offLedger": true}]}"#);
+            assert_eq!(false, res["authorized"]);
+            assert_eq!(json!(_role(TRUSTEE, 2)), res["actions"][0]["missing"]);
+        }
+
+        #[test]
+        fn check_request_auth_works_for_wildcard_rule_and_owner() {
+            let ledger_service = LedgerService::new();
+
+            let rule_constraint = Constraint::RoleConstraint(RoleConstraint {
+                sig_count: 1,
+                role: Some("*".to_string()),
+                metadata: None,
+                need_to_be_owner: true,
+                off_ledger_signature: false,
+            });
+            let rules = vec![_rule(AuthActionKey::edit(NYM, "verkey", "*", "*"), rule_constraint)];
+
+            let action = AuthActionKey::edit(NYM, "verkey", "*", "*");
+
+            let res = _check(&ledger_service, vec![action.clone()], &rules, r#"{"isOwner": true}"#);
+            assert_eq!(true, res["authorized"]);
+
+            let res = _check(&ledger_service, vec![action], &rules, r#"{"role": "TRUSTEE"}"#);
+            assert_eq!(false, res["authorized"]);
+            assert_eq!(true, res["actions"][0]["missing"]["need_to_be_owner"]);
+        }
+
+        #[test]
+        fn check_request_auth_works_for_no_rule() {
+            let ledger_service = LedgerService::new();
+
+            let res = _check(&ledger_service, vec![AuthActionKey::add(SCHEMA, "*", "*")], &[], r#"{"role": "TRUSTEE"}"#);
+            assert_eq!(false, res["authorized"]);
+            assert!(res["actions"][0]["constraint"].is_null());
+        }
+
+        #[test]
+        fn parse_submitter_role_info_works_for_invalid_role() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.parse_submitter_role_info(r#"{"role": "UNKNOWN"}"#);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+    }
+
+    mod endorsement_bundle {
+        use super::*;
+
+        const ENDORSER_DID: &str = "V4SGRU86Z58d6TV7PBUe6f";
+
+        fn _endorsed_request(ledger_service: &LedgerService) -> serde_json::Value {
+            let request = ledger_service.build_nym_request(IDENTIFIER, DEST, Some(VERKEY), None, Some("ENDORSER")).unwrap();
+            let mut request: serde_json::Value = serde_json::from_str(&request).unwrap();
+            request["endorser"] = json!(ENDORSER_DID);
+            request["signature"] = json!("signature");
+            request
+        }
+
+        #[test]
+        fn build_endorsement_bundle_works() {
+            let ledger_service = LedgerService::new();
+
+            let request = _endorsed_request(&ledger_service);
+
+            let bundle = ledger_service.build_endorsement_bundle(&request.to_string(), Some(json!({"note": "please endorse"}))).unwrap();
+            let bundle = ledger_service.parse_endorsement_bundle(&bundle).unwrap();
+
+            assert_eq!(IDENTIFIER, bundle.author);
+            assert_eq!(ENDORSER_DID, bundle.endorser);
+            assert_eq!(NYM, bundle.txn_type);
+            assert_eq!(format!("NYM: write DID {}, set role ENDORSER, set verkey {}", DEST, VERKEY), bundle.summary);
+            assert_eq!(json!({"note": "please endorse"}), bundle.metadata.unwrap());
+            assert_eq!(request, bundle.request);
+        }
+
+        #[test]
+        fn build_endorsement_bundle_works_for_multi_signed_request() {
+            let ledger_service = LedgerService::new();
+
+            let mut request = _endorsed_request(&ledger_service);
+            request.as_object_mut().unwrap().remove("signature");
+            request["signatures"] = json!({IDENTIFIER: "signature"});
+
+            ledger_service.build_endorsement_bundle(&request.to_string(), None).unwrap();
+        }
+
+        #[test]
+        fn build_endorsement_bundle_works_for_missed_endorser() {
+            let ledger_service = LedgerService::new();
+
+            let mut request = _endorsed_request(&ledger_service);
+            request.as_object_mut().unwrap().remove("endorser");
+
+            let res = ledger_service.build_endorsement_bundle(&request.to_string(), None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_endorsement_bundle_works_for_unsigned_request() {
+            let ledger_service = LedgerService::new();
+
+            let mut request = _endorsed_request(&ledger_service);
+            request.as_object_mut().unwrap().remove("signature");
+
+            let res = ledger_service.build_endorsement_bundle(&request.to_string(), None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn parse_endorsement_bundle_works_for_endorser_mismatch() {
+            let ledger_service = LedgerService::new();
+
+            let request = _endorsed_request(&ledger_service);
+
+            let bundle = ledger_service.build_endorsement_bundle(&request.to_string(), None).unwrap();
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["endorser"] = json!(DEST);
+
+            let res = ledger_service.parse_endorsement_bundle(&bundle.to_string());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn parse_endorsement_bundle_works_for_forged_summary() {
+            let ledger_service = LedgerService::new();
+
+            let request = _endorsed_request(&ledger_service);
+
+            let bundle = ledger_service.build_endorsement_bundle(&request.to_string(), None).unwrap();
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["summary"] = json!(format!("NYM: write DID {}", DEST));
+
+            let res = ledger_service.parse_endorsement_bundle(&bundle.to_string());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn parse_endorsement_bundle_works_for_forged_txn_type() {
+            let ledger_service = LedgerService::new();
+
+            let request = _endorsed_request(&ledger_service);
+
+            let bundle = ledger_service.build_endorsement_bundle(&request.to_string(), None).unwrap();
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["txnType"] = json!(ATTRIB);
+
+            let res = ledger_service.parse_endorsement_bundle(&bundle.to_string());
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn request_summary_works_for_schema() {
+            let ledger_service = LedgerService::new();
+
+            let mut attr_names: AttributeNames = AttributeNames::new();
+            attr_names.insert("name".to_string());
+
+            let schema = SchemaV1 {
+                id: Schema::schema_id(IDENTIFIER, "gvt", "1.0"),
+                name: "gvt".to_string(),
+                version: "1.0".to_string(),
+                attr_names,
+                seq_no: None,
+            };
+
+            let request = ledger_service.build_schema_request(IDENTIFIER, schema).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!("SCHEMA: write schema gvt version 1.0 with attributes: name", _request_summary(&request));
+        }
+
+        #[test]
+        fn request_summary_works_for_raw_attrib() {
+            let ledger_service = LedgerService::new();
+
+            let request = ledger_service.build_attrib_request(IDENTIFIER, DEST, None, Some(r#"{"endpoint": {"ha": "127.0.0.1:5555"}}"#), None).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!(format!("ATTRIB: write raw attribute (endpoint) of DID {}", DEST), _request_summary(&request));
+        }
+    }
+
+    mod rich_schema {
+        use super::*;
+
+        const RS_ID: &str = "did:sov:8a9QLQbmwcXRbhyAnMxq8Y";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+
+        fn _content() -> String {
+            json!({"@id": RS_ID, "@type": "rdfs:Class", "name": "Text"}).to_string()
+        }
+
+        #[test]
+        fn build_rich_schema_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": RICH_SCHEMA,
+                "id": RS_ID,
+                "content": _content(),
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION,
+                "rsType": "sch",
+                "ver": "1"
+            });
+
+            let request = ledger_service.build_rich_schema_request(IDENTIFIER, RS_ID, &_content(), RS_NAME, RS_VERSION, "sch", None).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_context() {
+            let ledger_service = LedgerService::new();
+
+            let content = json!({"@context": {"name": "http://schema.org/name"}}).to_string();
+
+            let request = ledger_service.build_rich_schema_request(IDENTIFIER, RS_ID, &content, RS_NAME, RS_VERSION, "ctx", None).unwrap();
+            let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+
+            assert_eq!(JSON_LD_CONTEXT, request["operation"]["type"]);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_context_without_context_field() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(IDENTIFIER, RS_ID, &_content(), RS_NAME, RS_VERSION, "ctx", None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_invalid_type() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(IDENTIFIER, RS_ID, &_content(), RS_NAME, RS_VERSION, "other", None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_invalid_content() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(IDENTIFIER, RS_ID, "not json", RS_NAME, RS_VERSION, "sch", None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_rich_schema_request_works_for_id_mismatch() {
+            let ledger_service = LedgerService::new();
+
+            let res = ledger_service.build_rich_schema_request(IDENTIFIER, "did:sov:other", &_content(), RS_NAME, RS_VERSION, "sch", None);
+            assert_kind!(IndyErrorKind::InvalidStructure, res);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_id_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({"type": GET_RICH_SCHEMA_OBJECT_BY_ID, "id": RS_ID});
+
+            let request = ledger_service.build_get_rich_schema_object_by_id_request(Some(IDENTIFIER), RS_ID).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn build_get_rich_schema_object_by_metadata_request_works() {
+            let ledger_service = LedgerService::new();
+
+            let expected_result = json!({
+                "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                "rsType": "sch",
+                "rsName": RS_NAME,
+                "rsVersion": RS_VERSION
+            });
+
+            let request = ledger_service.build_get_rich_schema_object_by_metadata_request(Some(IDENTIFIER), "sch", RS_NAME, RS_VERSION).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {
+                    "type": GET_RICH_SCHEMA_OBJECT_BY_METADATA,
+                    "seqNo": 12,
+                    "txnTime": 1562367600,
+                    "data": {"id": RS_ID, "content": _content(), "rsName": RS_NAME, "rsVersion": RS_VERSION, "rsType": "sch", "ver": "1", "from": IDENTIFIER}
+                }
+            }).to_string();
+
+            let (id, object) = ledger_service.parse_get_rich_schema_object_response(&response).unwrap();
+            let object: serde_json::Value = serde_json::from_str(&object).unwrap();
+
+            assert_eq!(RS_ID, id);
+            assert_eq!(_content(), object["content"]);
+            assert_eq!("sch", object["rsType"]);
+            assert_eq!(12, object["seqNo"]);
+            assert_eq!(1562367600, object["txnTime"]);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_not_found() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({
+                "op": "REPLY",
+                "result": {"type": GET_RICH_SCHEMA_OBJECT_BY_ID, "id": RS_ID, "seqNo": null, "txnTime": null, "data": null}
+            }).to_string();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&response);
+            assert_kind!(IndyErrorKind::LedgerItemNotFound, res);
+        }
+
+        #[test]
+        fn parse_get_rich_schema_object_response_works_for_other_type() {
+            let ledger_service = LedgerService::new();
+
+            let response = json!({"op": "REPLY", "result": {"type": GET_SCHEMA, "data": null}}).to_string();
+
+            let res = ledger_service.parse_get_rich_schema_object_response(&response);
+            assert_kind!(IndyErrorKind::InvalidTransaction, res);
+        }
+    }
+
     #[test]
     fn datetime_to_date(){
         assert_eq!(0, LedgerService::datetime_to_date_timestamp(0));
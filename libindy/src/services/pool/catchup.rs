@@ -4,11 +4,12 @@ use std::collections::HashSet;
 use serde_json;
 use failure::Context;
 
+use domain::ledger::request::ProtocolVersion;
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::merkle_tree_factory;
 use rust_base58::{FromBase58, ToBase58};
-use services::pool::types::{CatchupReq, Message};
+use services::pool::types::{CatchupReq, LedgerStatus, Message};
 
 pub enum CatchupProgress {
     ShouldBeStarted(
@@ -45,6 +46,37 @@ pub fn build_catchup_req(merkle: &MerkleTree, target_mt_size: usize) -> IndyResu
     Ok(Some((req_id, req_json)))
 }
 
+/// Builds LEDGER_STATUS of empty ledger, so nodes reply with the size and the root of their ledger.
+pub fn build_ledger_status_req(ledger_id: usize) -> IndyResult<String> {
+    let protocol_version = ProtocolVersion::get();
+
+    let ls = LedgerStatus {
+        txnSeqNo: 0,
+        merkleRoot: MerkleTree::from_vec(Vec::new())?.root_hash().to_base58(),
+        ledgerId: ledger_id as u8,
+        ppSeqNo: None,
+        viewNo: None,
+        protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
+        multiSignature: None,
+    };
+
+    serde_json::to_string(&Message::LedgerStatus(ls))
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus")
+}
+
+/// Builds CATCHUP_REQ for the range of transactions of any ledger.
+pub fn build_ledger_catchup_req(ledger_id: usize, seq_no_start: usize, seq_no_end: usize, catchup_till: usize) -> IndyResult<String> {
+    let cr = CatchupReq {
+        ledgerId: ledger_id,
+        seqNoStart: seq_no_start,
+        seqNoEnd: seq_no_end,
+        catchupTill: catchup_till,
+    };
+
+    serde_json::to_string(&Message::CatchupReq(cr))
+        .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CatchupRequest")
+}
+
 pub fn check_nodes_responses_on_status(nodes_votes: &HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
                                        merkle_tree: &MerkleTree,
                                        node_cnt: usize,
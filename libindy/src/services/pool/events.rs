@@ -6,7 +6,7 @@ use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::{PoolService, types:: *};
 
-pub const REQUESTS_FOR_STATE_PROOFS: [&str; 10] = [
+pub const REQUESTS_FOR_STATE_PROOFS: [&str; 11] = [
     constants::GET_NYM,
     constants::GET_TXN_AUTHR_AGRMT,
     constants::GET_TXN_AUTHR_AGRMT_AML,
@@ -17,6 +17,7 @@ pub const REQUESTS_FOR_STATE_PROOFS: [&str; 10] = [
     constants::GET_REVOC_REG_DEF,
     constants::GET_REVOC_REG_DELTA,
     constants::GET_AUTH_RULE,
+    constants::GET_FROZEN_LEDGERS,
 ];
 
 const REQUEST_FOR_FULL: [&str; 2] = [
@@ -83,6 +84,7 @@ pub enum PoolEvent {
         Vec<u8>, //target_mt_root
         usize, //target_mt_size
         MerkleTree,
+        Option<SJsonValue>, //multi-signature of target_mt_root
     ),
     CatchupRestart(
         MerkleTree,
@@ -91,7 +93,8 @@ pub enum PoolEvent {
     #[allow(dead_code)] //FIXME
     PoolOutdated,
     Synced(
-        MerkleTree
+        MerkleTree,
+        Option<SJsonValue>, //multi-signature of merkle root
     ),
     #[allow(dead_code)] //FIXME
     NodesBlacklisted,
@@ -118,6 +121,7 @@ pub enum RequestEvent {
         MerkleTree,
         usize, // target mt size
         Vec<u8>, // target mt root
+        Option<SJsonValue>, // multi-signature of target mt root
     ),
     Timeout(
         String, //req_id
@@ -143,6 +147,16 @@ pub enum RequestEvent {
         Option<i32>, // timeout
         Option<String>, // nodes
     ),
+    CustomLedgerStatusRequest(
+        LedgerStatus,
+        String, // message
+        String, // req_id
+    ),
+    CustomCatchupRequest(
+        CatchupReq,
+        String, // message
+        String, // req_id
+    ),
     ConsistencyProof(
         ConsistencyProof,
         String, //node alias
@@ -183,6 +197,13 @@ impl RequestEvent {
             RequestEvent::CustomSingleRequest(_, ref id, _, _) => id.to_string(),
             RequestEvent::CustomConsensusRequest(_, ref id) => id.to_string(),
             RequestEvent::CustomFullRequest(_, ref id, _, _) => id.to_string(),
+            RequestEvent::CustomLedgerStatusRequest(_, _, ref id) => id.to_string(),
+            RequestEvent::CustomCatchupRequest(_, _, ref id) => id.to_string(),
+            RequestEvent::LedgerStatus(ref ls, Some(_), None) => ledger_status_req_id(ls.ledgerId as usize),
+            RequestEvent::ConsistencyProof(ref cp, _) => ledger_status_req_id(cp.ledgerId),
+            RequestEvent::CatchupRep(ref rep, _) => rep.min_tx()
+                .map(|seq_no_start| catchup_req_id(rep.ledgerId, seq_no_start))
+                .unwrap_or_default(),
             RequestEvent::Reply(_, _, _, ref id) => id.to_string(),
             RequestEvent::ReqACK(_, _, _, ref id) => id.to_string(),
             RequestEvent::ReqNACK(_, _, _, ref id) => id.to_string(),
@@ -200,7 +221,7 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                     match parsed {
                         //TODO change mapping for CatchupReq. May be return None
                         //TODO: REMOVE UNWRAP!!!!!
-                        Message::CatchupReq(_) => RequestEvent::CatchupReq(MerkleTree::from_vec(Vec::new()).unwrap(), 0, vec![]),
+                        Message::CatchupReq(_) => RequestEvent::CatchupReq(MerkleTree::from_vec(Vec::new()).unwrap(), 0, vec![], None),
                         Message::CatchupRep(rep) => RequestEvent::CatchupRep(rep, node_alias),
                         Message::LedgerStatus(ls) => RequestEvent::LedgerStatus(ls, Some(node_alias), None),
                         Message::ConsistencyProof(cp) => RequestEvent::ConsistencyProof(cp, node_alias),
@@ -226,6 +247,18 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                     })
             }
             PoolEvent::SendRequest(_, msg, timeout, nodes) => {
+                match Message::from_raw_str(&msg) {
+                    Ok(Message::LedgerStatus(ls)) => {
+                        let req_id = ledger_status_req_id(ls.ledgerId as usize);
+                        return Some(RequestEvent::CustomLedgerStatusRequest(ls, msg, req_id));
+                    }
+                    Ok(Message::CatchupReq(cr)) => {
+                        let req_id = catchup_req_id(cr.ledgerId, cr.seqNoStart);
+                        return Some(RequestEvent::CustomCatchupRequest(cr, msg, req_id));
+                    }
+                    _ => ()
+                }
+
                 let parsed_req = _parse_req_id_and_op(&msg);
                 if let Ok((ref req, ref req_id, ref op)) = parsed_req {
                     if REQUEST_FOR_FULL.contains(&op.as_str()) {
@@ -236,7 +269,7 @@ impl Into<Option<RequestEvent>> for PoolEvent {
                         None
                     } else if REQUESTS_FOR_STATE_PROOFS.contains(&op.as_str()) {
                         let key = super::state_proof::parse_key_from_request_for_builtin_sp(&req);
-                        let timestamps = _parse_timestamp_from_req_for_builtin_sp(req, &op);
+                        let timestamps = parse_timestamp_from_req_for_builtin_sp(req, &op);
                         Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), key, timestamps))
                     } else if PoolService::get_sp_parser(&op.as_str()).is_some() {
                         Some(RequestEvent::CustomSingleRequest(msg, req_id.clone(), None, (None, None)))
@@ -254,7 +287,17 @@ impl Into<Option<RequestEvent>> for PoolEvent {
     }
 }
 
-fn _parse_timestamp_from_req_for_builtin_sp(req: &SJsonValue, op: &str) -> (Option<u64>, Option<u64>) {
+/// Node replies to LEDGER_STATUS and CATCHUP_REQ don't contain request id,
+/// so sent requests and replies are matched by ledger and requested transactions.
+fn ledger_status_req_id(ledger_id: usize) -> String {
+    format!("LEDGER_STATUS:{}", ledger_id)
+}
+
+fn catchup_req_id(ledger_id: usize, seq_no_start: usize) -> String {
+    format!("CATCHUP_REQ:{}:{}", ledger_id, seq_no_start)
+}
+
+pub fn parse_timestamp_from_req_for_builtin_sp(req: &SJsonValue, op: &str) -> (Option<u64>, Option<u64>) {
     if !REQUESTS_FOR_STATE_PROOFS_IN_THE_PAST.contains(&op) {
         return (None, None);
     }
@@ -0,0 +1,1400 @@
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{BufRead, BufReader};
+use std::sync::{Arc, Mutex};
+
+use hex;
+use rmp_serde;
+use serde_json;
+use time;
+use time::{Duration, Tm};
+use serde_json::Value as SJsonValue;
+
+use domain::ledger::constants;
+use domain::pool::{LocalFaults, ReadStrategy};
+use errors::prelude::*;
+use services::pool::events::*;
+use services::pool::networker::Networker;
+use services::pool::types::*;
+use utils::crypto::hash::{Hash, hash as openssl_hash};
+use utils::environment;
+use utils::sequence;
+
+use rust_base58::ToBase58;
+
+use super::zmq;
+use super::zmq::PollItem;
+use super::zmq::Socket as ZSocket;
+
+const POOL_LEDGER_ID: usize = 0;
+const DOMAIN_LEDGER_ID: usize = 1;
+const CONFIG_LEDGER_ID: usize = 2;
+const AUDIT_LEDGER_ID: u64 = 3;
+
+lazy_static! {
+    static ref LOCAL_LEDGERS: Mutex<HashMap<String, Arc<Mutex<LocalLedger>>>> = Mutex::new(HashMap::new());
+}
+
+/// Returns in-memory ledger emulated for pool with given name.
+///
+/// Ledger is created on first call: pool ledger is seeded from pool genesis transactions
+/// and domain ledger from optional `domain_genesis_txn` file. Later calls share the same ledger,
+/// so transactions written through one opened pool handle survive pool reopening.
+pub fn open(pool_name: &str, domain_genesis_txn: Option<&str>) -> IndyResult<Arc<Mutex<LocalLedger>>> {
+    let mut ledgers = LOCAL_LEDGERS.lock().unwrap();
+
+    if let Some(ledger) = ledgers.get(pool_name) {
+        return Ok(ledger.clone());
+    }
+
+    let mut pool_genesis = environment::pool_path(pool_name);
+    pool_genesis.push(pool_name);
+    pool_genesis.set_extension("txn");
+
+    if !pool_genesis.exists() {
+        return Err(err_msg(IndyErrorKind::PoolNotCreated, format!("Pool is not created for name: {:?}", pool_name)));
+    }
+
+    let mut ledger = LocalLedger::new();
+
+    for txn in _read_genesis_txns(&pool_genesis.to_string_lossy())? {
+        ledger.ledgers[POOL_LEDGER_ID].append(txn)?;
+    }
+
+    if let Some(domain_genesis_txn) = domain_genesis_txn {
+        for txn in _read_genesis_txns(domain_genesis_txn)? {
+            ledger.append_genesis_domain_txn(txn)?;
+        }
+    }
+
+    let ledger = Arc::new(Mutex::new(ledger));
+    ledgers.insert(pool_name.to_string(), ledger.clone());
+    Ok(ledger)
+}
+
+/// Drops in-memory ledger emulated for pool with given name (if any).
+pub fn forget(pool_name: &str) {
+    LOCAL_LEDGERS.lock().unwrap().remove(pool_name);
+}
+
+fn _read_genesis_txns(file_name: &str) -> IndyResult<Vec<SJsonValue>> {
+    let f = fs::File::open(file_name)
+        .to_indy(IndyErrorKind::IOError, format!("Can't open genesis txn file {:?}", file_name))?;
+
+    let mut txns = Vec::new();
+
+    for line in BufReader::new(&f).lines() {
+        let line = line.to_indy(IndyErrorKind::IOError, "Can't read from genesis txn file")?;
+
+        if line.trim().is_empty() { continue; }
+
+        txns.push(serde_json::from_str(line.trim())
+            .to_indy(IndyErrorKind::InvalidStructure, "Genesis txn is mailformed json")?);
+    }
+
+    Ok(txns)
+}
+
+/// Networker that doesn't touch the network: every node of the pool is emulated
+/// on top of shared `LocalLedger`. Node replies are delivered through inproc zmq socket,
+/// so `PoolThread` polls them exactly like replies of real nodes.
+///
+/// Replies lost by `LocalFaults` are never delivered, such requests time out as with real nodes.
+pub struct LocalLedgerNetworker {
+    ledger: Arc<Mutex<LocalLedger>>,
+    nodes: Vec<RemoteNode>,
+    preordered_nodes: Vec<String>,
+    resend: HashMap<String, (usize, String)>,
+    faults: LocalFaults,
+    lost_replies: HashSet<String>,
+    timeouts: HashMap<(String, String), Tm>,
+    _ctx: zmq::Context,
+    send_sock: ZSocket,
+    recv_sock: ZSocket,
+}
+
+impl LocalLedgerNetworker {
+    pub fn with_ledger(ledger: Arc<Mutex<LocalLedger>>, preordered_nodes: Vec<String>, faults: LocalFaults) -> Self {
+        let ctx = zmq::Context::new();
+        let recv_sock = ctx.socket(zmq::SocketType::PAIR).expect("FIXME");
+        let send_sock = ctx.socket(zmq::SocketType::PAIR).expect("FIXME");
+        let inproc_sock_name = format!("inproc://local_ledger_{}", sequence::get_next_id());
+
+        recv_sock.bind(&inproc_sock_name).expect("FIXME");
+        send_sock.connect(&inproc_sock_name).expect("FIXME");
+
+        LocalLedgerNetworker {
+            ledger,
+            nodes: Vec::new(),
+            preordered_nodes,
+            resend: HashMap::new(),
+            faults,
+            lost_replies: HashSet::new(),
+            timeouts: HashMap::new(),
+            _ctx: ctx,
+            send_sock,
+            recv_sock,
+        }
+    }
+
+    fn _send_msg_to_one_node(&mut self, idx: usize, req_id: &str, msg: &str, timeout: i64) {
+        let node_alias = self.nodes[idx].name.clone();
+
+        let replies = self.ledger.lock().unwrap().process_msg(msg, &node_alias, self.nodes.len());
+
+        if self._is_reply_lost(req_id, &replies) {
+            trace!("LocalLedgerNetworker: node {} loses replies on {}", node_alias, req_id);
+            self.timeouts.insert((req_id.to_string(), node_alias), time::now() + Duration::seconds(timeout));
+            return;
+        }
+
+        for reply in replies {
+            trace!("LocalLedgerNetworker: node {} replies {}", node_alias, reply);
+            if let Err(err) = self.send_sock.send_multipart(&[node_alias.as_bytes(), reply.as_bytes()], zmq::DONTWAIT) {
+                error!("LocalLedgerNetworker: can't deliver reply of node {}: {:?}", node_alias, err);
+            }
+        }
+    }
+
+    /// Nodes acknowledge writes only, so the first reply tells whether the message is a write.
+    fn _is_reply_lost(&mut self, req_id: &str, replies: &[String]) -> bool {
+        if self.lost_replies.contains(req_id) {
+            return true;
+        }
+
+        let is_write = replies.first()
+            .and_then(|reply| serde_json::from_str::<SJsonValue>(reply).ok())
+            .map(|reply| reply["op"] == json!("REQACK"))
+            .unwrap_or(false);
+
+        if is_write && self.faults.lost_write_replies > 0 {
+            self.faults.lost_write_replies -= 1;
+            self.lost_replies.insert(req_id.to_string());
+            return true;
+        }
+
+        false
+    }
+}
+
+impl Networker for LocalLedgerNetworker {
+    fn new(_active_timeout: i64, _conn_limit: usize, preordered_nodes: Vec<String>, _read_strategy: ReadStrategy) -> Self {
+        LocalLedgerNetworker::with_ledger(Arc::new(Mutex::new(LocalLedger::new())), preordered_nodes, LocalFaults::default())
+    }
+
+    fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent> {
+        let mut events = Vec::new();
+
+        if poll_items.get(0).map(|pi| pi.is_readable()).unwrap_or(false) {
+            while let Ok(parts) = self.recv_sock.recv_multipart(zmq::DONTWAIT) {
+                if let (Some(node_alias), Some(reply)) = (parts.get(0), parts.get(1)) {
+                    events.push(PoolEvent::NodeReply(String::from_utf8_lossy(reply).to_string(),
+                                                     String::from_utf8_lossy(node_alias).to_string()));
+                }
+            }
+        }
+
+        events
+    }
+
+    fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        match pe {
+            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)) => {
+                if !self.nodes.is_empty() {
+                    self._send_msg_to_one_node(0, &req_id, &msg, timeout);
+                    self.resend.insert(req_id, (0, msg));
+                }
+            }
+            Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
+                for idx in 0..self.nodes.len() {
+                    if nodes_to_send.as_ref().map(|nodes| nodes.contains(&self.nodes[idx].name)).unwrap_or(true) {
+                        self._send_msg_to_one_node(idx, &req_id, &msg, timeout);
+                    }
+                }
+            }
+            Some(NetworkerEvent::Resend(req_id, timeout)) => {
+                let resend = if let Some(&mut (ref mut cnt, ref msg)) = self.resend.get_mut(&req_id) {
+                    *cnt += 1;
+                    Some((*cnt % self.nodes.len(), msg.clone()))
+                } else {
+                    error!("Unknown req_id for resending {}", req_id);
+                    None
+                };
+                if let Some((idx, msg)) = resend {
+                    self._send_msg_to_one_node(idx, &req_id, &msg, timeout);
+                }
+            }
+            Some(NetworkerEvent::NodesStateUpdated(mut nodes)) => {
+                trace!("LocalLedgerNetworker::process_event: nodes_updated {:?}", nodes);
+                let preordered_nodes = &self.preordered_nodes;
+                nodes.sort_by_key(|node: &RemoteNode| -> usize {
+                    preordered_nodes.iter()
+                        .position(|name| node.name.eq(name))
+                        .unwrap_or(usize::max_value())
+                });
+                self.nodes = nodes;
+            }
+            Some(NetworkerEvent::ExtendTimeout(req_id, node_alias, timeout)) => {
+                if let Some(due) = self.timeouts.get_mut(&(req_id, node_alias)) {
+                    *due = time::now() + Duration::seconds(timeout);
+                }
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))) => {
+                self.timeouts.remove(&(req_id, node_alias));
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, None)) => {
+                self.timeouts.retain(|&(ref timeout_req_id, _), _| *timeout_req_id != req_id);
+                self.lost_replies.remove(&req_id);
+                self.resend.remove(&req_id);
+            }
+            _ => ()
+        }
+        None
+    }
+
+    fn get_timeout(&self) -> ((String, String), i64) {
+        // emulated nodes reply immediately, so only lost replies are waited for
+        let now = time::now();
+
+        self.timeouts.iter()
+            .map(|(key, due)| (key.clone(), (*due - now).num_milliseconds()))
+            .min_by_key(|&(_, timeout)| timeout)
+            .unwrap_or((("".to_string(), "".to_string()), ::std::i64::MAX))
+    }
+
+    fn get_poll_items(&self) -> Vec<PollItem> {
+        vec![self.recv_sock.as_poll_item(zmq::POLLIN)]
+    }
+
+    fn take_latency(&self, _req_id: &str, _node_alias: &str) -> Option<::std::time::Duration> {
+        // emulated nodes are in-process, there is no network latency to measure
+        None
+    }
+}
+
+/// Append-only sequence of transactions with RFC 6962 merkle tree on top of it.
+/// Leaf data and hashing match `MerkleTree`, so roots and proofs are accepted by the client side.
+struct LedgerData {
+    txns: Vec<SJsonValue>,
+    leaves: Vec<Vec<u8>>,
+}
+
+impl LedgerData {
+    fn new() -> LedgerData {
+        LedgerData { txns: Vec::new(), leaves: Vec::new() }
+    }
+
+    fn size(&self) -> usize {
+        self.txns.len()
+    }
+
+    fn append(&mut self, txn: SJsonValue) -> IndyResult<usize> {
+        let bytes = rmp_serde::to_vec_named(&txn)
+            .to_indy(IndyErrorKind::InvalidState, "Can't encode txn as message pack")?;
+
+        self.leaves.push(Hash::hash_leaf(&bytes)?.to_vec());
+        self.txns.push(txn);
+        Ok(self.txns.len())
+    }
+
+    fn get(&self, seq_no: usize) -> Option<&SJsonValue> {
+        if seq_no == 0 { None } else { self.txns.get(seq_no - 1) }
+    }
+
+    fn root_hash(&self, size: usize) -> IndyResult<Vec<u8>> {
+        _subtree_hash(&self.leaves[..size])
+    }
+
+    fn consistency_proof(&self, old_size: usize, new_size: usize) -> IndyResult<Vec<Vec<u8>>> {
+        let mut proof = Vec::new();
+        if old_size > 0 && old_size < new_size {
+            _consistency_subproof(old_size, &self.leaves[..new_size], true, &mut proof)?;
+        }
+        Ok(proof)
+    }
+
+    /// Audit path of txn in the tree of `seq_no` size, the same way nodes build it for replies.
+    fn audit_path(&self, seq_no: usize) -> IndyResult<Vec<Vec<u8>>> {
+        let mut path = Vec::new();
+        _audit_path(seq_no - 1, &self.leaves[..seq_no], &mut path)?;
+        Ok(path)
+    }
+}
+
+fn _split_point(size: usize) -> usize {
+    let mut k = 1;
+    while k << 1 < size {
+        k <<= 1;
+    }
+    k
+}
+
+fn _subtree_hash(leaves: &[Vec<u8>]) -> IndyResult<Vec<u8>> {
+    match leaves.len() {
+        0 => Ok(Hash::hash_empty()?.to_vec()),
+        1 => Ok(leaves[0].clone()),
+        size => {
+            let k = _split_point(size);
+            Ok(Hash::hash_nodes(&_subtree_hash(&leaves[..k])?, &_subtree_hash(&leaves[k..])?)?.to_vec())
+        }
+    }
+}
+
+fn _consistency_subproof(old_size: usize, leaves: &[Vec<u8>], complete: bool, proof: &mut Vec<Vec<u8>>) -> IndyResult<()> {
+    if old_size == leaves.len() {
+        if !complete {
+            proof.push(_subtree_hash(leaves)?);
+        }
+        return Ok(());
+    }
+
+    let k = _split_point(leaves.len());
+
+    if old_size <= k {
+        _consistency_subproof(old_size, &leaves[..k], complete, proof)?;
+        proof.push(_subtree_hash(&leaves[k..])?);
+    } else {
+        _consistency_subproof(old_size - k, &leaves[k..], false, proof)?;
+        proof.push(_subtree_hash(&leaves[..k])?);
+    }
+    Ok(())
+}
+
+fn _audit_path(idx: usize, leaves: &[Vec<u8>], path: &mut Vec<Vec<u8>>) -> IndyResult<()> {
+    if leaves.len() <= 1 {
+        return Ok(());
+    }
+
+    let k = _split_point(leaves.len());
+
+    if idx < k {
+        _audit_path(idx, &leaves[..k], path)?;
+        path.push(_subtree_hash(&leaves[k..])?);
+    } else {
+        _audit_path(idx - k, &leaves[k..], path)?;
+        path.push(_subtree_hash(&leaves[..k])?);
+    }
+    Ok(())
+}
+
+#[derive(Clone, Debug)]
+struct StateEntry {
+    seq_no: usize,
+    txn_time: u64,
+    value: SJsonValue,
+}
+
+/// In-memory pool, domain and config ledgers plus state built from them.
+///
+/// Answers catchup messages and client requests the way validator nodes do,
+/// but doesn't verify signatures or permissions of the submitted transactions.
+pub struct LocalLedger {
+    ledgers: Vec<LedgerData>,
+    state: HashMap<String, Vec<StateEntry>>,
+    write_replies: HashMap<String, Vec<String>>,
+}
+
+impl LocalLedger {
+    pub fn new() -> LocalLedger {
+        LocalLedger {
+            // indexed by ledger id: pool, domain, config
+            ledgers: vec![LedgerData::new(), LedgerData::new(), LedgerData::new()],
+            state: HashMap::new(),
+            write_replies: HashMap::new(),
+        }
+    }
+
+    fn append_genesis_domain_txn(&mut self, txn: SJsonValue) -> IndyResult<()> {
+        let seq_no = self.ledgers[DOMAIN_LEDGER_ID].append(txn.clone())?;
+
+        // genesis domain ledger usually contains only NYMs of trustees and stewards
+        if txn["txn"]["type"].as_str() == Some(constants::NYM) {
+            let identifier = txn["txn"]["metadata"]["from"].as_str().unwrap_or("").to_string();
+            let txn_time = txn["txnMetadata"]["txnTime"].as_u64().unwrap_or(0);
+            self._apply_nym(&identifier, &txn["txn"]["data"], seq_no, txn_time);
+        }
+
+        Ok(())
+    }
+
+    /// Processes message sent to node `node_alias` and returns node replies.
+    pub fn process_msg(&mut self, msg: &str, node_alias: &str, nodes_cnt: usize) -> Vec<String> {
+        let msg: SJsonValue = match serde_json::from_str(msg) {
+            Ok(msg) => msg,
+            Err(_) => {
+                warn!("LocalLedger: node {} ignores malformed message {:?}", node_alias, msg);
+                return Vec::new();
+            }
+        };
+
+        let res = match msg["op"].as_str() {
+            Some("LEDGER_STATUS") => self._process_ledger_status(&msg).map(|reply| vec![reply]),
+            Some("CATCHUP_REQ") => self._process_catchup_req(&msg).map(|reply| reply.into_iter().collect()),
+            Some(op) => {
+                warn!("LocalLedger: node {} ignores message with op {:?}", node_alias, op);
+                Ok(Vec::new())
+            }
+            None => self._process_request(&msg, node_alias, nodes_cnt)
+        };
+
+        res.unwrap_or_else(|err| {
+            error!("LocalLedger: node {} can't process message: {:?}", node_alias, err);
+            Vec::new()
+        })
+    }
+
+    fn _process_ledger_status(&self, msg: &SJsonValue) -> IndyResult<String> {
+        let ls: LedgerStatus = serde_json::from_value(msg.clone())
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid LEDGER_STATUS message")?;
+
+        let ledger = self.ledgers.get(ls.ledgerId as usize)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger id {}", ls.ledgerId)))?;
+
+        let size = ledger.size();
+
+        let reply = if ls.txnSeqNo > 0 && ls.txnSeqNo < size {
+            Message::ConsistencyProof(ConsistencyProof {
+                seqNoStart: ls.txnSeqNo,
+                seqNoEnd: size,
+                ledgerId: ls.ledgerId as usize,
+                hashes: ledger.consistency_proof(ls.txnSeqNo, size)?.iter().map(|hash| hash.to_base58()).collect(),
+                oldMerkleRoot: ledger.root_hash(ls.txnSeqNo)?.to_base58(),
+                newMerkleRoot: ledger.root_hash(size)?.to_base58(),
+                multiSignature: None,
+            })
+        } else {
+            Message::LedgerStatus(LedgerStatus {
+                txnSeqNo: size,
+                merkleRoot: ledger.root_hash(size)?.to_base58(),
+                ledgerId: ls.ledgerId,
+                ppSeqNo: None,
+                viewNo: None,
+                protocolVersion: ls.protocolVersion,
+                multiSignature: None,
+            })
+        };
+
+        serde_json::to_string(&reply)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize LEDGER_STATUS reply")
+    }
+
+    fn _process_catchup_req(&self, msg: &SJsonValue) -> IndyResult<Option<String>> {
+        let cr: CatchupReq = serde_json::from_value(msg.clone())
+            .to_indy(IndyErrorKind::InvalidStructure, "Invalid CATCHUP_REQ message")?;
+
+        let ledger = self.ledgers.get(cr.ledgerId)
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, format!("Unknown ledger id {}", cr.ledgerId)))?;
+
+        if cr.seqNoStart == 0 || cr.seqNoStart > cr.seqNoEnd || cr.catchupTill > ledger.size() || cr.seqNoEnd > cr.catchupTill {
+            warn!("LocalLedger: can't serve catchup request {:?}, ledger size {}", cr, ledger.size());
+            return Ok(None);
+        }
+
+        let txns = (cr.seqNoStart..cr.seqNoEnd + 1)
+            .map(|seq_no| (seq_no.to_string(), ledger.txns[seq_no - 1].clone()))
+            .collect();
+
+        let rep = CatchupRep {
+            ledgerId: cr.ledgerId,
+            consProof: ledger.consistency_proof(cr.seqNoEnd, cr.catchupTill)?.iter().map(|hash| hash.to_base58()).collect(),
+            txns,
+        };
+
+        serde_json::to_string(&Message::CatchupRep(rep))
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize CATCHUP_REP reply")
+            .map(Some)
+    }
+
+    fn _process_request(&mut self, req: &SJsonValue, node_alias: &str, nodes_cnt: usize) -> IndyResult<Vec<String>> {
+        let req_id = match req["reqId"].as_u64() {
+            Some(req_id) => req_id,
+            None => {
+                warn!("LocalLedger: node {} ignores request without reqId", node_alias);
+                return Ok(Vec::new());
+            }
+        };
+
+        let identifier = req["identifier"].as_str().unwrap_or("").to_string();
+
+        let type_ = match req["operation"]["type"].as_str() {
+            Some(type_) => type_.to_string(),
+            None => return Ok(vec![_nack(&identifier, req_id, "Missing operation type")?])
+        };
+
+        let write_key = format!("{}:{}", identifier, req_id);
+
+        if let Some(replies) = self.write_replies.get(&write_key) {
+            return Ok(replies.clone());
+        }
+
+        let op = &req["operation"];
+
+        let result = match type_.as_str() {
+            constants::GET_NYM => self._get_nym(op),
+            constants::GET_ATTR => self._get_attrib(op),
+            constants::GET_SCHEMA => self._get_schema(op),
+            constants::GET_CRED_DEF => self._get_cred_def(op),
+            constants::GET_REVOC_REG_DEF => self._get_single_state(format!("revoc_reg_def:{}", op["id"].as_str().unwrap_or("")), None),
+            constants::GET_REVOC_REG => self._get_revoc_reg(op),
+            constants::GET_REVOC_REG_DELTA => self._get_revoc_reg_delta(op),
+            constants::GET_TXN => self._get_txn(op),
+            constants::GET_TXN_AUTHR_AGRMT => self._get_txn_author_agreement(op),
+            constants::GET_TXN_AUTHR_AGRMT_AML => self._get_acceptance_mechanisms(op),
+            constants::GET_AUTH_RULE => self._get_auth_rule(op),
+            constants::GET_RICH_SCHEMA_OBJECT_BY_ID => self._get_single_state(format!("rich_schema:id:{}", op["id"].as_str().unwrap_or("")), None),
+            constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA => self._get_single_state(_rich_schema_metadata_key(op), None),
+            constants::GET_FROZEN_LEDGERS => self._get_single_state("frozen_ledgers".to_string(), None),
+            constants::GET_VALIDATOR_INFO => Ok(self._get_validator_info(node_alias, nodes_cnt)),
+            constants::POOL_RESTART => Ok(json!({})),
+            _ => {
+                let replies = match self._write(req, &identifier, req_id, &type_)? {
+                    Some(Ok(txn)) => vec![_ack(&identifier, req_id)?, _reply(txn)?],
+                    Some(Err(reason)) => vec![_ack(&identifier, req_id)?, _reject(&identifier, req_id, &reason)?],
+                    None => vec![_nack(&identifier, req_id, &format!("Unknown transaction type {}", type_))?]
+                };
+                self.write_replies.insert(write_key, replies.clone());
+                return Ok(replies);
+            }
+        }?;
+
+        let mut reply = op.clone();
+        _merge(&mut reply, result);
+        _merge(&mut reply, json!({"type": type_, "identifier": identifier, "reqId": req_id}));
+
+        Ok(vec![_reply(reply)?])
+    }
+
+    fn _write(&mut self, req: &SJsonValue, identifier: &str, req_id: u64, type_: &str) -> IndyResult<Option<Result<SJsonValue, String>>> {
+        let ledger_id = match type_ {
+            constants::NODE => POOL_LEDGER_ID,
+            constants::NYM | constants::ATTRIB | constants::SCHEMA | constants::CRED_DEF |
+            constants::REVOC_REG_DEF | constants::REVOC_REG_ENTRY | constants::JSON_LD_CONTEXT | constants::RICH_SCHEMA |
+            constants::RICH_SCHEMA_ENCODING | constants::RICH_SCHEMA_MAPPING | constants::RICH_SCHEMA_CRED_DEF |
+            constants::RICH_SCHEMA_PRES_DEF => DOMAIN_LEDGER_ID,
+            constants::TXN_AUTHR_AGRMT | constants::TXN_AUTHR_AGRMT_AML | constants::AUTH_RULE |
+            constants::AUTH_RULES | constants::POOL_CONFIG | constants::POOL_UPGRADE | constants::LEDGERS_FREEZE => CONFIG_LEDGER_ID,
+            _ => return Ok(None)
+        };
+
+        let op = &req["operation"];
+
+        if let Err(reason) = self._validate_write(type_, identifier, op) {
+            return Ok(Some(Err(reason)));
+        }
+
+        let mut data = op.clone();
+        if let Some(data) = data.as_object_mut() {
+            data.remove("type");
+        }
+
+        let mut metadata = json!({"from": identifier, "reqId": req_id});
+        for field in ["endorser", "taaAcceptance"].iter() {
+            if !req[field].is_null() {
+                metadata[field] = req[field].clone();
+            }
+        }
+
+        let signatures: Vec<SJsonValue> = match (req["signature"].as_str(), req["signatures"].as_object()) {
+            (Some(signature), _) => vec![json!({"from": identifier, "value": signature})],
+            (None, Some(signatures)) => signatures.iter().map(|(from, value)| json!({"from": from, "value": value})).collect(),
+            (None, None) => Vec::new()
+        };
+
+        let txn_time = time::get_time().sec as u64;
+        let seq_no = self.ledgers[ledger_id].size() + 1;
+
+        let txn = json!({
+            "txn": {
+                "type": type_,
+                "data": data,
+                "metadata": metadata,
+                "protocolVersion": req["protocolVersion"].clone(),
+            },
+            "txnMetadata": {
+                "seqNo": seq_no,
+                "txnTime": txn_time,
+            },
+            "reqSignature": if signatures.is_empty() { json!({}) } else { json!({"type": "ED25519", "values": signatures}) },
+            "ver": "1",
+        });
+
+        self.ledgers[ledger_id].append(txn.clone())?;
+        self._apply_write(type_, identifier, op, seq_no, txn_time);
+
+        let ledger = &self.ledgers[ledger_id];
+
+        let mut result = txn;
+        result["rootHash"] = json!(ledger.root_hash(ledger.size())?.to_base58());
+        result["auditPath"] = json!(ledger.audit_path(seq_no)?.iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+
+        Ok(Some(Ok(result)))
+    }
+
+    fn _validate_write(&self, type_: &str, identifier: &str, op: &SJsonValue) -> Result<(), String> {
+        if identifier.is_empty() {
+            return Err("Missing identifier".to_string());
+        }
+
+        match type_ {
+            constants::NYM => {
+                op["dest"].as_str().ok_or("Missing dest")?;
+            }
+            constants::ATTRIB => {
+                let dest = op["dest"].as_str().ok_or("Missing dest")?;
+
+                if self._get_state(&format!("nym:{}", dest), None).is_none() {
+                    return Err(format!("dest {} not found", dest));
+                }
+
+                let cnt = ["raw", "hash", "enc"].iter().filter(|field| !op[**field].is_null()).count();
+                if cnt != 1 {
+                    return Err("Exactly one of raw, hash or enc must be set".to_string());
+                }
+
+                if let Some(raw) = op["raw"].as_str() {
+                    _raw_attrib_name(raw).ok_or("raw must be json object with single attribute")?;
+                }
+            }
+            constants::SCHEMA => {
+                let name = op["data"]["name"].as_str().ok_or("Missing schema name")?;
+                let version = op["data"]["version"].as_str().ok_or("Missing schema version")?;
+
+                if self._get_state(&format!("schema:{}:{}:{}", identifier, name, version), None).is_some() {
+                    return Err(format!("{} can have one and only one SCHEMA with name {} and version {}", identifier, name, version));
+                }
+            }
+            constants::CRED_DEF => {
+                let schema_seq_no = op["ref"].as_u64().ok_or("Missing ref")?;
+
+                let is_schema = self.ledgers[DOMAIN_LEDGER_ID].get(schema_seq_no as usize)
+                    .map(|txn| txn["txn"]["type"].as_str() == Some(constants::SCHEMA))
+                    .unwrap_or(false);
+
+                if !is_schema {
+                    return Err(format!("Mentioned seqNo ({}) isn't seqNo of any SCHEMA txn", schema_seq_no));
+                }
+            }
+            constants::REVOC_REG_DEF => {
+                op["id"].as_str().ok_or("Missing id")?;
+                op["credDefId"].as_str().ok_or("Missing credDefId")?;
+            }
+            constants::REVOC_REG_ENTRY => {
+                let revoc_reg_def_id = op["revocRegDefId"].as_str().ok_or("Missing revocRegDefId")?;
+
+                if self._get_state(&format!("revoc_reg_def:{}", revoc_reg_def_id), None).is_none() {
+                    return Err(format!("There is no any REVOC_REG_DEF by path: {}", revoc_reg_def_id));
+                }
+            }
+            constants::JSON_LD_CONTEXT | constants::RICH_SCHEMA | constants::RICH_SCHEMA_ENCODING |
+            constants::RICH_SCHEMA_MAPPING | constants::RICH_SCHEMA_CRED_DEF | constants::RICH_SCHEMA_PRES_DEF => {
+                let id = op["id"].as_str().ok_or("Missing id")?;
+                op["content"].as_str().ok_or("Missing content")?;
+                op["rsType"].as_str().ok_or("Missing rsType")?;
+                let name = op["rsName"].as_str().ok_or("Missing rsName")?;
+                let version = op["rsVersion"].as_str().ok_or("Missing rsVersion")?;
+
+                if self._get_state(&format!("rich_schema:id:{}", id), None).is_some() {
+                    return Err(format!("Rich Schema object with id {} already exists", id));
+                }
+
+                if self._get_state(&_rich_schema_metadata_key(op), None).is_some() {
+                    return Err(format!("Rich Schema object with name {} and version {} already exists", name, version));
+                }
+            }
+            constants::TXN_AUTHR_AGRMT => {
+                op["text"].as_str().ok_or("Missing text")?;
+                let version = op["version"].as_str().ok_or("Missing version")?;
+
+                if self._get_state(&format!("taa:v:{}", version), None).is_some() {
+                    return Err(format!("Changing existing version of transaction author agreement is forbidden: {}", version));
+                }
+            }
+            constants::TXN_AUTHR_AGRMT_AML => {
+                let version = op["version"].as_str().ok_or("Missing version")?;
+
+                if self._get_state(&format!("aml:v:{}", version), None).is_some() {
+                    return Err(format!("Version of TAA AML must be unique and it cannot be modified: {}", version));
+                }
+            }
+            constants::LEDGERS_FREEZE => {
+                let ledgers_ids = op["ledgers_ids"].as_array().ok_or("Missing ledgers_ids")?;
+
+                if ledgers_ids.iter().any(|ledger_id| ledger_id.as_u64().map(|ledger_id| ledger_id <= AUDIT_LEDGER_ID).unwrap_or(true)) {
+                    return Err("ledgers_ids must contain ids of plugin ledgers only".to_string());
+                }
+            }
+            _ => ()
+        }
+
+        Ok(())
+    }
+
+    fn _apply_write(&mut self, type_: &str, identifier: &str, op: &SJsonValue, seq_no: usize, txn_time: u64) {
+        match type_ {
+            constants::NYM => self._apply_nym(identifier, op, seq_no, txn_time),
+            constants::ATTRIB => {
+                let dest = op["dest"].as_str().unwrap_or("");
+
+                let (name, value) = if let Some(raw) = op["raw"].as_str() {
+                    (_raw_attrib_name(raw).unwrap_or_default(), raw)
+                } else if let Some(hash) = op["hash"].as_str() {
+                    (hash.to_string(), hash)
+                } else {
+                    let enc = op["enc"].as_str().unwrap_or("");
+                    (enc.to_string(), enc)
+                };
+
+                self._set_state(format!("attr:{}:{}", dest, name), seq_no, txn_time, json!(value));
+            }
+            constants::SCHEMA => {
+                let key = format!("schema:{}:{}:{}",
+                                  identifier,
+                                  op["data"]["name"].as_str().unwrap_or(""),
+                                  op["data"]["version"].as_str().unwrap_or(""));
+                self._set_state(key, seq_no, txn_time, op["data"].clone());
+            }
+            constants::CRED_DEF => {
+                let key = _cred_def_key(identifier, op);
+                self._set_state(key, seq_no, txn_time, op["data"].clone());
+            }
+            constants::REVOC_REG_DEF => {
+                let mut value = op.clone();
+                value.as_object_mut().map(|value| value.remove("type"));
+                value["ver"] = json!("1.0");
+                self._set_state(format!("revoc_reg_def:{}", op["id"].as_str().unwrap_or("")), seq_no, txn_time, value);
+            }
+            constants::REVOC_REG_ENTRY => {
+                let key = format!("revoc_reg:{}", op["revocRegDefId"].as_str().unwrap_or(""));
+
+                let value = json!({
+                    "revocDefType": op["revocDefType"],
+                    "revocRegDefId": op["revocRegDefId"],
+                    "accum": op["value"]["accum"],
+                    "issued": op["value"]["issued"],
+                    "revoked": op["value"]["revoked"],
+                });
+
+                self._set_state(key, seq_no, txn_time, value);
+            }
+            constants::TXN_AUTHR_AGRMT => {
+                let text = op["text"].as_str().unwrap_or("");
+                let version = op["version"].as_str().unwrap_or("");
+                let digest = openssl_hash(format!("{}{}", version, text).as_bytes())
+                    .map(|digest| hex::encode(digest))
+                    .unwrap_or_default();
+
+                let value = json!({
+                    "text": text,
+                    "version": version,
+                    "digest": digest,
+                    "ratification_ts": txn_time,
+                });
+
+                self._set_state(format!("taa:v:{}", version), seq_no, txn_time, value.clone());
+                self._set_state(format!("taa:d:{}", digest), seq_no, txn_time, value.clone());
+                self._set_state("taa:latest".to_string(), seq_no, txn_time, value);
+            }
+            constants::TXN_AUTHR_AGRMT_AML => {
+                let mut value = op.clone();
+                value.as_object_mut().map(|value| value.remove("type"));
+
+                self._set_state(format!("aml:v:{}", op["version"].as_str().unwrap_or("")), seq_no, txn_time, value.clone());
+                self._set_state("aml:latest".to_string(), seq_no, txn_time, value);
+            }
+            constants::JSON_LD_CONTEXT | constants::RICH_SCHEMA | constants::RICH_SCHEMA_ENCODING |
+            constants::RICH_SCHEMA_MAPPING | constants::RICH_SCHEMA_CRED_DEF | constants::RICH_SCHEMA_PRES_DEF => {
+                let mut value = op.clone();
+                value.as_object_mut().map(|value| value.remove("type"));
+                value["from"] = json!(identifier);
+
+                self._set_state(format!("rich_schema:id:{}", op["id"].as_str().unwrap_or("")), seq_no, txn_time, value.clone());
+                self._set_state(_rich_schema_metadata_key(op), seq_no, txn_time, value);
+            }
+            constants::LEDGERS_FREEZE => {
+                let mut value = self._get_state("frozen_ledgers", None)
+                    .map(|entry| entry.value.clone())
+                    .unwrap_or(json!({}));
+
+                // Plugin ledgers aren't kept by local pool, so there are no roots to store for them
+                for ledger_id in op["ledgers_ids"].as_array().map(Vec::as_slice).unwrap_or(&[]) {
+                    value[ledger_id.to_string()] = json!({"ledger": null, "state": null, "seq_no": 0});
+                }
+
+                self._set_state("frozen_ledgers".to_string(), seq_no, txn_time, value);
+            }
+            constants::AUTH_RULE => self._apply_auth_rule(op, seq_no, txn_time),
+            constants::AUTH_RULES => {
+                if let Some(rules) = op["rules"].as_array() {
+                    for rule in rules {
+                        self._apply_auth_rule(rule, seq_no, txn_time);
+                    }
+                }
+            }
+            _ => ()
+        }
+    }
+
+    fn _apply_nym(&mut self, identifier: &str, op: &SJsonValue, seq_no: usize, txn_time: u64) {
+        let dest = op["dest"].as_str().unwrap_or("").to_string();
+        let key = format!("nym:{}", dest);
+
+        let mut value = self._get_state(&key, None)
+            .map(|entry| entry.value.clone())
+            .unwrap_or(json!({"identifier": identifier, "dest": dest, "role": null, "verkey": null}));
+
+        for field in ["role", "verkey", "alias"].iter() {
+            if let Some(field_value) = op.get(*field) {
+                value[*field] = field_value.clone();
+            }
+        }
+
+        self._set_state(key, seq_no, txn_time, value);
+    }
+
+    fn _apply_auth_rule(&mut self, rule: &SJsonValue, seq_no: usize, txn_time: u64) {
+        let key = _auth_rule_key(rule);
+
+        let value = json!({
+            "auth_type": rule["auth_type"],
+            "auth_action": rule["auth_action"],
+            "field": rule["field"],
+            "old_value": rule["old_value"],
+            "new_value": rule["new_value"],
+            "constraint": rule["constraint"],
+        });
+
+        self._set_state(key, seq_no, txn_time, value);
+    }
+
+    fn _set_state(&mut self, key: String, seq_no: usize, txn_time: u64, value: SJsonValue) {
+        self.state.entry(key).or_insert_with(Vec::new).push(StateEntry { seq_no, txn_time, value });
+    }
+
+    /// Returns latest state value written not later than `timestamp` (if set).
+    fn _get_state(&self, key: &str, timestamp: Option<u64>) -> Option<&StateEntry> {
+        self.state.get(key)
+            .and_then(|entries| entries.iter()
+                .rev()
+                .find(|entry| timestamp.map(|timestamp| entry.txn_time <= timestamp).unwrap_or(true)))
+    }
+
+    fn _get_single_state(&self, key: String, timestamp: Option<u64>) -> IndyResult<SJsonValue> {
+        Ok(match self._get_state(&key, timestamp) {
+            Some(entry) => json!({"seqNo": entry.seq_no, "txnTime": entry.txn_time, "data": entry.value}),
+            None => json!({"seqNo": null, "txnTime": null, "data": null}),
+        })
+    }
+
+    fn _get_nym(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = format!("nym:{}", op["dest"].as_str().unwrap_or(""));
+
+        Ok(match self._get_state(&key, None) {
+            Some(entry) => {
+                let mut data = entry.value.clone();
+                data["seqNo"] = json!(entry.seq_no);
+                data["txnTime"] = json!(entry.txn_time);
+
+                json!({
+                    "seqNo": entry.seq_no,
+                    "txnTime": entry.txn_time,
+                    "data": serde_json::to_string(&data)
+                        .to_indy(IndyErrorKind::InvalidState, "Can't serialize NYM data")?,
+                })
+            }
+            None => json!({"seqNo": null, "txnTime": null, "data": null}),
+        })
+    }
+
+    fn _get_attrib(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let name = op["raw"].as_str().or(op["hash"].as_str()).or(op["enc"].as_str()).unwrap_or("");
+        let key = format!("attr:{}:{}", op["dest"].as_str().unwrap_or(""), name);
+        self._get_single_state(key, None)
+    }
+
+    fn _get_schema(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = format!("schema:{}:{}:{}",
+                          op["dest"].as_str().unwrap_or(""),
+                          op["data"]["name"].as_str().unwrap_or(""),
+                          op["data"]["version"].as_str().unwrap_or(""));
+
+        Ok(match self._get_state(&key, None) {
+            Some(entry) => json!({"seqNo": entry.seq_no, "txnTime": entry.txn_time, "data": entry.value}),
+            // like nodes, echo requested name and version without attributes
+            None => json!({"seqNo": null, "txnTime": null, "data": op["data"]}),
+        })
+    }
+
+    fn _get_cred_def(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = _cred_def_key(op["origin"].as_str().unwrap_or(""), op);
+        self._get_single_state(key, None)
+    }
+
+    fn _get_revoc_reg(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = format!("revoc_reg:{}", op["revocRegDefId"].as_str().unwrap_or(""));
+
+        Ok(match self._get_state(&key, op["timestamp"].as_u64()) {
+            Some(entry) => json!({
+                "seqNo": entry.seq_no,
+                "txnTime": entry.txn_time,
+                "data": {
+                    "revocDefType": entry.value["revocDefType"],
+                    "revocRegDefId": entry.value["revocRegDefId"],
+                    "value": {"accum": entry.value["accum"]},
+                },
+            }),
+            None => json!({"seqNo": null, "txnTime": null, "data": null}),
+        })
+    }
+
+    fn _get_revoc_reg_delta(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = format!("revoc_reg:{}", op["revocRegDefId"].as_str().unwrap_or(""));
+        let from = op["from"].as_u64();
+        let to = op["to"].as_u64();
+
+        let (entry_to, entries) = match (self._get_state(&key, to), self.state.get(&key)) {
+            (Some(entry_to), Some(entries)) => (entry_to, entries),
+            _ => return Ok(json!({"seqNo": null, "txnTime": null, "data": null}))
+        };
+
+        let entry_from = from.and_then(|from| self._get_state(&key, Some(from)));
+
+        let mut issued: HashSet<u64> = HashSet::new();
+        let mut revoked: HashSet<u64> = HashSet::new();
+
+        for entry in entries.iter()
+            .filter(|entry| entry.seq_no <= entry_to.seq_no)
+            .filter(|entry| entry_from.map(|entry_from| entry.seq_no > entry_from.seq_no).unwrap_or(true)) {
+            for idx in entry.value["issued"].as_array().into_iter().flat_map(|v| v.iter()).filter_map(SJsonValue::as_u64) {
+                revoked.remove(&idx);
+                issued.insert(idx);
+            }
+            for idx in entry.value["revoked"].as_array().into_iter().flat_map(|v| v.iter()).filter_map(SJsonValue::as_u64) {
+                issued.remove(&idx);
+                revoked.insert(idx);
+            }
+        }
+
+        let accum_from = entry_from.map(|entry| json!({
+            "value": {"accum": entry.value["accum"]},
+            "txnTime": entry.txn_time,
+            "seqNo": entry.seq_no,
+        }));
+
+        Ok(json!({
+            "seqNo": entry_to.seq_no,
+            "txnTime": entry_to.txn_time,
+            "data": {
+                "revocDefType": entry_to.value["revocDefType"],
+                "revocRegDefId": entry_to.value["revocRegDefId"],
+                "value": {
+                    "accum_to": {
+                        "value": {"accum": entry_to.value["accum"]},
+                        "txnTime": entry_to.txn_time,
+                        "seqNo": entry_to.seq_no,
+                    },
+                    "accum_from": accum_from,
+                    "issued": issued,
+                    "revoked": revoked,
+                },
+            },
+        }))
+    }
+
+    fn _get_txn(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let ledger_id = op["ledgerId"].as_u64().unwrap_or(DOMAIN_LEDGER_ID as u64) as usize;
+        let seq_no = op["data"].as_u64().unwrap_or(0) as usize;
+
+        let ledger = match self.ledgers.get(ledger_id) {
+            Some(ledger) => ledger,
+            None => return Ok(json!({"seqNo": null, "data": null}))
+        };
+
+        Ok(match ledger.get(seq_no) {
+            Some(txn) => {
+                let mut data = txn.clone();
+                data["rootHash"] = json!(ledger.root_hash(seq_no)?.to_base58());
+                data["auditPath"] = json!(ledger.audit_path(seq_no)?.iter().map(|hash| hash.to_base58()).collect::<Vec<String>>());
+                json!({"seqNo": seq_no, "data": data})
+            }
+            None => json!({"seqNo": null, "data": null}),
+        })
+    }
+
+    fn _get_txn_author_agreement(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = match (op["version"].as_str(), op["digest"].as_str()) {
+            (Some(version), _) => format!("taa:v:{}", version),
+            (None, Some(digest)) => format!("taa:d:{}", digest),
+            (None, None) => "taa:latest".to_string()
+        };
+        self._get_single_state(key, op["timestamp"].as_u64())
+    }
+
+    fn _get_acceptance_mechanisms(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let key = match op["version"].as_str() {
+            Some(version) => format!("aml:v:{}", version),
+            None => "aml:latest".to_string()
+        };
+        self._get_single_state(key, op["timestamp"].as_u64())
+    }
+
+    fn _get_auth_rule(&self, op: &SJsonValue) -> IndyResult<SJsonValue> {
+        let rules: Vec<SJsonValue> = if op["auth_type"].is_null() {
+            let mut keys: Vec<&String> = self.state.keys().filter(|key| key.starts_with("auth_rule:")).collect();
+            keys.sort();
+            keys.into_iter().filter_map(|key| self._get_state(key, None)).map(|entry| entry.value.clone()).collect()
+        } else {
+            self._get_state(&_auth_rule_key(op), None).map(|entry| entry.value.clone()).into_iter().collect()
+        };
+
+        Ok(json!({"data": rules}))
+    }
+
+    fn _get_validator_info(&self, node_alias: &str, nodes_cnt: usize) -> SJsonValue {
+        json!({
+            "data": {
+                "alias": node_alias,
+                "Node_info": {
+                    "Name": node_alias,
+                    "Mode": "participating",
+                    "Catchup_status": {
+                        "Ledger_statuses": {"0": "synced", "1": "synced", "2": "synced"},
+                    },
+                },
+                "Pool_info": {
+                    "Total_nodes_count": nodes_cnt,
+                },
+                "timestamp": time::get_time().sec,
+            }
+        })
+    }
+}
+
+fn _raw_attrib_name(raw: &str) -> Option<String> {
+    serde_json::from_str::<SJsonValue>(raw).ok()
+        .and_then(|raw| raw.as_object()
+            .filter(|raw| raw.len() == 1)
+            .and_then(|raw| raw.keys().next().cloned()))
+}
+
+fn _cred_def_key(origin: &str, op: &SJsonValue) -> String {
+    format!("cred_def:{}:{}:{}:{}",
+            origin,
+            op["ref"].as_u64().unwrap_or(0),
+            op["signature_type"].as_str().unwrap_or("CL"),
+            op["tag"].as_str().unwrap_or("tag"))
+}
+
+fn _rich_schema_metadata_key(op: &SJsonValue) -> String {
+    format!("rich_schema:meta:{}:{}:{}",
+            op["rsType"].as_str().unwrap_or(""),
+            op["rsName"].as_str().unwrap_or(""),
+            op["rsVersion"].as_str().unwrap_or(""))
+}
+
+fn _auth_rule_key(rule: &SJsonValue) -> String {
+    let auth_action = rule["auth_action"].as_str().unwrap_or("");
+    let default_old_value = if auth_action == "ADD" { "*" } else { "" };
+
+    format!("auth_rule:{}--{}--{}--{}--{}",
+            rule["auth_type"].as_str().unwrap_or(""),
+            auth_action,
+            rule["field"].as_str().unwrap_or(""),
+            rule["old_value"].as_str().unwrap_or(default_old_value),
+            rule["new_value"].as_str().unwrap_or(""))
+}
+
+fn _merge(target: &mut SJsonValue, source: SJsonValue) {
+    if let (Some(target), SJsonValue::Object(source)) = (target.as_object_mut(), source) {
+        target.extend(source);
+    }
+}
+
+fn _ack(identifier: &str, req_id: u64) -> IndyResult<String> {
+    serde_json::to_string(&json!({"op": "REQACK", "identifier": identifier, "reqId": req_id}))
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize REQACK")
+}
+
+fn _nack(identifier: &str, req_id: u64, reason: &str) -> IndyResult<String> {
+    serde_json::to_string(&json!({"op": "REQNACK", "identifier": identifier, "reqId": req_id, "reason": reason}))
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize REQNACK")
+}
+
+fn _reject(identifier: &str, req_id: u64, reason: &str) -> IndyResult<String> {
+    serde_json::to_string(&json!({"op": "REJECT", "identifier": identifier, "reqId": req_id, "reason": reason}))
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize REJECT")
+}
+
+fn _reply(result: SJsonValue) -> IndyResult<String> {
+    serde_json::to_string(&json!({"op": "REPLY", "result": result}))
+        .to_indy(IndyErrorKind::InvalidState, "Can't serialize REPLY")
+}
+
+#[cfg(test)]
+mod tests {
+    use services::ledger::merkletree::merkletree::MerkleTree;
+
+    use super::*;
+
+    const IDENTIFIER: &str = "V4SGRU86Z58d6TV7PBUe6f";
+    const DEST: &str = "VsKV7grR1BUE29mG2Fm2kX";
+    const NODE: &str = "Node1";
+
+    fn _leaves(cnt: usize) -> Vec<Vec<u8>> {
+        (0..cnt).map(|i| format!("leaf_{}", i).into_bytes()).collect()
+    }
+
+    fn _ledger_data(cnt: usize) -> LedgerData {
+        let mut ledger = LedgerData::new();
+        for i in 0..cnt {
+            ledger.append(json!({"seq": i})).unwrap();
+        }
+        ledger
+    }
+
+    fn _merkle_tree(ledger: &LedgerData, size: usize) -> MerkleTree {
+        MerkleTree::from_vec(ledger.txns[..size].iter().map(|txn| rmp_serde::to_vec_named(txn).unwrap()).collect()).unwrap()
+    }
+
+    fn _request(req_id: u64, operation: SJsonValue) -> String {
+        json!({"identifier": IDENTIFIER, "reqId": req_id, "operation": operation, "protocolVersion": 2}).to_string()
+    }
+
+    fn _process(ledger: &mut LocalLedger, msg: &str) -> Vec<SJsonValue> {
+        ledger.process_msg(msg, NODE, 4).iter().map(|reply| serde_json::from_str(reply).unwrap()).collect()
+    }
+
+    #[test]
+    fn ledger_data_root_hash_matches_merkle_tree() {
+        for cnt in 0..12 {
+            let ledger = _ledger_data(cnt);
+            let merkle_tree = _merkle_tree(&ledger, cnt);
+            assert_eq!(merkle_tree.root_hash(), &ledger.root_hash(cnt).unwrap(), "size {}", cnt);
+        }
+    }
+
+    #[test]
+    fn ledger_data_consistency_proof_is_accepted_by_merkle_tree() {
+        let ledger = _ledger_data(11);
+
+        for old_size in 1..11 {
+            for new_size in old_size..12 {
+                let proof = ledger.consistency_proof(old_size, new_size).unwrap();
+                let merkle_tree = _merkle_tree(&ledger, old_size);
+                assert!(merkle_tree.consistency_proof(&ledger.root_hash(new_size).unwrap(), new_size, &proof).unwrap(),
+                        "old size {}, new size {}", old_size, new_size);
+            }
+        }
+    }
+
+    #[test]
+    fn ledger_data_audit_path_leads_to_root() {
+        let leaves = _leaves(7);
+        let ledger = LedgerData { txns: vec![json!({}); 7], leaves: leaves.clone() };
+
+        let path = ledger.audit_path(6).unwrap();
+        assert_eq!(3, path.len());
+
+        // leaf 5 pairs with leaf 4, then with leaf 6, then with left subtree of size 4
+        let hash = Hash::hash_nodes(&leaves[4], &leaves[5]).unwrap().to_vec();
+        assert_eq!(path[0], leaves[4]);
+        let hash = Hash::hash_nodes(&hash, &path[1]).unwrap().to_vec();
+        let hash = Hash::hash_nodes(&path[2], &hash).unwrap().to_vec();
+        assert_eq!(ledger.root_hash(7).unwrap(), hash);
+    }
+
+    #[test]
+    fn local_ledger_answers_ledger_status_for_same_ledger() {
+        let mut ledger = LocalLedger::new();
+        ledger.ledgers[POOL_LEDGER_ID] = _ledger_data(4);
+
+        let root = ledger.ledgers[POOL_LEDGER_ID].root_hash(4).unwrap().to_base58();
+        let msg = json!({"op": "LEDGER_STATUS", "txnSeqNo": 4, "merkleRoot": root, "ledgerId": 0, "ppSeqNo": null, "viewNo": null}).to_string();
+
+        let replies = _process(&mut ledger, &msg);
+        assert_eq!(1, replies.len());
+        assert_eq!("LEDGER_STATUS", replies[0]["op"]);
+        assert_eq!(4, replies[0]["txnSeqNo"]);
+        assert_eq!(root, replies[0]["merkleRoot"]);
+    }
+
+    #[test]
+    fn local_ledger_answers_consistency_proof_for_outdated_ledger() {
+        let mut ledger = LocalLedger::new();
+        ledger.ledgers[POOL_LEDGER_ID] = _ledger_data(6);
+
+        let old_root = ledger.ledgers[POOL_LEDGER_ID].root_hash(3).unwrap().to_base58();
+        let msg = json!({"op": "LEDGER_STATUS", "txnSeqNo": 3, "merkleRoot": old_root, "ledgerId": 0, "ppSeqNo": null, "viewNo": null}).to_string();
+
+        let replies = _process(&mut ledger, &msg);
+        assert_eq!("CONSISTENCY_PROOF", replies[0]["op"]);
+        assert_eq!(3, replies[0]["seqNoStart"]);
+        assert_eq!(6, replies[0]["seqNoEnd"]);
+        assert_eq!(old_root, replies[0]["oldMerkleRoot"]);
+    }
+
+    #[test]
+    fn local_ledger_answers_catchup_req() {
+        let mut ledger = LocalLedger::new();
+        ledger.ledgers[POOL_LEDGER_ID] = _ledger_data(5);
+
+        let msg = json!({"op": "CATCHUP_REQ", "ledgerId": 0, "seqNoStart": 3, "seqNoEnd": 5, "catchupTill": 5}).to_string();
+
+        let replies = _process(&mut ledger, &msg);
+        let rep: CatchupRep = serde_json::from_value(replies[0].clone()).unwrap();
+        assert_eq!(3, rep.txns.len());
+        assert_eq!(json!({"seq": 2}), rep.txns["3"]);
+        assert!(rep.consProof.is_empty());
+    }
+
+    #[test]
+    fn local_ledger_writes_and_reads_nym() {
+        let mut ledger = LocalLedger::new();
+
+        let write = _request(1, json!({"type": constants::NYM, "dest": DEST, "verkey": "~CnEDk9HrMnmiHXEV1WFgbV", "role": "101"}));
+
+        let replies = _process(&mut ledger, &write);
+        assert_eq!(2, replies.len());
+        assert_eq!("REQACK", replies[0]["op"]);
+        assert_eq!("REPLY", replies[1]["op"]);
+        assert_eq!(1, replies[1]["result"]["txnMetadata"]["seqNo"]);
+        assert_eq!(1, replies[1]["result"]["txn"]["metadata"]["reqId"]);
+        assert_eq!(ledger.ledgers[DOMAIN_LEDGER_ID].root_hash(1).unwrap().to_base58(), replies[1]["result"]["rootHash"]);
+
+        let read = _request(2, json!({"type": constants::GET_NYM, "dest": DEST}));
+
+        let replies = _process(&mut ledger, &read);
+        assert_eq!(1, replies.len());
+        assert_eq!(1, replies[0]["result"]["seqNo"]);
+        assert_eq!(2, replies[0]["result"]["reqId"]);
+
+        let data: SJsonValue = serde_json::from_str(replies[0]["result"]["data"].as_str().unwrap()).unwrap();
+        assert_eq!(DEST, data["dest"]);
+        assert_eq!(IDENTIFIER, data["identifier"]);
+        assert_eq!("101", data["role"]);
+        assert_eq!("~CnEDk9HrMnmiHXEV1WFgbV", data["verkey"]);
+    }
+
+    #[test]
+    fn local_ledger_applies_write_once_for_all_nodes() {
+        let mut ledger = LocalLedger::new();
+
+        let write = _request(1, json!({"type": constants::NYM, "dest": DEST}));
+
+        let first = ledger.process_msg(&write, "Node1", 4);
+        let second = ledger.process_msg(&write, "Node2", 4);
+
+        assert_eq!(first, second);
+        assert_eq!(1, ledger.ledgers[DOMAIN_LEDGER_ID].size());
+    }
+
+    #[test]
+    fn local_ledger_rejects_attrib_for_unknown_dest() {
+        let mut ledger = LocalLedger::new();
+
+        let write = _request(1, json!({"type": constants::ATTRIB, "dest": DEST, "raw": r#"{"endpoint":{"ha":"127.0.0.1:5555"}}"#}));
+
+        let replies = _process(&mut ledger, &write);
+        assert_eq!("REJECT", replies[1]["op"]);
+        assert_eq!(0, ledger.ledgers[DOMAIN_LEDGER_ID].size());
+    }
+
+    #[test]
+    fn local_ledger_writes_and_reads_schema() {
+        let mut ledger = LocalLedger::new();
+
+        let write = _request(1, json!({"type": constants::SCHEMA, "data": {"name": "gvt", "version": "1.0", "attr_names": ["name"]}}));
+        assert_eq!("REPLY", _process(&mut ledger, &write)[1]["op"]);
+
+        let read = _request(2, json!({"type": constants::GET_SCHEMA, "dest": IDENTIFIER, "data": {"name": "gvt", "version": "1.0"}}));
+        let replies = _process(&mut ledger, &read);
+        assert_eq!(1, replies[0]["result"]["seqNo"]);
+        assert_eq!(json!(["name"]), replies[0]["result"]["data"]["attr_names"]);
+
+        let duplicate = _request(3, json!({"type": constants::SCHEMA, "data": {"name": "gvt", "version": "1.0", "attr_names": ["age"]}}));
+        assert_eq!("REJECT", _process(&mut ledger, &duplicate)[1]["op"]);
+    }
+
+    #[test]
+    fn local_ledger_writes_and_reads_rich_schema_object() {
+        let mut ledger = LocalLedger::new();
+
+        let operation = json!({
+            "type": constants::RICH_SCHEMA,
+            "id": "did:sov:8a9QLQbmwcXRbhyAnMxq8Y",
+            "content": r#"{"@id":"did:sov:8a9QLQbmwcXRbhyAnMxq8Y"}"#,
+            "rsName": "SimpleRichSchema",
+            "rsVersion": "1.0",
+            "rsType": "sch",
+            "ver": "1"
+        });
+
+        assert_eq!("REPLY", _process(&mut ledger, &_request(1, operation.clone()))[1]["op"]);
+
+        let read = _request(2, json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_ID, "id": "did:sov:8a9QLQbmwcXRbhyAnMxq8Y"}));
+        let replies = _process(&mut ledger, &read);
+        assert_eq!(1, replies[0]["result"]["seqNo"]);
+        assert_eq!("SimpleRichSchema", replies[0]["result"]["data"]["rsName"]);
+
+        let read = _request(3, json!({"type": constants::GET_RICH_SCHEMA_OBJECT_BY_METADATA, "rsType": "sch", "rsName": "SimpleRichSchema", "rsVersion": "1.0"}));
+        let replies = _process(&mut ledger, &read);
+        assert_eq!("did:sov:8a9QLQbmwcXRbhyAnMxq8Y", replies[0]["result"]["data"]["id"]);
+
+        assert_eq!("REJECT", _process(&mut ledger, &_request(4, operation))[1]["op"]);
+    }
+
+    #[test]
+    fn local_ledger_writes_and_reads_frozen_ledgers() {
+        let mut ledger = LocalLedger::new();
+
+        let replies = _process(&mut ledger, &_request(1, json!({"type": constants::GET_FROZEN_LEDGERS})));
+        assert!(replies[0]["result"]["data"].is_null());
+
+        assert_eq!("REPLY", _process(&mut ledger, &_request(2, json!({"type": constants::LEDGERS_FREEZE, "ledgers_ids": [10]})))[1]["op"]);
+        assert_eq!("REPLY", _process(&mut ledger, &_request(3, json!({"type": constants::LEDGERS_FREEZE, "ledgers_ids": [11]})))[1]["op"]);
+
+        let replies = _process(&mut ledger, &_request(4, json!({"type": constants::GET_FROZEN_LEDGERS})));
+        assert_eq!(2, replies[0]["result"]["seqNo"]);
+        assert_eq!(0, replies[0]["result"]["data"]["10"]["seq_no"]);
+        assert_eq!(0, replies[0]["result"]["data"]["11"]["seq_no"]);
+
+        assert_eq!("REJECT", _process(&mut ledger, &_request(5, json!({"type": constants::LEDGERS_FREEZE, "ledgers_ids": [1]})))[1]["op"]);
+    }
+
+    #[test]
+    fn local_ledger_answers_get_txn() {
+        let mut ledger = LocalLedger::new();
+
+        _process(&mut ledger, &_request(1, json!({"type": constants::NYM, "dest": DEST})));
+
+        let replies = _process(&mut ledger, &_request(2, json!({"type": constants::GET_TXN, "data": 1, "ledgerId": 1})));
+        assert_eq!(DEST, replies[0]["result"]["data"]["txn"]["data"]["dest"]);
+        assert!(replies[0]["result"]["data"]["rootHash"].is_string());
+        assert!(replies[0]["result"]["data"]["auditPath"].is_array());
+
+        let replies = _process(&mut ledger, &_request(3, json!({"type": constants::GET_TXN, "data": 2, "ledgerId": 1})));
+        assert!(replies[0]["result"]["data"].is_null());
+    }
+
+    #[test]
+    fn local_ledger_nacks_unknown_txn_type() {
+        let mut ledger = LocalLedger::new();
+
+        let replies = _process(&mut ledger, &_request(1, json!({"type": "99999"})));
+        assert_eq!("REQNACK", replies[0]["op"]);
+    }
+
+    #[test]
+    fn local_ledger_networker_loses_replies_for_first_writes() {
+        let ledger = Arc::new(Mutex::new(LocalLedger::new()));
+        let mut networker = LocalLedgerNetworker::with_ledger(ledger, vec![], LocalFaults { lost_write_replies: 1 });
+
+        let write_replies = vec![_ack(IDENTIFIER, 1).unwrap(), _reply(json!({})).unwrap()];
+        let read_replies = vec![_reply(json!({})).unwrap()];
+
+        assert!(networker._is_reply_lost("1", &write_replies));
+        // replies of other nodes on the same request are lost too
+        assert!(networker._is_reply_lost("1", &write_replies));
+        assert!(!networker._is_reply_lost("2", &write_replies));
+        assert!(!networker._is_reply_lost("3", &read_replies));
+    }
+}
@@ -9,12 +9,56 @@ use serde_json::Value as SJsonValue;
 use domain::ledger::request::ProtocolVersion;
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
+use services::pool::request_handler::DEFAULT_GENERATOR;
+use services::pool::state_proof;
 use services::pool::types::{NodeTransaction, NodeTransactionV0, NodeTransactionV1};
 use utils::environment;
 
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+use rust_base58::{FromBase58, ToBase58};
+use time;
+
+use super::ursa::bls::{Generator, VerKey};
 
 const POOL_EXT : &str = "txn";
+const SNAPSHOT_FILE : &str = "snapshot";
+const SNAPSHOT_EXT : &str = "bin";
+
+/// Pool ledger state persisted after successful catchup.
+///
+/// Allows to open pool without replaying of cached transactions:
+/// merkle tree and node list are restored as is and catchup starts from the snapshot height.
+/// Snapshot beyond genesis transactions is used only if its root is signed by genesis nodes.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSnapshot {
+    pub size: usize,
+    pub root_hash: String,
+    pub timestamp: u64,
+    pub protocol_version: usize,
+    pub cache_len: u64,
+    pub nodes: HashMap<String, NodeTransactionV1>,
+    pub tree: MerkleTree,
+    /// BLS multi-signature of pool nodes over `root_hash` received during catchup
+    #[serde(default)]
+    pub multi_signature: Option<SJsonValue>,
+}
+
+impl PoolSnapshot {
+    pub fn info(&self) -> SJsonValue {
+        let mut nodes: Vec<&str> = self.nodes.values()
+            .map(|txn| txn.txn.data.data.alias.as_str())
+            .collect();
+        nodes.sort();
+
+        json!({
+            "size": self.size,
+            "rootHash": self.root_hash,
+            "timestamp": self.timestamp,
+            "nodes": nodes,
+        })
+    }
+}
 
 pub fn create(pool_name: &str) -> IndyResult<MerkleTree> {
     let mut p = environment::pool_path(pool_name);
@@ -40,7 +84,20 @@ pub fn create(pool_name: &str) -> IndyResult<MerkleTree> {
     }
 }
 
+pub fn create_with_nodes(pool_name: &str) -> IndyResult<(MerkleTree, HashMap<String, NodeTransactionV1>)> {
+    if let Some(snapshot) = _load_snapshot(pool_name) {
+        trace!("Restoring merkle tree from snapshot");
+        return Ok((snapshot.tree, snapshot.nodes));
+    }
+
+    let merkle = create(pool_name)?;
+    let nodes = build_node_state(&merkle)?;
+    Ok((merkle, nodes))
+}
+
 pub fn drop_cache(pool_name: &str) -> IndyResult<()> {
+    drop_snapshot(pool_name)?;
+
     let p = get_pool_stored_path(pool_name, false);
     if p.exists() {
         warn!("Cache is invalid -- dropping it!");
@@ -232,6 +289,179 @@ pub fn from_file(txn_file: &str) -> IndyResult<MerkleTree> {
     _from_genesis(&PathBuf::from(txn_file))
 }
 
+pub fn save_snapshot(pool_name: &str, merkle: &MerkleTree, multi_signature: Option<SJsonValue>) -> IndyResult<PoolSnapshot> {
+    let root_hash = merkle.root_hash().to_base58();
+
+    // keep multi-signature received during previous catchup if ledger is not changed since
+    let multi_signature = multi_signature.or_else(|| {
+        let p = get_pool_stored_path_base(pool_name, false, SNAPSHOT_FILE, SNAPSHOT_EXT);
+        if !p.exists() {
+            return None;
+        }
+
+        _read_snapshot(&p).ok()
+            .and_then(|snapshot| if snapshot.root_hash == root_hash { snapshot.multi_signature } else { None })
+    });
+
+    let snapshot = PoolSnapshot {
+        size: merkle.count(),
+        root_hash,
+        timestamp: time::get_time().sec as u64,
+        protocol_version: ProtocolVersion::get(),
+        cache_len: _cache_len(pool_name),
+        nodes: build_node_state(merkle)?,
+        tree: merkle.clone(),
+        multi_signature,
+    };
+
+    let bytes = rmp_serde::encode::to_vec_named(&snapshot)
+        .to_indy(IndyErrorKind::InvalidState, "Can't encode pool ledger snapshot")?;
+
+    // write to temporary file first to not leave broken snapshot if interrupted
+    let p = get_pool_stored_path_base(pool_name, false, SNAPSHOT_FILE, SNAPSHOT_EXT);
+    let mut p_tmp = p.clone();
+    p_tmp.set_extension(format!("{}.tmp", SNAPSHOT_EXT));
+
+    {
+        let mut file = fs::File::create(&p_tmp)
+            .to_indy(IndyErrorKind::IOError, "Can't create pool ledger snapshot file")?;
+
+        file.write_all(&bytes)
+            .to_indy(IndyErrorKind::IOError, "Can't write to pool ledger snapshot file")?;
+    }
+
+    fs::rename(&p_tmp, &p)
+        .to_indy(IndyErrorKind::IOError, "Can't write to pool ledger snapshot file")?;
+
+    Ok(snapshot)
+}
+
+pub fn update_snapshot(pool_name: &str, merkle: &MerkleTree, multi_signature: Option<SJsonValue>) -> IndyResult<()> {
+    if merkle.count() == 0 {
+        return Ok(());
+    }
+
+    let is_actual = _load_snapshot(pool_name)
+        .map(|snapshot| snapshot.size == merkle.count()
+            && snapshot.root_hash == merkle.root_hash().to_base58()
+            && (snapshot.multi_signature.is_some() || multi_signature.is_none()))
+        .unwrap_or(false);
+
+    if !is_actual {
+        save_snapshot(pool_name, merkle, multi_signature)?;
+    }
+
+    Ok(())
+}
+
+pub fn drop_snapshot(pool_name: &str) -> IndyResult<()> {
+    let p = get_pool_stored_path_base(pool_name, false, SNAPSHOT_FILE, SNAPSHOT_EXT);
+    if p.exists() {
+        fs::remove_file(p)
+            .to_indy(IndyErrorKind::IOError, "Can't drop pool ledger snapshot file")?;
+    }
+    Ok(())
+}
+
+pub fn rebuild(pool_name: &str) -> IndyResult<()> {
+    if get_pool_stored_path(pool_name, false).exists() {
+        drop_cache(pool_name)
+    } else {
+        drop_snapshot(pool_name)
+    }
+}
+
+fn _read_snapshot(p: &PathBuf) -> IndyResult<PoolSnapshot> {
+    let bytes = fs::read(p)
+        .to_indy(IndyErrorKind::IOError, "Can't read pool ledger snapshot file")?;
+
+    rmp_serde::decode::from_slice::<PoolSnapshot>(&bytes)
+        .to_indy(IndyErrorKind::InvalidStructure, "Malformed pool ledger snapshot file")
+}
+
+fn _load_snapshot(pool_name: &str) -> Option<PoolSnapshot> {
+    let p = get_pool_stored_path_base(pool_name, false, SNAPSHOT_FILE, SNAPSHOT_EXT);
+    if !p.exists() {
+        return None;
+    }
+
+    let snapshot = match _read_snapshot(&p) {
+        Ok(snapshot) => snapshot,
+        Err(err) => {
+            warn!("Pool ledger snapshot is ignored: {:?}", err);
+            return None;
+        }
+    };
+
+    // snapshot is valid only for the cache it was taken from
+    if snapshot.protocol_version != ProtocolVersion::get()
+        || snapshot.cache_len != _cache_len(pool_name)
+        || snapshot.tree.count() != snapshot.size
+        || snapshot.tree.root_hash().to_base58() != snapshot.root_hash {
+        warn!("Pool ledger snapshot is outdated -- ignoring it");
+        return None;
+    }
+
+    if !_is_snapshot_trusted(pool_name, &snapshot) {
+        warn!("Pool ledger snapshot is not signed by pool nodes -- ignoring it");
+        return None;
+    }
+
+    Some(snapshot)
+}
+
+/// Snapshot is trusted if it matches genesis transactions
+/// or its root is signed by the nodes listed in genesis transactions.
+fn _is_snapshot_trusted(pool_name: &str, snapshot: &PoolSnapshot) -> bool {
+    let genesis = match _from_genesis(&get_pool_stored_path_base(pool_name, false, pool_name, POOL_EXT)) {
+        Ok(genesis) => genesis,
+        Err(err) => {
+            warn!("Can't read genesis txns to check pool ledger snapshot: {:?}", err);
+            return false;
+        }
+    };
+
+    if genesis.count() == snapshot.size && genesis.root_hash().to_base58() == snapshot.root_hash {
+        return true;
+    }
+
+    let multi_signature = match snapshot.multi_signature {
+        Some(ref multi_signature) => multi_signature,
+        None => return false
+    };
+
+    if multi_signature["value"]["ledger_id"].as_u64() != Some(0)
+        || multi_signature["value"]["txn_root_hash"].as_str() != Some(snapshot.root_hash.as_str()) {
+        return false;
+    }
+
+    let nodes: HashMap<String, Option<VerKey>> = match build_node_state(&genesis) {
+        Ok(nodes) => nodes.values()
+            .map(|txn| {
+                let verkey = txn.txn.data.data.blskey.as_ref()
+                    .and_then(|blskey| blskey.as_str().from_base58().ok())
+                    .and_then(|blskey| VerKey::from_bytes(&blskey).ok());
+                (txn.txn.data.data.alias.clone(), verkey)
+            })
+            .collect(),
+        Err(err) => {
+            warn!("Can't build node state from genesis txns to check pool ledger snapshot: {:?}", err);
+            return false;
+        }
+    };
+
+    let f = nodes.len().saturating_sub(1) / 3;
+    let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+    state_proof::verify_multi_signature(multi_signature, &nodes, f, &gen)
+}
+
+fn _cache_len(pool_name: &str) -> u64 {
+    fs::metadata(get_pool_stored_path(pool_name, false))
+        .map(|metadata| metadata.len())
+        .unwrap_or(0)
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -243,6 +473,7 @@ mod tests {
     use utils::test;
 
     use super::*;
+    use super::super::ursa::bls::{Bls, MultiSignature, SignKey, Signature};
 
     fn _set_protocol_version(version: usize) {
         ProtocolVersion::set(version);
@@ -386,6 +617,201 @@ mod tests {
         test::cleanup_storage("pool_worker_build_node_state_works_for_new_format");
     }
 
+    #[test]
+    fn create_with_nodes_works_for_snapshot() {
+        test::cleanup_storage("create_with_nodes_works_for_snapshot");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "create_with_nodes_works_for_snapshot";
+        _write_genesis_txns(pool_name, &test::gen_txns().join("\n"));
+
+        let merkle_tree = super::create(pool_name).unwrap();
+        let snapshot = super::save_snapshot(pool_name, &merkle_tree, None).unwrap();
+        assert_eq!(4, snapshot.size);
+        assert_eq!(json!(["Node1", "Node2", "Node3", "Node4"]), snapshot.info()["nodes"]);
+
+        let (restored_tree, nodes) = super::create_with_nodes(pool_name).unwrap();
+        assert_eq!(merkle_tree.root_hash(), restored_tree.root_hash());
+        assert_eq!(super::build_node_state(&merkle_tree).unwrap(), nodes);
+
+        test::cleanup_storage("create_with_nodes_works_for_snapshot");
+    }
+
+    #[test]
+    fn create_with_nodes_works_for_outdated_snapshot() {
+        test::cleanup_storage("create_with_nodes_works_for_outdated_snapshot");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "create_with_nodes_works_for_outdated_snapshot";
+        let node_txns = test::gen_txns();
+        _write_genesis_txns(pool_name, &node_txns[0..3].join("\n"));
+
+        let merkle_tree = super::create(pool_name).unwrap();
+        super::save_snapshot(pool_name, &merkle_tree, None).unwrap();
+
+        let txn4: SJsonValue = serde_json::from_str(&node_txns[3]).unwrap();
+        super::dump_new_txns(pool_name, &vec![rmp_serde::to_vec_named(&txn4).unwrap()]).unwrap();
+
+        let (restored_tree, nodes) = super::create_with_nodes(pool_name).unwrap();
+        assert_eq!(4, restored_tree.count());
+        assert_eq!(4, nodes.len());
+
+        test::cleanup_storage("create_with_nodes_works_for_outdated_snapshot");
+    }
+
+    fn _gen_txns_with_bls_keys(sign_keys: &[SignKey]) -> Vec<String> {
+        let gen = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+
+        test::gen_txns().iter().zip(sign_keys)
+            .map(|(txn, sign_key)| {
+                let mut txn: SJsonValue = serde_json::from_str(txn).unwrap();
+                txn["txn"]["data"]["data"]["blskey"] = json!(VerKey::new(&gen, sign_key).unwrap().as_bytes().to_base58());
+                txn.to_string()
+            })
+            .collect()
+    }
+
+    fn _multi_signature(root_hash: &str, signers: &[(&str, &SignKey)]) -> SJsonValue {
+        let value = json!({
+            "ledger_id": 0,
+            "pool_state_root_hash": "7Wdj3rrMCZ1R1M78H4xK5jxikmdUUGW2kbfJQ1HoEpK",
+            "state_root_hash": "DqNWEKvp3GxkbnkXfMZpnDjNVmdD6nCnbrRRN25J8Pg8",
+            "timestamp": 1558958541,
+            "txn_root_hash": root_hash,
+        });
+        let msg = rmp_serde::to_vec_named(&value).unwrap();
+
+        let signatures: Vec<Signature> = signers.iter()
+            .map(|&(_, sign_key)| Bls::sign(&msg, sign_key).unwrap())
+            .collect();
+        let signatures: Vec<&Signature> = signatures.iter().collect();
+
+        json!({
+            "signature": MultiSignature::new(&signatures).unwrap().as_bytes().to_base58(),
+            "participants": signers.iter().map(|&(alias, _)| alias).collect::<Vec<&str>>(),
+            "value": value,
+        })
+    }
+
+    fn _prepare_pool_with_new_txn(pool_name: &str, sign_keys: &[SignKey]) -> MerkleTree {
+        let node_txns = _gen_txns_with_bls_keys(sign_keys);
+        _write_genesis_txns(pool_name, &node_txns[0..3].join("\n"));
+
+        let txn4: SJsonValue = serde_json::from_str(&node_txns[3]).unwrap();
+        super::dump_new_txns(pool_name, &vec![rmp_serde::to_vec_named(&txn4).unwrap()]).unwrap();
+
+        super::create(pool_name).unwrap()
+    }
+
+    #[test]
+    fn create_with_nodes_works_for_signed_snapshot() {
+        test::cleanup_storage("create_with_nodes_works_for_signed_snapshot");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "create_with_nodes_works_for_signed_snapshot";
+        let sign_keys: Vec<SignKey> = (0..4).map(|_| SignKey::new(None).unwrap()).collect();
+        let merkle_tree = _prepare_pool_with_new_txn(pool_name, &sign_keys);
+
+        let multi_signature = _multi_signature(&merkle_tree.root_hash().to_base58(),
+                                               &[("Node1", &sign_keys[0]), ("Node2", &sign_keys[1]), ("Node3", &sign_keys[2])]);
+        super::save_snapshot(pool_name, &merkle_tree, Some(multi_signature)).unwrap();
+
+        let snapshot = super::_load_snapshot(pool_name).unwrap();
+        assert_eq!(4, snapshot.size);
+        assert_eq!(4, snapshot.nodes.len());
+
+        test::cleanup_storage("create_with_nodes_works_for_signed_snapshot");
+    }
+
+    #[test]
+    fn create_with_nodes_works_for_unsigned_snapshot() {
+        test::cleanup_storage("create_with_nodes_works_for_unsigned_snapshot");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "create_with_nodes_works_for_unsigned_snapshot";
+        let sign_keys: Vec<SignKey> = (0..4).map(|_| SignKey::new(None).unwrap()).collect();
+        let merkle_tree = _prepare_pool_with_new_txn(pool_name, &sign_keys);
+
+        super::save_snapshot(pool_name, &merkle_tree, None).unwrap();
+        assert!(super::_load_snapshot(pool_name).is_none());
+
+        let (restored_tree, nodes) = super::create_with_nodes(pool_name).unwrap();
+        assert_eq!(merkle_tree.root_hash(), restored_tree.root_hash());
+        assert_eq!(4, nodes.len());
+
+        test::cleanup_storage("create_with_nodes_works_for_unsigned_snapshot");
+    }
+
+    #[test]
+    fn create_with_nodes_works_for_snapshot_signed_by_unknown_keys() {
+        test::cleanup_storage("create_with_nodes_works_for_snapshot_signed_by_unknown_keys");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "create_with_nodes_works_for_snapshot_signed_by_unknown_keys";
+        let sign_keys: Vec<SignKey> = (0..4).map(|_| SignKey::new(None).unwrap()).collect();
+        let merkle_tree = _prepare_pool_with_new_txn(pool_name, &sign_keys);
+
+        let forged_keys: Vec<SignKey> = (0..3).map(|_| SignKey::new(None).unwrap()).collect();
+        let multi_signature = _multi_signature(&merkle_tree.root_hash().to_base58(),
+                                               &[("Node1", &forged_keys[0]), ("Node2", &forged_keys[1]), ("Node3", &forged_keys[2])]);
+        super::save_snapshot(pool_name, &merkle_tree, Some(multi_signature)).unwrap();
+
+        assert!(super::_load_snapshot(pool_name).is_none());
+
+        test::cleanup_storage("create_with_nodes_works_for_snapshot_signed_by_unknown_keys");
+    }
+
+    #[test]
+    fn save_snapshot_works_for_keeping_multi_signature_of_same_root() {
+        test::cleanup_storage("save_snapshot_works_for_keeping_multi_signature_of_same_root");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "save_snapshot_works_for_keeping_multi_signature_of_same_root";
+        let sign_keys: Vec<SignKey> = (0..4).map(|_| SignKey::new(None).unwrap()).collect();
+        let merkle_tree = _prepare_pool_with_new_txn(pool_name, &sign_keys);
+
+        let multi_signature = _multi_signature(&merkle_tree.root_hash().to_base58(),
+                                               &[("Node1", &sign_keys[0]), ("Node2", &sign_keys[1]), ("Node3", &sign_keys[2])]);
+        super::save_snapshot(pool_name, &merkle_tree, Some(multi_signature.clone())).unwrap();
+
+        let snapshot = super::save_snapshot(pool_name, &merkle_tree, None).unwrap();
+        assert_eq!(Some(multi_signature), snapshot.multi_signature);
+        assert!(super::_load_snapshot(pool_name).is_some());
+
+        test::cleanup_storage("save_snapshot_works_for_keeping_multi_signature_of_same_root");
+    }
+
+    #[test]
+    fn drop_cache_works_for_snapshot() {
+        test::cleanup_storage("drop_cache_works_for_snapshot");
+
+        _set_protocol_version(TEST_PROTOCOL_VERSION);
+
+        let pool_name = "drop_cache_works_for_snapshot";
+        let node_txns = test::gen_txns();
+        _write_genesis_txns(pool_name, &node_txns[0..3].join("\n"));
+
+        let txn4: SJsonValue = serde_json::from_str(&node_txns[3]).unwrap();
+        super::dump_new_txns(pool_name, &vec![rmp_serde::to_vec_named(&txn4).unwrap()]).unwrap();
+
+        let merkle_tree = super::create(pool_name).unwrap();
+        super::save_snapshot(pool_name, &merkle_tree, None).unwrap();
+
+        super::drop_cache(pool_name).unwrap();
+        assert!(!get_pool_stored_path_base(pool_name, false, SNAPSHOT_FILE, SNAPSHOT_EXT).exists());
+
+        let (restored_tree, _) = super::create_with_nodes(pool_name).unwrap();
+        assert_eq!(3, restored_tree.count());
+
+        test::cleanup_storage("drop_cache_works_for_snapshot");
+    }
+
     #[test]
     fn pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version() {
         test::cleanup_storage("pool_worker_build_node_state_works_for_old_txns_format_and_2_protocol_version");
@@ -0,0 +1,206 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use time;
+
+/// Upper bounds (in ms) of latency histogram buckets. Last bucket collects everything above.
+const LATENCY_BUCKETS_MS: [u64; 9] = [10, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+lazy_static! {
+    static ref POOL_METRICS: Mutex<HashMap<String, PoolMetrics>> = Mutex::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PoolMetrics {
+    pub catchup_state: String,
+    pub catchup_state_since: i64,
+    pub consensus_failures: u64,
+    pub nodes: BTreeMap<String, NodeMetrics>,
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize)]
+pub struct NodeMetrics {
+    pub acks: u64,
+    pub nacks: u64,
+    pub rejects: u64,
+    pub replies: u64,
+    pub timeouts: u64,
+    pub latency: LatencyHistogram,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyHistogram {
+    pub count: u64,
+    pub min_ms: Option<u64>,
+    pub max_ms: Option<u64>,
+    pub avg_ms: Option<u64>,
+    pub buckets: Vec<LatencyBucket>,
+    #[serde(skip)]
+    sum_ms: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LatencyBucket {
+    /// Inclusive upper bound of bucket, `None` for the last unbounded one.
+    pub le_ms: Option<u64>,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeEvent {
+    Ack,
+    Nack,
+    Reject,
+    Reply,
+    Timeout,
+}
+
+impl PoolMetrics {
+    fn new() -> PoolMetrics {
+        PoolMetrics {
+            catchup_state: "initialization".to_string(),
+            catchup_state_since: time::get_time().sec,
+            consensus_failures: 0,
+            nodes: BTreeMap::new(),
+        }
+    }
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> LatencyHistogram {
+        let mut buckets: Vec<LatencyBucket> = LATENCY_BUCKETS_MS.iter()
+            .map(|le_ms| LatencyBucket { le_ms: Some(*le_ms), count: 0 })
+            .collect();
+        buckets.push(LatencyBucket { le_ms: None, count: 0 });
+
+        LatencyHistogram {
+            count: 0,
+            min_ms: None,
+            max_ms: None,
+            avg_ms: None,
+            buckets,
+            sum_ms: 0,
+        }
+    }
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, latency: Duration) {
+        let ms = latency.as_secs() * 1000 + u64::from(latency.subsec_millis());
+
+        self.count += 1;
+        self.sum_ms += ms;
+        self.min_ms = Some(self.min_ms.map_or(ms, |min| ::std::cmp::min(min, ms)));
+        self.max_ms = Some(self.max_ms.map_or(ms, |max| ::std::cmp::max(max, ms)));
+        self.avg_ms = Some(self.sum_ms / self.count);
+
+        if let Some(bucket) = self.buckets.iter_mut().find(|bucket| bucket.le_ms.map(|le_ms| ms <= le_ms).unwrap_or(true)) {
+            bucket.count += 1;
+        }
+    }
+}
+
+/// Starts collecting metrics of pool from scratch. Called on every pool opening.
+pub fn reset(pool_name: &str) {
+    POOL_METRICS.lock().unwrap().insert(pool_name.to_string(), PoolMetrics::new());
+}
+
+pub fn remove(pool_name: &str) {
+    POOL_METRICS.lock().unwrap().remove(pool_name);
+}
+
+pub fn get(pool_name: &str) -> Option<PoolMetrics> {
+    POOL_METRICS.lock().unwrap().get(pool_name).cloned()
+}
+
+pub fn set_catchup_state(pool_name: &str, state: &str) {
+    _update(pool_name, |metrics| {
+        if metrics.catchup_state != state {
+            metrics.catchup_state = state.to_string();
+            metrics.catchup_state_since = time::get_time().sec;
+        }
+    })
+}
+
+pub fn record_consensus_failure(pool_name: &str) {
+    _update(pool_name, |metrics| metrics.consensus_failures += 1)
+}
+
+/// Records message (or its absence) from node. `latency` is set for the first node response on request.
+pub fn record_node_event(pool_name: &str, node_alias: &str, event: NodeEvent, latency: Option<Duration>) {
+    _update(pool_name, |metrics| {
+        let node = metrics.nodes.entry(node_alias.to_string()).or_insert_with(NodeMetrics::default);
+
+        match event {
+            NodeEvent::Ack => node.acks += 1,
+            NodeEvent::Nack => node.nacks += 1,
+            NodeEvent::Reject => node.rejects += 1,
+            NodeEvent::Reply => node.replies += 1,
+            NodeEvent::Timeout => node.timeouts += 1,
+        }
+
+        if let Some(latency) = latency {
+            node.latency.observe(latency);
+        }
+    })
+}
+
+fn _update<F>(pool_name: &str, f: F) where F: FnOnce(&mut PoolMetrics) {
+    if let Some(metrics) = POOL_METRICS.lock().unwrap().get_mut(pool_name) {
+        f(metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latency_histogram_observe_works() {
+        let mut histogram = LatencyHistogram::default();
+
+        histogram.observe(Duration::from_millis(5));
+        histogram.observe(Duration::from_millis(120));
+        histogram.observe(Duration::from_secs(30));
+
+        assert_eq!(3, histogram.count);
+        assert_eq!(Some(5), histogram.min_ms);
+        assert_eq!(Some(30000), histogram.max_ms);
+        assert_eq!(Some((5 + 120 + 30000) / 3), histogram.avg_ms);
+        assert_eq!(1, histogram.buckets[0].count);
+        assert_eq!(1, histogram.buckets[3].count);
+        assert_eq!(None, histogram.buckets.last().unwrap().le_ms);
+        assert_eq!(1, histogram.buckets.last().unwrap().count);
+    }
+
+    #[test]
+    fn record_node_event_works() {
+        let pool_name = "record_node_event_works";
+        reset(pool_name);
+
+        record_node_event(pool_name, "Node1", NodeEvent::Ack, Some(Duration::from_millis(20)));
+        record_node_event(pool_name, "Node1", NodeEvent::Reply, None);
+        record_node_event(pool_name, "Node2", NodeEvent::Timeout, None);
+        record_consensus_failure(pool_name);
+        set_catchup_state(pool_name, "active");
+
+        let metrics = get(pool_name).unwrap();
+        assert_eq!("active", metrics.catchup_state);
+        assert_eq!(1, metrics.consensus_failures);
+        assert_eq!(1, metrics.nodes["Node1"].acks);
+        assert_eq!(1, metrics.nodes["Node1"].replies);
+        assert_eq!(1, metrics.nodes["Node1"].latency.count);
+        assert_eq!(1, metrics.nodes["Node2"].timeouts);
+        assert_eq!(0, metrics.nodes["Node2"].latency.count);
+
+        remove(pool_name);
+        assert!(get(pool_name).is_none());
+    }
+
+    #[test]
+    fn record_node_event_works_for_unknown_pool() {
+        record_node_event("record_node_event_works_for_unknown_pool", "Node1", NodeEvent::Reply, None);
+        assert!(get("record_node_event_works_for_unknown_pool").is_none());
+    }
+}
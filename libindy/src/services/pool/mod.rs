@@ -19,7 +19,7 @@ use serde::de::DeserializeOwned;
 
 use api::ledger::{CustomFree, CustomTransactionParser};
 use domain::{
-    pool::{PoolConfig, PoolOpenConfig},
+    pool::{NetworkerType, PoolConfig, PoolOpenConfig, WriteRetryPolicy},
     ledger::response::{
         Message,
         Reply,
@@ -31,18 +31,27 @@ use services::pool::pool::{Pool, ZMQPool};
 use utils::environment;
 use utils::sequence;
 
+use self::ursa::bls::VerKey;
+
 mod catchup;
 mod commander;
 mod events;
+mod local_ledger;
 mod merkle_tree_factory;
+mod metrics;
 mod networker;
 mod pool;
 mod request_handler;
 mod state_proof;
+mod traffic;
 mod types;
 
+pub use self::request_handler::DEFAULT_GENERATOR;
+
 lazy_static! {
     static ref REGISTERED_SP_PARSERS: Mutex<HashMap<String, (CustomTransactionParser, CustomFree)>> = Mutex::new(HashMap::new());
+    // f and BLS keys of nodes of active pools. Used for verification of replies outside of pool thread.
+    static ref POOL_NODES: Mutex<HashMap<String, (usize, HashMap<String, Option<VerKey>>)>> = Mutex::new(HashMap::new());
 }
 
 pub struct PoolService {
@@ -135,7 +144,11 @@ impl PoolService {
         let path = environment::pool_path(name);
 
         fs::remove_dir_all(path)
-            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")
+            .to_indy(IndyErrorKind::IOError, "Can't delete pool config directory")?;
+
+        local_ledger::forget(name);
+        metrics::remove(name);
+        Ok(())
     }
 
     pub fn open(&self, name: &str, config: Option<PoolOpenConfig>) -> IndyResult<i32> {
@@ -148,9 +161,50 @@ impl PoolService {
 
         let config = config.unwrap_or(PoolOpenConfig::default());
 
+        if config.batch_limit == Some(0) {
+            return Err(err_msg(IndyErrorKind::InvalidStructure, "\"batch_limit\" must be greater than 0"));
+        }
+
+        if config.force_rebuild {
+            merkle_tree_factory::rebuild(name)?;
+        }
+
+        let local_ledger = match config.networker {
+            NetworkerType::Local => Some(local_ledger::open(name, config.domain_genesis_txn.as_ref().map(String::as_str))?),
+            _ => None,
+        };
+
+        let capture = match config.networker {
+            NetworkerType::Replay => {
+                let replay_traffic = config.replay_traffic.as_ref()
+                    .ok_or(err_msg(IndyErrorKind::InvalidStructure, "\"replay_traffic\" is required for \"replay\" networker"))?;
+                Some(traffic::Capture::load(replay_traffic)?)
+            }
+            _ => None,
+        };
+
+        let recorder = match config.record_traffic {
+            Some(ref record_traffic) => Some(traffic::TrafficRecorder::create(record_traffic)?),
+            None => None,
+        };
+
         let pool_handle: i32 = sequence::get_next_id();
         let mut new_pool = Pool::new(name, pool_handle, config);
 
+        metrics::reset(name);
+
+        if let Some(local_ledger) = local_ledger {
+            new_pool = new_pool.with_local_ledger(local_ledger);
+        }
+
+        if let Some(capture) = capture {
+            new_pool = new_pool.with_traffic_capture(capture);
+        }
+
+        if let Some(recorder) = recorder {
+            new_pool = new_pool.with_traffic_recorder(recorder);
+        }
+
         let zmq_ctx = zmq::Context::new();
         let recv_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR)?;
         let send_cmd_sock = zmq_ctx.socket(zmq::SocketType::PAIR)?;
@@ -222,7 +276,10 @@ impl PoolService {
         let mut pools = self.open_pools.try_borrow_mut()?;
 
         match pools.remove(&handle) {
-            Some(ref pool) => self._send_msg(cmd_id, "exit", &pool.cmd_socket, None, None)?,
+            Some(ref pool) => {
+                POOL_NODES.lock().unwrap().remove(pool.pool.get_name());
+                self._send_msg(cmd_id, "exit", &pool.cmd_socket, None, None)?
+            }
             None => return Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
         }
 
@@ -233,6 +290,95 @@ impl PoolService {
         self.send_action(handle, "refresh", None, None)
     }
 
+    /// Requests size and merkle root of the ledger which f+1 nodes agree on.
+    /// Reply is LEDGER_STATUS message.
+    pub fn get_ledger_status(&self, handle: i32, ledger_id: usize) -> IndyResult<i32> {
+        let msg = catchup::build_ledger_status_req(ledger_id)?;
+        self.send_tx(handle, &msg)
+    }
+
+    /// Requests transactions of the ledger from a single node the same way as catchup does.
+    /// Reply is CATCHUP_REP message with consistency proof for the ledger of `catchup_till` size.
+    pub fn get_ledger_txns(&self, handle: i32, ledger_id: usize, seq_no_start: usize, seq_no_end: usize, catchup_till: usize) -> IndyResult<i32> {
+        let msg = catchup::build_ledger_catchup_req(ledger_id, seq_no_start, seq_no_end, catchup_till)?;
+        self.send_tx(handle, &msg)
+    }
+
+    pub fn check_open(&self, handle: i32) -> IndyResult<()> {
+        if self.open_pools.try_borrow()?.contains_key(&handle) {
+            Ok(())
+        } else {
+            Err(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))
+        }
+    }
+
+    /// Checks that reply on read request is confirmed by state proof signed by nodes of the opened pool.
+    pub fn check_state_proof(&self, handle: i32, request: &str, response: &str) -> IndyResult<bool> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        let (f, nodes) = match POOL_NODES.lock().unwrap().get(pool.pool.get_name()) {
+            Some(&(f, ref nodes)) => (f, nodes.clone()),
+            None => return Ok(false)
+        };
+
+        let request: serde_json::Value = serde_json::from_str(request)
+            .to_indy(IndyErrorKind::InvalidStructure, "Cannot deserialize request")?;
+
+        let txn_type = request["operation"]["type"].as_str()
+            .ok_or(err_msg(IndyErrorKind::InvalidStructure, "Request doesn't contain operation type"))?;
+
+        let (sp_key, timestamps) = if events::REQUESTS_FOR_STATE_PROOFS.contains(&txn_type) {
+            (state_proof::parse_key_from_request_for_builtin_sp(&request),
+             events::parse_timestamp_from_req_for_builtin_sp(&request, txn_type))
+        } else if PoolService::get_sp_parser(txn_type).is_some() {
+            (None, (None, None))
+        } else {
+            return Ok(false);
+        };
+
+        Ok(request_handler::check_reply_state_proof(response, f, &nodes, sp_key.as_ref().map(Vec::as_slice), timestamps))
+    }
+
+    pub fn create_snapshot(&self, name: &str) -> IndyResult<String> {
+        let merkle = merkle_tree_factory::create(name)?;
+        let snapshot = merkle_tree_factory::save_snapshot(name, &merkle, None)?;
+        Ok(snapshot.info().to_string())
+    }
+
+    pub fn get_metrics(&self, handle: i32) -> IndyResult<String> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        let metrics = metrics::get(pool.pool.get_name())
+            .ok_or(err_msg(IndyErrorKind::InvalidState, "No metrics collected for pool"))?;
+
+        serde_json::to_string(&metrics)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize pool metrics")
+    }
+
+    pub fn get_write_retry_policy(&self, handle: i32) -> IndyResult<Option<WriteRetryPolicy>> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        Ok(pool.pool.get_write_retry().cloned())
+    }
+
+    pub fn get_batch_limit(&self, handle: i32) -> IndyResult<usize> {
+        let pools = self.open_pools.try_borrow()?;
+
+        let pool = pools.get(&handle)
+            .ok_or(err_msg(IndyErrorKind::InvalidPoolHandle, format!("No pool with requested handle {}", handle)))?;
+
+        Ok(pool.pool.get_batch_limit())
+    }
+
     fn _send_msg(&self, cmd_id: i32, msg: &str, socket: &Socket, nodes: Option<&str>, timeout: Option<i32>) -> IndyResult<()> {
         let mut buf = [0u8; 4];
         let mut buf_to = [0u8; 4];
@@ -264,6 +410,10 @@ impl PoolService {
     }
 }
 
+fn set_pool_nodes(pool_name: &str, f: usize, nodes: &HashMap<String, Option<VerKey>>) {
+    POOL_NODES.lock().unwrap().insert(pool_name.to_string(), (f, nodes.clone()));
+}
+
 lazy_static! {
     static ref THRESHOLD: Mutex<u64> = Mutex::new(600);
 }
@@ -1,10 +1,14 @@
 use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::rc::Rc;
+use std::time::Duration as StdDuration;
 
 use rand::thread_rng;
 use rand::prelude::SliceRandom;
 use time::Tm;
 
+use domain::pool::ReadStrategy;
 use errors::prelude::*;
 use services::pool::events::*;
 use services::pool::types::*;
@@ -15,12 +19,18 @@ use super::time::Duration;
 use super::zmq::PollItem;
 use super::zmq::Socket as ZSocket;
 
+/// Weight of the latest sample in moving-average node latency.
+const LATENCY_EWMA_ALPHA: f64 = 0.3;
+
 pub trait Networker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>) -> Self;
+    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, read_strategy: ReadStrategy) -> Self;
     fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent>;
     fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent>;
     fn get_timeout(&self) -> ((String, String), i64);
     fn get_poll_items(&self) -> Vec<PollItem>;
+    /// Round-trip latency of request measured from sending it to the node till the node reply.
+    /// Each measurement is returned once.
+    fn take_latency(&self, req_id: &str, node_alias: &str) -> Option<StdDuration>;
 }
 
 pub struct ZMQNetworker {
@@ -30,10 +40,47 @@ pub struct ZMQNetworker {
     active_timeout: i64,
     conn_limit: usize,
     preordered_nodes: Vec<String>,
+    read_strategy: ReadStrategy,
+    node_stats: Rc<RefCell<HashMap<String, NodeStats>>>,
+}
+
+/// Health of node observed by networker, shared between all pool connections.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NodeStats {
+    /// Exponential moving average of round-trip latency, `None` until the first reply.
+    latency_ms: Option<f64>,
+    /// Number of requests in a row the node didn't answer in time.
+    failures: u32,
+}
+
+impl NodeStats {
+    fn on_reply(&mut self, latency_ms: f64) {
+        self.latency_ms = Some(match self.latency_ms {
+            Some(avg) => avg + LATENCY_EWMA_ALPHA * (latency_ms - avg),
+            None => latency_ms,
+        });
+        self.failures = 0;
+    }
+
+    fn on_timeout(&mut self) {
+        self.failures += 1;
+    }
+
+    /// Unhealthy nodes go last, nodes with unknown latency are asked before measured ones
+    /// so every node gets a chance to show its latency.
+    fn cmp_priority(&self, other: &NodeStats) -> Ordering {
+        self.failures.cmp(&other.failures)
+            .then_with(|| match (self.latency_ms, other.latency_ms) {
+                (None, None) => Ordering::Equal,
+                (None, Some(_)) => Ordering::Less,
+                (Some(_), None) => Ordering::Greater,
+                (Some(l1), Some(l2)) => l1.partial_cmp(&l2).unwrap_or(Ordering::Equal),
+            })
+    }
 }
 
 impl Networker for ZMQNetworker {
-    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>) -> Self {
+    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, read_strategy: ReadStrategy) -> Self {
         ZMQNetworker {
             req_id_mappings: HashMap::new(),
             pool_connections: BTreeMap::new(),
@@ -41,6 +88,8 @@ impl Networker for ZMQNetworker {
             active_timeout,
             conn_limit,
             preordered_nodes,
+            read_strategy,
+            node_stats: Rc::new(RefCell::new(HashMap::new())),
         }
     }
 
@@ -83,7 +132,8 @@ impl Networker for ZMQNetworker {
                     None => {
                         trace!("send request in new conn");
                         let pc_id = sequence::get_next_id();
-                        let mut pc = PoolConnection::new(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone());
+                        let mut pc = PoolConnection::with_read_strategy(self.nodes.clone(), self.active_timeout, self.preordered_nodes.clone(),
+                                                                        self.read_strategy, self.node_stats.clone());
                         pc.send_request(pe).expect("FIXME");
                         self.pool_connections.insert(pc_id, pc);
                         self.req_id_mappings.insert(req_id.clone(), pc_id);
@@ -162,6 +212,12 @@ impl Networker for ZMQNetworker {
         self.pool_connections.iter()
             .flat_map(|(_, pool)| pool.get_poll_items()).collect()
     }
+
+    fn take_latency(&self, req_id: &str, node_alias: &str) -> Option<StdDuration> {
+        self.req_id_mappings.get(req_id)
+            .and_then(|idx| self.pool_connections.get(idx))
+            .and_then(|pc| pc.latencies.borrow_mut().remove(&(req_id.to_string(), node_alias.to_string())))
+    }
 }
 
 pub struct PoolConnection {
@@ -169,20 +225,30 @@ pub struct PoolConnection {
     sockets: Vec<Option<ZSocket>>,
     ctx: zmq::Context,
     key_pair: zmq::CurveKeyPair,
-    resend: RefCell<HashMap<String, (usize, String)>>,
+    resend: RefCell<HashMap<String, (usize, String, Vec<usize>)>>,
     timeouts: RefCell<HashMap<(String, String), Tm>>,
+    sent: RefCell<HashMap<(String, String), Tm>>,
+    latencies: RefCell<HashMap<(String, String), StdDuration>>,
     time_created: time::Tm,
     req_cnt: usize,
     active_timeout: i64,
+    read_strategy: ReadStrategy,
+    node_stats: Rc<RefCell<HashMap<String, NodeStats>>>,
 }
 
 impl PoolConnection {
-    fn new(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>) -> Self {
+    fn new(nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>) -> Self {
+        PoolConnection::with_read_strategy(nodes, active_timeout, preordered_nodes,
+                                           ReadStrategy::Preordered, Rc::new(RefCell::new(HashMap::new())))
+    }
+
+    fn with_read_strategy(mut nodes: Vec<RemoteNode>, active_timeout: i64, preordered_nodes: Vec<String>,
+                          read_strategy: ReadStrategy, node_stats: Rc<RefCell<HashMap<String, NodeStats>>>) -> Self {
         trace!("PoolConnection::new: from nodes {:?}", nodes);
 
         nodes.shuffle(&mut thread_rng());
 
-        if !preordered_nodes.is_empty() {
+        if read_strategy != ReadStrategy::Random && !preordered_nodes.is_empty() {
             nodes.sort_by_key(|node: &RemoteNode| -> usize {
                 preordered_nodes.iter()
                     .position(|&ref name| node.name.eq(name))
@@ -202,8 +268,12 @@ impl PoolConnection {
             resend: RefCell::new(HashMap::new()),
             time_created: time::now(),
             timeouts: RefCell::new(HashMap::new()),
+            sent: RefCell::new(HashMap::new()),
+            latencies: RefCell::new(HashMap::new()),
             req_cnt: 0,
             active_timeout,
+            read_strategy,
+            node_stats,
         }
     }
 
@@ -216,6 +286,7 @@ impl PoolConnection {
             if let (&Some(ref s), rn) = (&self.sockets[i], &self.nodes[i]) {
                 if poll_items[pi_idx].is_readable() {
                     if let Ok(Ok(str)) = s.recv_string(zmq::DONTWAIT) {
+                        self._on_node_message(&rn.name, &str);
                         vec.push(PoolEvent::NodeReply(
                             str,
                             rn.name.clone(),
@@ -257,8 +328,9 @@ impl PoolConnection {
         match pe {
             Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)) => {
                 self.req_cnt += 1;
-                self._send_msg_to_one_node(0, req_id.clone(), msg.clone(), timeout)?;
-                self.resend.borrow_mut().insert(req_id, (0, msg));
+                let order = self._read_order();
+                self._send_msg_to_one_node(order[0], req_id.clone(), msg.clone(), timeout)?;
+                self.resend.borrow_mut().insert(req_id, (0, msg, order));
             }
             Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
                 self.req_cnt += 1;
@@ -269,16 +341,25 @@ impl PoolConnection {
                 }
             }
             Some(NetworkerEvent::Resend(req_id, timeout)) => {
-                let resend = if let Some(&mut (ref mut cnt, ref req)) = self.resend.borrow_mut().get_mut(&req_id) {
+                let resend = if let Some(&mut (ref mut cnt, ref req, ref order)) = self.resend.borrow_mut().get_mut(&req_id) {
                     *cnt += 1;
-                    //TODO: FIXME: We can collect consensus just walking through if we are not collecting node aliases on the upper layer.
-                    Some((*cnt % self.nodes.len(), req.clone()))
+                    if self.read_strategy != ReadStrategy::Adaptive {
+                        //TODO: FIXME: We can collect consensus just walking through if we are not collecting node aliases on the upper layer.
+                        vec![(order[*cnt % order.len()], req.clone())]
+                    } else if *cnt == 1 {
+                        vec![(order[1 % order.len()], req.clone())]
+                    } else {
+                        // the fastest nodes failed to give verifiable reply - fall back to f+1 consensus of the whole pool
+                        let idxs = order.iter().skip(*cnt).map(|idx| (*idx, req.clone())).collect();
+                        *cnt = ::std::cmp::max(*cnt, order.len());
+                        idxs
+                    }
                 } else {
                     error!("Unknown req_id for resending {}", req_id); //FIXME handle at RH level
-                    None
+                    vec![]
                 };
-                if let Some((idx, req)) = resend {
-                    self._send_msg_to_one_node(idx, req_id, req, timeout)?;
+                for (idx, req) in resend {
+                    self._send_msg_to_one_node(idx, req_id.clone(), req, timeout)?;
                 }
             }
             _ => ()
@@ -298,12 +379,63 @@ impl PoolConnection {
     fn clean_timeout(&self, req_id: &str, node_alias: Option<String>) {
         match node_alias {
             Some(node_alias) => {
-                self.timeouts.borrow_mut().remove(&(req_id.to_string(), node_alias));
+                let key = (req_id.to_string(), node_alias);
+                let timeout = self.timeouts.borrow_mut().remove(&key);
+
+                // node is cleaned without any message from it after timeout expiration
+                if self.sent.borrow_mut().remove(&key).is_some() && timeout.map(|timeout| timeout <= time::now()).unwrap_or(false) {
+                    self.node_stats.borrow_mut().entry(key.1).or_insert_with(NodeStats::default).on_timeout();
+                }
             }
             None => {
                 let keys_to_remove: Vec<(String, String)> = self.timeouts.borrow().keys()
                     .cloned().filter(|&(ref req_id_timeout, _)| req_id == req_id_timeout).collect();
                 keys_to_remove.iter().for_each(|key| { self.timeouts.borrow_mut().remove(key); });
+                self.sent.borrow_mut().retain(|&(ref req_id_sent, _), _| req_id != req_id_sent);
+                self.latencies.borrow_mut().retain(|&(ref req_id_sent, _), _| req_id != req_id_sent);
+            }
+        }
+    }
+
+    /// Order of node indexes to be asked by request expecting a single reply.
+    fn _read_order(&self) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.nodes.len()).collect();
+
+        if self.read_strategy == ReadStrategy::Adaptive {
+            let node_stats = self.node_stats.borrow();
+            let default_stats = NodeStats::default();
+            // sort is stable, so nodes with equal stats keep preordered/random order
+            order.sort_by(|&idx1, &idx2| {
+                let stats1 = node_stats.get(&self.nodes[idx1].name).unwrap_or(&default_stats);
+                let stats2 = node_stats.get(&self.nodes[idx2].name).unwrap_or(&default_stats);
+                stats1.cmp_priority(stats2)
+            });
+        }
+
+        order
+    }
+
+    /// Only REPLY, REQNACK and REJECT complete the request sent to node, REQACK is just a receipt.
+    fn _on_node_message(&self, node_alias: &str, msg: &str) {
+        let req_id = match Message::from_raw_str(msg) {
+            Ok(Message::Reply(ref reply)) => reply.req_id(),
+            Ok(Message::ReqNACK(ref response)) | Ok(Message::Reject(ref response)) => response.req_id(),
+            _ => return
+        };
+
+        self._on_node_reply(&req_id.to_string(), node_alias);
+    }
+
+    fn _on_node_reply(&self, req_id: &str, node_alias: &str) {
+        let key = (req_id.to_string(), node_alias.to_string());
+        let sent = self.sent.borrow_mut().remove(&key);
+
+        if let Some(sent) = sent {
+            let latency = time::now() - sent;
+            self.node_stats.borrow_mut().entry(node_alias.to_string()).or_insert_with(NodeStats::default)
+                .on_reply(latency.num_milliseconds() as f64);
+            if let Ok(latency) = latency.to_std() {
+                self.latencies.borrow_mut().insert(key, latency);
             }
         }
     }
@@ -322,6 +454,7 @@ impl PoolConnection {
             let s = self._get_socket(idx)?;
             s.send_str(&req, zmq::DONTWAIT)?;
         }
+        self.sent.borrow_mut().insert((req_id.clone(), self.nodes[idx].name.clone()), time::now());
         self.timeouts.borrow_mut().insert((req_id, self.nodes[idx].name.clone()), time::now() + Duration::seconds(timeout));
         trace!("_send_msg_to_one_node <<");
         Ok(())
@@ -360,7 +493,7 @@ pub struct MockNetworker {
 
 #[cfg(test)]
 impl Networker for MockNetworker {
-    fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>) -> Self {
+    fn new(_active_timeout: i64, _conn_limit: usize, _preordered_nodes: Vec<String>, _read_strategy: ReadStrategy) -> Self {
         MockNetworker {
             events: Vec::new(),
         }
@@ -382,6 +515,10 @@ impl Networker for MockNetworker {
     fn get_poll_items(&self) -> Vec<PollItem> {
         unimplemented!()
     }
+
+    fn take_latency(&self, _req_id: &str, _node_alias: &str) -> Option<StdDuration> {
+        None
+    }
 }
 
 
@@ -410,6 +547,35 @@ pub mod networker_tests {
         }
     }
 
+    mod node_stats {
+        use super::*;
+
+        #[test]
+        fn node_stats_on_reply_works() {
+            let mut stats = NodeStats::default();
+
+            stats.on_timeout();
+            stats.on_reply(100.0);
+            assert_eq!(Some(100.0), stats.latency_ms);
+            assert_eq!(0, stats.failures);
+
+            stats.on_reply(200.0);
+            assert_eq!(Some(100.0 + LATENCY_EWMA_ALPHA * 100.0), stats.latency_ms);
+        }
+
+        #[test]
+        fn node_stats_cmp_priority_works() {
+            let fast = NodeStats { latency_ms: Some(10.0), failures: 0 };
+            let slow = NodeStats { latency_ms: Some(500.0), failures: 0 };
+            let unknown = NodeStats { latency_ms: None, failures: 0 };
+            let failed = NodeStats { latency_ms: Some(1.0), failures: 1 };
+
+            assert_eq!(Ordering::Less, fast.cmp_priority(&slow));
+            assert_eq!(Ordering::Less, unknown.cmp_priority(&fast));
+            assert_eq!(Ordering::Greater, failed.cmp_priority(&slow));
+        }
+    }
+
     #[cfg(test)]
     mod networker {
         use std::ops::Sub;
@@ -418,12 +584,12 @@ pub mod networker_tests {
 
         #[test]
         pub fn networker_new_works() {
-            ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
         }
 
         #[test]
         pub fn networker_process_event_works() {
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(None);
         }
 
@@ -432,7 +598,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             assert_eq!(0, networker.nodes.len());
 
@@ -447,7 +613,7 @@ pub mod networker_tests {
             let handle = nodes_emulator::start(&mut txn);
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             assert!(networker.pool_connections.is_empty());
@@ -473,7 +639,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
             networker.process_event(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, None)));
@@ -496,7 +662,7 @@ pub mod networker_tests {
 
             let send_cnt = 2;
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec!["n2".to_string(), "n1".to_string()]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec!["n2".to_string(), "n1".to_string()], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
 
@@ -526,7 +692,7 @@ pub mod networker_tests {
             let handle_2 = nodes_emulator::start(&mut txn_2);
             let rn_2 = _remote_node(&txn_2);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn_1, rn_2])));
             networker.process_event(Some(NetworkerEvent::SendAllRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT, Some(vec![NODE_NAME.to_string()]))));
@@ -542,7 +708,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -565,7 +731,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -588,7 +754,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -617,7 +783,7 @@ pub mod networker_tests {
             let rn = _remote_node(&txn);
             let conn = PoolConnection::new(vec![rn.clone()], POOL_CON_ACTIVE_TO, vec![]);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.pool_connections.insert(1, conn);
@@ -634,7 +800,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
 
@@ -650,7 +816,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -668,7 +834,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
             networker.process_event(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT)));
@@ -687,7 +853,7 @@ pub mod networker_tests {
             let txn = nodes_emulator::node();
             let rn = _remote_node(&txn);
 
-            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![]);
+            let mut networker = ZMQNetworker::new(POOL_CON_ACTIVE_TO, MAX_REQ_PER_POOL_CON, vec![], ReadStrategy::Preordered);
 
             networker.process_event(Some(NetworkerEvent::NodesStateUpdated(vec![rn])));
 
@@ -873,6 +1039,55 @@ pub mod networker_tests {
             assert!(!conn.has_active_requests());
         }
 
+        #[test]
+        fn pool_connection_on_node_reply_works_for_latency() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            let key = (REQ_ID.to_string(), NODE_NAME.to_string());
+            conn.sent.borrow_mut().insert(key.clone(), time::now().sub(Duration::milliseconds(100)));
+
+            conn._on_node_reply(REQ_ID, NODE_NAME);
+
+            assert!(conn.sent.borrow().is_empty());
+            assert!(conn.latencies.borrow_mut().remove(&key).unwrap() >= StdDuration::from_millis(100));
+        }
+
+        #[test]
+        fn pool_connection_on_node_message_works_for_ack_and_reply() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            let key = (REQ_ID.to_string(), NODE_NAME.to_string());
+            conn.sent.borrow_mut().insert(key.clone(), time::now());
+
+            conn._on_node_message(NODE_NAME, &json!({"op": "REQACK", "reqId": 1}).to_string());
+            assert!(conn.sent.borrow().contains_key(&key));
+            assert!(conn.node_stats.borrow().get(NODE_NAME).is_none());
+
+            conn._on_node_message(NODE_NAME, &json!({"op": "REPLY", "result": {"reqId": 1}}).to_string());
+            assert!(!conn.sent.borrow().contains_key(&key));
+            assert!(conn.node_stats.borrow().get(NODE_NAME).unwrap().latency_ms.is_some());
+        }
+
+        #[test]
+        fn pool_connection_on_node_message_works_for_reply_on_other_request() {
+            let txn = nodes_emulator::node();
+            let rn = _remote_node(&txn);
+
+            let conn = PoolConnection::new(vec![rn], POOL_CON_ACTIVE_TO, vec![]);
+
+            let key = (REQ_ID.to_string(), NODE_NAME.to_string());
+            conn.sent.borrow_mut().insert(key.clone(), time::now());
+
+            conn._on_node_message(NODE_NAME, &json!({"op": "REQNACK", "reqId": 2}).to_string());
+            assert!(conn.sent.borrow().contains_key(&key));
+        }
+
         #[test]
         fn pool_connection_get_socket_works() {
             let txn = nodes_emulator::node();
@@ -990,6 +1205,74 @@ pub mod networker_tests {
             }
         }
 
+        #[test]
+        fn pool_connection_resend_works_for_adaptive_strategy() {
+            let mut txn_1 = nodes_emulator::node();
+            let handle_1 = nodes_emulator::start(&mut txn_1);
+            let rn_1 = _remote_node(&txn_1);
+
+            let mut txn_2 = nodes_emulator::node_2();
+            let handle_2 = nodes_emulator::start(&mut txn_2);
+            let rn_2 = _remote_node(&txn_2);
+
+            let mut conn = PoolConnection::with_read_strategy(vec![rn_1, rn_2], POOL_CON_ACTIVE_TO, vec![],
+                                                              ReadStrategy::Adaptive, Rc::new(RefCell::new(HashMap::new())));
+
+            conn.send_request(Some(NetworkerEvent::SendOneRequest(MESSAGE.to_string(), REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+            conn.send_request(Some(NetworkerEvent::Resend(REQ_ID.to_string(), POOL_ACK_TIMEOUT))).unwrap();
+
+            // all nodes are already asked, so fallback doesn't send anything
+            for handle in vec![handle_1, handle_2] {
+                assert_eq!(MESSAGE.to_string(), nodes_emulator::next(&handle).unwrap());
+                assert!(nodes_emulator::next(&handle).is_none());
+            }
+        }
+
+        #[test]
+        fn pool_connection_read_order_works_for_adaptive_strategy() {
+            let txn_1 = nodes_emulator::node();
+            let rn_1 = _remote_node(&txn_1);
+
+            let txn_2 = nodes_emulator::node_2();
+            let rn_2 = _remote_node(&txn_2);
+
+            let node_stats = Rc::new(RefCell::new(HashMap::new()));
+            node_stats.borrow_mut().insert(rn_1.name.clone(), NodeStats { latency_ms: Some(300.0), failures: 0 });
+            node_stats.borrow_mut().insert(rn_2.name.clone(), NodeStats { latency_ms: Some(20.0), failures: 0 });
+
+            let conn = PoolConnection::with_read_strategy(vec![rn_1.clone(), rn_2.clone()], POOL_CON_ACTIVE_TO, vec![rn_1.name.clone()],
+                                                          ReadStrategy::Adaptive, node_stats.clone());
+
+            let order = conn._read_order();
+            assert_eq!(rn_2.name, conn.nodes[order[0]].name);
+            assert_eq!(rn_1.name, conn.nodes[order[1]].name);
+
+            node_stats.borrow_mut().get_mut(&rn_2.name).unwrap().on_timeout();
+
+            let order = conn._read_order();
+            assert_eq!(rn_1.name, conn.nodes[order[0]].name);
+            assert_eq!(rn_2.name, conn.nodes[order[1]].name);
+        }
+
+        #[test]
+        fn pool_connection_read_order_works_for_preordered_strategy() {
+            let txn_1 = nodes_emulator::node();
+            let rn_1 = _remote_node(&txn_1);
+
+            let txn_2 = nodes_emulator::node_2();
+            let rn_2 = _remote_node(&txn_2);
+
+            let node_stats = Rc::new(RefCell::new(HashMap::new()));
+            node_stats.borrow_mut().insert(rn_2.name.clone(), NodeStats { latency_ms: Some(20.0), failures: 0 });
+
+            let conn = PoolConnection::with_read_strategy(vec![rn_1.clone(), rn_2.clone()], POOL_CON_ACTIVE_TO, vec![rn_1.name.clone()],
+                                                          ReadStrategy::Preordered, node_stats);
+
+            let order = conn._read_order();
+            assert_eq!(rn_1.name, conn.nodes[order[0]].name);
+        }
+
         #[test]
         fn pool_connection_send_works_for_invalid_node() {
             let txn = nodes_emulator::node();
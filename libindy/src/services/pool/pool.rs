@@ -3,26 +3,32 @@ use std::collections::HashMap;
 use std::collections::VecDeque;
 use std::marker::PhantomData;
 use std::rc::Rc;
+use std::sync::{Arc, Mutex};
 use std::thread;
 use std::thread::JoinHandle;
 
 use failure::Context;
+use serde_json::Value as SJsonValue;
 
 use commands::Command;
 use commands::CommandExecutor;
 use commands::ledger::LedgerCommand;
 use commands::pool::PoolCommand;
 use domain::ledger::request::ProtocolVersion;
-use domain::pool::PoolOpenConfig;
+use domain::pool::{LocalFaults, PoolOpenConfig, ReadStrategy, WriteRetryPolicy};
 use errors::prelude::*;
 use services::ledger::merkletree::merkletree::MerkleTree;
 use services::pool::commander::Commander;
 use services::pool::events::*;
+use services::pool::local_ledger::{LocalLedger, LocalLedgerNetworker};
 use services::pool::merkle_tree_factory;
+use services::pool::metrics;
 use services::pool::networker::{Networker, ZMQNetworker};
 use services::pool::request_handler::{RequestHandler, RequestHandlerImpl};
+use services::pool::traffic::{Capture, RecordingNetworker, ReplayNetworker, TrafficRecorder};
 use rust_base58::{FromBase58, ToBase58};
-use services::pool::types::{LedgerStatus, RemoteNode};
+use services::pool::types::{LedgerStatus, NodeTransactionV1, RemoteNode};
+use time;
 use utils::crypto::ed25519_sign;
 
 use super::ursa::bls::VerKey;
@@ -316,18 +322,19 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                             PoolState::Terminated(state.into())
                         }
                     }
-                    PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree) => {
+                    PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree, multi_signature) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle_tree) {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             let mut request_handler = R::new(state.networker.clone(), _get_f(nodes.len()), &vec![], &nodes, &pool_name, timeout, extended_timeout);
-                            request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root)));
+                            request_handler.process_event(Some(RequestEvent::CatchupReq(merkle_tree, target_mt_size, target_mt_root, multi_signature)));
                             PoolState::SyncCatchup((request_handler, state).into())
                         } else {
                             PoolState::Terminated(state.into())
                         }
                     }
-                    PoolEvent::Synced(merkle) => {
+                    PoolEvent::Synced(merkle, multi_signature) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle) {
+                            _update_snapshot(&pool_name, &merkle, multi_signature);
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh);
                             PoolState::Active((state, nodes).into())
@@ -434,8 +441,9 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
                         PoolState::Closed(state.into())
                     }
                     PoolEvent::NodesBlacklisted => PoolState::Terminated(state.into()),
-                    PoolEvent::Synced(merkle) => {
+                    PoolEvent::Synced(merkle, multi_signature) => {
                         if let Ok((nodes, remotes)) = _get_nodes_and_remotes(&merkle).map_err(map_err_err!()) {
+                            _update_snapshot(&pool_name, &merkle, multi_signature);
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::NodesStateUpdated(remotes)));
                             _send_open_refresh_ack(state.cmd_id, id, state.refresh);
                             PoolState::Active((state, nodes).into())
@@ -460,6 +468,24 @@ impl<T: Networker, R: RequestHandler<T>> PoolSM<T, R> {
             PoolState::Closed(_) => true,
         }
     }
+
+    fn catchup_state(&self) -> &'static str {
+        match self.state {
+            PoolState::Initialization(_) => "initialization",
+            PoolState::GettingCatchupTarget(_) => "getting_catchup_target",
+            PoolState::Active(_) => "active",
+            PoolState::SyncCatchup(_) => "sync_catchup",
+            PoolState::Terminated(_) => "terminated",
+            PoolState::Closed(_) => "closed",
+        }
+    }
+
+    fn nodes(&self) -> Option<&HashMap<String, Option<VerKey>>> {
+        match self.state {
+            PoolState::Active(ref state) => Some(&state.nodes),
+            _ => None
+        }
+    }
 }
 
 pub struct Pool<S: Networker, R: RequestHandler<S>> {
@@ -471,12 +497,20 @@ pub struct Pool<S: Networker, R: RequestHandler<S>> {
     extended_timeout: i64,
     active_timeout: i64,
     conn_limit: usize,
+    batch_limit: usize,
     preordered_nodes: Vec<String>,
+    read_strategy: ReadStrategy,
+    local_ledger: Option<Arc<Mutex<LocalLedger>>>,
+    traffic_capture: Option<Capture>,
+    traffic_recorder: Option<TrafficRecorder>,
+    write_retry: Option<WriteRetryPolicy>,
+    local_faults: LocalFaults,
 }
 
 impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn new(name: &str, id: i32, config: PoolOpenConfig) -> Self {
         trace!("Pool::new name {}, id {}, config {:?}", name, id, config);
+        let local_faults = config.local_faults();
         Pool {
             _pd: PhantomData::<(S, R)>,
             worker: None,
@@ -486,10 +520,35 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
             extended_timeout: config.extended_timeout,
             active_timeout: config.conn_active_timeout,
             conn_limit: config.conn_limit,
+            batch_limit: config.batch_limit.unwrap_or(config.conn_limit),
             preordered_nodes: config.preordered_nodes,
+            read_strategy: config.read_strategy,
+            write_retry: config.write_retry,
+            local_faults,
+            local_ledger: None,
+            traffic_capture: None,
+            traffic_recorder: None,
         }
     }
 
+    /// Makes pool talk to in-process emulated nodes instead of real ones.
+    pub fn with_local_ledger(mut self, local_ledger: Arc<Mutex<LocalLedger>>) -> Self {
+        self.local_ledger = Some(local_ledger);
+        self
+    }
+
+    /// Makes pool replay node replies from traffic capture instead of talking to real nodes.
+    pub fn with_traffic_capture(mut self, capture: Capture) -> Self {
+        self.traffic_capture = Some(capture);
+        self
+    }
+
+    /// Makes pool record its traffic.
+    pub fn with_traffic_recorder(mut self, recorder: TrafficRecorder) -> Self {
+        self.traffic_recorder = Some(recorder);
+        self
+    }
+
     pub fn work(&mut self, cmd_socket: zmq::Socket) {
         let name = self.name.as_str().to_string();
         let id = self.id.clone();
@@ -498,11 +557,42 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
         let active_timeout = self.active_timeout;
         let conn_limit = self.conn_limit;
         let preordered_nodes = self.preordered_nodes.clone();
+        let read_strategy = self.read_strategy;
+        let recorder = self.traffic_recorder.take();
+
+        if let Some(capture) = self.traffic_capture.take() {
+            self.worker = Some(thread::spawn(move || {
+                let networker = ReplayNetworker::with_capture(capture, preordered_nodes);
+                _work_with_networker(cmd_socket, name, id, timeout, extended_timeout, networker, recorder);
+            }));
+            return;
+        }
+
+        if let Some(local_ledger) = self.local_ledger.clone() {
+            let local_faults = self.local_faults.clone();
+            self.worker = Some(thread::spawn(move || {
+                let networker = LocalLedgerNetworker::with_ledger(local_ledger, preordered_nodes, local_faults);
+                _work_with_networker(cmd_socket, name, id, timeout, extended_timeout, networker, recorder);
+            }));
+            return;
+        }
+
+        if let Some(recorder) = recorder {
+            self.worker = Some(thread::spawn(move || {
+                let networker = S::new(active_timeout, conn_limit, preordered_nodes, read_strategy);
+                let networker = RecordingNetworker::with_recorder(networker, recorder);
+                let mut pool_thread: PoolThread<RecordingNetworker<S>, RequestHandlerImpl<RecordingNetworker<S>>> =
+                    PoolThread::with_networker(cmd_socket, name, id, timeout, extended_timeout, networker);
+                pool_thread.work();
+            }));
+            return;
+        }
+
         self.worker = Some(thread::spawn(move || {
             let mut pool_thread: PoolThread<S, R> = PoolThread::new(cmd_socket, name, id,
                                                                     timeout, extended_timeout,
                                                                     active_timeout, conn_limit,
-                                                                    preordered_nodes);
+                                                                    preordered_nodes, read_strategy);
             pool_thread.work();
         }));
     }
@@ -514,6 +604,31 @@ impl<S: Networker, R: RequestHandler<S>> Pool<S, R> {
     pub fn get_id(&self) -> i32 {
         self.id
     }
+
+    pub fn get_write_retry(&self) -> Option<&WriteRetryPolicy> {
+        self.write_retry.as_ref()
+    }
+
+    pub fn get_batch_limit(&self) -> usize {
+        self.batch_limit
+    }
+}
+
+fn _work_with_networker<N: Networker>(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64,
+                                      networker: N, recorder: Option<TrafficRecorder>) {
+    match recorder {
+        Some(recorder) => {
+            let networker = RecordingNetworker::with_recorder(networker, recorder);
+            let mut pool_thread: PoolThread<RecordingNetworker<N>, RequestHandlerImpl<RecordingNetworker<N>>> =
+                PoolThread::with_networker(cmd_socket, name, id, timeout, extended_timeout, networker);
+            pool_thread.work();
+        }
+        None => {
+            let mut pool_thread: PoolThread<N, RequestHandlerImpl<N>> =
+                PoolThread::with_networker(cmd_socket, name, id, timeout, extended_timeout, networker);
+            pool_thread.work();
+        }
+    }
 }
 
 struct PoolThread<S: Networker, R: RequestHandler<S>> {
@@ -524,8 +639,13 @@ struct PoolThread<S: Networker, R: RequestHandler<S>> {
 }
 
 impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
-    pub fn new(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>) -> Self {
-        let networker = Rc::new(RefCell::new(S::new(active_timeout, conn_limit, preordered_nodes)));
+    pub fn new(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64, active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, read_strategy: ReadStrategy) -> Self {
+        PoolThread::with_networker(cmd_socket, name, id, timeout, extended_timeout,
+                                   S::new(active_timeout, conn_limit, preordered_nodes, read_strategy))
+    }
+
+    pub fn with_networker(cmd_socket: zmq::Socket, name: String, id: i32, timeout: i64, extended_timeout: i64, networker: S) -> Self {
+        let networker = Rc::new(RefCell::new(networker));
         PoolThread {
             pool_sm: Some(PoolSM::new(networker.clone(), &name, id, timeout, extended_timeout)),
             events: VecDeque::new(),
@@ -550,7 +670,26 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
             trace!("received pool event: {:?}", pe);
             match pe {
                 Some(pe) => {
+                    let prev_state = self.pool_sm.as_ref().map(|w| w.catchup_state());
+                    let synced = match pe {
+                        PoolEvent::Synced(..) => true,
+                        _ => false
+                    };
                     self.pool_sm = self.pool_sm.take().map(|w| w.handle_event(pe));
+                    if let Some(ref pool_sm) = self.pool_sm {
+                        let state = pool_sm.catchup_state();
+                        metrics::set_catchup_state(&pool_sm.pool_name, state);
+
+                        if prev_state != Some(state) || synced {
+                            if let Some(nodes) = pool_sm.nodes() {
+                                super::set_pool_nodes(&pool_sm.pool_name, _get_f(nodes.len()), nodes);
+                            }
+                        }
+
+                        if prev_state != Some(state) {
+                            _send_state_changed(pool_sm, prev_state);
+                        }
+                    }
                 }
                 _ => ()
             }
@@ -592,6 +731,25 @@ impl<S: Networker, R: RequestHandler<S>> PoolThread<S, R> {
     }
 }
 
+fn _send_state_changed<T: Networker, R: RequestHandler<T>>(pool_sm: &PoolSM<T, R>, prev_state: Option<&str>) {
+    let mut event = json!({
+        "poolHandle": pool_sm.id,
+        "poolName": pool_sm.pool_name,
+        "state": pool_sm.catchup_state(),
+        "previousState": prev_state,
+        "timestamp": time::get_time().sec,
+    });
+
+    if let Some(nodes) = pool_sm.nodes() {
+        let mut aliases: Vec<&String> = nodes.keys().collect();
+        aliases.sort();
+        event["nodes"] = json!(aliases);
+    }
+
+    CommandExecutor::instance().send(Command::Pool(
+        PoolCommand::StateChanged(pool_sm.id, event.to_string()))).unwrap();
+}
+
 fn _get_f(cnt: usize) -> usize {
     if cnt < 4 {
         return 0;
@@ -605,9 +763,9 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
     timeout: i64,
     extended_timeout: i64) -> IndyResult<R>
 {
-    let mut merkle = merkle_tree_factory::create(pool_name)?;
+    let (mut merkle, nodes) = merkle_tree_factory::create_with_nodes(pool_name)?;
 
-    let (nodes, remotes) = match _get_nodes_and_remotes(&merkle) {
+    let (nodes, remotes) = match _nodes_and_remotes(&nodes) {
         Ok(n) => n,
         Err(err) => {
             match merkle_tree_factory::drop_cache(pool_name) {
@@ -626,6 +784,12 @@ fn _get_request_handler_with_ledger_status_sent<T: Networker, R: RequestHandler<
     Ok(request_handler)
 }
 
+fn _update_snapshot(pool_name: &str, merkle: &MerkleTree, multi_signature: Option<SJsonValue>) {
+    if let Err(err) = merkle_tree_factory::update_snapshot(pool_name, merkle, multi_signature) {
+        warn!("Can't persist pool ledger snapshot: {:?}", err);
+    }
+}
+
 fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus{
     let protocol_version = ProtocolVersion::get();
 
@@ -636,12 +800,16 @@ fn _ledger_status(merkle: &MerkleTree) -> LedgerStatus{
         ppSeqNo: None,
         viewNo: None,
         protocolVersion: if protocol_version > 1 { Some(protocol_version) } else { None },
+        multiSignature: None,
     }
 }
 
 fn _get_nodes_and_remotes(merkle: &MerkleTree) -> IndyResult<(HashMap<String, Option<VerKey>>, Vec<RemoteNode>)> {
     let nodes = merkle_tree_factory::build_node_state(merkle)?;
+    _nodes_and_remotes(&nodes)
+}
 
+fn _nodes_and_remotes(nodes: &HashMap<String, NodeTransactionV1>) -> IndyResult<(HashMap<String, Option<VerKey>>, Vec<RemoteNode>)> {
     Ok(nodes.iter().map(|(_, txn)| {
         let node_alias = txn.txn.data.data.alias.clone();
 
@@ -797,7 +965,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_new_initialization_works() {
-            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "name", 1, 0, 0);
+            let _p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "name", 1, 0, 0);
         }
 
         #[test]
@@ -807,7 +975,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_check_cache_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_check_cache_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_check_cache_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             assert_match!(PoolState::GettingCatchupTarget(_), p.state);
 
@@ -817,7 +985,7 @@ mod tests {
         #[test]
         pub fn pool_wrapper_check_cache_works_for_no_pool_created() {
             let p: PoolSM<MockNetworker, MockRequestHandler> =
-                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))),
+                PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))),
                             "pool_wrapper_check_cache_works_for_no_pool_created", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             assert_match!(PoolState::Terminated(_), p.state);
@@ -825,7 +993,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_terminated_close_works() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_close_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_terminated_close_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Close(2));
             assert_match!(PoolState::Closed(_), p.state);
@@ -834,7 +1002,7 @@ mod tests {
         #[test]
         pub fn pool_wrapper_terminated_refresh_works() {
             test::cleanup_pool("pool_wrapper_terminated_refresh_works");
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_terminated_refresh_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_terminated_refresh_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
 
             ProtocolVersion::set(2);
@@ -851,7 +1019,7 @@ mod tests {
                 pool_name: "pool_wrapper_terminated_timeout_works".to_string(),
                 id: 1,
                 state: PoolState::Terminated(TerminatedState {
-                    networker: Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))),
+                    networker: Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))),
                 }),
                 timeout: 0,
                 extended_timeout: 0,
@@ -871,7 +1039,7 @@ mod tests {
 
         #[test]
         pub fn pool_wrapper_cloe_works_from_initialization() {
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_cloe_works_from_initialization", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_cloe_works_from_initialization", 1, 0, 0);
             let p = p.handle_event(PoolEvent::Close(1));
             assert_match!(PoolState::Closed(_), p.state);
         }
@@ -883,7 +1051,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_close_works_from_getting_catchup_target");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_close_works_from_getting_catchup_target", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_close_works_from_getting_catchup_target", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::Close(2));
             assert_match!(PoolState::Closed(_), p.state);
@@ -898,7 +1066,7 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_catchup_target_not_found_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_catchup_target_not_found_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_catchup_target_not_found_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             let p = p.handle_event(PoolEvent::CatchupTargetNotFound(err_msg(IndyErrorKind::PoolTimeout, "Pool timeout")));
             assert_match!(PoolState::Terminated(_), p.state);
@@ -913,9 +1081,9 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_getting_catchup_target_synced_works");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_getting_catchup_target_synced_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             assert_match!(PoolState::Active(_), p.state);
 
             test::cleanup_storage("pool_wrapper_getting_catchup_target_synced_works");
@@ -928,10 +1096,10 @@ mod tests {
             ProtocolVersion::set(2);
             _write_genesis_txns("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error");
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_synced_works_for_node_state_error", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_getting_catchup_target_synced_works_for_node_state_error", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             ProtocolVersion::set(1);
-            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error").unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error").unwrap(), None));
             assert_match!(PoolState::Terminated(_), p.state);
 
             test::cleanup_storage("pool_wrapper_getting_catchup_target_synced_works_for_node_state_error");
@@ -946,9 +1114,9 @@ mod tests {
 
             let mt = merkle_tree_factory::create("pool_wrapper_getting_catchup_target_catchup_target_found_works").unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_catchup_target_found_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_getting_catchup_target_catchup_target_found_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
+            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt, None));
             assert_match!(PoolState::SyncCatchup(_), p.state);
 
             test::cleanup_storage("pool_wrapper_getting_catchup_target_catchup_target_found_works");
@@ -963,10 +1131,10 @@ mod tests {
 
             let mt = merkle_tree_factory::create("pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error").unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
             ProtocolVersion::set(1);
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
+            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt, None));
             assert_match!(PoolState::Terminated(_), p.state);
 
             test::cleanup_storage("pool_wrapper_getting_catchup_target_catchup_target_found_works_for_node_state_error");
@@ -981,9 +1149,9 @@ mod tests {
 
             let mt = merkle_tree_factory::create("pool_wrapper_sync_catchup_close_works").unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_sync_catchup_close_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_sync_catchup_close_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
+            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt, None));
             let p = p.handle_event(PoolEvent::Close(2));
             assert_match!(PoolState::Closed(_), p.state);
 
@@ -999,10 +1167,10 @@ mod tests {
 
             let mt = merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works").unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_sync_catchup_synced_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_sync_catchup_synced_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
-            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works").unwrap()));
+            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt, None));
+            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works").unwrap(), None));
             assert_match!(PoolState::Active(_), p.state);
 
             test::cleanup_storage("pool_wrapper_sync_catchup_synced_works");
@@ -1017,11 +1185,11 @@ mod tests {
 
             let mt = merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works_for_node_state_error").unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_sync_catchup_synced_works_for_node_state_error", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_sync_catchup_synced_works_for_node_state_error", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt));
+            let p = p.handle_event(PoolEvent::CatchupTargetFound(mt.root_hash().to_vec(), mt.count, mt, None));
             ProtocolVersion::set(1);
-            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works_for_node_state_error").unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(merkle_tree_factory::create("pool_wrapper_sync_catchup_synced_works_for_node_state_error").unwrap(), None));
             assert_match!(PoolState::Terminated(_), p.state);
 
             test::cleanup_storage("pool_wrapper_sync_catchup_synced_works_for_node_state_error");
@@ -1041,9 +1209,9 @@ mod tests {
                 }
             }).to_string();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_send_request_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_active_send_request_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1070,9 +1238,9 @@ mod tests {
                 }
             }).to_string();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_send_request_works_for_no_req_id", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_active_send_request_works_for_no_req_id", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1113,9 +1281,9 @@ mod tests {
 
             let rep = serde_json::to_string(&rep).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_node_reply_works", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_active_node_reply_works", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
@@ -1143,9 +1311,9 @@ mod tests {
                 }
             }).to_string();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_sends_requests_to_two_nodes", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_sends_requests_to_two_nodes", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             assert_match!(PoolState::Active(_), p.state);
             match p.state {
@@ -1186,9 +1354,9 @@ mod tests {
 
             let rep = serde_json::to_string(&rep).unwrap();
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_node_reply_works_for_no_request", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_active_node_reply_works_for_no_request", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep, "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
@@ -1219,9 +1387,9 @@ mod tests {
 
             let rep = r#"{}"#;
 
-            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![]))), "pool_wrapper_active_node_reply_works_for_invalid_reply", 1, 0, 0);
+            let p: PoolSM<MockNetworker, MockRequestHandler> = PoolSM::new(Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered))), "pool_wrapper_active_node_reply_works_for_invalid_reply", 1, 0, 0);
             let p = p.handle_event(PoolEvent::CheckCache(1));
-            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap()));
+            let p = p.handle_event(PoolEvent::Synced(MerkleTree::from_vec(vec![]).unwrap(), None));
             let p = p.handle_event(PoolEvent::SendRequest(3, req, None, None));
             let p = p.handle_event(PoolEvent::NodeReply(rep.to_string(), "node".to_string()));
             assert_match!(PoolState::Active(_), p.state);
@@ -22,9 +22,11 @@ use services::pool::events::PoolEvent;
 use services::pool::events::RequestEvent;
 use services::pool::get_last_signed_time;
 use services::pool::merkle_tree_factory;
+use services::pool::metrics;
+use services::pool::metrics::NodeEvent;
 use services::pool::networker::Networker;
 use services::pool::state_proof;
-use services::pool::types::CatchupRep;
+use services::pool::types::{CatchupRep, CatchupReq, LedgerStatus, Message};
 use services::pool::types::HashableValue;
 
 use super::ursa::bls::Generator;
@@ -32,7 +34,7 @@ use super::ursa::bls::VerKey;
 
 use std::hash::{Hash, Hasher};
 use log_derive::logfn;
-use rust_base58::FromBase58;
+use rust_base58::{FromBase58, ToBase58};
 
 struct RequestSM<T: Networker> {
     f: usize,
@@ -46,12 +48,14 @@ struct RequestSM<T: Networker> {
 }
 
 /// Transitions of request state
-/// Start -> Start, Single, Consensus, CatchupSingle, CatchupConsensus, Full, Finish
+/// Start -> Start, Single, Consensus, CatchupSingle, CatchupConsensus, Full, LedgerStatusConsensus, LedgerCatchup, Finish
 /// Single -> Single, Finish
 /// Consensus -> Consensus, Finish
 /// CatchupSingle -> CatchupSingle, Finish
 /// CatchupConsensus -> CatchupConsensus, Finish
 /// Full -> Full, Finish
+/// LedgerStatusConsensus -> LedgerStatusConsensus, Finish
+/// LedgerCatchup -> LedgerCatchup, Finish
 /// Finish -> Finish
 enum RequestState<T: Networker> {
     Start(StartState<T>),
@@ -60,6 +64,8 @@ enum RequestState<T: Networker> {
     CatchupSingle(CatchupSingleState<T>),
     CatchupConsensus(CatchupConsensusState<T>),
     Full(FullState<T>),
+    LedgerStatusConsensus(LedgerStatusConsensusState<T>),
+    LedgerCatchup(LedgerCatchupState<T>),
     Finish(FinishState),
 }
 
@@ -120,6 +126,7 @@ struct ConsensusState<T: Networker> {
 
 struct CatchupConsensusState<T: Networker> {
     replies: HashMap<(String, usize, Option<Vec<String>>), HashSet<String>>,
+    multi_signatures: HashMap<String, SJsonValue>,
     networker: Rc<RefCell<T>>,
     merkle_tree: MerkleTree,
 }
@@ -127,6 +134,7 @@ struct CatchupConsensusState<T: Networker> {
 struct CatchupSingleState<T: Networker> {
     target_mt_root: Vec<u8>,
     target_mt_size: usize,
+    multi_signature: Option<SJsonValue>,
     merkle_tree: MerkleTree,
     networker: Rc<RefCell<T>>,
     req_id: String,
@@ -147,6 +155,24 @@ struct FullState<T: Networker> {
     networker: Rc<RefCell<T>>,
 }
 
+/// Size and root of a ledger requested by user, not only of the pool ledger.
+struct LedgerStatusConsensusState<T: Networker> {
+    ledger_id: usize,
+    req_id: String,
+    replies: HashMap<(String, usize), HashSet<String>>,
+    timeout_nodes: HashSet<String>,
+    networker: Rc<RefCell<T>>,
+}
+
+/// Transactions of a ledger requested by user. Consistency proof is verified by requester
+/// as it is the only one who knows merkle tree of preceding transactions.
+struct LedgerCatchupState<T: Networker> {
+    catchup_req: CatchupReq,
+    req_id: String,
+    attempts: usize,
+    networker: Rc<RefCell<T>>,
+}
+
 struct FinishState {}
 
 impl<T: Networker> From<(StartState<T>, Option<Vec<u8>>, (Option<u64>, Option<u64>))> for SingleState<T> {
@@ -177,17 +203,19 @@ impl<T: Networker> From<(MerkleTree, StartState<T>)> for CatchupConsensusState<T
     fn from((merkle_tree, state): (MerkleTree, StartState<T>)) -> Self {
         CatchupConsensusState {
             replies: HashMap::new(),
+            multi_signatures: HashMap::new(),
             networker: state.networker.clone(),
             merkle_tree,
         }
     }
 }
 
-impl<T: Networker> From<(MerkleTree, StartState<T>, Vec<u8>, usize, String)> for CatchupSingleState<T> {
-    fn from((merkle_tree, state, target_mt_root, target_mt_size, req_id): (MerkleTree, StartState<T>, Vec<u8>, usize, String)) -> Self {
+impl<T: Networker> From<(MerkleTree, StartState<T>, Vec<u8>, usize, Option<SJsonValue>, String)> for CatchupSingleState<T> {
+    fn from((merkle_tree, state, target_mt_root, target_mt_size, multi_signature, req_id): (MerkleTree, StartState<T>, Vec<u8>, usize, Option<SJsonValue>, String)) -> Self {
         CatchupSingleState {
             target_mt_root,
             target_mt_size,
+            multi_signature,
             networker: state.networker.clone(),
             merkle_tree,
             req_id,
@@ -215,6 +243,29 @@ impl<T: Networker> From<(Option<Vec<String>>, StartState<T>)> for FullState<T> {
     }
 }
 
+impl<T: Networker> From<(usize, String, StartState<T>)> for LedgerStatusConsensusState<T> {
+    fn from((ledger_id, req_id, state): (usize, String, StartState<T>)) -> Self {
+        LedgerStatusConsensusState {
+            ledger_id,
+            req_id,
+            replies: HashMap::new(),
+            timeout_nodes: HashSet::new(),
+            networker: state.networker.clone(),
+        }
+    }
+}
+
+impl<T: Networker> From<(CatchupReq, String, StartState<T>)> for LedgerCatchupState<T> {
+    fn from((catchup_req, req_id, state): (CatchupReq, String, StartState<T>)) -> Self {
+        LedgerCatchupState {
+            catchup_req,
+            req_id,
+            attempts: 0,
+            networker: state.networker.clone(),
+        }
+    }
+}
+
 impl<T: Networker> RequestState<T> {
     fn finish() -> RequestState<T> {
         RequestState::Finish(FinishState {})
@@ -255,15 +306,15 @@ impl<T: Networker> RequestSM<T> {
                         state.networker.borrow_mut().process_event(ne);
                         (RequestState::CatchupConsensus((merkle, state).into()), None)
                     }
-                    RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root) => {
+                    RequestEvent::CatchupReq(merkle, target_mt_size, target_mt_root, multi_signature) => {
                         match build_catchup_req(&merkle, target_mt_size) {
                             Ok(Some((req_id, req_json))) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(req_json, req_id.clone(), timeout)));
-                                (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, req_id).into()), None)
+                                (RequestState::CatchupSingle((merkle, state, target_mt_root, target_mt_size, multi_signature, req_id).into()), None)
                             }
                             Ok(None) => {
                                 warn!("No transactions to catch up!");
-                                (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
+                                (RequestState::finish(), Some(PoolEvent::Synced(merkle, multi_signature)))
                             }
                             Err(e) => {
                                 _send_replies(&cmd_ids, Err(e));
@@ -307,6 +358,14 @@ impl<T: Networker> RequestSM<T> {
                         state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, None)));
                         (RequestState::Consensus(state.into()), None)
                     }
+                    RequestEvent::CustomLedgerStatusRequest(ls, msg, req_id) => {
+                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendAllRequest(msg, req_id.clone(), extended_timeout, None)));
+                        (RequestState::LedgerStatusConsensus((ls.ledgerId as usize, req_id, state).into()), None)
+                    }
+                    RequestEvent::CustomCatchupRequest(cr, msg, req_id) => {
+                        state.networker.borrow_mut().process_event(Some(NetworkerEvent::SendOneRequest(msg, req_id.clone(), timeout)));
+                        (RequestState::LedgerCatchup((cr, req_id, state).into()), None)
+                    }
                     _ => {
                         (RequestState::Start(state), None)
                     }
@@ -335,15 +394,14 @@ impl<T: Networker> RequestSM<T> {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
                                 (RequestState::Consensus(state), None)
                             } else {
-                                //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-                                _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                                _send_consensus_failure(&cmd_ids, &pool_name);
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                                 (RequestState::finish(), None)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
                             if state.denied_nodes.len() + state.replies.len() == nodes.len() {
-                                _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                                _send_consensus_failure(&cmd_ids, &pool_name);
                                 (RequestState::finish(), None)
                             } else {
                                 (RequestState::Consensus(state), None)
@@ -360,8 +418,7 @@ impl<T: Networker> RequestSM<T> {
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
                             (RequestState::Consensus(state.into()), None)
                         } else {
-                            //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-                            _send_replies(&cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+                            _send_consensus_failure(&cmd_ids, &pool_name);
                             state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
                             (RequestState::finish(), None)
                         }
@@ -400,11 +457,11 @@ impl<T: Networker> RequestSM<T> {
                                 _send_ok_replies(&cmd_ids, if cnt > f { &soonest } else { &raw_msg });
                                 (RequestState::finish(), None)
                             } else {
-                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                                (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, &pool_name), None)
                             }
                         } else {
                             state.denied_nodes.insert(node_alias.clone());
-                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                            (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, &pool_name), None)
                         }
                     }
                     RequestEvent::ReqACK(_, _, node_alias, req_id) => {
@@ -413,7 +470,7 @@ impl<T: Networker> RequestSM<T> {
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         state.timeout_nodes.insert(node_alias.clone());
-                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout), None)
+                        (state.try_to_continue(req_id, node_alias, &cmd_ids, nodes.len(), timeout, &pool_name), None)
                     }
                     RequestEvent::Terminate => {
                         _finish_request(&cmd_ids);
@@ -427,19 +484,19 @@ impl<T: Networker> RequestSM<T> {
                     RequestEvent::LedgerStatus(ls, Some(node_alias), _) => {
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
-                            ls.merkleRoot.clone(), ls.txnSeqNo, None,
+                            ls.merkleRoot.clone(), ls.txnSeqNo, None, ls.multiSignature,
                             node_alias, ls.merkleRoot, f, &nodes, &pool_name)
                     }
                     RequestEvent::ConsistencyProof(cp, node_alias) => {
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
-                            cp.newMerkleRoot, cp.seqNoEnd, Some(cp.hashes),
+                            cp.newMerkleRoot, cp.seqNoEnd, Some(cp.hashes), cp.multiSignature,
                             node_alias, cp.oldMerkleRoot, f, &nodes, &pool_name)
                     }
                     RequestEvent::Timeout(req_id, node_alias) => {
                         RequestSM::_catchup_target_handle_consensus_state(
                             state,
-                            "timeout".to_string(), 0, None,
+                            "timeout".to_string(), 0, None, None,
                             node_alias, req_id, f, &nodes, &pool_name)
                     }
 
@@ -456,7 +513,7 @@ impl<T: Networker> RequestSM<T> {
                         match _process_catchup_reply(&mut cr, &mut state.merkle_tree, &state.target_mt_root, state.target_mt_size, &pool_name) {
                             Ok(merkle) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
-                                (RequestState::finish(), Some(PoolEvent::Synced(merkle)))
+                                (RequestState::finish(), Some(PoolEvent::Synced(merkle, state.multi_signature)))
                             }
                             Err(_) => {
                                 state.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(state.req_id.clone(), timeout)));
@@ -495,6 +552,44 @@ impl<T: Networker> RequestSM<T> {
                     _ => (RequestState::Full(state), None),
                 }
             }
+            RequestState::LedgerStatusConsensus(state) => {
+                match re {
+                    RequestEvent::LedgerStatus(ls, Some(node_alias), _) =>
+                        (RequestSM::_ledger_status_handle_consensus_state(
+                            state, Some((ls.merkleRoot, ls.txnSeqNo)), node_alias, f, &cmd_ids, &nodes, &pool_name), None),
+                    RequestEvent::ConsistencyProof(cp, node_alias) =>
+                        (RequestSM::_ledger_status_handle_consensus_state(
+                            state, Some((cp.newMerkleRoot, cp.seqNoEnd)), node_alias, f, &cmd_ids, &nodes, &pool_name), None),
+                    RequestEvent::Timeout(_, node_alias) =>
+                        (RequestSM::_ledger_status_handle_consensus_state(
+                            state, None, node_alias, f, &cmd_ids, &nodes, &pool_name), None),
+                    RequestEvent::Terminate => {
+                        _finish_request(&cmd_ids);
+                        (RequestState::finish(), None)
+                    }
+                    _ => (RequestState::LedgerStatusConsensus(state), None)
+                }
+            }
+            RequestState::LedgerCatchup(state) => {
+                match re {
+                    RequestEvent::CatchupRep(rep, node_alias) => {
+                        if state.is_reply_complete(&rep) {
+                            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
+                            _send_replies(&cmd_ids, serde_json::to_string(&Message::CatchupRep(rep))
+                                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize CatchupRep"));
+                            (RequestState::finish(), None)
+                        } else {
+                            (state.try_next_node(node_alias, &cmd_ids, nodes.len(), timeout), None)
+                        }
+                    }
+                    RequestEvent::Timeout(_, node_alias) => (state.try_next_node(node_alias, &cmd_ids, nodes.len(), timeout), None),
+                    RequestEvent::Terminate => {
+                        _finish_request(&cmd_ids);
+                        (RequestState::finish(), None)
+                    }
+                    _ => (RequestState::LedgerCatchup(state), None)
+                }
+            }
             RequestState::Finish(state) => (RequestState::Finish(state), None)
         };
         (RequestSM::step(f, cmd_ids, nodes, generator, pool_name, timeout, extended_timeout, state), event)
@@ -507,7 +602,9 @@ impl<T: Networker> RequestSM<T> {
             RequestState::Single(_) |
             RequestState::CatchupSingle(_) |
             RequestState::CatchupConsensus(_) |
-            RequestState::Full(_) => false,
+            RequestState::Full(_) |
+            RequestState::LedgerStatusConsensus(_) |
+            RequestState::LedgerCatchup(_) => false,
             RequestState::Finish(_) => true
         }
     }
@@ -543,12 +640,55 @@ impl<T: Networker> RequestSM<T> {
         }
     }
 
+    fn _ledger_status_handle_consensus_state(mut state: LedgerStatusConsensusState<T>,
+                                             status: Option<(String, usize)>, node_alias: String,
+                                             f: usize, cmd_ids: &Vec<i32>,
+                                             nodes: &HashMap<String, Option<VerKey>>,
+                                             pool_name: &str) -> RequestState<T> {
+        if state.has_responded(&node_alias) {
+            return RequestState::LedgerStatusConsensus(state);
+        }
+
+        match status {
+            Some(status) => { state.replies.entry(status).or_insert_with(HashSet::new).insert(node_alias.clone()); }
+            None => { state.timeout_nodes.insert(node_alias.clone()); }
+        }
+
+        let agreed_status = state.replies.iter()
+            .find(|&(_, node_aliases)| node_aliases.len() > f)
+            .map(|(status, _)| status.clone());
+
+        if let Some((merkle_root, txn_seq_no)) = agreed_status {
+            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
+            let ls = LedgerStatus {
+                txnSeqNo: txn_seq_no,
+                merkleRoot: merkle_root,
+                ledgerId: state.ledger_id as u8,
+                ppSeqNo: None,
+                viewNo: None,
+                protocolVersion: None,
+                multiSignature: None,
+            };
+            _send_replies(cmd_ids, serde_json::to_string(&Message::LedgerStatus(ls))
+                .to_indy(IndyErrorKind::InvalidState, "Cannot serialize LedgerStatus"));
+            RequestState::finish()
+        } else if state.is_consensus_reachable(f, nodes.len()) {
+            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), Some(node_alias))));
+            RequestState::LedgerStatusConsensus(state)
+        } else {
+            state.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(state.req_id.clone(), None)));
+            _send_consensus_failure(cmd_ids, pool_name);
+            RequestState::finish()
+        }
+    }
+
     fn _catchup_target_handle_consensus_state(mut state: CatchupConsensusState<T>,
                                               mt_root: String, sz: usize, cons_proof: Option<Vec<String>>,
+                                              multi_signature: Option<SJsonValue>,
                                               node_alias: String, req_id: String,
                                               f: usize, nodes: &HashMap<String, Option<VerKey>>,
                                               pool_name: &str) -> (RequestState<T>, Option<PoolEvent>) {
-        let (finished, result) = RequestSM::_process_catchup_target(mt_root, sz, cons_proof,
+        let (finished, result) = RequestSM::_process_catchup_target(mt_root, sz, cons_proof, multi_signature,
                                                                     &node_alias, &mut state, f, nodes, pool_name);
 
         match (finished, result) {
@@ -570,11 +710,20 @@ impl<T: Networker> RequestSM<T> {
     fn _process_catchup_target(merkle_root: String,
                                txn_seq_no: usize,
                                hashes: Option<Vec<String>>,
+                               multi_signature: Option<SJsonValue>,
                                node_alias: &str,
                                state: &mut CatchupConsensusState<T>,
                                f: usize,
                                nodes: &HashMap<String, Option<VerKey>>,
                                pool_name: &str) -> (bool, Option<PoolEvent>) {
+        // multi-signature is only kept to be persisted with pool ledger snapshot and verified on its load
+        if let Some(multi_signature) = multi_signature {
+            if multi_signature["value"]["ledger_id"].as_u64() == Some(0)
+                && multi_signature["value"]["txn_root_hash"].as_str() == Some(merkle_root.as_str()) {
+                state.multi_signatures.insert(merkle_root.clone(), multi_signature);
+            }
+        }
+
         let key = (merkle_root, txn_seq_no, hashes);
         let contains = state.replies.get_mut(&key)
             .map(|set| { set.insert(node_alias.to_string()); })
@@ -590,11 +739,19 @@ impl<T: Networker> RequestSM<T> {
                                               f,
                                               &pool_name) {
             Ok(CatchupProgress::InProgress) => (false, None),
-            Ok(CatchupProgress::NotNeeded(merkle_tree)) => (true, Some(PoolEvent::Synced(merkle_tree))),
+            Ok(CatchupProgress::NotNeeded(merkle_tree)) => {
+                let multi_signature = state.multi_signatures.remove(&merkle_tree.root_hash().to_base58());
+                (true, Some(PoolEvent::Synced(merkle_tree, multi_signature)))
+            }
             Ok(CatchupProgress::Restart(merkle_tree)) => (false, Some(PoolEvent::CatchupRestart(merkle_tree))),
-            Ok(CatchupProgress::ShouldBeStarted(target_mt_root, target_mt_size, merkle_tree)) =>
-                (true, Some(PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree))),
-            Err(err) => (true, Some(PoolEvent::CatchupTargetNotFound(err))),
+            Ok(CatchupProgress::ShouldBeStarted(target_mt_root, target_mt_size, merkle_tree)) => {
+                let multi_signature = state.multi_signatures.remove(&target_mt_root.to_base58());
+                (true, Some(PoolEvent::CatchupTargetFound(target_mt_root, target_mt_size, merkle_tree, multi_signature)))
+            }
+            Err(err) => {
+                metrics::record_consensus_failure(pool_name);
+                (true, Some(PoolEvent::CatchupTargetNotFound(err)))
+            }
         }
     }
 }
@@ -606,19 +763,24 @@ pub trait RequestHandler<T: Networker> {
 }
 
 pub struct RequestHandlerImpl<T: Networker> {
-    request_wrapper: Option<RequestSM<T>>
+    request_wrapper: Option<RequestSM<T>>,
+    networker: Rc<RefCell<T>>,
+    pool_name: String,
 }
 
 impl<T: Networker> RequestHandler<T> for RequestHandlerImpl<T> {
     fn new(networker: Rc<RefCell<T>>, f: usize, cmd_ids: &Vec<i32>, nodes: &HashMap<String, Option<VerKey>>, pool_name: &str, timeout: i64, extended_timeout: i64) -> Self {
         RequestHandlerImpl {
-            request_wrapper: Some(RequestSM::new(networker, f, cmd_ids, nodes, pool_name, timeout, extended_timeout)),
+            request_wrapper: Some(RequestSM::new(networker.clone(), f, cmd_ids, nodes, pool_name, timeout, extended_timeout)),
+            networker,
+            pool_name: pool_name.to_string(),
         }
     }
 
     fn process_event(&mut self, ore: Option<RequestEvent>) -> Option<PoolEvent> {
         match ore {
             Some(re) => {
+                self._record_metrics(&re);
                 if let Some((rw, res)) = self.request_wrapper.take().map(|w| w.handle_event(re)) {
                     self.request_wrapper = Some(rw);
                     res
@@ -636,21 +798,44 @@ impl<T: Networker> RequestHandler<T> for RequestHandlerImpl<T> {
     }
 }
 
+impl<T: Networker> RequestHandlerImpl<T> {
+    fn _record_metrics(&self, re: &RequestEvent) {
+        let (node_alias, event) = match *re {
+            RequestEvent::ReqACK(_, _, ref node_alias, _) => (node_alias, NodeEvent::Ack),
+            RequestEvent::ReqNACK(_, _, ref node_alias, _) => (node_alias, NodeEvent::Nack),
+            RequestEvent::Reject(_, _, ref node_alias, _) => (node_alias, NodeEvent::Reject),
+            RequestEvent::Reply(_, _, ref node_alias, _) |
+            RequestEvent::LedgerStatus(_, Some(ref node_alias), _) |
+            RequestEvent::ConsistencyProof(_, ref node_alias) |
+            RequestEvent::CatchupRep(_, ref node_alias) => (node_alias, NodeEvent::Reply),
+            RequestEvent::Timeout(_, ref node_alias) if !node_alias.is_empty() => (node_alias, NodeEvent::Timeout),
+            _ => return
+        };
+
+        let latency = if event != NodeEvent::Timeout {
+            self.networker.borrow().take_latency(&re.get_req_id(), node_alias)
+        } else {
+            None
+        };
+
+        metrics::record_node_event(&self.pool_name, node_alias, event, latency);
+    }
+}
+
 impl<T: Networker> SingleState<T> {
     fn is_consensus_reachable(&self, total_nodes_cnt: usize) -> bool {
         (self.timeout_nodes.len() + self.denied_nodes.len() + self.replies.values().map(|set| set.len()).sum::<usize>())
             < total_nodes_cnt
     }
 
-    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &Vec<i32>, nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+    fn try_to_continue(self, req_id: String, node_alias: String, cmd_ids: &Vec<i32>, nodes_cnt: usize, timeout: i64, pool_name: &str) -> RequestState<T> {
         if self.is_consensus_reachable(nodes_cnt) {
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(req_id.clone(), timeout)));
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))));
             RequestState::Single(self)
         } else {
-            //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
-            _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")));
+            _send_consensus_failure(cmd_ids, pool_name);
             self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(req_id, None)));
             RequestState::finish()
         }
@@ -665,6 +850,40 @@ impl<T: Networker> ConsensusState<T> {
     }
 }
 
+impl<T: Networker> LedgerStatusConsensusState<T> {
+    fn has_responded(&self, node_alias: &str) -> bool {
+        self.timeout_nodes.contains(node_alias) || self.replies.values().any(|set| set.contains(node_alias))
+    }
+
+    fn is_consensus_reachable(&self, f: usize, total_nodes_cnt: usize) -> bool {
+        let rep_no: usize = self.replies.values().map(|set| set.len()).sum();
+        let max_no = self.replies.values().map(|set| set.len()).max().unwrap_or(0);
+        max_no + total_nodes_cnt - rep_no - self.timeout_nodes.len() > f
+    }
+}
+
+impl<T: Networker> LedgerCatchupState<T> {
+    fn is_reply_complete(&self, rep: &CatchupRep) -> bool {
+        let cr = &self.catchup_req;
+        rep.ledgerId == cr.ledgerId &&
+            rep.txns.len() == cr.seqNoEnd + 1 - cr.seqNoStart &&
+            (cr.seqNoStart..cr.seqNoEnd + 1).all(|seq_no| rep.txns.contains_key(&seq_no.to_string()))
+    }
+
+    fn try_next_node(mut self, node_alias: String, cmd_ids: &Vec<i32>, nodes_cnt: usize, timeout: i64) -> RequestState<T> {
+        self.attempts += 1;
+        if self.attempts < nodes_cnt {
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::Resend(self.req_id.clone(), timeout)));
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(self.req_id.clone(), Some(node_alias))));
+            RequestState::LedgerCatchup(self)
+        } else {
+            self.networker.borrow_mut().process_event(Some(NetworkerEvent::CleanTimeout(self.req_id.clone(), None)));
+            _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "No node returned requested transactions")));
+            RequestState::finish()
+        }
+    }
+}
+
 fn _parse_nack(denied_nodes: &mut HashSet<String>, f: usize, raw_msg: &str, cmd_ids: &Vec<i32>, node_alias: &str) -> bool {
     if denied_nodes.len() == f {
         _send_ok_replies(cmd_ids, raw_msg);
@@ -695,6 +914,12 @@ fn _process_catchup_reply(rep: &mut CatchupRep, merkle: &MerkleTree, target_mt_r
     Ok(merkle)
 }
 
+fn _send_consensus_failure(cmd_ids: &Vec<i32>, pool_name: &str) {
+    metrics::record_consensus_failure(pool_name);
+    //TODO: maybe we should change the error, but it was made to escape changing of ErrorCode returned to client
+    _send_replies(cmd_ids, Err(err_msg(IndyErrorKind::PoolTimeout, "Consensus is impossible")))
+}
+
 fn _send_ok_replies(cmd_ids: &Vec<i32>, msg: &str) {
     _send_replies(cmd_ids, Ok(msg.to_string()))
 }
@@ -712,6 +937,17 @@ fn _send_replies(cmd_ids: &Vec<i32>, msg: IndyResult<String>) {
     });
 }
 
+/// Verifies state proof of reply outside of request processing, e.g. before putting it into cache.
+pub fn check_reply_state_proof(raw_msg: &str, f: usize, nodes: &HashMap<String, Option<VerKey>>, sp_key: Option<&[u8]>, requested_timestamps: (Option<u64>, Option<u64>)) -> bool {
+    let generator: Generator = Generator::from_bytes(&DEFAULT_GENERATOR.from_base58().unwrap()).unwrap();
+    let last_write_time = get_last_signed_time(raw_msg).unwrap_or(0);
+
+    match _get_msg_result_without_state_proof(raw_msg) {
+        Ok((result, _)) if result.is_object() => _check_state_proof(&result, f, &generator, nodes, raw_msg, sp_key, requested_timestamps, last_write_time),
+        _ => false
+    }
+}
+
 fn _get_msg_result_without_state_proof(msg: &str) -> IndyResult<(SJsonValue, SJsonValue)> {
     let msg = serde_json::from_str::<SJsonValue>(msg)
         .to_indy(IndyErrorKind::InvalidStructure, "Response is malformed json")?;
@@ -826,6 +1062,7 @@ fn _get_cur_time() -> u64 {
 
 #[cfg(test)]
 pub mod tests {
+    use domain::pool::ReadStrategy;
     use services::ledger::merkletree::tree::Tree;
     use services::pool::networker::MockNetworker;
     use services::pool::types::{ConsistencyProof, LedgerStatus, Reply, ReplyResultV1, ReplyTxnV1, ReplyV1, Response, ResponseMetadata, ResponseV1};
@@ -871,6 +1108,7 @@ pub mod tests {
                 ppSeqNo: None,
                 viewNo: None,
                 protocolVersion: None,
+                multiSignature: None,
             }
         }
     }
@@ -907,6 +1145,7 @@ pub mod tests {
                 hashes: Vec::new(),
                 oldMerkleRoot: String::new(),
                 newMerkleRoot: String::new(),
+                multiSignature: None,
             }
         }
     }
@@ -922,7 +1161,7 @@ pub mod tests {
     }
 
     fn _request_handler(pool_name: &str, f: usize, nodes_cnt: usize) -> RequestHandlerImpl<MockNetworker> {
-        let networker = Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![])));
+        let networker = Rc::new(RefCell::new(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered)));
 
         let mut default_nodes: HashMap<String, Option<VerKey>> = HashMap::new();
         default_nodes.insert(NODE.to_string(), None);
@@ -974,14 +1213,14 @@ pub mod tests {
         #[test]
         fn request_handler_process_catchup_req_event_from_start_works() {
             let mut request_handler = _request_handler("request_handler_process_catchup_req_event_from_start_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], None)));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
 
         #[test]
         fn request_handler_process_catchup_req_event_from_start_works_for_no_transactions_to_catchup() {
             let mut request_handler = _request_handler("request_handler_process_catchup_req_event_from_start_works_for_no_transactions_to_catchup", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 0, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 0, vec![], None)));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
 
@@ -1616,7 +1855,7 @@ pub mod tests {
                 nodes_count: 0,
             };
 
-            request_handler.process_event(Some(RequestEvent::CatchupReq(mt, 2, vec![55, 104, 239, 91, 37, 160, 29, 25, 192, 253, 166, 135, 242, 53, 75, 41, 224, 4, 130, 27, 206, 133, 87, 231, 0, 133, 55, 159, 83, 105, 7, 237])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(mt, 2, vec![55, 104, 239, 91, 37, 160, 29, 25, 192, 253, 166, 135, 242, 53, 75, 41, 224, 4, 130, 27, 206, 133, 87, 231, 0, 133, 55, 159, 83, 105, 7, 237], None)));
 
             let mut txns: HashMap<String, SJsonValue> = HashMap::new();
             txns.insert("2".to_string(), serde_json::from_str::<SJsonValue>(r#"{"reqSignature":{},"txn":{"data":{"data":{"alias":"Node2","client_port":9704,"blskey":"37rAPpXVoxzKhz7d9gkUe52XuXryuLXoM6P6LbWDB7LSbG62Lsb33sfG7zqS8TK1MXwuCHj1FKNzVpsnafmqLG1vXN88rt38mNFs9TENzm4QHdBzsvCuoBnPH7rpYYDo9DZNJePaDvRvqJKByCabubJz3XXKbEeshzpz4Ma5QYpJqjk","node_port":9703,"node_ip":"10.0.0.2","services":["VALIDATOR"],"client_ip":"10.0.0.2"},"dest":"8ECVSk179mjsjKRLWiQtssMLgp6EPhWXtaYyStWPSGAb"},"metadata":{"from":"EbP4aYNeTHL6q385GuVpRV"},"type":"0"},"txnMetadata":{"seqNo":2,"txnId":"1ac8aece2a18ced660fef8694b61aac3af08ba875ce3026a160acbc3a3af35fc"},"ver":"1"}"#).unwrap());
@@ -1631,7 +1870,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_catchup_reply_event_from_catchup_single_state_works_for_error() {
             let mut request_handler = _request_handler("request_handler_process_catchup_reply_event_from_catchup_single_state_works_for_error", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], None)));
             request_handler.process_event(Some(RequestEvent::CatchupRep(CatchupRep::default(), NODE.to_string())));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1639,7 +1878,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_timeout_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_timeout_event_from_catchup_single_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], None)));
             request_handler.process_event(Some(RequestEvent::Timeout(REQ_ID.to_string(), NODE.to_string())));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1647,7 +1886,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_terminate_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_terminate_event_from_catchup_single_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], None)));
             request_handler.process_event(Some(RequestEvent::Terminate));
             assert_match!(RequestState::Finish(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -1655,7 +1894,7 @@ pub mod tests {
         #[test]
         fn request_handler_process_other_event_from_catchup_single_state_works() {
             let mut request_handler = _request_handler("request_handler_process_other_event_from_catchup_single_state_works", 0, 1);
-            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![])));
+            request_handler.process_event(Some(RequestEvent::CatchupReq(MerkleTree::default(), 1, vec![], None)));
             request_handler.process_event(Some(RequestEvent::Pong));
             assert_match!(RequestState::CatchupSingle(_), request_handler.request_wrapper.unwrap().state);
         }
@@ -132,6 +132,20 @@ pub fn verify_parsed_sp(parsed_sps: Vec<ParsedSP>,
     true
 }
 
+pub fn verify_multi_signature(multi_signature: &SJsonValue,
+                              nodes: &HashMap<String, Option<VerKey>>,
+                              f: usize,
+                              gen: &Generator) -> bool {
+    let (signature, participants, value) =
+        unwrap_opt_or_return!(_parse_reply_for_proof_signature_checking(multi_signature), false);
+
+    _verify_proof_signature(signature,
+                            participants.as_slice(),
+                            &value,
+                            nodes, f, gen)
+        .map_err(|err| warn!("{:?}", err)).unwrap_or(false)
+}
+
 #[logfn(Trace)]
 pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Vec<u8>> {
     use sha2::digest::{FixedOutput, Input};
@@ -248,6 +262,10 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 "3:latest".to_owned()
             }
         }
+        constants::GET_FROZEN_LEDGERS => {
+            trace!("TransactionHandler::parse_reply_for_builtin_sp: GET_FROZEN_LEDGERS");
+            "4:FROZEN_LEDGERS".to_owned()
+        }
         _ => {
             trace!("TransactionHandler::parse_reply_for_builtin_sp: <<< Unsupported transaction");
             return None;
@@ -266,7 +284,8 @@ pub fn parse_key_from_request_for_builtin_sp(json_msg: &SJsonValue) -> Option<Ve
                 return None;
             }
         }
-        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE => {
+        constants::GET_REVOC_REG | constants::GET_REVOC_REG_DELTA | constants::GET_TXN_AUTHR_AGRMT | constants::GET_TXN_AUTHR_AGRMT_AML | constants::GET_AUTH_RULE |
+        constants::GET_FROZEN_LEDGERS => {
             Vec::new()
         }
         constants::GET_REVOC_REG_DEF => {
@@ -624,7 +643,6 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
 
         match xtype {
             //TODO constants::GET_TXN => check ledger MerkleTree proofs?
-            //TODO constants::GET_DDO => support DDO
             constants::GET_NYM => {
                 value["identifier"] = parsed_data["identifier"].clone();
                 value["role"] = parsed_data["role"].clone();
@@ -635,7 +653,8 @@ fn _parse_reply_for_proof_value(json_msg: &SJsonValue, data: Option<&str>, parse
                 hasher.input(data.as_bytes());
                 value["val"] = SJsonValue::String(hex::encode(hasher.fixed_result()));
             }
-            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML => {
+            constants::GET_CRED_DEF | constants::GET_REVOC_REG_DEF | constants::GET_REVOC_REG | constants::GET_TXN_AUTHR_AGRMT_AML |
+            constants::GET_FROZEN_LEDGERS => {
                 value["val"] = parsed_data.clone();
             }
             constants::GET_AUTH_RULE => {
@@ -1333,4 +1352,26 @@ mod tests {
                            }),
                    }));
     }
+
+    #[test]
+    fn parse_key_from_request_for_builtin_sp_works_for_get_frozen_ledgers() {
+        let request = json!({
+            "operation": {
+                "type": constants::GET_FROZEN_LEDGERS
+            }
+        });
+
+        assert_eq!(b"4:FROZEN_LEDGERS".to_vec(), parse_key_from_request_for_builtin_sp(&request).unwrap());
+    }
+
+    #[test]
+    fn parse_reply_for_proof_value_works_for_get_frozen_ledgers() {
+        let frozen_ledgers = json!({"10": {"ledger": "ledger_root", "state": "state_root", "seq_no": 1}});
+        let reply = json!({"seqNo": 5, "txnTime": 1613000000});
+
+        let value = _parse_reply_for_proof_value(&reply, Some(&frozen_ledgers.to_string()), &frozen_ledgers,
+                                                 constants::GET_FROZEN_LEDGERS, b"4:FROZEN_LEDGERS").unwrap().unwrap();
+
+        assert_eq!(json!({"lsn": 5, "lut": 1613000000, "val": frozen_ledgers}), serde_json::from_str::<SJsonValue>(&value).unwrap());
+    }
 }
@@ -0,0 +1,574 @@
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+
+use serde_json;
+use serde_json::Value as SJsonValue;
+use time;
+use time::{Duration, Tm};
+
+use domain::pool::ReadStrategy;
+use errors::prelude::*;
+use services::pool::events::*;
+use services::pool::networker::Networker;
+use services::pool::types::*;
+
+use super::zmq::PollItem;
+
+/// Line of traffic capture file: message sent to pool or reply received from node.
+/// `time` is the number of milliseconds since the start of recording.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(tag = "event", rename_all = "lowercase")]
+pub enum TrafficEntry {
+    Request {
+        time: i64,
+        #[serde(rename = "reqId")]
+        req_id: String,
+        nodes: Option<Vec<String>>,
+        msg: String,
+    },
+    Reply {
+        time: i64,
+        node: String,
+        msg: String,
+    },
+}
+
+/// Appends traffic of opened pool to capture file as JSON lines.
+pub struct TrafficRecorder {
+    file: fs::File,
+    started: Tm,
+}
+
+impl TrafficRecorder {
+    pub fn create(path: &str) -> IndyResult<TrafficRecorder> {
+        let file = fs::File::create(path)
+            .to_indy(IndyErrorKind::IOError, format!("Can't create traffic capture file {:?}", path))?;
+
+        Ok(TrafficRecorder {
+            file,
+            started: time::now(),
+        })
+    }
+
+    fn elapsed(&self) -> i64 {
+        (time::now() - self.started).num_milliseconds()
+    }
+
+    fn record(&mut self, entry: &TrafficEntry) {
+        let res = serde_json::to_string(entry)
+            .to_indy(IndyErrorKind::InvalidState, "Can't serialize traffic entry")
+            .and_then(|line| writeln!(self.file, "{}", line)
+                .to_indy(IndyErrorKind::IOError, "Can't write to traffic capture file"));
+
+        if let Err(err) = res {
+            error!("TrafficRecorder: {:?}", err);
+        }
+    }
+}
+
+/// Networker that passes everything to the wrapped one and records
+/// sent messages and node replies to capture file.
+pub struct RecordingNetworker<N: Networker> {
+    networker: N,
+    recorder: Option<RefCell<TrafficRecorder>>,
+}
+
+impl<N: Networker> RecordingNetworker<N> {
+    pub fn with_recorder(networker: N, recorder: TrafficRecorder) -> Self {
+        RecordingNetworker {
+            networker,
+            recorder: Some(RefCell::new(recorder)),
+        }
+    }
+
+    fn _record_request(&self, req_id: &str, nodes: Option<Vec<String>>, msg: &str) {
+        if let Some(ref recorder) = self.recorder {
+            let mut recorder = recorder.borrow_mut();
+            let time = recorder.elapsed();
+            recorder.record(&TrafficEntry::Request { time, req_id: req_id.to_string(), nodes, msg: msg.to_string() });
+        }
+    }
+
+    fn _record_reply(&self, node: &str, msg: &str) {
+        if let Some(ref recorder) = self.recorder {
+            let mut recorder = recorder.borrow_mut();
+            let time = recorder.elapsed();
+            recorder.record(&TrafficEntry::Reply { time, node: node.to_string(), msg: msg.to_string() });
+        }
+    }
+}
+
+impl<N: Networker> Networker for RecordingNetworker<N> {
+    fn new(active_timeout: i64, conn_limit: usize, preordered_nodes: Vec<String>, read_strategy: ReadStrategy) -> Self {
+        RecordingNetworker {
+            networker: N::new(active_timeout, conn_limit, preordered_nodes, read_strategy),
+            recorder: None,
+        }
+    }
+
+    fn fetch_events(&self, poll_items: &[PollItem]) -> Vec<PoolEvent> {
+        let events = self.networker.fetch_events(poll_items);
+
+        for event in events.iter() {
+            if let PoolEvent::NodeReply(ref msg, ref node) = *event {
+                self._record_reply(node, msg);
+            }
+        }
+
+        events
+    }
+
+    fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        match pe {
+            Some(NetworkerEvent::SendOneRequest(ref msg, ref req_id, _)) =>
+                self._record_request(req_id, None, msg),
+            Some(NetworkerEvent::SendAllRequest(ref msg, ref req_id, _, ref nodes)) =>
+                self._record_request(req_id, nodes.clone(), msg),
+            _ => ()
+        }
+
+        self.networker.process_event(pe)
+    }
+
+    fn get_timeout(&self) -> ((String, String), i64) {
+        self.networker.get_timeout()
+    }
+
+    fn get_poll_items(&self) -> Vec<PollItem> {
+        self.networker.get_poll_items()
+    }
+
+    fn take_latency(&self, req_id: &str, node_alias: &str) -> Option<::std::time::Duration> {
+        self.networker.take_latency(req_id, node_alias)
+    }
+}
+
+struct CapturedRequest {
+    msg: String,
+    req_id: Option<String>,
+    time: i64,
+    replies: Vec<(String, i64, String)>,
+    served: HashSet<String>,
+}
+
+/// Recorded traffic with every node reply attached to the request it answers.
+pub struct Capture {
+    requests: Vec<CapturedRequest>,
+}
+
+impl Capture {
+    pub fn load(path: &str) -> IndyResult<Capture> {
+        let file = fs::File::open(path)
+            .to_indy(IndyErrorKind::IOError, format!("Can't open traffic capture file {:?}", path))?;
+
+        let mut entries = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line.to_indy(IndyErrorKind::IOError, "Can't read from traffic capture file")?;
+
+            if line.trim().is_empty() { continue; }
+
+            entries.push(serde_json::from_str::<TrafficEntry>(&line)
+                .to_indy(IndyErrorKind::InvalidStructure, format!("Invalid traffic capture entry: {}", line))?);
+        }
+
+        Ok(Capture::from_entries(entries))
+    }
+
+    /// Reply is attached to the latest request sent to its node with the same `reqId`.
+    /// Replies without `reqId` (ledger status, consistency proofs, catchup replies)
+    /// are attached to the latest request without `reqId`.
+    pub fn from_entries(entries: Vec<TrafficEntry>) -> Capture {
+        let mut requests: Vec<CapturedRequest> = Vec::new();
+        let mut targets: Vec<Option<Vec<String>>> = Vec::new();
+
+        for entry in entries {
+            match entry {
+                TrafficEntry::Request { time, nodes, msg, .. } => {
+                    requests.push(CapturedRequest {
+                        req_id: _req_id(&msg, false),
+                        msg,
+                        time,
+                        replies: Vec::new(),
+                        served: HashSet::new(),
+                    });
+                    targets.push(nodes);
+                }
+                TrafficEntry::Reply { time, node, msg } => {
+                    let reply_req_id = _req_id(&msg, true);
+
+                    let candidates: Vec<usize> = (0..requests.len()).rev()
+                        .filter(|idx| targets[*idx].as_ref().map(|nodes| nodes.contains(&node)).unwrap_or(true))
+                        .collect();
+
+                    let idx = candidates.iter()
+                        .find(|idx| requests[**idx].req_id == reply_req_id)
+                        .or_else(|| candidates.first());
+
+                    match idx {
+                        Some(idx) => {
+                            let request = &mut requests[*idx];
+                            let delay = time - request.time;
+                            request.replies.push((node, delay, msg));
+                        }
+                        None => warn!("Capture: reply of node {} recorded before any request is ignored", node)
+                    }
+                }
+            }
+        }
+
+        Capture { requests }
+    }
+
+    /// Nodes replied to the request in order of their replies.
+    fn repliers(&self, msg: &str) -> Vec<String> {
+        let mut repliers: Vec<String> = Vec::new();
+
+        if let Some(idx) = self._find(msg, None) {
+            for &(ref node, _, _) in self.requests[idx].replies.iter() {
+                if !repliers.contains(node) {
+                    repliers.push(node.clone());
+                }
+            }
+        }
+
+        repliers
+    }
+
+    /// Returns replies of node to the request with their delays.
+    /// Each recorded request is replayed once for every node.
+    fn take_replies(&mut self, msg: &str, node: &str) -> Vec<(i64, String)> {
+        match self._find(msg, Some(node)) {
+            Some(idx) => {
+                let request = &mut self.requests[idx];
+                request.served.insert(node.to_string());
+                request.replies.iter()
+                    .filter(|&&(ref alias, _, _)| alias == node)
+                    .map(|&(_, delay, ref reply)| (delay, reply.clone()))
+                    .collect()
+            }
+            None => {
+                warn!("Capture: no recorded replies of node {} for {}", node, msg);
+                Vec::new()
+            }
+        }
+    }
+
+    fn _find(&self, msg: &str, node: Option<&str>) -> Option<usize> {
+        let not_served = |request: &CapturedRequest| node.map(|node| !request.served.contains(node)).unwrap_or(true);
+
+        self.requests.iter()
+            .position(|request| request.msg == msg && not_served(request))
+            .or_else(|| {
+                let req_id = _req_id(msg, false)?;
+                self.requests.iter()
+                    .position(|request| request.req_id.as_ref() == Some(&req_id) && not_served(request))
+            })
+    }
+}
+
+fn _req_id(msg: &str, is_reply: bool) -> Option<String> {
+    let msg: SJsonValue = serde_json::from_str(msg).ok()?;
+
+    let req_id = if is_reply && msg["reqId"].is_null() { &msg["result"]["reqId"] } else { &msg["reqId"] };
+
+    match *req_id {
+        SJsonValue::Null => None,
+        ref req_id => Some(req_id.to_string())
+    }
+}
+
+/// Networker that doesn't touch the network: node replies are taken from traffic capture
+/// and delivered with recorded delays. Node that has no recorded reply on request stays silent,
+/// so the request is timed out for it exactly like in recorded session.
+pub struct ReplayNetworker {
+    capture: Capture,
+    nodes: Vec<RemoteNode>,
+    preordered_nodes: Vec<String>,
+    resend: HashMap<String, (usize, String, Vec<usize>)>,
+    timeouts: HashMap<(String, String), Tm>,
+    deliveries: RefCell<Vec<(Tm, String, String)>>,
+}
+
+impl ReplayNetworker {
+    pub fn with_capture(capture: Capture, preordered_nodes: Vec<String>) -> Self {
+        ReplayNetworker {
+            capture,
+            nodes: Vec::new(),
+            preordered_nodes,
+            resend: HashMap::new(),
+            timeouts: HashMap::new(),
+            deliveries: RefCell::new(Vec::new()),
+        }
+    }
+
+    fn _send_msg_to_one_node(&mut self, idx: usize, req_id: &str, msg: &str, timeout: i64) {
+        let node_alias = self.nodes[idx].name.clone();
+        let now = time::now();
+
+        self.timeouts.insert((req_id.to_string(), node_alias.clone()), now + Duration::seconds(timeout));
+
+        for (delay, reply) in self.capture.take_replies(msg, &node_alias) {
+            trace!("ReplayNetworker: node {} replies in {} ms {}", node_alias, delay, reply);
+            self.deliveries.borrow_mut().push((now + Duration::milliseconds(delay), node_alias.clone(), reply));
+        }
+    }
+
+    /// Nodes replied in recorded session are asked first.
+    fn _read_order(&self, msg: &str) -> Vec<usize> {
+        let repliers = self.capture.repliers(msg);
+
+        let mut order: Vec<usize> = (0..self.nodes.len()).collect();
+        order.sort_by_key(|idx| repliers.iter()
+            .position(|name| self.nodes[*idx].name.eq(name))
+            .unwrap_or(usize::max_value()));
+        order
+    }
+}
+
+impl Networker for ReplayNetworker {
+    fn new(_active_timeout: i64, _conn_limit: usize, preordered_nodes: Vec<String>, _read_strategy: ReadStrategy) -> Self {
+        ReplayNetworker::with_capture(Capture { requests: Vec::new() }, preordered_nodes)
+    }
+
+    fn fetch_events(&self, _poll_items: &[PollItem]) -> Vec<PoolEvent> {
+        let now = time::now();
+        let mut deliveries = self.deliveries.borrow_mut();
+
+        // stable sort keeps recorded order of replies with the same due time
+        deliveries.sort_by_key(|&(due, _, _)| due);
+
+        let cnt = deliveries.iter().take_while(|&&(due, _, _)| due <= now).count();
+
+        deliveries.drain(..cnt)
+            .map(|(_, node_alias, reply)| PoolEvent::NodeReply(reply, node_alias))
+            .collect()
+    }
+
+    fn process_event(&mut self, pe: Option<NetworkerEvent>) -> Option<RequestEvent> {
+        match pe {
+            Some(NetworkerEvent::SendOneRequest(msg, req_id, timeout)) => {
+                if !self.nodes.is_empty() {
+                    let order = self._read_order(&msg);
+                    self._send_msg_to_one_node(order[0], &req_id, &msg, timeout);
+                    self.resend.insert(req_id, (0, msg, order));
+                }
+            }
+            Some(NetworkerEvent::SendAllRequest(msg, req_id, timeout, nodes_to_send)) => {
+                for idx in 0..self.nodes.len() {
+                    if nodes_to_send.as_ref().map(|nodes| nodes.contains(&self.nodes[idx].name)).unwrap_or(true) {
+                        self._send_msg_to_one_node(idx, &req_id, &msg, timeout);
+                    }
+                }
+            }
+            Some(NetworkerEvent::Resend(req_id, timeout)) => {
+                let resend = if let Some(&mut (ref mut cnt, ref msg, ref order)) = self.resend.get_mut(&req_id) {
+                    *cnt += 1;
+                    Some((order[*cnt % order.len()], msg.clone()))
+                } else {
+                    error!("Unknown req_id for resending {}", req_id);
+                    None
+                };
+                if let Some((idx, msg)) = resend {
+                    self._send_msg_to_one_node(idx, &req_id, &msg, timeout);
+                }
+            }
+            Some(NetworkerEvent::NodesStateUpdated(mut nodes)) => {
+                trace!("ReplayNetworker::process_event: nodes_updated {:?}", nodes);
+                let preordered_nodes = &self.preordered_nodes;
+                nodes.sort_by_key(|node: &RemoteNode| -> usize {
+                    preordered_nodes.iter()
+                        .position(|name| node.name.eq(name))
+                        .unwrap_or(usize::max_value())
+                });
+                self.nodes = nodes;
+            }
+            Some(NetworkerEvent::ExtendTimeout(req_id, node_alias, timeout)) => {
+                if let Some(due) = self.timeouts.get_mut(&(req_id, node_alias)) {
+                    *due = time::now() + Duration::seconds(timeout);
+                }
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, Some(node_alias))) => {
+                self.timeouts.remove(&(req_id, node_alias));
+            }
+            Some(NetworkerEvent::CleanTimeout(req_id, None)) => {
+                self.timeouts.retain(|&(ref timeout_req_id, _), _| *timeout_req_id != req_id);
+                self.resend.remove(&req_id);
+            }
+            _ => ()
+        }
+        None
+    }
+
+    fn get_timeout(&self) -> ((String, String), i64) {
+        let now = time::now();
+
+        let delivery = self.deliveries.borrow().iter()
+            .map(|&(due, _, _)| (due - now).num_milliseconds())
+            .min()
+            .map(|timeout| (("".to_string(), "".to_string()), timeout));
+
+        let timeout = self.timeouts.iter()
+            .map(|(key, due)| (key.clone(), (*due - now).num_milliseconds()))
+            .min_by_key(|&(_, timeout)| timeout);
+
+        match (delivery, timeout) {
+            (Some(delivery), Some(timeout)) => if delivery.1 <= timeout.1 { delivery } else { timeout },
+            (Some(delivery), None) => delivery,
+            (None, Some(timeout)) => timeout,
+            (None, None) => (("".to_string(), "".to_string()), ::std::i64::MAX),
+        }
+    }
+
+    fn get_poll_items(&self) -> Vec<PollItem> {
+        Vec::new()
+    }
+
+    fn take_latency(&self, _req_id: &str, _node_alias: &str) -> Option<::std::time::Duration> {
+        // replayed replies are delivered by schedule of capture, not by real nodes
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use services::pool::networker::MockNetworker;
+    use utils::environment;
+
+    use super::*;
+
+    const LEDGER_STATUS: &str = r#"{"op":"LEDGER_STATUS","txnSeqNo":4}"#;
+    const GET_NYM: &str = r#"{"reqId":1,"operation":{"type":"105"}}"#;
+    const GET_NYM_2: &str = r#"{"reqId":2,"operation":{"type":"105"}}"#;
+    const GET_NYM_REPLY: &str = r#"{"op":"REPLY","result":{"reqId":1,"data":null}}"#;
+    const GET_NYM_2_REPLY: &str = r#"{"op":"REPLY","result":{"reqId":2,"data":null}}"#;
+
+    fn _request(time: i64, nodes: Option<Vec<&str>>, msg: &str) -> TrafficEntry {
+        TrafficEntry::Request {
+            time,
+            req_id: "req".to_string(),
+            nodes: nodes.map(|nodes| nodes.iter().map(|node| node.to_string()).collect()),
+            msg: msg.to_string(),
+        }
+    }
+
+    fn _reply(time: i64, node: &str, msg: &str) -> TrafficEntry {
+        TrafficEntry::Reply { time, node: node.to_string(), msg: msg.to_string() }
+    }
+
+    fn _nodes(names: &[&str]) -> Vec<RemoteNode> {
+        names.iter()
+            .map(|name| RemoteNode {
+                name: name.to_string(),
+                public_key: Vec::new(),
+                zaddr: String::new(),
+                is_blacklisted: false,
+            })
+            .collect()
+    }
+
+    fn _capture() -> Capture {
+        Capture::from_entries(vec![
+            _request(0, None, LEDGER_STATUS),
+            _reply(5, "n1", LEDGER_STATUS),
+            _request(10, None, GET_NYM),
+            _request(11, None, GET_NYM_2),
+            _reply(15, "n2", GET_NYM_REPLY),
+            _reply(16, "n1", LEDGER_STATUS),
+            _reply(17, "n2", GET_NYM_2_REPLY),
+        ])
+    }
+
+    #[test]
+    fn capture_attaches_replies_to_requests() {
+        let mut capture = _capture();
+
+        assert_eq!(vec![(5, LEDGER_STATUS.to_string()), (16, LEDGER_STATUS.to_string())], capture.take_replies(LEDGER_STATUS, "n1"));
+        assert_eq!(vec![(5, GET_NYM_REPLY.to_string())], capture.take_replies(GET_NYM, "n2"));
+        assert_eq!(vec![(6, GET_NYM_2_REPLY.to_string())], capture.take_replies(GET_NYM_2, "n2"));
+        assert_eq!(vec!["n2".to_string()], capture.repliers(GET_NYM));
+    }
+
+    #[test]
+    fn capture_replays_request_once_for_node() {
+        let mut capture = _capture();
+
+        assert_eq!(1, capture.take_replies(GET_NYM, "n2").len());
+        assert!(capture.take_replies(GET_NYM, "n2").is_empty());
+        assert!(capture.take_replies(GET_NYM, "n1").is_empty());
+    }
+
+    #[test]
+    fn capture_attaches_replies_to_requests_sent_to_node() {
+        let mut capture = Capture::from_entries(vec![
+            _request(0, Some(vec!["n1"]), r#"{"op":"CATCHUP_REQ","seqNoStart":1}"#),
+            _request(1, Some(vec!["n2"]), r#"{"op":"CATCHUP_REQ","seqNoStart":2}"#),
+            _reply(5, "n1", r#"{"op":"CATCHUP_REP"}"#),
+        ]);
+
+        assert_eq!(1, capture.take_replies(r#"{"op":"CATCHUP_REQ","seqNoStart":1}"#, "n1").len());
+    }
+
+    #[test]
+    fn recording_networker_records_traffic() {
+        let path = environment::tmp_file_path("recording_networker_records_traffic.jsonl");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+        {
+            let recorder = TrafficRecorder::create(path.to_str().unwrap()).unwrap();
+            let mut networker = RecordingNetworker::with_recorder(MockNetworker::new(0, 0, vec![], ReadStrategy::Preordered), recorder);
+
+            networker.process_event(Some(NetworkerEvent::SendOneRequest(GET_NYM.to_string(), "1".to_string(), 1)));
+            networker.process_event(Some(NetworkerEvent::SendAllRequest(LEDGER_STATUS.to_string(), "2".to_string(), 1, Some(vec!["n1".to_string()]))));
+
+            assert_eq!(2, networker.networker.events.len());
+        }
+
+        let mut capture = Capture::load(path.to_str().unwrap()).unwrap();
+        assert_eq!(2, capture.requests.len());
+        assert!(capture.take_replies(GET_NYM, "n1").is_empty());
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn replay_networker_delivers_recorded_replies() {
+        let mut networker = ReplayNetworker::with_capture(_capture(), vec![]);
+        networker.process_event(Some(NetworkerEvent::NodesStateUpdated(_nodes(&["n1", "n2"]))));
+
+        networker.process_event(Some(NetworkerEvent::SendOneRequest(GET_NYM.to_string(), "1".to_string(), 10)));
+
+        thread::sleep(::std::time::Duration::from_millis(10));
+
+        let events = networker.fetch_events(&[]);
+        assert_eq!(1, events.len());
+        match events[0] {
+            PoolEvent::NodeReply(ref reply, ref node) => {
+                assert_eq!(GET_NYM_REPLY, reply);
+                assert_eq!("n2", node);
+            }
+            _ => panic!("unexpected event")
+        }
+
+        let ((req_id, node), _) = networker.get_timeout();
+        assert_eq!(("1", "n2"), (req_id.as_str(), node.as_str()));
+    }
+
+    #[test]
+    fn replay_networker_keeps_silence_for_unknown_request() {
+        let mut networker = ReplayNetworker::with_capture(_capture(), vec![]);
+        networker.process_event(Some(NetworkerEvent::NodesStateUpdated(_nodes(&["n1", "n2"]))));
+
+        networker.process_event(Some(NetworkerEvent::SendAllRequest(r#"{"reqId":3}"#.to_string(), "3".to_string(), 10, None)));
+
+        assert!(networker.fetch_events(&[]).is_empty());
+        assert_eq!(2, networker.timeouts.len());
+
+        networker.process_event(Some(NetworkerEvent::CleanTimeout("3".to_string(), None)));
+        assert!(networker.timeouts.is_empty());
+    }
+}
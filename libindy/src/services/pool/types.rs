@@ -207,6 +207,9 @@ pub struct LedgerStatus {
     pub viewNo: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub protocolVersion: Option<usize>,
+    /// BLS multi-signature of nodes over `merkleRoot` if node provides it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiSignature: Option<serde_json::Value>,
 }
 
 #[allow(non_snake_case)]
@@ -219,6 +222,9 @@ pub struct ConsistencyProof {
     pub hashes: Vec<String>,
     pub oldMerkleRoot: String,
     pub newMerkleRoot: String,
+    /// BLS multi-signature of nodes over `newMerkleRoot` if node provides it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub multiSignature: Option<serde_json::Value>,
 }
 
 #[allow(non_snake_case)]
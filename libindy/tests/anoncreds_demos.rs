@@ -38,6 +38,7 @@ use utils::domain::anoncreds::schema::Schema;
 use utils::domain::anoncreds::credential_definition::CredentialDefinition;
 use utils::domain::anoncreds::credential_attr_tag_policy::CredentialAttrTagPolicy;
 use utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryDefinition;
+use utils::domain::anoncreds::revocation_registry_rotation::{ActiveRevocationRegistry, RevocationRegistryPublication};
 use utils::domain::anoncreds::credential_for_proof_request::{CredentialsForProofRequest};
 use utils::domain::anoncreds::proof::Proof;
 use utils::domain::anoncreds::revocation_state::RevocationState;
@@ -2052,6 +2053,218 @@ mod demos {
         utils::tear_down("anoncreds_works_for_issuance_by_default_revocation_strategy_for_full_revocation_registry");
     }
 
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_rotation() {
+        utils::setup("anoncreds_works_for_revocation_registry_rotation");
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_rotation").unwrap();
+
+        //2. Prover creates wallet, gets wallet handle
+        let (prover_wallet_handle, prover_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_rotation").unwrap();
+
+        //3. Issuer creates schema
+        let (_, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                               GVT_SCHEMA_NAME,
+                                                               SCHEMA_VERSION,
+                                                               GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        //4. Issuer creates credential definition
+        let (cred_def_id, cred_def_json) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                                          ISSUER_DID,
+                                                                                          &schema_json,
+                                                                                          TAG_1,
+                                                                                          None,
+                                                                                          Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //5. Issuer enables revocation registry rotation for 2 Credentials per registry
+        let active_rev_reg_json = anoncreds::issuer_enable_revoc_reg_rotation(issuer_wallet_handle,
+                                                                              ISSUER_DID,
+                                                                              &cred_def_id,
+                                                                              &anoncreds::revoc_reg_rotation_config(2, 0.5)).unwrap();
+        let first_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_eq!(0, first_rev_reg.issued);
+        assert_eq!(2, first_rev_reg.max_cred_num);
+        assert!(!first_rev_reg.published);
+        assert!(first_rev_reg.next_rev_reg_id.is_none());
+
+        //6. Issuer gets requests publishing the first registry
+        let publications_json = anoncreds::issuer_get_pending_revoc_reg_publications(issuer_wallet_handle, &cred_def_id).unwrap();
+        let publications: Vec<RevocationRegistryPublication> = serde_json::from_str(&publications_json).unwrap();
+        assert_eq!(vec![("REVOC_REG_DEF", first_rev_reg.rev_reg_id.as_str()), ("REVOC_REG_ENTRY", first_rev_reg.rev_reg_id.as_str())],
+                   publications.iter().map(|publication| (publication.txn_type.as_str(), publication.rev_reg_id.as_str())).collect::<Vec<_>>());
+
+        let blob_storage_reader_handle = utils::blob_storage::open_reader(TYPE, &anoncreds::tails_writer_config()).unwrap();
+
+        //7. Prover creates Master Secret
+        anoncreds::prover_create_master_secret(prover_wallet_handle, COMMON_MASTER_SECRET).unwrap();
+
+        //8. Issuance of the first Credential reaches fill threshold: the next registry is created in advance
+        anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                            prover_wallet_handle,
+                                                            issuer_wallet_handle,
+                                                            CREDENTIAL1_ID,
+                                                            &anoncreds::gvt_credential_values_json(),
+                                                            &cred_def_id,
+                                                            &cred_def_json,
+                                                            &first_rev_reg.rev_reg_id,
+                                                            &serde_json::to_string(&first_rev_reg.rev_reg_def).unwrap(),
+                                                            blob_storage_reader_handle);
+
+        let active_rev_reg_json = anoncreds::issuer_get_active_revoc_reg(issuer_wallet_handle, &cred_def_id).unwrap();
+        let active_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_eq!(first_rev_reg.rev_reg_id, active_rev_reg.rev_reg_id);
+        assert_eq!(1, active_rev_reg.issued);
+        let next_rev_reg_id = active_rev_reg.next_rev_reg_id.unwrap();
+
+        //9. Issuance of the second Credential fills the registry: the next registry isn't published yet, so it isn't active
+        anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                            prover_wallet_handle,
+                                                            issuer_wallet_handle,
+                                                            CREDENTIAL2_ID,
+                                                            &anoncreds::gvt2_credential_values_json(),
+                                                            &cred_def_id,
+                                                            &cred_def_json,
+                                                            &first_rev_reg.rev_reg_id,
+                                                            &serde_json::to_string(&first_rev_reg.rev_reg_def).unwrap(),
+                                                            blob_storage_reader_handle);
+
+        let active_rev_reg_json = anoncreds::issuer_get_active_revoc_reg(issuer_wallet_handle, &cred_def_id).unwrap();
+        let active_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_eq!(first_rev_reg.rev_reg_id, active_rev_reg.rev_reg_id);
+        assert_eq!(2, active_rev_reg.issued);
+        assert_eq!(Some(next_rev_reg_id.clone()), active_rev_reg.next_rev_reg_id);
+
+        //10. Issuer confirms publication of the next registry: it becomes active
+        anoncreds::issuer_confirm_revoc_reg_publication(issuer_wallet_handle, &cred_def_id, &next_rev_reg_id).unwrap();
+
+        let active_rev_reg_json = anoncreds::issuer_get_active_revoc_reg(issuer_wallet_handle, &cred_def_id).unwrap();
+        let second_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_eq!(next_rev_reg_id, second_rev_reg.rev_reg_id);
+        assert_eq!(0, second_rev_reg.issued);
+        assert!(second_rev_reg.published);
+        assert!(second_rev_reg.next_rev_reg_id.is_none());
+
+        //11. Issuance of the third Credential uses the new active registry and reaches fill threshold
+        anoncreds::multi_steps_create_revocation_credential(COMMON_MASTER_SECRET,
+                                                            prover_wallet_handle,
+                                                            issuer_wallet_handle,
+                                                            CREDENTIAL3_ID,
+                                                            &anoncreds::gvt3_credential_values_json(),
+                                                            &cred_def_id,
+                                                            &cred_def_json,
+                                                            &second_rev_reg.rev_reg_id,
+                                                            &serde_json::to_string(&second_rev_reg.rev_reg_def).unwrap(),
+                                                            blob_storage_reader_handle);
+
+        let active_rev_reg_json = anoncreds::issuer_get_active_revoc_reg(issuer_wallet_handle, &cred_def_id).unwrap();
+        let active_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_eq!(second_rev_reg.rev_reg_id, active_rev_reg.rev_reg_id);
+        let third_rev_reg_id = active_rev_reg.next_rev_reg_id.unwrap();
+
+        //12. Issuer confirms publication of the first registry
+        anoncreds::issuer_confirm_revoc_reg_publication(issuer_wallet_handle, &cred_def_id, &first_rev_reg.rev_reg_id).unwrap();
+
+        let publications_json = anoncreds::issuer_get_pending_revoc_reg_publications(issuer_wallet_handle, &cred_def_id).unwrap();
+        let publications: Vec<RevocationRegistryPublication> = serde_json::from_str(&publications_json).unwrap();
+        assert_eq!(2, publications.len());
+        assert!(publications.iter().all(|publication| publication.rev_reg_id == third_rev_reg_id));
+
+        let res = anoncreds::issuer_confirm_revoc_reg_publication(issuer_wallet_handle, &cred_def_id, &first_rev_reg.rev_reg_id);
+        assert_code!(ErrorCode::WalletItemNotFound, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+        wallet::close_and_delete_wallet(prover_wallet_handle, &prover_wallet_config).unwrap();
+
+        utils::tear_down("anoncreds_works_for_revocation_registry_rotation");
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_rotation_enabled_twice() {
+        utils::setup("anoncreds_works_for_revocation_registry_rotation_enabled_twice");
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_rotation_enabled_twice").unwrap();
+
+        //2. Issuer creates schema
+        let (_, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                               GVT_SCHEMA_NAME,
+                                                               SCHEMA_VERSION,
+                                                               GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        //3. Issuer creates credential definition
+        let (cred_def_id, _) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                              ISSUER_DID,
+                                                                              &schema_json,
+                                                                              TAG_1,
+                                                                              None,
+                                                                              Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //4. Issuer can't enable rotation with invalid fill threshold
+        let res = anoncreds::issuer_enable_revoc_reg_rotation(issuer_wallet_handle, ISSUER_DID, &cred_def_id,
+                                                              &anoncreds::revoc_reg_rotation_config(2, 1.5));
+        assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+        //5. Issuer enables rotation
+        anoncreds::issuer_enable_revoc_reg_rotation(issuer_wallet_handle, ISSUER_DID, &cred_def_id,
+                                                    &anoncreds::revoc_reg_rotation_config(2, 0.5)).unwrap();
+
+        //6. Issuer can't enable rotation for the same credential definition again
+        let res = anoncreds::issuer_enable_revoc_reg_rotation(issuer_wallet_handle, ISSUER_DID, &cred_def_id,
+                                                              &anoncreds::revoc_reg_rotation_config(2, 0.5));
+        assert_code!(ErrorCode::WalletItemAlreadyExists, res);
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+
+        utils::tear_down("anoncreds_works_for_revocation_registry_rotation_enabled_twice");
+    }
+
+    #[cfg(feature = "revocation_tests")]
+    #[test]
+    fn anoncreds_works_for_revocation_registry_rotation_with_taken_tag() {
+        utils::setup("anoncreds_works_for_revocation_registry_rotation_with_taken_tag");
+
+        //1. Issuer creates wallet, gets wallet handle
+        let (issuer_wallet_handle, issuer_wallet_config) = wallet::create_and_open_default_wallet("anoncreds_works_for_revocation_registry_rotation_with_taken_tag").unwrap();
+
+        //2. Issuer creates schema
+        let (_, schema_json) = anoncreds::issuer_create_schema(ISSUER_DID,
+                                                               GVT_SCHEMA_NAME,
+                                                               SCHEMA_VERSION,
+                                                               GVT_SCHEMA_ATTRIBUTES).unwrap();
+
+        //3. Issuer creates credential definition
+        let (cred_def_id, _) = anoncreds::issuer_create_credential_definition(issuer_wallet_handle,
+                                                                              ISSUER_DID,
+                                                                              &schema_json,
+                                                                              TAG_1,
+                                                                              None,
+                                                                              Some(&anoncreds::revocation_cred_def_config())).unwrap();
+
+        //4. Registry with the first tag of rotation is already stored, e.g. left by failed rotation
+        let tails_writer_handle = utils::blob_storage::open_writer("default", &anoncreds::tails_writer_config()).unwrap();
+        let (taken_rev_reg_id, _, _) = anoncreds::issuer_create_and_store_revoc_reg(issuer_wallet_handle,
+                                                                                    ISSUER_DID,
+                                                                                    None,
+                                                                                    "1",
+                                                                                    &cred_def_id,
+                                                                                    r#"{"max_cred_num": 2}"#,
+                                                                                    tails_writer_handle).unwrap();
+
+        //5. Issuer enables rotation: the taken tag is skipped
+        let active_rev_reg_json = anoncreds::issuer_enable_revoc_reg_rotation(issuer_wallet_handle, ISSUER_DID, &cred_def_id,
+                                                                              &anoncreds::revoc_reg_rotation_config(2, 0.5)).unwrap();
+        let active_rev_reg: ActiveRevocationRegistry = serde_json::from_str(&active_rev_reg_json).unwrap();
+        assert_ne!(taken_rev_reg_id, active_rev_reg.rev_reg_id);
+        assert!(active_rev_reg.rev_reg_id.ends_with(":2"));
+
+        wallet::close_and_delete_wallet(issuer_wallet_handle, &issuer_wallet_config).unwrap();
+
+        utils::tear_down("anoncreds_works_for_revocation_registry_rotation_with_taken_tag");
+    }
+
     #[cfg(feature = "revocation_tests")]
     #[test]
     fn anoncreds_works_for_issuance_by_demand_revocation_strategy_for_revoke_not_issued_credential_id() {
@@ -450,4 +450,353 @@ mod high_cases {
             utils::tear_down_with_wallet(wallet_handle, "indy_purge_cred_def_cache_older_than_1000_seconds", &wallet_config);
         }
     }
-}
\ No newline at end of file
+
+    mod request_cache {
+        use super::*;
+        use utils::constants::*;
+
+        #[test]
+        fn indy_get_cached_request_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_cached_request_works");
+
+            let get_nym_request = utils::ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let response1 = get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({}).to_string()).unwrap();
+            utils::pool::check_response_type(&response1, utils::types::ResponseType::REPLY);
+
+            // now retrieve it from cache with request which differs only in reqId
+            let get_nym_request = utils::ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let response2 = get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noUpdate": true}).to_string()).unwrap();
+
+            assert_eq!(response1, response2);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_cached_request_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_cached_request_only_cache_no_cached_data() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_cached_request_only_cache_no_cached_data");
+
+            let get_nym_request = utils::ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let res = get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_cached_request_only_cache_no_cached_data", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_cached_request_no_store_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_cached_request_no_store_works");
+
+            let get_nym_request = utils::ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noStore": true}).to_string()).unwrap();
+
+            // it should not be present inside of cache, because of noStore option in previous request.
+            let res = get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_cached_request_no_store_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_cached_request_for_invalid_request() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_cached_request_for_invalid_request");
+
+            let res = get_cached_request(pool_handle, wallet_handle, r#"{"operation": {}}"#, &json!({}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_cached_request_for_invalid_request", &wallet_config);
+        }
+
+        #[test]
+        fn indy_purge_request_cache_for_txn_type() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_purge_request_cache_for_txn_type");
+
+            let get_nym_request = utils::ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({}).to_string()).unwrap();
+
+            // other transaction type, GET_NYM reply stays in cache
+            purge_request_cache(wallet_handle, &json!({"txnType": "107"}).to_string()).unwrap();
+            get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noUpdate": true}).to_string()).unwrap();
+
+            purge_request_cache(wallet_handle, &json!({"txnType": "105"}).to_string()).unwrap();
+            let res = get_cached_request(pool_handle, wallet_handle, &get_nym_request, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_purge_request_cache_for_txn_type", &wallet_config);
+        }
+
+        #[test]
+        fn indy_purge_request_cache_no_options() {
+            let (wallet_handle, wallet_config) = utils::setup_with_wallet("indy_purge_request_cache_no_options");
+
+            purge_request_cache(wallet_handle, "{}").unwrap();
+
+            utils::tear_down_with_wallet(wallet_handle, "indy_purge_request_cache_no_options", &wallet_config);
+        }
+    }
+
+    mod rich_schema_cache {
+        use super::*;
+        use utils::constants::*;
+
+        const RS_ID: &str = "did:sov:8a9QLQbmwcXRbhyAnMxq8Y";
+
+        fn _post_rich_schema(pool_handle: i32, wallet_handle: i32) -> String {
+            let (trustee_did, _) = utils::did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let content = json!({"@id": RS_ID, "@type": "rdfs:Class"}).to_string();
+            let request = utils::ledger::build_rich_schema_request(&trustee_did, RS_ID, &content, "SimpleRichSchema", "1.0", "sch", None).unwrap();
+            let response = utils::ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &request).unwrap();
+            utils::pool::check_response_type(&response, utils::types::ResponseType::REPLY);
+
+            trustee_did
+        }
+
+        #[test]
+        fn indy_get_rich_schema_object_cache_works() {
+            let (wallet_handle, wallet_config) = utils::setup_with_wallet("indy_get_rich_schema_object_cache_works");
+            let pool_handle = utils::pool::create_and_open_local_pool_ledger("indy_get_rich_schema_object_cache_works").unwrap();
+
+            let trustee_did = _post_rich_schema(pool_handle, wallet_handle);
+
+            let object_json1 = get_rich_schema_object_cache(pool_handle, wallet_handle, &trustee_did, RS_ID, &json!({}).to_string()).unwrap();
+            let object: serde_json::Value = serde_json::from_str(&object_json1).unwrap();
+            assert_eq!(RS_ID, object["id"].as_str().unwrap());
+
+            // now retrieve it from cache
+            let object_json2 = get_rich_schema_object_cache(pool_handle, wallet_handle, &trustee_did, RS_ID, &json!({"noUpdate": true}).to_string()).unwrap();
+            assert_eq!(object_json1, object_json2);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_rich_schema_object_cache_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_purge_rich_schema_cache_works() {
+            let (wallet_handle, wallet_config) = utils::setup_with_wallet("indy_purge_rich_schema_cache_works");
+            let pool_handle = utils::pool::create_and_open_local_pool_ledger("indy_purge_rich_schema_cache_works").unwrap();
+
+            let trustee_did = _post_rich_schema(pool_handle, wallet_handle);
+
+            get_rich_schema_object_cache(pool_handle, wallet_handle, &trustee_did, RS_ID, &json!({}).to_string()).unwrap();
+
+            purge_rich_schema_cache(wallet_handle, &json!({}).to_string()).unwrap();
+
+            let res = get_rich_schema_object_cache(pool_handle, wallet_handle, &trustee_did, RS_ID, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_purge_rich_schema_cache_works", &wallet_config);
+        }
+    }
+
+    mod did_doc_cache {
+        use super::*;
+        use utils::constants::*;
+
+        #[test]
+        fn indy_resolve_did_works() {
+            let (wallet_handle, pool_handle, did, verkey, wallet_config) = utils::setup_new_identity("indy_resolve_did_works");
+
+            let attrib_request = utils::ledger::build_attrib_request(&did, &did, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+            let response = utils::ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, &attrib_request).unwrap();
+            utils::pool::check_response_type(&response, utils::types::ResponseType::REPLY);
+
+            let result = resolve_did(pool_handle, wallet_handle, &format!("did:sov:{}", did), &json!({}).to_string()).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            let did_document = &result["didDocument"];
+            assert_eq!(json!(format!("did:sov:{}", did)), did_document["id"]);
+            assert_eq!(json!(verkey), did_document["verificationMethod"][0]["publicKeyBase58"]);
+            assert_eq!(json!("127.0.0.1:5555"), did_document["service"][0]["serviceEndpoint"]);
+
+            let metadata = &result["didResolutionMetadata"];
+            assert!(metadata["nym"]["seqNo"].is_u64());
+            assert!(metadata["endpoint"]["seqNo"].is_u64());
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_did_without_endpoint() {
+            let (wallet_handle, pool_handle, did, _, wallet_config) = utils::setup_new_identity("indy_resolve_did_works_for_did_without_endpoint");
+
+            let result = resolve_did(pool_handle, wallet_handle, &did, &json!({}).to_string()).unwrap();
+            let result: serde_json::Value = serde_json::from_str(&result).unwrap();
+
+            assert_eq!(json!([]), result["didDocument"]["service"]);
+            assert_eq!(serde_json::Value::Null, result["didResolutionMetadata"]["endpoint"]);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_works_for_did_without_endpoint", &wallet_config);
+        }
+
+        #[test]
+        fn indy_resolve_did_cache_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_resolve_did_cache_works");
+
+            let result1 = resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, &json!({}).to_string()).unwrap();
+
+            // now retrieve it from cache by qualified DID
+            let result2 = resolve_did(pool_handle, wallet_handle, &format!("did:sov:{}", DID_TRUSTEE), &json!({"noUpdate": true}).to_string()).unwrap();
+
+            assert_eq!(result1, result2);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_cache_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_resolve_did_only_cache_no_cached_data() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_resolve_did_only_cache_no_cached_data");
+
+            let res = resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_only_cache_no_cached_data", &wallet_config);
+        }
+
+        #[test]
+        fn indy_resolve_did_for_unknown_did() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_resolve_did_for_unknown_did");
+
+            let (did, _) = utils::did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let res = resolve_did(pool_handle, wallet_handle, &did, &json!({}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_for_unknown_did", &wallet_config);
+        }
+
+        #[test]
+        fn indy_resolve_did_works_for_reply_without_state_proof() {
+            let (wallet_handle, wallet_config) = utils::setup_with_wallet("indy_resolve_did_works_for_reply_without_state_proof");
+            let pool_handle = utils::pool::create_and_open_local_pool_ledger("indy_resolve_did_works_for_reply_without_state_proof").unwrap();
+
+            let (trustee_did, _) = utils::did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did, verkey) = utils::did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = utils::ledger::build_nym_request(&trustee_did, &did, Some(&verkey), None, None).unwrap();
+            let response = utils::ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            utils::pool::check_response_type(&response, utils::types::ResponseType::REPLY);
+
+            resolve_did(pool_handle, wallet_handle, &did, &json!({}).to_string()).unwrap();
+
+            // emulated nodes don't sign state, so the document isn't stored
+            let res = resolve_did(pool_handle, wallet_handle, &did, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_resolve_did_works_for_reply_without_state_proof", &wallet_config);
+        }
+
+        #[test]
+        fn indy_purge_did_doc_cache_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_purge_did_doc_cache_works");
+
+            resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, &json!({}).to_string()).unwrap();
+
+            purge_did_doc_cache(wallet_handle, &json!({}).to_string()).unwrap();
+
+            let res = resolve_did(pool_handle, wallet_handle, DID_TRUSTEE, &json!({"noUpdate": true}).to_string());
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_purge_did_doc_cache_works", &wallet_config);
+        }
+    }
+
+    mod revocation_history {
+        use super::*;
+        use utils::{ledger, non_secrets};
+
+        const REV_REG_STATE_CACHE: &str = "rev_reg_state_cache";
+
+        #[test]
+        fn indy_get_revocation_history_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_revocation_history_works");
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let to = time::get_time().sec + 300;
+            let history = get_revocation_history(pool_handle, wallet_handle, rev_reg_id, 0, to, &json!({}).to_string()).unwrap();
+            let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+
+            assert_eq!(rev_reg_id, history["revRegDefId"].as_str().unwrap());
+
+            let entries = history["entries"].as_array().unwrap();
+            assert!(!entries.is_empty());
+            assert!(entries[0]["value"]["accum"].is_string());
+            assert!(history["delta"]["value"]["accum"].is_string());
+
+            // the first registry entry is stored inside of cache
+            let timestamp = entries[0]["timestamp"].as_u64().unwrap();
+            non_secrets::get_wallet_record(wallet_handle, REV_REG_STATE_CACHE, &format!("{}:{}", rev_reg_id, timestamp), "{}").unwrap();
+
+            let cached_history = get_revocation_history(pool_handle, wallet_handle, rev_reg_id, 0, to, &json!({}).to_string()).unwrap();
+            let cached_history: serde_json::Value = serde_json::from_str(&cached_history).unwrap();
+            assert_eq!(history, cached_history);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_revocation_history_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_revocation_history_works_for_no_store() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_revocation_history_works_for_no_store");
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let to = time::get_time().sec + 300;
+            let history = get_revocation_history(pool_handle, wallet_handle, rev_reg_id, 0, to, &json!({"noStore": true}).to_string()).unwrap();
+            let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+
+            let timestamp = history["entries"][0]["timestamp"].as_u64().unwrap();
+            let res = non_secrets::get_wallet_record(wallet_handle, REV_REG_STATE_CACHE, &format!("{}:{}", rev_reg_id, timestamp), "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_revocation_history_works_for_no_store", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_revocation_history_works_for_interval_without_entries() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_revocation_history_works_for_interval_without_entries");
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let from = time::get_time().sec + 300;
+            let history = get_revocation_history(pool_handle, wallet_handle, rev_reg_id, from, from + 1, &json!({}).to_string()).unwrap();
+            let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+
+            assert!(history["entries"].as_array().unwrap().is_empty());
+            assert!(history["delta"].is_null());
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_revocation_history_works_for_interval_without_entries", &wallet_config);
+        }
+
+        #[test]
+        fn indy_get_revocation_history_works_for_invalid_interval() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_revocation_history_works_for_invalid_interval");
+
+            let res = get_revocation_history(pool_handle, wallet_handle, "RevRegId", 100, 10, &json!({}).to_string());
+            assert_code!(ErrorCode::CommonInvalidParam6, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_get_revocation_history_works_for_invalid_interval", &wallet_config);
+        }
+
+        #[test]
+        fn indy_purge_rev_reg_state_cache_works() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_purge_rev_reg_state_cache_works");
+
+            let (_, _, rev_reg_id) = ledger::post_entities();
+
+            let to = time::get_time().sec + 300;
+            let history = get_revocation_history(pool_handle, wallet_handle, rev_reg_id, 0, to, &json!({}).to_string()).unwrap();
+            let history: serde_json::Value = serde_json::from_str(&history).unwrap();
+
+            purge_rev_reg_state_cache(wallet_handle, &json!({}).to_string()).unwrap();
+
+            let timestamp = history["entries"][0]["timestamp"].as_u64().unwrap();
+            let res = non_secrets::get_wallet_record(wallet_handle, REV_REG_STATE_CACHE, &format!("{}:{}", rev_reg_id, timestamp), "{}");
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_purge_rev_reg_state_cache_works", &wallet_config);
+        }
+    }
+}
@@ -824,6 +824,79 @@ mod high_cases {
         }
     }
 
+    mod generate_bls_keys {
+        use super::*;
+
+        #[test]
+        fn indy_generate_bls_keys_works_for_seed() {
+            let (blskey, blskey_pop) = ledger::generate_bls_keys(Some("000000000000000000000000000Node1")).unwrap();
+
+            let node_data: serde_json::Value = serde_json::from_str(NODE_DATA).unwrap();
+            assert_eq!(node_data["blskey"].as_str().unwrap(), blskey);
+            assert_eq!(node_data["blskey_pop"].as_str().unwrap(), blskey_pop);
+        }
+
+        #[test]
+        fn indy_generate_bls_keys_works_for_node_request() {
+            let (blskey, blskey_pop) = ledger::generate_bls_keys(None).unwrap();
+
+            let node_data = json!({"alias": "Node5", "blskey": blskey, "blskey_pop": blskey_pop}).to_string();
+            ledger::build_node_request(IDENTIFIER, DEST, &node_data).unwrap();
+        }
+    }
+
+    mod genesis_transactions {
+        use super::*;
+
+        fn _nodes_config(nodes_count: u8) -> String {
+            let nodes: Vec<serde_json::Value> = (1..=nodes_count).map(|i| json!({
+                "alias": format!("Node{}", i),
+                "seed": format!("000000000000000000000000000Node{}", i),
+                "steward_seed": format!("000000000000000000000000Steward{}", i),
+                "node_ip": "10.0.0.2",
+                "node_port": 9700 + 2 * i as i32 - 1,
+                "client_ip": "10.0.0.2",
+                "client_port": 9700 + 2 * i as i32,
+            })).collect();
+
+            json!({"trustee_seeds": [TRUSTEE_SEED], "nodes": nodes}).to_string()
+        }
+
+        #[test]
+        fn indy_build_genesis_transactions_works() {
+            let (pool_txns, domain_txns) = ledger::build_genesis_transactions(&_nodes_config(4)).unwrap();
+
+            let pool_txns: Vec<serde_json::Value> = pool_txns.lines().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+            assert_eq!(4, pool_txns.len());
+            assert_eq!("Node1", pool_txns[0]["txn"]["data"]["data"]["alias"].as_str().unwrap());
+            assert_eq!("Gw6pDLhcBcoQesN72qfotTgFa7cbuqZpkX3Xo6pLhPhv", pool_txns[0]["txn"]["data"]["dest"].as_str().unwrap());
+            assert_eq!(IDENTIFIER, pool_txns[0]["txn"]["metadata"]["from"].as_str().unwrap());
+            assert_eq!(json!(["VALIDATOR"]), pool_txns[0]["txn"]["data"]["data"]["services"]);
+            assert!(pool_txns[0]["txn"]["data"]["data"]["blskey"].is_string());
+            assert!(pool_txns[0]["txn"]["data"]["data"]["blskey_pop"].is_string());
+
+            let domain_txns: Vec<serde_json::Value> = domain_txns.lines().map(|txn| serde_json::from_str(txn).unwrap()).collect();
+            assert_eq!(5, domain_txns.len());
+            assert_eq!(json!({"dest": DID_TRUSTEE, "role": "0", "verkey": "~CoRER63DVYnWZtK8uAzNbx"}), domain_txns[0]["txn"]["data"]);
+            assert_eq!(json!({"dest": IDENTIFIER, "role": "2", "verkey": "~7TYfekw4GUagBnBVCqPjiC"}), domain_txns[1]["txn"]["data"]);
+            assert_eq!(DID_TRUSTEE, domain_txns[1]["txn"]["metadata"]["from"].as_str().unwrap());
+        }
+
+        #[test]
+        fn indy_build_genesis_transactions_works_for_pool_ledger_config() {
+            const POOL_NAME: &str = "indy_build_genesis_transactions_works_for_pool_ledger_config";
+            utils::setup(POOL_NAME);
+
+            let (pool_txns, _) = ledger::build_genesis_transactions(&_nodes_config(4)).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file(POOL_NAME, &pool_txns, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(POOL_NAME, Some(&pool_config)).unwrap();
+
+            utils::tear_down(POOL_NAME);
+        }
+    }
+
     mod cred_def_requests {
         use super::*;
 
@@ -1303,6 +1376,65 @@ mod high_cases {
         }
     }
 
+    mod ledgers_freeze {
+        use super::*;
+        use utils::{pool, ledger, did};
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works() {
+            let expected_result = json!({
+                "type": constants::LEDGERS_FREEZE,
+                "ledgers_ids": [10, 11]
+            });
+
+            let request = ledger::build_ledgers_freeze_request(DID_TRUSTEE, "[10, 11]").unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_base_ledger() {
+            let res = ledger::build_ledgers_freeze_request(DID_TRUSTEE, "[1]");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_ledgers_freeze_request_works_for_invalid_ledgers_ids() {
+            let res = ledger::build_ledgers_freeze_request(DID_TRUSTEE, r#"["ledger"]"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_get_frozen_ledgers_request_works() {
+            let expected_result = json!({
+                "type": constants::GET_FROZEN_LEDGERS
+            });
+
+            let request = ledger::build_get_frozen_ledgers_request(Some(DID_TRUSTEE)).unwrap();
+            check_request(&request, expected_result);
+        }
+
+        #[test]
+        fn indy_ledgers_freeze_requests_work() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_ledgers_freeze_requests_work");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_ledgers_freeze_requests_work").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = ledger::build_ledgers_freeze_request(&trustee_did, "[10]").unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let get_request = ledger::build_get_frozen_ledgers_request(None).unwrap();
+            let get_response = ledger::submit_request(pool_handle, &get_request).unwrap();
+            let get_response: serde_json::Value = serde_json::from_str(&get_response).unwrap();
+
+            assert!(get_response["result"]["data"]["10"].is_object());
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_ledgers_freeze_requests_work", &config);
+        }
+    }
+
     mod revoc_reg_def_requests {
         use super::*;
 
@@ -3045,6 +3177,98 @@ mod high_cases {
 
             utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_author_agreement_works_for_using_not_last_taa", &wallet_config);
         }
+
+        fn _setup_local_trustee(name: &str) -> (i32, i32, String, String) {
+            let (wallet_handle, wallet_config) = utils::setup_with_wallet(name);
+            let pool_handle = pool::create_and_open_local_pool_ledger(name).unwrap();
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            (wallet_handle, pool_handle, trustee_did, wallet_config)
+        }
+
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_works() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = _setup_local_trustee("indy_set_txn_author_agreement_acceptance_works");
+
+            let (_, aml_label, _, _) = _set_aml(pool_handle, wallet_handle, &trustee_did);
+            let (taa_text, taa_version) = _set_taa(pool_handle, wallet_handle, &trustee_did);
+
+            let time_of_acceptance = time::get_time().sec as u64;
+            ledger::set_txn_author_agreement_acceptance(pool_handle, Some(&taa_text), Some(&taa_version), None, &aml_label, time_of_acceptance).unwrap();
+
+            let (did_, verkey_) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let nym_req = ledger::build_nym_request(&trustee_did, &did_, Some(&verkey_), None, None).unwrap();
+            let nym_resp = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_req).unwrap();
+            pool::check_response_type(&nym_resp, ResponseType::REPLY);
+
+            let expected_acceptance = ledger::append_txn_author_agreement_acceptance_to_request(&nym_req, Some(&taa_text), Some(&taa_version),
+                                                                                                None, &aml_label, time_of_acceptance).unwrap();
+            let expected_acceptance: serde_json::Value = serde_json::from_str(&expected_acceptance).unwrap();
+
+            let nym_resp: serde_json::Value = serde_json::from_str(&nym_resp).unwrap();
+            assert_eq!(expected_acceptance["taaAcceptance"], nym_resp["result"]["txn"]["metadata"]["taaAcceptance"]);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_set_txn_author_agreement_acceptance_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_works_for_not_last_taa() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = _setup_local_trustee("indy_set_txn_author_agreement_acceptance_works_for_not_last_taa");
+
+            let (_, aml_label, _, _) = _set_aml(pool_handle, wallet_handle, &trustee_did);
+            let (taa_text, taa_version) = _set_taa(pool_handle, wallet_handle, &trustee_did);
+            _set_taa(pool_handle, wallet_handle, &trustee_did);
+
+            let res = ledger::set_txn_author_agreement_acceptance(pool_handle, Some(&taa_text), Some(&taa_version), None,
+                                                                  &aml_label, time::get_time().sec as u64);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_set_txn_author_agreement_acceptance_works_for_not_last_taa", &wallet_config);
+        }
+
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_works_for_invalid_aml() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = _setup_local_trustee("indy_set_txn_author_agreement_acceptance_works_for_invalid_aml");
+
+            _set_aml(pool_handle, wallet_handle, &trustee_did);
+            let (taa_text, taa_version) = _set_taa(pool_handle, wallet_handle, &trustee_did);
+
+            let res = ledger::set_txn_author_agreement_acceptance(pool_handle, Some(&taa_text), Some(&taa_version), None,
+                                                                  "INVALID AML LABEL", time::get_time().sec as u64);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_set_txn_author_agreement_acceptance_works_for_invalid_aml", &wallet_config);
+        }
+
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_works_for_not_set_taa() {
+            utils::setup("indy_set_txn_author_agreement_acceptance_works_for_not_set_taa");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_set_txn_author_agreement_acceptance_works_for_not_set_taa").unwrap();
+
+            let res = ledger::set_txn_author_agreement_acceptance(pool_handle, Some(TEXT), Some(VERSION), None,
+                                                                  ACCEPTANCE_MECH_TYPE, time::get_time().sec as u64);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_set_txn_author_agreement_acceptance_works_for_not_set_taa");
+        }
+
+        #[test]
+        fn indy_set_txn_author_agreement_acceptance_works_for_invalid_pool_handle() {
+            utils::setup("indy_set_txn_author_agreement_acceptance_works_for_invalid_pool_handle");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_set_txn_author_agreement_acceptance_works_for_invalid_pool_handle").unwrap();
+
+            let res = ledger::set_txn_author_agreement_acceptance(pool_handle + 1, Some(TEXT), Some(VERSION), None,
+                                                                  ACCEPTANCE_MECH_TYPE, TIME_OF_ACCEPTANCE);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_set_txn_author_agreement_acceptance_works_for_invalid_pool_handle");
+        }
     }
 
     mod append_request_endorser {
@@ -3123,170 +3347,1009 @@ mod high_cases {
 
             utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_request_by_endorser_for_both_author_and_endorser_must_sign", &config);
         }
-    }
-}
-
-mod medium_cases {
-    use super::*;
-
-    mod requests {
-        use super::*;
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_sign_and_submit_request_works_for_not_found_signer() {
-            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_sign_and_submit_request_works_for_not_found_signer");
+        fn indy_endorse_request_works() {
+            let (wallet_handle, pool_handle, endorser_did, _, config) = utils::setup_new_endorser("indy_endorse_request_works");
+            let my_did = _setup_new_identity(wallet_handle, pool_handle);
 
-            let res = ledger::sign_and_submit_request(pool_handle, wallet_handle, &DID, REQUEST);
-            assert_code!(ErrorCode::WalletItemNotFound, res);
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &endorser_did).unwrap();
+            let request = ledger::sign_request(wallet_handle, &my_did, &request).unwrap();
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_sign_and_submit_request_works_for_not_found_signer", &wallet_config);
-        }
+            let bundle = ledger::build_endorsement_bundle(&request, Some(r#"{"note": "please endorse"}"#)).unwrap();
 
-        #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_request_works_for_invalid_message() {
-            let pool_handle = utils::setup_with_pool("indy_submit_request_works_for_invalid_message");
+            let review = ledger::review_endorsement_bundle(pool_handle, &bundle).unwrap();
+            let review: serde_json::Value = serde_json::from_str(&review).unwrap();
+            assert_eq!(true, review["valid"]);
+            assert_eq!(my_did, review["author"].as_str().unwrap());
+            assert_eq!(constants::SCHEMA, review["txnType"].as_str().unwrap());
+            assert_eq!("please endorse", review["metadata"]["note"].as_str().unwrap());
+            assert_eq!(1, review["signatures"].as_array().unwrap().len());
 
-            let res = ledger::submit_request(pool_handle, "request");
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+            let request = ledger::endorse_request(pool_handle, wallet_handle, &endorser_did, &bundle).unwrap();
+            let response = ledger::submit_request(pool_handle, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
 
-            utils::tear_down_with_pool(pool_handle, "indy_submit_request_works_for_invalid_message");
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_endorse_request_works", &config);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_sign_and_submit_request_works_for_invalid_json() {
-            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_sign_and_submit_request_works_for_invalid_json");
+        fn indy_endorse_request_works_for_other_endorser() {
+            let (wallet_handle, pool_handle, endorser_did, _, config) = utils::setup_new_endorser("indy_endorse_request_works_for_other_endorser");
+            let my_did = _setup_new_identity(wallet_handle, pool_handle);
 
-            let res = ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, "request");
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &endorser_did).unwrap();
+            let request = ledger::sign_request(wallet_handle, &my_did, &request).unwrap();
+
+            let bundle = ledger::build_endorsement_bundle(&request, None).unwrap();
+
+            let res = ledger::endorse_request(pool_handle, wallet_handle, DID_TRUSTEE, &bundle);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_sign_and_submit_request_works_for_invalid_json", &wallet_config);
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_endorse_request_works_for_other_endorser", &config);
         }
-    }
-
-    mod submit_action {
-        use super::*;
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_action_works_for_pool_restart_for_unknown_node_name() {
-            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_submit_action_works_for_pool_restart_for_unknown_node_name");
+        fn indy_endorse_request_works_for_modified_request() {
+            let (wallet_handle, pool_handle, endorser_did, _, config) = utils::setup_new_endorser("indy_endorse_request_works_for_modified_request");
+            let my_did = _setup_new_identity(wallet_handle, pool_handle);
 
-            let get_validator_info_request = ledger::build_get_validator_info_request(&did).unwrap();
-            let get_validator_info_request = ledger::sign_request(wallet_handle, &did, &get_validator_info_request).unwrap();
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::append_request_endorser(&request, &endorser_did).unwrap();
+            let request = ledger::sign_request(wallet_handle, &my_did, &request).unwrap();
 
-            let nodes = r#"["Other Node"]"#;
-            let res = ledger::submit_action(pool_handle, &get_validator_info_request, Some(nodes), None);
+            let bundle = ledger::build_endorsement_bundle(&request, None).unwrap();
+            let mut bundle: serde_json::Value = serde_json::from_str(&bundle).unwrap();
+            bundle["request"]["operation"]["data"]["version"] = json!("2.0");
+            let bundle = bundle.to_string();
+
+            let review = ledger::review_endorsement_bundle(pool_handle, &bundle).unwrap();
+            let review: serde_json::Value = serde_json::from_str(&review).unwrap();
+            assert_eq!(false, review["valid"]);
+            assert_eq!(false, review["signatures"][0]["valid"]);
+
+            let res = ledger::endorse_request(pool_handle, wallet_handle, &endorser_did, &bundle);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_action_works_for_pool_restart_for_unknown_node_name", &wallet_config);
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_endorse_request_works_for_modified_request", &config);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_action_works_for_pool_restart_for_invalid_nodes_format() {
-            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_submit_action_works_for_pool_restart_for_invalid_nodes_format");
+        fn indy_build_endorsement_bundle_works_for_request_without_endorser() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_build_endorsement_bundle_works_for_request_without_endorser");
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
 
-            let get_validator_info_request = ledger::build_get_validator_info_request(&did).unwrap();
-            let get_validator_info_request = ledger::sign_request(wallet_handle, &did, &get_validator_info_request).unwrap();
+            let request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+            let request = ledger::sign_request(wallet_handle, &my_did, &request).unwrap();
 
-            let nodes = r#""Node1""#;
-            let res = ledger::submit_action(pool_handle, &get_validator_info_request, Some(nodes), None);
+            let res = ledger::build_endorsement_bundle(&request, None);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_action_works_for_pool_restart_for_invalid_nodes_format", &wallet_config);
+            utils::tear_down_with_wallet(wallet_handle, "indy_build_endorsement_bundle_works_for_request_without_endorser", &config);
         }
     }
 
-    mod nym_requests {
+    mod verify_txn_inclusion {
         use super::*;
+        use utils::{pool, ledger, did};
 
-        #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_send_nym_request_works_for_only_required_fields() {
-            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_for_only_required_fields");
-            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+        fn _write_nym(wallet_handle: i32, pool_handle: i32) -> serde_json::Value {
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
 
-            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REPLY);
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_for_only_required_fields", &wallet_config);
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            nym_response["result"].clone()
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_send_nym_request_works_with_option_fields() {
-            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_with_option_fields");
-            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+        fn indy_verify_txn_inclusion_works_for_write_reply() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_verify_txn_inclusion_works_for_write_reply");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_verify_txn_inclusion_works_for_write_reply").unwrap();
 
-            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_verkey), Some("some_alias"), Some("STEWARD")).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REPLY);
+            let txn = _write_nym(wallet_handle, pool_handle);
+            let root_hash = txn["rootHash"].as_str().unwrap();
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_with_option_fields", &wallet_config);
+            let valid = ledger::verify_txn_inclusion(root_hash, &txn.to_string(), None, 0).unwrap();
+            assert!(valid);
+
+            let audit_path = txn["auditPath"].to_string();
+            let valid = ledger::verify_txn_inclusion(root_hash, &txn.to_string(), Some(&audit_path), 0).unwrap();
+            assert!(valid);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_verify_txn_inclusion_works_for_write_reply", &config);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_send_nym_request_works_for_different_roles() {
-            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_for_different_roles");
+        fn indy_verify_txn_inclusion_works_for_changed_txn() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_verify_txn_inclusion_works_for_changed_txn");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_verify_txn_inclusion_works_for_changed_txn").unwrap();
 
-            for role in ["STEWARD", "TRUSTEE", "TRUST_ANCHOR", "ENDORSER", "NETWORK_MONITOR"].iter() {
-                let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
-                let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, Some(role)).unwrap();
-                let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
-                pool::check_response_type(&response, ResponseType::REPLY);
-            }
+            let mut txn = _write_nym(wallet_handle, pool_handle);
+            let root_hash = txn["rootHash"].as_str().unwrap().to_string();
+            txn["txn"]["data"]["verkey"] = json!(VERKEY_MY1);
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_for_different_roles", &wallet_config);
+            let valid = ledger::verify_txn_inclusion(&root_hash, &txn.to_string(), None, 0).unwrap();
+            assert!(!valid);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_verify_txn_inclusion_works_for_changed_txn", &config);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_build_nym_requests_works_for_wrong_role() {
-            let res = ledger::build_nym_request(&IDENTIFIER, &DEST, None, None, Some("WRONG_ROLE"));
-            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        fn indy_verify_txn_inclusion_works_for_get_txn_reply() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_verify_txn_inclusion_works_for_get_txn_reply");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_verify_txn_inclusion_works_for_get_txn_reply").unwrap();
+
+            let txn = _write_nym(wallet_handle, pool_handle);
+            let seq_no = txn["txnMetadata"]["seqNo"].as_i64().unwrap() as i32;
+
+            let get_txn_request = ledger::build_get_txn_request(None, seq_no, None).unwrap();
+            let get_txn_response = ledger::submit_request(pool_handle, &get_txn_request).unwrap();
+            let get_txn_response: serde_json::Value = serde_json::from_str(&get_txn_response).unwrap();
+
+            // nodes build proofs for the ledger of txn seqNo size, so root hash of write reply matches
+            let root_hash = txn["rootHash"].as_str().unwrap();
+            let data = get_txn_response["result"]["data"].to_string();
+            let valid = ledger::verify_txn_inclusion(root_hash, &data, None, 0).unwrap();
+            assert!(valid);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_verify_txn_inclusion_works_for_get_txn_reply", &config);
         }
+    }
+
+    mod ledger_iterator {
+        use super::*;
+        use utils::{pool, ledger, did};
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_nym_request_works_for_wrong_signer_role() {
-            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_nym_request_works_for_wrong_signer_role");
-            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+        fn indy_ledger_iterator_works_for_pool_ledger() {
+            utils::setup("indy_ledger_iterator_works_for_pool_ledger");
 
-            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REPLY);
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_ledger_iterator_works_for_pool_ledger").unwrap();
 
-            let (my_did2, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
-            let nym_request = ledger::build_nym_request(&my_did, &my_did2, None, None, None).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &nym_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REQNACK);
+            let iterator_handle = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1).unwrap();
+            let txns = ledger::read_ledger_iterator(iterator_handle).unwrap();
+            ledger::close_ledger_iterator(iterator_handle).unwrap();
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_nym_request_works_for_wrong_signer_role", &wallet_config);
+            assert_eq!(vec![1, 2, 3, 4], txns.iter().map(|txn| txn["seqNo"].as_u64().unwrap()).collect::<Vec<u64>>());
+            assert!(txns.iter().all(|txn| txn["type"].as_str().unwrap() == constants::NODE));
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_ledger_iterator_works_for_pool_ledger");
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_nym_request_works_for_unknown_signer_did() {
-            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_nym_request_works_for_unknown_signer_did");
+        fn indy_ledger_iterator_works_for_range_of_domain_ledger() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_ledger_iterator_works_for_range_of_domain_ledger");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_ledger_iterator_works_for_range_of_domain_ledger").unwrap();
 
-            let (did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
 
-            let nym_request = ledger::build_nym_request(&did, DID, None, None, None).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, &nym_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REQNACK);
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            let seq_no = nym_response["result"]["txnMetadata"]["seqNo"].as_i64().unwrap();
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_nym_request_works_for_unknown_signer_did", &wallet_config);
+            let iterator_handle = ledger::open_ledger_iterator(pool_handle, None, seq_no, seq_no).unwrap();
+            let txns = ledger::read_ledger_iterator(iterator_handle).unwrap();
+            ledger::close_ledger_iterator(iterator_handle).unwrap();
+
+            assert_eq!(1, txns.len());
+            assert_eq!(seq_no as u64, txns[0]["seqNo"].as_u64().unwrap());
+            assert_eq!(my_did, txns[0]["data"]["dest"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_ledger_iterator_works_for_range_of_domain_ledger", &config);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_get_nym_request_works_for_unknown_did() {
-            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_nym_request_works_for_unknown_did");
+        fn indy_open_ledger_iterator_works_for_invalid_range() {
+            utils::setup("indy_open_ledger_iterator_works_for_invalid_range");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_open_ledger_iterator_works_for_invalid_range").unwrap();
+
+            let res = ledger::open_ledger_iterator(pool_handle, None, 0, -1);
+            assert_code!(ErrorCode::CommonInvalidParam4, res);
+
+            let res = ledger::open_ledger_iterator(pool_handle, None, 3, 2);
+            assert_code!(ErrorCode::CommonInvalidParam5, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_open_ledger_iterator_works_for_invalid_range");
+        }
+
+        #[test]
+        fn indy_open_ledger_iterator_works_for_concurrent_iterators() {
+            utils::setup("indy_open_ledger_iterator_works_for_concurrent_iterators");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_open_ledger_iterator_works_for_concurrent_iterators").unwrap();
+
+            let pool_iterator_handle = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1).unwrap();
+
+            let res = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1);
+            assert_code!(ErrorCode::CommonInvalidState, res);
+
+            let domain_iterator_handle = ledger::open_ledger_iterator(pool_handle, None, 1, -1).unwrap();
+
+            let pool_txns = ledger::read_ledger_iterator(pool_iterator_handle).unwrap();
+            let domain_txns = ledger::read_ledger_iterator(domain_iterator_handle).unwrap();
+            ledger::close_ledger_iterator(pool_iterator_handle).unwrap();
+            ledger::close_ledger_iterator(domain_iterator_handle).unwrap();
+
+            assert_eq!(4, pool_txns.len());
+            assert!(pool_txns.iter().all(|txn| txn["type"].as_str().unwrap() == constants::NODE));
+            assert!(domain_txns.iter().all(|txn| txn["type"].as_str().unwrap() != constants::NODE));
+
+            let pool_iterator_handle = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1).unwrap();
+            ledger::close_ledger_iterator(pool_iterator_handle).unwrap();
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_open_ledger_iterator_works_for_concurrent_iterators");
+        }
+
+        #[test]
+        fn indy_ledger_iterator_next_works_for_closed_iterator() {
+            utils::setup("indy_ledger_iterator_next_works_for_closed_iterator");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_ledger_iterator_next_works_for_closed_iterator").unwrap();
+
+            let iterator_handle = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1).unwrap();
+            ledger::close_ledger_iterator(iterator_handle).unwrap();
+
+            let res = ledger::ledger_iterator_next(iterator_handle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_ledger_iterator_next_works_for_closed_iterator");
+        }
+
+        #[test]
+        fn indy_ledger_iterator_next_works_for_closed_pool() {
+            utils::setup("indy_ledger_iterator_next_works_for_closed_pool");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_ledger_iterator_next_works_for_closed_pool").unwrap();
+
+            let iterator_handle = ledger::open_ledger_iterator(pool_handle, Some("POOL"), 1, -1).unwrap();
+
+            pool::close(pool_handle).unwrap();
+
+            let res = ledger::ledger_iterator_next(iterator_handle);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down("indy_ledger_iterator_next_works_for_closed_pool");
+        }
+    }
+
+    mod parse_get_responses {
+        use super::*;
+        use utils::{pool, ledger, did};
+
+        #[test]
+        fn indy_parse_get_nym_attrib_and_txn_responses_work() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_parse_get_nym_attrib_and_txn_responses_work");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_parse_get_nym_attrib_and_txn_responses_work").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let abbr_vk = did::abbreviate_verkey(&my_did, &my_vk).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&abbr_vk), None, Some("ENDORSER")).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            let seq_no = nym_response["result"]["txnMetadata"]["seqNo"].as_u64().unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(None, &my_did).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            let nym: serde_json::Value = serde_json::from_str(&ledger::parse_get_nym_response(&get_nym_response).unwrap()).unwrap();
+            assert_eq!(my_did, nym["did"].as_str().unwrap());
+            assert_eq!(my_vk, nym["verkey"].as_str().unwrap());
+            assert_eq!("ENDORSER", nym["role"].as_str().unwrap());
+            assert_eq!(seq_no, nym["seqNo"].as_u64().unwrap());
+
+            let attrib_request = ledger::build_attrib_request(&my_did, &my_did, None, Some(ATTRIB_RAW_DATA), None).unwrap();
+            let attrib_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &attrib_request).unwrap();
+            pool::check_response_type(&attrib_response, ResponseType::REPLY);
+
+            let get_attrib_request = ledger::build_get_attrib_request(None, &my_did, Some("endpoint"), None, None).unwrap();
+            let get_attrib_response = ledger::submit_request(pool_handle, &get_attrib_request).unwrap();
+            let attrib: serde_json::Value = serde_json::from_str(&ledger::parse_get_attrib_response(&get_attrib_response).unwrap()).unwrap();
+            assert_eq!(my_did, attrib["did"].as_str().unwrap());
+            assert_eq!(serde_json::from_str::<serde_json::Value>(ATTRIB_RAW_DATA).unwrap(), attrib["raw"]);
+            assert!(attrib["hash"].is_null());
+
+            let get_txn_request = ledger::build_get_txn_request(None, seq_no as i32, None).unwrap();
+            let get_txn_response = ledger::submit_request(pool_handle, &get_txn_request).unwrap();
+            let txn: serde_json::Value = serde_json::from_str(&ledger::parse_get_txn_response(&get_txn_response).unwrap()).unwrap();
+            assert_eq!(constants::NYM, txn["type"].as_str().unwrap());
+            assert_eq!(seq_no, txn["seqNo"].as_u64().unwrap());
+            assert_eq!(trustee_did, txn["from"].as_str().unwrap());
+            assert_eq!(my_did, txn["data"]["dest"].as_str().unwrap());
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_parse_get_nym_attrib_and_txn_responses_work", &config);
+        }
+
+        #[test]
+        fn indy_parse_get_responses_work_for_not_found() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_parse_get_responses_work_for_not_found");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_parse_get_responses_work_for_not_found").unwrap();
+
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(None, &my_did).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            let res = ledger::parse_get_nym_response(&get_nym_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            let get_txn_request = ledger::build_get_txn_request(None, i32::max_value(), None).unwrap();
+            let get_txn_response = ledger::submit_request(pool_handle, &get_txn_request).unwrap();
+            let res = ledger::parse_get_txn_response(&get_txn_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_parse_get_responses_work_for_not_found", &config);
+        }
+
+        #[test]
+        fn indy_parse_get_nym_response_works_for_invalid_response() {
+            utils::setup("indy_parse_get_nym_response_works_for_invalid_response");
+
+            let res = ledger::parse_get_nym_response(r#"{"op":"REPLY","result":{"type":"101"}}"#);
+            assert_code!(ErrorCode::LedgerInvalidTransaction, res);
+
+            utils::tear_down("indy_parse_get_nym_response_works_for_invalid_response");
+        }
+    }
+
+    mod rich_schema_requests {
+        use super::*;
+        use utils::{pool, ledger, did};
+
+        const RS_ID: &str = "did:sov:8a9QLQbmwcXRbhyAnMxq8Y";
+        const RS_NAME: &str = "SimpleRichSchema";
+        const RS_VERSION: &str = "1.0";
+
+        fn _content() -> String {
+            json!({"@id": RS_ID, "@type": "rdfs:Class", "name": "Text"}).to_string()
+        }
+
+        #[test]
+        fn indy_rich_schema_requests_work() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_rich_schema_requests_work");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_rich_schema_requests_work").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = ledger::build_rich_schema_request(&trustee_did, RS_ID, &_content(), RS_NAME, RS_VERSION, "sch", None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let get_request = ledger::build_get_rich_schema_object_by_id_request(None, RS_ID).unwrap();
+            let get_response = ledger::submit_request(pool_handle, &get_request).unwrap();
+            let (id, object) = ledger::parse_get_rich_schema_object_response(&get_response).unwrap();
+            let object: serde_json::Value = serde_json::from_str(&object).unwrap();
+
+            assert_eq!(RS_ID, id);
+            assert_eq!(_content(), object["content"].as_str().unwrap());
+            assert_eq!("sch", object["rsType"].as_str().unwrap());
+            assert!(object["seqNo"].is_u64());
+
+            let get_request = ledger::build_get_rich_schema_object_by_metadata_request(None, "sch", RS_NAME, RS_VERSION).unwrap();
+            let get_response = ledger::submit_request(pool_handle, &get_request).unwrap();
+            let (id, _) = ledger::parse_get_rich_schema_object_response(&get_response).unwrap();
+            assert_eq!(RS_ID, id);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_rich_schema_requests_work", &config);
+        }
+
+        #[test]
+        fn indy_rich_schema_request_works_for_duplicate_metadata() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_rich_schema_request_works_for_duplicate_metadata");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_rich_schema_request_works_for_duplicate_metadata").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let content = json!({"@context": {"name": "http://schema.org/name"}}).to_string();
+
+            let request = ledger::build_rich_schema_request(&trustee_did, RS_ID, &content, RS_NAME, RS_VERSION, "ctx", None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let request = ledger::build_rich_schema_request(&trustee_did, "did:sov:2f9QLQbmwcXRbhyAnMxq8Y", &content, RS_NAME, RS_VERSION, "ctx", None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &request).unwrap();
+            pool::check_response_type(&response, ResponseType::REJECT);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_rich_schema_request_works_for_duplicate_metadata", &config);
+        }
+
+        #[test]
+        fn indy_parse_get_rich_schema_object_response_works_for_not_found() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_parse_get_rich_schema_object_response_works_for_not_found");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_parse_get_rich_schema_object_response_works_for_not_found").unwrap();
+
+            let get_request = ledger::build_get_rich_schema_object_by_id_request(None, RS_ID).unwrap();
+            let get_response = ledger::submit_request(pool_handle, &get_request).unwrap();
+            let res = ledger::parse_get_rich_schema_object_response(&get_response);
+            assert_code!(ErrorCode::LedgerNotFound, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_parse_get_rich_schema_object_response_works_for_not_found", &config);
+        }
+
+        #[test]
+        fn indy_build_rich_schema_request_works_for_invalid_type() {
+            utils::setup("indy_build_rich_schema_request_works_for_invalid_type");
+
+            let res = ledger::build_rich_schema_request(DID_TRUSTEE, RS_ID, &_content(), RS_NAME, RS_VERSION, "unknown", None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down("indy_build_rich_schema_request_works_for_invalid_type");
+        }
+    }
+
+    #[cfg(feature = "local_faults_tests")]
+    mod write_retry {
+        use super::*;
+        use std::fs;
+        use std::path::Path;
+        use utils::{pool, ledger, did, environment};
+
+        const TIMEOUT: i64 = 1;
+        const INITIAL_DELAY: i64 = 100;
+
+        fn _open_pool_losing_first_write_reply(pool_name: &str, max_attempts: u32, traffic_path: &Path) -> i32 {
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+
+            let open_config = json!({
+                "networker": "local",
+                "timeout": TIMEOUT,
+                "write_retry": {"max_attempts": max_attempts, "initial_delay": INITIAL_DELAY, "jitter": false},
+                "local_faults": {"lost_write_replies": 1},
+                "record_traffic": traffic_path.to_str().unwrap(),
+            }).to_string();
+            pool::open_pool_ledger(pool_name, Some(&open_config)).unwrap()
+        }
+
+        /// Sending time and message of recorded requests of given type.
+        fn _sent_requests(traffic_path: &Path, txn_type: &str) -> Vec<(i64, serde_json::Value)> {
+            fs::read_to_string(traffic_path).unwrap()
+                .lines()
+                .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+                .filter(|entry| entry["event"] == json!("request"))
+                .map(|entry| (entry["time"].as_i64().unwrap(), serde_json::from_str::<serde_json::Value>(entry["msg"].as_str().unwrap()).unwrap()))
+                .filter(|&(_, ref msg)| msg["operation"]["type"] == json!(txn_type))
+                .collect()
+        }
+
+        #[test]
+        fn indy_sign_and_submit_request_works_for_write_retry_policy() {
+            let pool_name = "indy_sign_and_submit_request_works_for_write_retry_policy";
+            let (wallet_handle, config) = utils::setup_with_wallet(pool_name);
+
+            let traffic_path = environment::tmp_file_path("write_retry_policy.jsonl");
+            let pool_handle = _open_pool_losing_first_write_reply(pool_name, 5, &traffic_path);
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+
+            let nym_response: serde_json::Value = serde_json::from_str(&nym_response).unwrap();
+            assert_eq!(json!(my_did), nym_response["result"]["txn"]["data"]["dest"]);
+
+            pool::close(pool_handle).unwrap();
+
+            // reply is lost, but read-back finds the write ordered, so it is not submitted again
+            let nyms = _sent_requests(&traffic_path, constants::NYM);
+            assert_eq!(1, nyms.len());
+
+            // read-back is started after the write timed out and the first retry delay passed
+            let checks = _sent_requests(&traffic_path, constants::GET_NYM);
+            assert_eq!(1, checks.len());
+            assert!(checks[0].0 - nyms[0].0 >= TIMEOUT * 1000 + INITIAL_DELAY);
+            assert_eq!(json!(my_did), checks[0].1["operation"]["dest"]);
+
+            assert_eq!(1, _sent_requests(&traffic_path, constants::GET_TXN).len());
+
+            utils::tear_down_with_wallet(wallet_handle, pool_name, &config);
+        }
+
+        #[test]
+        fn indy_sign_and_submit_request_works_for_write_retry_policy_without_retries() {
+            let pool_name = "indy_sign_and_submit_request_works_for_write_retry_policy_without_retries";
+            let (wallet_handle, config) = utils::setup_with_wallet(pool_name);
+
+            let traffic_path = environment::tmp_file_path("write_retry_policy_without_retries.jsonl");
+            let pool_handle = _open_pool_losing_first_write_reply(pool_name, 1, &traffic_path);
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let res = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request);
+            assert_code!(ErrorCode::PoolLedgerTimeout, res);
+
+            pool::close(pool_handle).unwrap();
+
+            assert_eq!(1, _sent_requests(&traffic_path, constants::NYM).len());
+            assert!(_sent_requests(&traffic_path, constants::GET_NYM).is_empty());
+
+            utils::tear_down_with_wallet(wallet_handle, pool_name, &config);
+        }
+    }
+
+    mod submit_requests_batch {
+        use super::*;
+        use utils::{pool, ledger};
+
+        fn _get_pool_txn_requests(count: i32) -> Vec<String> {
+            (1..count + 1)
+                .map(|seq_no| ledger::build_get_txn_request(None, seq_no, Some("POOL")).unwrap())
+                .collect()
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works() {
+            utils::setup("indy_submit_requests_batch_works");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_requests_batch_works").unwrap();
+
+            let requests = _get_pool_txn_requests(4);
+            let responses = ledger::submit_requests_batch(pool_handle, &serde_json::to_string(&requests).unwrap()).unwrap();
+            let responses: Vec<serde_json::Value> = serde_json::from_str(&responses).unwrap();
+
+            assert_eq!(4, responses.len());
+            for (i, response) in responses.iter().enumerate() {
+                let response: serde_json::Value = serde_json::from_str(response["response"].as_str().unwrap()).unwrap();
+                assert_eq!((i + 1) as u64, response["result"]["data"]["txnMetadata"]["seqNo"].as_u64().unwrap());
+            }
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count() {
+            utils::setup("indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool("indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count", None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config("indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count", Some(pool_config.as_str())).unwrap();
+            let pool_handle = pool::open_pool_ledger("indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count",
+                                                     Some(r#"{"networker": "local", "batch_limit": 1}"#)).unwrap();
+
+            let requests = _get_pool_txn_requests(4);
+            let responses = ledger::submit_requests_batch(pool_handle, &serde_json::to_string(&requests).unwrap()).unwrap();
+            let responses: Vec<serde_json::Value> = serde_json::from_str(&responses).unwrap();
+
+            assert_eq!(4, responses.len());
+            assert!(responses.iter().all(|response| response["response"].is_string()));
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works_for_batch_limit_less_than_requests_count");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_zero_batch_limit() {
+            utils::setup("indy_submit_requests_batch_works_for_zero_batch_limit");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool("indy_submit_requests_batch_works_for_zero_batch_limit", None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config("indy_submit_requests_batch_works_for_zero_batch_limit", Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger("indy_submit_requests_batch_works_for_zero_batch_limit",
+                                             Some(r#"{"networker": "local", "batch_limit": 0}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down("indy_submit_requests_batch_works_for_zero_batch_limit");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_request_in_batch() {
+            utils::setup("indy_submit_requests_batch_works_for_invalid_request_in_batch");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_requests_batch_works_for_invalid_request_in_batch").unwrap();
+
+            let get_txn_request: serde_json::Value = serde_json::from_str(&ledger::build_get_txn_request(None, 1, Some("POOL")).unwrap()).unwrap();
+            let requests = json!([get_txn_request, "invalid request", get_txn_request]);
+
+            let responses = ledger::submit_requests_batch(pool_handle, &requests.to_string()).unwrap();
+            let responses: Vec<serde_json::Value> = serde_json::from_str(&responses).unwrap();
+
+            assert_eq!(3, responses.len());
+            assert!(responses[0]["response"].is_string());
+            assert_eq!(ErrorCode::CommonInvalidStructure as i64, responses[1]["error"]["code"].as_i64().unwrap());
+            assert!(responses[2]["response"].is_string());
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works_for_invalid_request_in_batch");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_empty_batch() {
+            utils::setup("indy_submit_requests_batch_works_for_empty_batch");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_requests_batch_works_for_empty_batch").unwrap();
+
+            let responses = ledger::submit_requests_batch(pool_handle, "[]").unwrap();
+            assert_eq!("[]", responses);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works_for_empty_batch");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_pool_handle() {
+            utils::setup("indy_submit_requests_batch_works_for_invalid_pool_handle");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_requests_batch_works_for_invalid_pool_handle").unwrap();
+
+            let requests = serde_json::to_string(&_get_pool_txn_requests(1)).unwrap();
+
+            let res = ledger::submit_requests_batch(pool_handle + 1, &requests);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works_for_invalid_pool_handle");
+        }
+
+        #[test]
+        fn indy_submit_requests_batch_works_for_invalid_requests_json() {
+            utils::setup("indy_submit_requests_batch_works_for_invalid_requests_json");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_requests_batch_works_for_invalid_requests_json").unwrap();
+
+            let res = ledger::submit_requests_batch(pool_handle, r#"{"not": "array"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down("indy_submit_requests_batch_works_for_invalid_requests_json");
+        }
+    }
+
+    mod submit_request_and_confirm {
+        use super::*;
+        use utils::{pool, ledger, did};
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_and_confirm_works() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_submit_request_and_confirm_works");
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let nym_request = ledger::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            let nym_response = ledger::submit_request_and_confirm(pool_handle, &nym_request, None).unwrap();
+            pool::check_response_type(&nym_response, ResponseType::REPLY);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&my_did), &my_did).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            let get_nym_response: serde_json::Value = serde_json::from_str(&get_nym_response).unwrap();
+            assert!(get_nym_response["result"]["data"].as_str().unwrap().contains(&my_vk));
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_request_and_confirm_works", &wallet_config);
+        }
+
+        #[test]
+        fn indy_submit_request_and_confirm_works_for_reply_without_state_proof() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_submit_request_and_confirm_works_for_reply_without_state_proof");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_request_and_confirm_works_for_reply_without_state_proof").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, None).unwrap();
+            let nym_request = ledger::sign_request(wallet_handle, &trustee_did, &nym_request).unwrap();
+
+            // emulated nodes don't sign state, so the write can't be confirmed
+            let res = ledger::submit_request_and_confirm(pool_handle, &nym_request, Some(r#"{"timeout": 1, "interval": 200}"#));
+            assert_code!(ErrorCode::PoolLedgerTimeout, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_submit_request_and_confirm_works_for_reply_without_state_proof", &config);
+        }
+
+        #[test]
+        fn indy_submit_request_and_confirm_works_for_not_supported_request() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_submit_request_and_confirm_works_for_not_supported_request");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_request_and_confirm_works_for_not_supported_request").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+
+            let request = ledger::build_pool_config_request(&trustee_did, true, false).unwrap();
+            let request = ledger::sign_request(wallet_handle, &trustee_did, &request).unwrap();
+
+            let res = ledger::submit_request_and_confirm(pool_handle, &request, None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_submit_request_and_confirm_works_for_not_supported_request", &config);
+        }
+
+        #[test]
+        fn indy_submit_request_and_confirm_works_for_invalid_options() {
+            utils::setup("indy_submit_request_and_confirm_works_for_invalid_options");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_submit_request_and_confirm_works_for_invalid_options").unwrap();
+
+            let res = ledger::submit_request_and_confirm(pool_handle, REQUEST, Some(r#"{"timeout": "1"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_pool(pool_handle, "indy_submit_request_and_confirm_works_for_invalid_options");
+        }
+    }
+
+    mod check_request_auth {
+        use super::*;
+        use utils::{pool, ledger, did};
+
+        const SCHEMA_CONSTRAINT: &str = r#"{
+            "constraint_id": "OR",
+            "auth_constraints": [
+                {"constraint_id": "ROLE", "role": "0", "sig_count": 1, "need_to_be_owner": false},
+                {"constraint_id": "ROLE", "role": "101", "sig_count": 1, "need_to_be_owner": false, "metadata": {"fees": "schema"}}
+            ]
+        }"#;
+
+        #[test]
+        fn indy_check_request_auth_works_for_schema() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_check_request_auth_works_for_schema");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_check_request_auth_works_for_schema").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let auth_rule_request = ledger::build_auth_rule_request(&trustee_did, constants::SCHEMA, "ADD", "*", None, Some("*"), SCHEMA_CONSTRAINT).unwrap();
+            let auth_rule_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &auth_rule_request).unwrap();
+            pool::check_response_type(&auth_rule_response, ResponseType::REPLY);
+
+            let schema_request = ledger::build_schema_request(&my_did, SCHEMA_DATA).unwrap();
+
+            let auth_check = ledger::check_request_auth(pool_handle, &schema_request, "{}").unwrap();
+            let auth_check: serde_json::Value = serde_json::from_str(&auth_check).unwrap();
+            assert_eq!(false, auth_check["authorized"]);
+            assert_eq!("OR", auth_check["actions"][0]["missing"]["constraint_id"]);
+
+            let auth_check = ledger::check_request_auth(pool_handle, &schema_request, r#"{"additionalSigners": [{"role": "ENDORSER"}]}"#).unwrap();
+            let auth_check: serde_json::Value = serde_json::from_str(&auth_check).unwrap();
+            assert_eq!(true, auth_check["authorized"]);
+            assert_eq!("101", auth_check["actions"][0]["satisfied"]["role"]);
+            assert_eq!("schema", auth_check["actions"][0]["satisfied"]["metadata"]["fees"]);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_check_request_auth_works_for_schema", &config);
+        }
+
+        #[test]
+        fn indy_check_request_auth_works_for_new_nym() {
+            let (wallet_handle, config) = utils::setup_with_wallet("indy_check_request_auth_works_for_new_nym");
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_check_request_auth_works_for_new_nym").unwrap();
+
+            let (trustee_did, _) = did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (my_did, my_vk) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let constraint = r#"{"constraint_id": "ROLE", "role": "0", "sig_count": 2, "need_to_be_owner": false}"#;
+            let auth_rule_request = ledger::build_auth_rule_request(&trustee_did, constants::NYM, "ADD", "role", None, Some("0"), constraint).unwrap();
+            let auth_rule_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &auth_rule_request).unwrap();
+            pool::check_response_type(&auth_rule_response, ResponseType::REPLY);
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_vk), None, Some("TRUSTEE")).unwrap();
+
+            let auth_check = ledger::check_request_auth(pool_handle, &nym_request, r#"{"role": "TRUSTEE"}"#).unwrap();
+            let auth_check: serde_json::Value = serde_json::from_str(&auth_check).unwrap();
+            assert_eq!(false, auth_check["authorized"]);
+            assert_eq!("role", auth_check["actions"][0]["field"]);
+            assert_eq!(1, auth_check["actions"][0]["missing"]["sig_count"]);
+
+            pool::close(pool_handle).unwrap();
+            utils::tear_down_with_wallet(wallet_handle, "indy_check_request_auth_works_for_new_nym", &config);
+        }
+
+        #[test]
+        fn indy_check_request_auth_works_for_not_supported_request() {
+            let pool_handle = utils::setup_with_pool("indy_check_request_auth_works_for_not_supported_request");
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+
+            let res = ledger::check_request_auth(pool_handle, &get_nym_request, "{}");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_pool(pool_handle, "indy_check_request_auth_works_for_not_supported_request");
+        }
+
+        #[test]
+        fn indy_check_request_auth_works_for_invalid_role_info() {
+            let pool_handle = utils::setup_with_pool("indy_check_request_auth_works_for_invalid_role_info");
+
+            let schema_request = ledger::build_schema_request(DID_TRUSTEE, SCHEMA_DATA).unwrap();
+
+            let res = ledger::check_request_auth(pool_handle, &schema_request, r#"{"role": "UNKNOWN"}"#);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_pool(pool_handle, "indy_check_request_auth_works_for_invalid_role_info");
+        }
+    }
+}
+
+mod medium_cases {
+    use super::*;
+
+    mod requests {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_sign_and_submit_request_works_for_not_found_signer() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_sign_and_submit_request_works_for_not_found_signer");
+
+            let res = ledger::sign_and_submit_request(pool_handle, wallet_handle, &DID, REQUEST);
+            assert_code!(ErrorCode::WalletItemNotFound, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_sign_and_submit_request_works_for_not_found_signer", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_request_works_for_invalid_message() {
+            let pool_handle = utils::setup_with_pool("indy_submit_request_works_for_invalid_message");
+
+            let res = ledger::submit_request(pool_handle, "request");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_pool(pool_handle, "indy_submit_request_works_for_invalid_message");
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_sign_and_submit_request_works_for_invalid_json() {
+            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_sign_and_submit_request_works_for_invalid_json");
+
+            let res = ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, "request");
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_sign_and_submit_request_works_for_invalid_json", &wallet_config);
+        }
+    }
+
+    mod submit_action {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_action_works_for_pool_restart_for_unknown_node_name() {
+            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_submit_action_works_for_pool_restart_for_unknown_node_name");
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(&did).unwrap();
+            let get_validator_info_request = ledger::sign_request(wallet_handle, &did, &get_validator_info_request).unwrap();
+
+            let nodes = r#"["Other Node"]"#;
+            let res = ledger::submit_action(pool_handle, &get_validator_info_request, Some(nodes), None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_action_works_for_pool_restart_for_unknown_node_name", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_submit_action_works_for_pool_restart_for_invalid_nodes_format() {
+            let (wallet_handle, pool_handle, did, wallet_config) = utils::setup_trustee("indy_submit_action_works_for_pool_restart_for_invalid_nodes_format");
+
+            let get_validator_info_request = ledger::build_get_validator_info_request(&did).unwrap();
+            let get_validator_info_request = ledger::sign_request(wallet_handle, &did, &get_validator_info_request).unwrap();
+
+            let nodes = r#""Node1""#;
+            let res = ledger::submit_action(pool_handle, &get_validator_info_request, Some(nodes), None);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_action_works_for_pool_restart_for_invalid_nodes_format", &wallet_config);
+        }
+    }
+
+    mod nym_requests {
+        use super::*;
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_send_nym_request_works_for_only_required_fields() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_for_only_required_fields");
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_for_only_required_fields", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_send_nym_request_works_with_option_fields() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_with_option_fields");
+            let (my_did, my_verkey) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, Some(&my_verkey), Some("some_alias"), Some("STEWARD")).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_with_option_fields", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_send_nym_request_works_for_different_roles() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_send_nym_request_works_for_different_roles");
+
+            for role in ["STEWARD", "TRUSTEE", "TRUST_ANCHOR", "ENDORSER", "NETWORK_MONITOR"].iter() {
+                let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+                let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, Some(role)).unwrap();
+                let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+                pool::check_response_type(&response, ResponseType::REPLY);
+            }
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_send_nym_request_works_for_different_roles", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_build_nym_requests_works_for_wrong_role() {
+            let res = ledger::build_nym_request(&IDENTIFIER, &DEST, None, None, Some("WRONG_ROLE"));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_nym_request_works_for_wrong_signer_role() {
+            let (wallet_handle, pool_handle, trustee_did, wallet_config) = utils::setup_trustee("indy_nym_request_works_for_wrong_signer_role");
+            let (my_did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &my_did, None, None, None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REPLY);
+
+            let (my_did2, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+            let nym_request = ledger::build_nym_request(&my_did, &my_did2, None, None, None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REQNACK);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_nym_request_works_for_wrong_signer_role", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_nym_request_works_for_unknown_signer_did() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_nym_request_works_for_unknown_signer_did");
+
+            let (did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
+
+            let nym_request = ledger::build_nym_request(&did, DID, None, None, None).unwrap();
+            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &did, &nym_request).unwrap();
+            pool::check_response_type(&response, ResponseType::REQNACK);
+
+            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_nym_request_works_for_unknown_signer_did", &wallet_config);
+        }
+
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn indy_get_nym_request_works_for_unknown_did() {
+            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_get_nym_request_works_for_unknown_did");
 
             let (did, _) = did::create_and_store_my_did(wallet_handle, None).unwrap();
 
@@ -3532,6 +4595,56 @@ mod medium_cases {
         }
     }
 
+    mod genesis_transactions {
+        use super::*;
+
+        #[test]
+        fn indy_build_genesis_transactions_works_for_empty_nodes() {
+            let res = ledger::build_genesis_transactions(&json!({"trustee_seeds": [TRUSTEE_SEED], "nodes": []}).to_string());
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_genesis_transactions_works_for_duplicated_alias() {
+            let node = |seed: &str, steward_seed: &str| json!({
+                "alias": "Node1",
+                "seed": seed,
+                "steward_seed": steward_seed,
+                "node_ip": "10.0.0.2",
+                "node_port": 9701,
+                "client_ip": "10.0.0.2",
+                "client_port": 9702,
+            });
+
+            let nodes_config = json!({
+                "trustee_seeds": [TRUSTEE_SEED],
+                "nodes": [node("000000000000000000000000000Node1", STEWARD_SEED), node("000000000000000000000000000Node2", MY1_SEED)]
+            }).to_string();
+
+            let res = ledger::build_genesis_transactions(&nodes_config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+
+        #[test]
+        fn indy_build_genesis_transactions_works_for_invalid_seed() {
+            let nodes_config = json!({
+                "trustee_seeds": [TRUSTEE_SEED],
+                "nodes": [{
+                    "alias": "Node1",
+                    "seed": "invalid_seed",
+                    "steward_seed": STEWARD_SEED,
+                    "node_ip": "10.0.0.2",
+                    "node_port": 9701,
+                    "client_ip": "10.0.0.2",
+                    "client_port": 9702,
+                }]
+            }).to_string();
+
+            let res = ledger::build_genesis_transactions(&nodes_config);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
+    }
+
     mod node_requests {
         use super::*;
 
@@ -3543,7 +4656,7 @@ mod medium_cases {
 
         #[test]
         fn indy_build_node_request_works_for_wrong_service() {
-            let data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 1, "alias":"some", "services": ["SERVICE"], "blskey": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW", "blskey_pop": "CnEDk9HrMnmiHXEV1WFgbVCRteYnPqsJwrTdcZaNhFVW"}"#;
+            let data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 1, "alias":"some", "services": ["SERVICE"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba", "blskey_pop": "RahHYiCvoNCtPTrVtP7nMC5eTYrsUA8WjXbdhNc8debh1agE9bGiJxWBXYNFbnJXoXhWFMvyqhqhRoq737YQemH5ik9oL7R4NTTCz2LEZhkgLJzB3QRQqJyBNyv7acbdHrAT8nQ9UkLbaVL9NBpnWXBTw4LEMePaSHEw66RzPNdAX1"}"#;
             let res = ledger::build_node_request(IDENTIFIER, DEST, data);
             assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
@@ -3575,33 +4688,23 @@ mod medium_cases {
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_node_request_works_for_new_node_without_bls_pop() {
-            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_submit_node_request_works_for_new_node_without_bls_pop");
-
-            let (my_did, _) = did::create_store_and_publish_my_did_from_steward(wallet_handle, pool_handle).unwrap();
-
+        fn indy_build_node_request_works_for_new_node_without_bls_pop() {
             let node_data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 2, "alias":"some", "services": ["VALIDATOR"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba"}"#;
-            let node_request = ledger::build_node_request(&my_did, DEST, node_data).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &node_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REQNACK);
-
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_node_request_works_for_new_node_without_bls_pop", &wallet_config);
+            let res = ledger::build_node_request(IDENTIFIER, DEST, node_data);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
 
         #[test]
-        #[cfg(feature = "local_nodes_pool")]
-        fn indy_submit_node_request_works_for_pop_not_correspond_blskey() {
-            let (wallet_handle, pool_handle, wallet_config) = utils::setup_with_wallet_and_pool("indy_submit_node_request_works_for_pop_not_correspond_blskey");
-
-            let (my_did, _) = did::create_store_and_publish_my_did_from_steward(wallet_handle, pool_handle).unwrap();
-
+        fn indy_build_node_request_works_for_pop_not_correspond_blskey() {
             let node_data = r#"{"node_ip":"10.0.0.100", "node_port": 1, "client_ip": "10.0.0.100", "client_port": 2, "alias":"some", "services": ["VALIDATOR"], "blskey": "4N8aUNHSgjQVgkpm8nhNEfDf6txHznoYREg9kirmJrkivgL4oSEimFF6nsQ6M41QvhM2Z33nves5vfSn9n1UwNFJBYtWVnHYMATn76vLuL3zU88KyeAYcHfsih3He6UHcXDxcaecHVz6jhCYz1P2UZn2bDVruL5wXpehgBfBaLKm3Ba", "blskey_pop": "RPLagxaR5xdimFzwmzYnz4ZhWtYQEj8iR5ZU53T2gitPCyCHQneUn2Huc4oeLd2B2HzkGnjAff4hWTJT6C7qHYB1Mv2wU5iHHGFWkhnTX9WsEAbunJCV2qcaXScKj4tTfvdDKfLiVuU2av6hbsMztirRze7LvYBkRHV3tGwyCptsrP"}"#;
-            let node_request = ledger::build_node_request(&my_did, DEST, node_data).unwrap();
-            let response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &my_did, &node_request).unwrap();
-            pool::check_response_type(&response, ResponseType::REQNACK);
+            let res = ledger::build_node_request(IDENTIFIER, DEST, node_data);
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+        }
 
-            utils::tear_down_with_wallet_and_pool(wallet_handle, pool_handle, "indy_submit_node_request_works_for_pop_not_correspond_blskey", &wallet_config);
+        #[test]
+        fn indy_generate_bls_keys_works_for_invalid_seed() {
+            let res = ledger::generate_bls_keys(Some("invalid_seed"));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
         }
     }
 
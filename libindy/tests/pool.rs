@@ -140,6 +140,99 @@ mod high_cases {
             utils::tear_down("open_pool_ledger_works_for_config");
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_adaptive_read_strategy() {
+            let pool_name = "open_pool_ledger_works_for_adaptive_read_strategy";
+            utils::setup(pool_name);
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let config = r#"{"read_strategy": "adaptive"}"#;
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(pool_name, Some(config)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            pool::check_response_type(&get_nym_response, utils::types::ResponseType::REPLY);
+
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down(pool_name);
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_local_networker() {
+            utils::setup("open_pool_ledger_works_for_local_networker");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_name = "open_pool_ledger_works_for_local_networker";
+            let config = r#"{"networker": "local"}"#;
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(pool_name, Some(config)).unwrap();
+
+            let (wallet_handle, wallet_config) = utils::wallet::create_and_open_default_wallet(pool_name).unwrap();
+            let (trustee_did, _) = utils::did::create_and_store_my_did(wallet_handle, Some(TRUSTEE_SEED)).unwrap();
+            let (did, verkey) = utils::did::create_and_store_my_did(wallet_handle, Some(MY1_SEED)).unwrap();
+
+            let nym_request = ledger::build_nym_request(&trustee_did, &did, Some(&verkey), None, None).unwrap();
+            let nym_response = ledger::sign_and_submit_request(pool_handle, wallet_handle, &trustee_did, &nym_request).unwrap();
+            pool::check_response_type(&nym_response, utils::types::ResponseType::REPLY);
+
+            let get_nym_request = ledger::build_get_nym_request(Some(&did), &did).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            let get_nym_response: serde_json::Value = serde_json::from_str(&get_nym_response).unwrap();
+            assert!(get_nym_response["result"]["data"].as_str().unwrap().contains(&verkey));
+
+            pool::close(pool_handle).unwrap();
+            utils::wallet::close_and_delete_wallet(wallet_handle, &wallet_config).unwrap();
+
+            utils::tear_down("open_pool_ledger_works_for_local_networker");
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_recorded_traffic_replay() {
+            utils::setup("open_pool_ledger_works_for_recorded_traffic_replay");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_name = "open_pool_ledger_works_for_recorded_traffic_replay";
+            let replay_pool_name = "open_pool_ledger_works_for_recorded_traffic_replay_2";
+            let traffic_path = environment::tmp_file_path("recorded_traffic_replay.jsonl");
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+            pool::create_pool_ledger_config(replay_pool_name, Some(pool_config.as_str())).unwrap();
+
+            let config = json!({"networker": "local", "record_traffic": traffic_path.to_str().unwrap()}).to_string();
+            let pool_handle = pool::open_pool_ledger(pool_name, Some(&config)).unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            let get_nym_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+
+            pool::close(pool_handle).unwrap();
+
+            let config = json!({"networker": "replay", "replay_traffic": traffic_path.to_str().unwrap()}).to_string();
+            let pool_handle = pool::open_pool_ledger(replay_pool_name, Some(&config)).unwrap();
+
+            let replayed_response = ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+            assert_eq!(get_nym_response, replayed_response);
+
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down("open_pool_ledger_works_for_recorded_traffic_replay");
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_twice() {
@@ -238,6 +331,161 @@ mod high_cases {
         }
     }
 
+    mod get_metrics {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_metrics_works() {
+            utils::setup("indy_get_pool_metrics_works");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_get_pool_metrics_works").unwrap();
+
+            let get_nym_request = ledger::build_get_nym_request(None, DID_TRUSTEE).unwrap();
+            ledger::submit_request(pool_handle, &get_nym_request).unwrap();
+
+            let metrics = pool::get_metrics(pool_handle).unwrap();
+            let metrics: serde_json::Value = serde_json::from_str(&metrics).unwrap();
+
+            assert_eq!("active", metrics["catchup_state"]);
+            assert_eq!(0, metrics["consensus_failures"]);
+
+            let replies: u64 = metrics["nodes"].as_object().unwrap().values()
+                .map(|node| node["replies"].as_u64().unwrap())
+                .sum();
+            assert!(replies > 0);
+
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down("indy_get_pool_metrics_works");
+        }
+    }
+
+    mod create_snapshot {
+        use super::*;
+
+        #[test]
+        fn indy_create_pool_snapshot_works() {
+            utils::setup("indy_create_pool_snapshot_works");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_name = "indy_create_pool_snapshot_works";
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+
+            let snapshot = pool::create_snapshot(pool_name).unwrap();
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+
+            assert_eq!(4, snapshot["size"].as_u64().unwrap());
+            assert!(snapshot["rootHash"].is_string());
+            assert_eq!(json!(["Node1", "Node2", "Node3", "Node4"]), snapshot["nodes"]);
+
+            utils::tear_down("indy_create_pool_snapshot_works");
+        }
+
+        #[test]
+        fn open_pool_ledger_works_for_snapshot() {
+            utils::setup("open_pool_ledger_works_for_snapshot");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_name = "open_pool_ledger_works_for_snapshot";
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+            pool::create_snapshot(pool_name).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(pool_name, Some(r#"{"networker": "local"}"#)).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            let pool_handle = pool::open_pool_ledger(pool_name, Some(r#"{"networker": "local", "force_rebuild": true}"#)).unwrap();
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down("open_pool_ledger_works_for_snapshot");
+        }
+    }
+
+    mod register_event_callback {
+        extern crate libc;
+
+        use super::*;
+
+        use self::libc::c_char;
+        use std::ffi::CStr;
+        use std::sync::Mutex;
+        use std::thread;
+        use std::time::Duration;
+
+        lazy_static! {
+            static ref POOL_EVENTS: Mutex<Vec<serde_json::Value>> = Mutex::new(Vec::new());
+        }
+
+        extern fn event_cb(pool_handle: i32, event_json: *const c_char) {
+            let event_json = unsafe { CStr::from_ptr(event_json).to_str().unwrap() };
+            let event: serde_json::Value = serde_json::from_str(event_json).unwrap();
+            assert_eq!(pool_handle, event["poolHandle"].as_i64().unwrap() as i32);
+            POOL_EVENTS.lock().unwrap().push(event);
+        }
+
+        fn wait_for_state(state: &str) -> bool {
+            for _ in 0..50 {
+                if POOL_EVENTS.lock().unwrap().iter().any(|event| event["state"] == state) {
+                    return true;
+                }
+                thread::sleep(Duration::from_millis(100));
+            }
+            false
+        }
+
+        #[test]
+        fn indy_register_pool_event_callback_works() {
+            utils::setup("indy_register_pool_event_callback_works");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_register_pool_event_callback_works").unwrap();
+
+            pool::register_event_callback(pool_handle, event_cb).unwrap();
+
+            pool::refresh(pool_handle).unwrap();
+            assert!(wait_for_state("getting_catchup_target"));
+            assert!(wait_for_state("active"));
+
+            {
+                let events = POOL_EVENTS.lock().unwrap();
+                let active = events.iter().find(|event| event["state"] == "active").unwrap();
+                assert_eq!(json!(["Node1", "Node2", "Node3", "Node4"]), active["nodes"]);
+            }
+
+            pool::close(pool_handle).unwrap();
+            assert!(wait_for_state("closed"));
+
+            utils::tear_down("indy_register_pool_event_callback_works");
+        }
+
+        #[test]
+        fn indy_register_pool_event_callback_by_name_works_for_opening() {
+            let pool_name = "indy_register_pool_event_callback_by_name_works_for_opening";
+            utils::setup(pool_name);
+
+            pool::register_event_callback_by_name(pool_name, event_cb).unwrap();
+
+            let pool_handle = pool::create_and_open_local_pool_ledger(pool_name).unwrap();
+
+            // catchup on opening is reported before the pool handle is returned
+            {
+                let events = POOL_EVENTS.lock().unwrap();
+                let events: Vec<&serde_json::Value> = events.iter().filter(|event| event["poolName"] == pool_name).collect();
+                assert!(events.iter().any(|event| event["state"] == "getting_catchup_target"));
+                assert!(events.iter().all(|event| event["poolHandle"] == pool_handle));
+            }
+
+            pool::close(pool_handle).unwrap();
+            assert!(wait_for_state("closed"));
+
+            utils::tear_down(pool_name);
+        }
+    }
+
     mod close {
         use super::*;
         extern crate futures;
@@ -473,6 +721,23 @@ mod medium_cases {
             utils::tear_down("open_pool_ledger_works_for_wrong_alias");
         }
 
+        #[test]
+        fn open_pool_ledger_works_for_replay_networker_without_traffic() {
+            utils::setup("open_pool_ledger_works_for_replay_networker_without_traffic");
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let pool_name = "open_pool_ledger_works_for_replay_networker_without_traffic";
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(pool_name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(pool_name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(pool_name, Some(r#"{"networker": "replay"}"#));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down("open_pool_ledger_works_for_replay_networker_without_traffic");
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_invalid_config() {
@@ -492,6 +757,25 @@ mod medium_cases {
             utils::tear_down("open_pool_ledger_works_for_invalid_config");
         }
 
+        #[test]
+        #[cfg(feature = "local_nodes_pool")]
+        fn open_pool_ledger_works_for_unknown_read_strategy() {
+            utils::setup("open_pool_ledger_works_for_unknown_read_strategy");
+            let name = "open_pool_ledger_works_for_unknown_read_strategy";
+            let config = r#"{"read_strategy": "fastest"}"#;
+
+            pool::set_protocol_version(PROTOCOL_VERSION).unwrap();
+
+            let txn_file_path = pool::create_genesis_txn_file_for_test_pool(name, None, None);
+            let pool_config = pool::pool_config_json(txn_file_path.as_path());
+            pool::create_pool_ledger_config(name, Some(pool_config.as_str())).unwrap();
+
+            let res = pool::open_pool_ledger(name, Some(config));
+            assert_code!(ErrorCode::CommonInvalidStructure, res);
+
+            utils::tear_down("open_pool_ledger_works_for_unknown_read_strategy");
+        }
+
         #[test]
         #[cfg(feature = "local_nodes_pool")]
         fn open_pool_ledger_works_for_incompatible_protocol_version() {
@@ -589,6 +873,58 @@ mod medium_cases {
         }
     }
 
+    mod get_metrics {
+        use super::*;
+
+        #[test]
+        fn indy_get_pool_metrics_works_for_invalid_handle() {
+            utils::setup("indy_get_pool_metrics_works_for_invalid_handle");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_get_pool_metrics_works_for_invalid_handle").unwrap();
+
+            let res = pool::get_metrics(pool_handle + 1);
+            assert_code!(ErrorCode::PoolLedgerInvalidPoolHandle, res);
+
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down("indy_get_pool_metrics_works_for_invalid_handle");
+        }
+    }
+
+    mod create_snapshot {
+        use super::*;
+
+        #[test]
+        fn indy_create_pool_snapshot_works_for_not_created_pool() {
+            utils::setup("indy_create_pool_snapshot_works_for_not_created_pool");
+
+            let res = pool::create_snapshot("indy_create_pool_snapshot_works_for_not_created_pool");
+            assert_code!(ErrorCode::PoolLedgerNotCreatedError, res);
+
+            utils::tear_down("indy_create_pool_snapshot_works_for_not_created_pool");
+        }
+    }
+
+    mod register_event_callback {
+        use super::*;
+
+        extern fn event_cb(_pool_handle: i32, _event_json: *const ::std::os::raw::c_char) {}
+
+        #[test]
+        fn indy_register_pool_event_callback_works_for_invalid_handle() {
+            utils::setup("indy_register_pool_event_callback_works_for_invalid_handle");
+
+            let pool_handle = pool::create_and_open_local_pool_ledger("indy_register_pool_event_callback_works_for_invalid_handle").unwrap();
+
+            let res = pool::register_event_callback(pool_handle + 1, event_cb);
+            assert_eq!(ErrorCode::PoolLedgerInvalidPoolHandle, res.unwrap_err());
+
+            pool::close(pool_handle).unwrap();
+
+            utils::tear_down("indy_register_pool_event_callback_works_for_invalid_handle");
+        }
+    }
+
     mod set_protocol_version {
         use super::*;
 
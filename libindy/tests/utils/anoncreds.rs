@@ -17,6 +17,7 @@ use std::collections::{HashSet, HashMap};
 use utils::domain::anoncreds::schema::{Schema, SchemaV1};
 use utils::domain::anoncreds::credential_definition::{CredentialDefinition, CredentialDefinitionConfig};
 use utils::domain::anoncreds::revocation_registry_definition::RevocationRegistryConfig;
+use utils::domain::anoncreds::revocation_registry_rotation::RevocationRegistryRotationConfig;
 use utils::domain::anoncreds::credential::{AttributeValues, CredentialInfo};
 use utils::domain::anoncreds::credential_for_proof_request::CredentialsForProofRequest;
 
@@ -77,6 +78,22 @@ pub fn issuer_merge_revocation_registry_deltas(rev_reg_delta: &str, other_rev_re
     anoncreds::issuer_merge_revocation_registry_deltas(rev_reg_delta, other_rev_reg_delta).wait()
 }
 
+pub fn issuer_enable_revoc_reg_rotation(wallet_handle: i32, issuer_did: &str, cred_def_id: &str, config_json: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_enable_revoc_reg_rotation(wallet_handle, issuer_did, cred_def_id, config_json).wait()
+}
+
+pub fn issuer_get_active_revoc_reg(wallet_handle: i32, cred_def_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_active_revoc_reg(wallet_handle, cred_def_id).wait()
+}
+
+pub fn issuer_get_pending_revoc_reg_publications(wallet_handle: i32, cred_def_id: &str) -> Result<String, IndyError> {
+    anoncreds::issuer_get_pending_revoc_reg_publications(wallet_handle, cred_def_id).wait()
+}
+
+pub fn issuer_confirm_revoc_reg_publication(wallet_handle: i32, cred_def_id: &str, rev_reg_id: &str) -> Result<(), IndyError> {
+    anoncreds::issuer_confirm_revoc_reg_publication(wallet_handle, cred_def_id, rev_reg_id).wait()
+}
+
 pub fn prover_create_master_secret(wallet_handle: i32, master_secret_id: &str) -> Result<String, IndyError> {
     anoncreds::prover_create_master_secret(wallet_handle, Some(master_secret_id)).wait()
 }
@@ -154,6 +171,11 @@ pub fn verifier_verify_proof(proof_request_json: &str, proof_json: &str, schemas
     anoncreds::verifier_verify_proof(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
 }
 
+pub fn verifier_verify_proof_with_report(proof_request_json: &str, proof_json: &str, schemas_json: &str,
+                                         cred_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Result<String, IndyError> {
+    anoncreds::verifier_verify_proof_with_report(proof_request_json, proof_json, schemas_json, cred_defs_json, rev_reg_defs_json, rev_regs_json).wait()
+}
+
 pub fn create_revocation_state(blob_storage_reader_handle: i32, rev_reg_def_json: &str,
                                rev_reg_delta_json: &str, timestamp: u64, cred_rev_id: &str) -> Result<String, IndyError> {
     anoncreds::create_revocation_state(blob_storage_reader_handle, rev_reg_def_json, rev_reg_delta_json, timestamp, cred_rev_id).wait()
@@ -184,6 +206,17 @@ pub fn issuance_by_default_rev_reg_config() -> String {
     serde_json::to_string(&RevocationRegistryConfig { max_cred_num: Some(5), issuance_type: Some("ISSUANCE_BY_DEFAULT".to_string()) }).unwrap()
 }
 
+pub fn revoc_reg_rotation_config(max_cred_num: u32, fill_threshold: f64) -> String {
+    serde_json::to_string(&RevocationRegistryRotationConfig {
+        tag: None,
+        issuance_type: None,
+        max_cred_num: Some(max_cred_num),
+        fill_threshold: Some(fill_threshold),
+        tails_writer_type: TYPE.to_string(),
+        tails_writer_config: serde_json::from_str(&tails_writer_config()).unwrap(),
+    }).unwrap()
+}
+
 pub fn gvt_schema_id() -> String {
     Schema::schema_id(ISSUER_DID, GVT_SCHEMA_NAME, SCHEMA_VERSION)
 }
@@ -540,6 +573,20 @@ pub fn proof_request_attr() -> String {
         }).to_string()
 }
 
+pub fn proof_request_attr_group() -> String {
+    json!({
+           "nonce":"123432421212",
+           "name":"proof_req_1",
+           "version":"0.1",
+           "requested_attributes": json!({
+               "attr1_referent": json!({
+                   "names": ["name", "sex"]
+               })
+           }),
+           "requested_predicates": json!({}),
+        }).to_string()
+}
+
 pub fn proof_json() -> String {
     r#"{
             "proof":{
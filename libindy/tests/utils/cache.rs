@@ -20,4 +20,36 @@ pub fn purge_schema_cache(wallet_handle: i32, options_json: &str) -> Result<(),
 
 pub fn purge_cred_def_cache(wallet_handle: i32, options_json: &str) -> Result<(), IndyError> {
     cache::purge_cred_def_cache(wallet_handle, options_json).wait()
-}
\ No newline at end of file
+}
+
+pub fn get_rich_schema_object_cache(pool_handle: i32, wallet_handle: i32, submitter_did: &str, id: &str, options_json: &str) -> Result<String, IndyError> {
+    cache::get_rich_schema_object(pool_handle, wallet_handle, submitter_did, id, options_json).wait()
+}
+
+pub fn purge_rich_schema_cache(wallet_handle: i32, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_rich_schema_cache(wallet_handle, options_json).wait()
+}
+
+pub fn get_cached_request(pool_handle: i32, wallet_handle: i32, request_json: &str, options_json: &str) -> Result<String, IndyError> {
+    cache::get_cached_request(pool_handle, wallet_handle, request_json, options_json).wait()
+}
+
+pub fn purge_request_cache(wallet_handle: i32, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_request_cache(wallet_handle, options_json).wait()
+}
+
+pub fn resolve_did(pool_handle: i32, wallet_handle: i32, did: &str, options_json: &str) -> Result<String, IndyError> {
+    cache::resolve_did(pool_handle, wallet_handle, did, options_json).wait()
+}
+
+pub fn purge_did_doc_cache(wallet_handle: i32, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_did_doc_cache(wallet_handle, options_json).wait()
+}
+
+pub fn get_revocation_history(pool_handle: i32, wallet_handle: i32, rev_reg_def_id: &str, from: i64, to: i64, options_json: &str) -> Result<String, IndyError> {
+    cache::get_revocation_history(pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json).wait()
+}
+
+pub fn purge_rev_reg_state_cache(wallet_handle: i32, options_json: &str) -> Result<(), IndyError> {
+    cache::purge_rev_reg_state_cache(wallet_handle, options_json).wait()
+}
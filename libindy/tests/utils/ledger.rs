@@ -34,6 +34,14 @@ pub fn submit_request(pool_handle: i32, request_json: &str) -> Result<String, In
     ledger::submit_request(pool_handle, request_json).wait()
 }
 
+pub fn submit_request_and_confirm(pool_handle: i32, request_json: &str, options_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::submit_request_and_confirm(pool_handle, request_json, options_json).wait()
+}
+
+pub fn submit_requests_batch(pool_handle: i32, requests_json: &str) -> Result<String, IndyError> {
+    ledger::submit_requests_batch(pool_handle, requests_json).wait()
+}
+
 pub fn submit_action(pool_handle: i32, request_json: &str, nodes: Option<&str>, timeout: Option<i32>) -> Result<String, IndyError> {
     ledger::submit_action(pool_handle, request_json, nodes, timeout).wait()
 }
@@ -91,10 +99,18 @@ pub fn build_get_attrib_request(submitter_did: Option<&str>, target_did: &str, r
     ledger::build_get_attrib_request(submitter_did, target_did, raw, hash, enc).wait()
 }
 
+pub fn parse_get_attrib_response(get_attrib_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_attrib_response(get_attrib_response).wait()
+}
+
 pub fn build_get_nym_request(submitter_did: Option<&str>, target_did: &str) -> Result<String, IndyError> {
     ledger::build_get_nym_request(submitter_did, target_did).wait()
 }
 
+pub fn parse_get_nym_response(get_nym_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_nym_response(get_nym_response).wait()
+}
+
 pub fn build_schema_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
     ledger::build_schema_request(submitter_did, data).wait()
 }
@@ -115,6 +131,14 @@ pub fn build_node_request(submitter_did: &str, target_did: &str, data: &str) ->
     ledger::build_node_request(submitter_did, target_did, data).wait()
 }
 
+pub fn generate_bls_keys(seed: Option<&str>) -> Result<(String, String), IndyError> {
+    ledger::generate_bls_keys(seed).wait()
+}
+
+pub fn build_genesis_transactions(nodes_config: &str) -> Result<(String, String), IndyError> {
+    ledger::build_genesis_transactions(nodes_config).wait()
+}
+
 pub fn build_get_validator_info_request(submitter_did: &str) -> Result<String, IndyError> {
     ledger::build_get_validator_info_request(submitter_did).wait()
 }
@@ -123,6 +147,10 @@ pub fn build_get_txn_request(submitter_did: Option<&str>, data: i32, ledger_type
     ledger::build_get_txn_request(submitter_did, ledger_type, data).wait()
 }
 
+pub fn parse_get_txn_response(get_txn_response: &str) -> Result<String, IndyError> {
+    ledger::parse_get_txn_response(get_txn_response).wait()
+}
+
 pub fn build_pool_config_request(submitter_did: &str, writes: bool, force: bool) -> Result<String, IndyError> {
     ledger::build_pool_config_request(submitter_did, writes, force).wait()
 }
@@ -139,6 +167,14 @@ pub fn build_pool_upgrade_request(submitter_did: &str, name: &str, version: &str
                                        timeout, schedule, justification, reinstall, force, package).wait()
 }
 
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Result<String, IndyError> {
+    ledger::build_ledgers_freeze_request(submitter_did, ledgers_ids).wait()
+}
+
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_get_frozen_ledgers_request(submitter_did).wait()
+}
+
 pub fn build_revoc_reg_def_request(submitter_did: &str, data: &str) -> Result<String, IndyError> {
     ledger::build_revoc_reg_def_request(submitter_did, data).wait()
 }
@@ -167,6 +203,23 @@ pub fn parse_get_cred_def_response(get_cred_def_response: &str) -> Result<(Strin
     ledger::parse_get_cred_def_response(get_cred_def_response).wait()
 }
 
+pub fn build_rich_schema_request(submitter_did: &str, id: &str, content: &str, rs_name: &str, rs_version: &str, rs_type: &str,
+                                 ver: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_rich_schema_request(submitter_did, id, content, rs_name, rs_version, rs_type, ver).wait()
+}
+
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, id: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_id_request(submitter_did, id).wait()
+}
+
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Result<String, IndyError> {
+    ledger::build_get_rich_schema_object_by_metadata_request(submitter_did, rs_type, rs_name, rs_version).wait()
+}
+
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Result<(String, String), IndyError> {
+    ledger::parse_get_rich_schema_object_response(get_rich_schema_object_response).wait()
+}
+
 pub fn parse_get_revoc_reg_def_response(get_revoc_reg_def_response: &str) -> Result<(String, String), IndyError> {
     ledger::parse_get_revoc_reg_def_response(get_revoc_reg_def_response).wait()
 }
@@ -224,6 +277,10 @@ pub fn build_get_auth_rule_request(submitter_did: Option<&str>,
     ledger::build_get_auth_rule_request(submitter_did, auth_type, auth_action, field, old_value, new_value).wait()
 }
 
+pub fn check_request_auth(pool_handle: i32, request_json: &str, submitter_role_info: &str) -> Result<String, IndyError> {
+    ledger::check_request_auth(pool_handle, request_json, submitter_role_info).wait()
+}
+
 pub fn build_txn_author_agreement_request(submitter_did: &str,
                                           text: &str,
                                           version: &str) -> Result<String, IndyError> {
@@ -257,11 +314,63 @@ pub fn append_txn_author_agreement_acceptance_to_request(request_json: &str,
     ledger::append_txn_author_agreement_acceptance_to_request(request_json, text, version, taa_digest, acc_mech_type, time_of_acceptance).wait()
 }
 
+pub fn set_txn_author_agreement_acceptance(pool_handle: i32,
+                                           text: Option<&str>,
+                                           version: Option<&str>,
+                                           taa_digest: Option<&str>,
+                                           acc_mech_type: &str,
+                                           time_of_acceptance: u64) -> Result<(), IndyError> {
+    ledger::set_txn_author_agreement_acceptance(pool_handle, text, version, taa_digest, acc_mech_type, time_of_acceptance).wait()
+}
+
 pub fn append_request_endorser(request_json: &str,
                                endorser_did: &str) -> Result<String, IndyError> {
     ledger::append_request_endorser(request_json, endorser_did).wait()
 }
 
+pub fn build_endorsement_bundle(request_json: &str, metadata_json: Option<&str>) -> Result<String, IndyError> {
+    ledger::build_endorsement_bundle(request_json, metadata_json).wait()
+}
+
+pub fn review_endorsement_bundle(pool_handle: i32, bundle_json: &str) -> Result<String, IndyError> {
+    ledger::review_endorsement_bundle(pool_handle, bundle_json).wait()
+}
+
+pub fn endorse_request(pool_handle: i32, wallet_handle: i32, endorser_did: &str, bundle_json: &str) -> Result<String, IndyError> {
+    ledger::endorse_request(pool_handle, wallet_handle, endorser_did, bundle_json).wait()
+}
+
+pub fn verify_txn_inclusion(root_hash: &str, txn_json: &str, audit_path_json: Option<&str>, ledger_size: i32) -> Result<bool, IndyError> {
+    ledger::verify_txn_inclusion(root_hash, txn_json, audit_path_json, ledger_size).wait()
+}
+
+pub fn open_ledger_iterator(pool_handle: i32, ledger_type: Option<&str>, from: i64, to: i64) -> Result<i32, IndyError> {
+    ledger::open_ledger_iterator(pool_handle, ledger_type, from, to).wait()
+}
+
+pub fn ledger_iterator_next(iterator_handle: i32) -> Result<String, IndyError> {
+    ledger::ledger_iterator_next(iterator_handle).wait()
+}
+
+pub fn close_ledger_iterator(iterator_handle: i32) -> Result<(), IndyError> {
+    ledger::close_ledger_iterator(iterator_handle).wait()
+}
+
+pub fn read_ledger_iterator(iterator_handle: i32) -> Result<Vec<serde_json::Value>, IndyError> {
+    let mut txns = Vec::new();
+
+    loop {
+        let batch = ledger_iterator_next(iterator_handle)?;
+        let batch: Vec<serde_json::Value> = serde_json::from_str(&batch).unwrap();
+
+        if batch.is_empty() {
+            return Ok(txns);
+        }
+
+        txns.extend(batch);
+    }
+}
+
 pub fn post_entities() -> (&'static str, &'static str, &'static str) {
     lazy_static! {
                     static ref COMMON_ENTITIES_INIT: Once = ONCE_INIT;
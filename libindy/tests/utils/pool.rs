@@ -1,5 +1,6 @@
 extern crate byteorder;
 extern crate futures;
+extern crate indy_sys;
 extern crate rmp_serde;
 
 use self::byteorder::{LittleEndian, WriteBytesExt};
@@ -11,12 +12,13 @@ use std::path::{Path, PathBuf};
 use indy::{ErrorCode, IndyError};
 use indy::pool;
 use self::futures::Future;
+use self::indy_sys::pool::{PoolEventCB, indy_register_pool_event_callback};
 use serde_json;
 use time;
 
 use utils::types::{Response, ResponseType};
 use utils::constants::PROTOCOL_VERSION;
-use utils::{environment, test};
+use utils::{callback, environment, results, test};
 
 #[derive(Serialize, Deserialize)]
 struct PoolConfig {
@@ -172,6 +174,14 @@ pub fn create_and_open_pool_ledger(pool_name: &str) -> Result<i32, IndyError> {
     open_pool_ledger(pool_name, None)
 }
 
+pub fn create_and_open_local_pool_ledger(pool_name: &str) -> Result<i32, IndyError> {
+    set_protocol_version(PROTOCOL_VERSION).unwrap();
+    let txn_file_path = create_genesis_txn_file_for_test_pool(pool_name, None, None);
+    let pool_config = pool_config_json(txn_file_path.as_path());
+    create_pool_ledger_config(pool_name, Some(pool_config.as_str()))?;
+    open_pool_ledger(pool_name, Some(r#"{"networker": "local"}"#))
+}
+
 pub fn refresh(pool_handle: i32) -> Result<(), IndyError> {
     pool::refresh_pool_ledger(pool_handle).wait()
 }
@@ -184,6 +194,26 @@ pub fn delete(pool_name: &str) -> Result<(), IndyError> {
     pool::delete_pool_ledger(pool_name).wait()
 }
 
+pub fn get_metrics(pool_handle: i32) -> Result<String, IndyError> {
+    pool::get_pool_metrics(pool_handle).wait()
+}
+
+pub fn create_snapshot(pool_name: &str) -> Result<String, IndyError> {
+    pool::create_pool_snapshot(pool_name).wait()
+}
+
+pub fn register_event_callback(pool_handle: i32, event_cb: PoolEventCB) -> Result<(), ErrorCode> {
+    let (receiver, command_handle, cb) = callback::_closure_to_cb_ec();
+
+    let err = unsafe { indy_register_pool_event_callback(command_handle, pool_handle, Some(event_cb), cb) };
+
+    results::result_to_empty(err, receiver)
+}
+
+pub fn register_event_callback_by_name(pool_name: &str, event_cb: PoolEventCB) -> Result<(), IndyError> {
+    pool::register_pool_event_callback_by_name(pool_name, event_cb).wait()
+}
+
 pub fn set_protocol_version(protocol_version: usize) -> Result<(), IndyError> {
     pool::set_protocol_version(protocol_version).wait()
 }
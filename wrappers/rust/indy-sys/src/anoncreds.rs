@@ -63,6 +63,33 @@ extern {
                                                         other_rev_reg_delta_json: CString,
                                                         cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_issuer_enable_revoc_reg_rotation(command_handle: CommandHandle,
+                                                 wallet_handle: WalletHandle,
+                                                 issuer_did: CString,
+                                                 cred_def_id: CString,
+                                                 config_json: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_get_active_revoc_reg(command_handle: CommandHandle,
+                                            wallet_handle: WalletHandle,
+                                            cred_def_id: CString,
+                                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_get_pending_revoc_reg_publications(command_handle: CommandHandle,
+                                                          wallet_handle: WalletHandle,
+                                                          cred_def_id: CString,
+                                                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_issuer_confirm_revoc_reg_publication(command_handle: CommandHandle,
+                                                     wallet_handle: WalletHandle,
+                                                     cred_def_id: CString,
+                                                     rev_reg_id: CString,
+                                                     cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_prover_create_master_secret(command_handle: CommandHandle,
                                             wallet_handle: WalletHandle,
@@ -183,6 +210,16 @@ extern {
                                       rev_regs_json: CString,
                                       cb: Option<ResponseBoolCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_verifier_verify_proof_with_report(command_handle: CommandHandle,
+                                                  proof_request_json: CString,
+                                                  proof_json: CString,
+                                                  schemas_json: CString,
+                                                  credential_defs_json: CString,
+                                                  rev_reg_defs_json: CString,
+                                                  rev_regs_json: CString,
+                                                  cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_create_revocation_state(command_handle: CommandHandle,
                                         blob_storage_reader_handle: BlobStorageReaderHandle,
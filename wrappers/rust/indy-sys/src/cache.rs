@@ -33,4 +33,63 @@ extern {
                                      wallet_handle: WalletHandle,
                                      options_json: CString,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_rich_schema_object(command_handle: CommandHandle,
+                                       pool_handle: PoolHandle,
+                                       wallet_handle: WalletHandle,
+                                       submitter_did: CString,
+                                       id: CString,
+                                       options_json: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_purge_rich_schema_cache(command_handle: CommandHandle,
+                                        wallet_handle: WalletHandle,
+                                        options_json: CString,
+                                        cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_cached_request(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   wallet_handle: WalletHandle,
+                                   request_json: CString,
+                                   options_json: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_purge_request_cache(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    options_json: CString,
+                                    cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_resolve_did(command_handle: CommandHandle,
+                            pool_handle: PoolHandle,
+                            wallet_handle: WalletHandle,
+                            did: CString,
+                            options_json: CString,
+                            cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_purge_did_doc_cache(command_handle: CommandHandle,
+                                    wallet_handle: WalletHandle,
+                                    options_json: CString,
+                                    cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_revocation_history(command_handle: CommandHandle,
+                                       pool_handle: PoolHandle,
+                                       wallet_handle: WalletHandle,
+                                       rev_reg_def_id: CString,
+                                       from: i64,
+                                       to: i64,
+                                       options_json: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_purge_rev_reg_state_cache(command_handle: CommandHandle,
+                                          wallet_handle: WalletHandle,
+                                          options_json: CString,
+                                          cb: Option<ResponseEmptyCB>) -> Error;
 }
@@ -1,6 +1,6 @@
 use super::*;
 
-use {CString, Error, CommandHandle, WalletHandle, PoolHandle};
+use {CString, Error, CommandHandle, WalletHandle, PoolHandle, SearchHandle};
 
 extern {
     #[no_mangle]
@@ -17,6 +17,19 @@ extern {
                                request_json: CString,
                                cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_submit_request_and_confirm(command_handle: CommandHandle,
+                                           pool_handle: PoolHandle,
+                                           request_json: CString,
+                                           options_json: CString,
+                                           cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_submit_requests_batch(command_handle: CommandHandle,
+                                      pool_handle: PoolHandle,
+                                      requests_json: CString,
+                                      cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_submit_action(command_handle: CommandHandle,
                               pool_handle: PoolHandle,
@@ -60,6 +73,11 @@ extern {
                                       target_did: CString,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_parse_get_nym_response(command_handle: CommandHandle,
+                                       get_nym_response: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_attrib_request(command_handle: CommandHandle,
                                      submitter_did: CString,
@@ -78,6 +96,11 @@ extern {
                                          enc: CString,
                                          cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_parse_get_attrib_response(command_handle: CommandHandle,
+                                          get_attrib_response: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_schema_request(command_handle: CommandHandle,
                                      submitter_did: CString,
@@ -119,6 +142,16 @@ extern {
                                    data: CString,
                                    cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_generate_bls_keys(command_handle: CommandHandle,
+                                  seed: CString,
+                                  cb: Option<ResponseStringStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_genesis_transactions(command_handle: CommandHandle,
+                                           nodes_config: CString,
+                                           cb: Option<ResponseStringStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_get_validator_info_request(command_handle: CommandHandle,
                                                  submitter_did: CString,
@@ -131,6 +164,11 @@ extern {
                                       seq_no: i32,
                                       cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_parse_get_txn_response(command_handle: CommandHandle,
+                                       get_txn_response: CString,
+                                       cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_pool_config_request(command_handle: CommandHandle,
                                           submitter_did: CString,
@@ -160,6 +198,17 @@ extern {
                                            package: CString,
                                            cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_build_ledgers_freeze_request(command_handle: CommandHandle,
+                                             submitter_did: CString,
+                                             ledgers_ids: CString,
+                                             cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_frozen_ledgers_request(command_handle: CommandHandle,
+                                                 submitter_did: CString,
+                                                 cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_revoc_reg_def_request(command_handle: CommandHandle,
                                             submitter_did: CString,
@@ -210,6 +259,36 @@ extern {
                                                    get_revoc_reg_delta_response: CString,
                                                    cb: Option<ResponseStringStringU64CB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_build_rich_schema_request(command_handle: CommandHandle,
+                                          submitter_did: CString,
+                                          id: CString,
+                                          content: CString,
+                                          rs_name: CString,
+                                          rs_version: CString,
+                                          rs_type: CString,
+                                          ver: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_rich_schema_object_by_id_request(command_handle: CommandHandle,
+                                                           submitter_did: CString,
+                                                           id: CString,
+                                                           cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle,
+                                                                 submitter_did: CString,
+                                                                 rs_type: CString,
+                                                                 rs_name: CString,
+                                                                 rs_version: CString,
+                                                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_parse_get_rich_schema_object_response(command_handle: CommandHandle,
+                                                      get_rich_schema_object_response: CString,
+                                                      cb: Option<ResponseStringStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_register_transaction_parser_for_sp(command_handle: CommandHandle,
                                                    txn_type: CString,
@@ -249,6 +328,13 @@ extern {
                                             new_value: CString,
                                             cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_check_request_auth(command_handle: CommandHandle,
+                                   pool_handle: PoolHandle,
+                                   request_json: CString,
+                                   submitter_role_info: CString,
+                                   cb: Option<ResponseStringCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_build_txn_author_agreement_request(command_handle: CommandHandle,
                                                    submitter_did: CString,
@@ -287,11 +373,67 @@ extern {
                                                                   time_of_acceptance: u64,
                                                                   cb: Option<ResponseStringCB>) -> Error;
 
+    #[no_mangle]
+    pub fn indy_set_txn_author_agreement_acceptance(command_handle: CommandHandle,
+                                                    pool_handle: PoolHandle,
+                                                    text: CString,
+                                                    version: CString,
+                                                    hash: CString,
+                                                    acc_mech_type: CString,
+                                                    time_of_acceptance: u64,
+                                                    cb: Option<ResponseEmptyCB>) -> Error;
+
     #[no_mangle]
     pub fn indy_append_request_endorser(command_handle: CommandHandle,
                                         request_json: CString,
                                         endorser_did: CString,
                                         cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_build_endorsement_bundle(command_handle: CommandHandle,
+                                         request_json: CString,
+                                         metadata_json: CString,
+                                         cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_review_endorsement_bundle(command_handle: CommandHandle,
+                                          pool_handle: PoolHandle,
+                                          bundle_json: CString,
+                                          cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_endorse_request(command_handle: CommandHandle,
+                                pool_handle: PoolHandle,
+                                wallet_handle: WalletHandle,
+                                endorser_did: CString,
+                                bundle_json: CString,
+                                cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_verify_txn_inclusion(command_handle: CommandHandle,
+                                     root_hash: CString,
+                                     txn_json: CString,
+                                     audit_path_json: CString,
+                                     ledger_size: i32,
+                                     cb: Option<ResponseBoolCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_open_ledger_iterator(command_handle: CommandHandle,
+                                     pool_handle: PoolHandle,
+                                     ledger_type: CString,
+                                     from: i64,
+                                     to: i64,
+                                     cb: Option<ResponseI32CB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_ledger_iterator_next(command_handle: CommandHandle,
+                                     iterator_handle: SearchHandle,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_close_ledger_iterator(command_handle: CommandHandle,
+                                      iterator_handle: SearchHandle,
+                                      cb: Option<ResponseEmptyCB>) -> Error;
 }
 
 pub type CustomTransactionParser = extern fn(reply_from_node: CString, parsed_sp: *mut CString) -> Error;
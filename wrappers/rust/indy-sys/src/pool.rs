@@ -39,5 +39,28 @@ extern {
     pub fn indy_set_protocol_version(command_handle: CommandHandle,
                                      protocol_version: usize,
                                      cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_get_pool_metrics(command_handle: CommandHandle,
+                                 handle: PoolHandle,
+                                 cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_create_pool_snapshot(command_handle: CommandHandle,
+                                     config_name: CString,
+                                     cb: Option<ResponseStringCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_register_pool_event_callback(command_handle: CommandHandle,
+                                             handle: PoolHandle,
+                                             event_cb: Option<PoolEventCB>,
+                                             cb: Option<ResponseEmptyCB>) -> Error;
+
+    #[no_mangle]
+    pub fn indy_register_pool_event_callback_by_name(command_handle: CommandHandle,
+                                                     config_name: CString,
+                                                     event_cb: Option<PoolEventCB>,
+                                                     cb: Option<ResponseEmptyCB>) -> Error;
 }
 
+pub type PoolEventCB = extern fn(pool_handle: PoolHandle, event_json: CString);
@@ -398,6 +398,124 @@ fn _issuer_merge_revocation_registry_deltas(command_handle: CommandHandle, rev_r
     })
 }
 
+/// Enables automatic rotation of revocation registries for the credential definition stored in the wallet.
+///
+/// Creates the first registry of the rotation. The next registry is created in advance when the active one
+/// reaches the fill threshold and becomes active when the active one is full and its publication is confirmed.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `issuer_did`: a DID of the issuer signing transactions to the Ledger
+/// * `cred_def_id`: id of stored in ledger credential definition supporting revocation
+/// * `config_json`: rotation configuration as json:
+///     {
+///         "tag": (optional) prefix of registry tags,
+///         "issuance_type": (optional) type of issuance,
+///         "max_cred_num": (optional) maximum number of credentials each registry can process (default 100000),
+///         "fill_threshold": (optional) part of used registry indices to create the next registry at (default 0.8),
+///         "tails_writer_type": type of blob storage writer to store tails,
+///         "tails_writer_config": config of blob storage writer to store tails
+///     }
+///
+/// # Returns
+/// * `active_revoc_reg_json` - active revocation registry (see issuer_get_active_revoc_reg)
+pub fn issuer_enable_revoc_reg_rotation(wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_enable_revoc_reg_rotation(command_handle, wallet_handle, issuer_did, cred_def_id, config_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_enable_revoc_reg_rotation(command_handle: CommandHandle, wallet_handle: WalletHandle, issuer_did: &str, cred_def_id: &str, config_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let issuer_did = c_str!(issuer_did);
+    let cred_def_id = c_str!(cred_def_id);
+    let config_json = c_str!(config_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_enable_revoc_reg_rotation(command_handle, wallet_handle, issuer_did.as_ptr(), cred_def_id.as_ptr(), config_json.as_ptr(), cb)
+    })
+}
+
+/// Gets revocation registry that must be used to issue credentials of the credential definition
+/// with enabled revocation registry rotation.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition with enabled revocation registry rotation
+///
+/// # Returns
+/// * `active_revoc_reg_json` - {
+///         "rev_reg_id": string,
+///         "rev_reg_def": <revoc_reg_def_json>,
+///         "issued": number,
+///         "max_cred_num": number,
+///         "published": bool,
+///         "next_rev_reg_id": Optional<string>
+///     }
+pub fn issuer_get_active_revoc_reg(wallet_handle: WalletHandle, cred_def_id: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_active_revoc_reg(command_handle, wallet_handle, cred_def_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_active_revoc_reg(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_active_revoc_reg(command_handle, wallet_handle, cred_def_id.as_ptr(), cb)
+    })
+}
+
+/// Gets REVOC_REG_DEF and REVOC_REG_ENTRY ledger requests of rotated revocation registries that aren't confirmed yet.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition with enabled revocation registry rotation
+///
+/// # Returns
+/// * `publications_json` - [{"rev_reg_id": string, "txn_type": string, "request": <request_json>}]
+pub fn issuer_get_pending_revoc_reg_publications(wallet_handle: WalletHandle, cred_def_id: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _issuer_get_pending_revoc_reg_publications(command_handle, wallet_handle, cred_def_id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _issuer_get_pending_revoc_reg_publications(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_get_pending_revoc_reg_publications(command_handle, wallet_handle, cred_def_id.as_ptr(), cb)
+    })
+}
+
+/// Removes requests of the revocation registry written to the ledger from pending publications.
+///
+/// # Arguments
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `cred_def_id`: id of credential definition with enabled revocation registry rotation
+/// * `rev_reg_id`: id of published revocation registry
+pub fn issuer_confirm_revoc_reg_publication(wallet_handle: WalletHandle, cred_def_id: &str, rev_reg_id: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _issuer_confirm_revoc_reg_publication(command_handle, wallet_handle, cred_def_id, rev_reg_id, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _issuer_confirm_revoc_reg_publication(command_handle: CommandHandle, wallet_handle: WalletHandle, cred_def_id: &str, rev_reg_id: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let cred_def_id = c_str!(cred_def_id);
+    let rev_reg_id = c_str!(rev_reg_id);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_issuer_confirm_revoc_reg_publication(command_handle, wallet_handle, cred_def_id.as_ptr(), rev_reg_id.as_ptr(), cb)
+    })
+}
+
 
 /// Creates a master secret with a given id and stores it in the wallet.
 /// The id must be unique.
@@ -791,6 +909,9 @@ fn _prover_close_credentials_search(command_handle: CommandHandle, search_handle
 /// `attr_info`: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string], // attribute names, (case insensitive and ignore spaces)
+///                 // NOTE: either "name" or "names" must be specified, but not both.
+///                 // Use "names" to request several attributes that have to be revealed from a single credential.
 ///         "restrictions": Optional<filter_json>, // see above
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -889,6 +1010,9 @@ fn _prover_get_credentials_for_proof_req(command_handle: CommandHandle, wallet_h
 /// `attr_info`: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string], // attribute names, (case insensitive and ignore spaces)
+///                 // NOTE: either "name" or "names" must be specified, but not both.
+///                 // Use "names" to request several attributes that have to be revealed from a single credential.
 ///         "restrictions": Optional<filter_json>, // see above
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1076,6 +1200,9 @@ fn _prover_close_credentials_search_for_proof_req(command_handle: CommandHandle,
 /// attr_info: Describes requested attribute
 ///     {
 ///         "name": string, // attribute name, (case insensitive and ignore spaces)
+///         "names": [string], // attribute names, (case insensitive and ignore spaces)
+///                 // NOTE: either "name" or "names" must be specified, but not both.
+///                 // Use "names" to request several attributes that have to be revealed from a single credential.
 ///         "restrictions": Optional<wql query>,
 ///         "non_revoked": Optional<<non_revoc_interval>>, // see below,
 ///                        // If specified prover must proof non-revocation
@@ -1112,6 +1239,9 @@ fn _prover_close_credentials_search_for_proof_req(command_handle: CommandHandle,
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {sub_proof_index: number, values: {"attr_name": {raw: string, encoded: string}}},
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1182,6 +1312,9 @@ fn _prover_create_proof(command_handle: CommandHandle, wallet_handle: WalletHand
 ///                 "requested_attr1_id": {sub_proof_index: number, raw: string, encoded: string},
 ///                 "requested_attr4_id": {sub_proof_index: number: string, encoded: string},
 ///             },
+///             "revealed_attr_groups": {
+///                 "requested_attr5_id": {sub_proof_index: number, values: {"attr_name": {raw: string, encoded: string}}},
+///             },
 ///             "unrevealed_attrs": {
 ///                 "requested_attr3_id": {sub_proof_index: number}
 ///             },
@@ -1254,6 +1387,50 @@ fn _verifier_verify_proof(command_handle: CommandHandle, proof_request_json: &st
     })
 }
 
+/// Verifies a proof (of multiple credential) the same way as `verifier_verify_proof`,
+/// but returns the outcome of each performed check instead of a bare boolean.
+///
+/// # Arguments
+/// * `proof_request_json`: proof request json (see `verifier_verify_proof`)
+/// * `proof_json`: created for request proof json (see `verifier_verify_proof`)
+/// * `schemas_json`: all schema jsons participating in the proof
+/// * `credential_defs_json`: all credential definitions json participating in the proof
+/// * `rev_reg_defs_json`: all revocation registry definitions json participating in the proof
+/// * `rev_regs_json`: all revocation registries json participating in the proof
+///
+/// # Returns
+/// * `report_json`: verification report json
+/// {
+///     "valid": bool,
+///     "requested_proof": <check>,
+///     "proof": <check>,
+///     "requested_attributes": {"<attr_referent>": <referent_report>},
+///     "requested_predicates": {"<predicate_referent>": <referent_report>},
+/// }
+/// where `referent_report` contains `valid`, `sub_proof_index` and `received`, `primary_proof`, `predicate`,
+/// `restrictions`, `timestamp`, `revocation` checks, and `check` is
+/// {"status": "passed" | "failed" | "skipped", "reason": Optional<reason code>, "message": Optional<string>}
+pub fn verifier_verify_proof_with_report(proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _verifier_verify_proof_with_report(command_handle, proof_request_json, proof_json, schemas_json, credential_defs_json, rev_reg_defs_json, rev_regs_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _verifier_verify_proof_with_report(command_handle: CommandHandle, proof_request_json: &str, proof_json: &str, schemas_json: &str, credential_defs_json: &str, rev_reg_defs_json: &str, rev_regs_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let proof_request_json = c_str!(proof_request_json);
+    let proof_json = c_str!(proof_json);
+    let schemas_json = c_str!(schemas_json);
+    let credential_defs_json = c_str!(credential_defs_json);
+    let rev_reg_defs_json = c_str!(rev_reg_defs_json);
+    let rev_regs_json = c_str!(rev_regs_json);
+
+    ErrorCode::from(unsafe {
+        anoncreds::indy_verifier_verify_proof_with_report(command_handle, proof_request_json.as_ptr(), proof_json.as_ptr(), schemas_json.as_ptr(), credential_defs_json.as_ptr(), rev_reg_defs_json.as_ptr(), rev_regs_json.as_ptr(), cb)
+    })
+}
+
 
 /// Create revocation state for a credential in the particular time moment.
 ///
@@ -176,4 +176,299 @@ fn _purge_cred_def_cache(command_handle: CommandHandle, wallet_handle: WalletHan
     let options_json = c_str!(options_json);
 
     ErrorCode::from(unsafe { cache::indy_purge_cred_def_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
-}
\ No newline at end of file
+}
+
+/// Gets Rich Schema object json data for specified object id.
+/// If data is present inside of cache, cached data is returned.
+/// Otherwise data is fetched from the ledger and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `submitter_did` - DID of the submitter stored in secured Wallet.
+/// * `id` - identifier of Rich Schema object.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// # Returns
+/// Rich Schema object json (look at `ledger::parse_get_rich_schema_object_response`).
+pub fn get_rich_schema_object(pool_handle: PoolHandle,
+                              wallet_handle: WalletHandle,
+                              submitter_did: &str,
+                              id: &str,
+                              options_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_rich_schema_object(command_handle, pool_handle, wallet_handle, submitter_did, id, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_rich_schema_object(command_handle: CommandHandle,
+                           pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           submitter_did: &str,
+                           id: &str,
+                           options_json: &str,
+                           cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let id = c_str!(id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_rich_schema_object(command_handle, pool_handle, wallet_handle, submitter_did.as_ptr(), id.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge Rich Schema objects cache.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_rich_schema_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_rich_schema_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_rich_schema_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_rich_schema_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Get reply on read request to the ledger (GET_NYM, GET_ATTRIB, GET_REVOC_REG_DEF, GET_REVOC_REG_DELTA, etc.).
+/// If reply is present inside of cache, cached data is returned.
+/// Otherwise request is sent to the ledger and reply confirmed by state proof is stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `request_json` - read request.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// # Returns
+/// Reply of the ledger on request.
+pub fn get_cached_request(pool_handle: PoolHandle,
+                          wallet_handle: WalletHandle,
+                          request_json: &str,
+                          options_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_cached_request(command_handle, pool_handle, wallet_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_cached_request(command_handle: CommandHandle,
+                       pool_handle: PoolHandle,
+                       wallet_handle: WalletHandle,
+                       request_json: &str,
+                       options_json: &str,
+                       cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_cached_request(command_handle, pool_handle, wallet_handle, request_json.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge cache of ledger replies.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///    txnType: (string, optional) Purge only replies on requests of this transaction type. All cached replies are purged by default.
+///  }
+pub fn purge_request_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_request_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_request_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_request_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Resolves did:sov DID into W3C DID Core document.
+/// DID Document is assembled from NYM transaction and raw `endpoint` attribute of the DID.
+/// If DID Document is present inside of cache, cached document is returned.
+/// Otherwise it is built from ledger replies and stored inside of cache for future use.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `did` - DID to resolve, either unqualified or qualified with `did:sov:` prefix.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noUpdate: (bool, optional, false by default) Use only cached data, do not try to update.
+///    noStore: (bool, optional, false by default) Skip storing fresh data if updated,
+///    minFresh: (int, optional, -1 by default) Return cached data if not older than this many seconds. -1 means do not check age.
+///  }
+/// # Returns
+/// DID resolution result json containing `didDocument` and `didResolutionMetadata`.
+pub fn resolve_did(pool_handle: PoolHandle,
+                   wallet_handle: WalletHandle,
+                   did: &str,
+                   options_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _resolve_did(command_handle, pool_handle, wallet_handle, did, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _resolve_did(command_handle: CommandHandle,
+                pool_handle: PoolHandle,
+                wallet_handle: WalletHandle,
+                did: &str,
+                options_json: &str,
+                cb: Option<ResponseStringCB>) -> ErrorCode {
+    let did = c_str!(did);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_resolve_did(command_handle, pool_handle, wallet_handle, did.as_ptr(), options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge DID Document cache.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_did_doc_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_did_doc_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_did_doc_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_did_doc_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
+
+/// Gets history of revocation registry: indices issued and revoked by each REVOC_REG_ENTRY transaction
+/// written in the given interval along with its ledger timestamp and accumulator value.
+/// States of revocation registry are stored inside of cache, so only the latest state is read from the ledger next time.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `rev_reg_def_id` - ID of the revocation registry definition.
+/// * `from` - Requested time represented as a total number of seconds from Unix Epoch. Pass 0 to get the whole history.
+/// * `to` - Requested time represented as a total number of seconds from Unix Epoch.
+/// * `options_json` -
+///  {
+///    noCache: (bool, optional, false by default) Skip usage of cache,
+///    noStore: (bool, optional, false by default) Skip storing fresh data,
+///  }
+/// # Returns
+/// Revocation history json:
+/// {
+///     revRegDefId: string,
+///     entries: [{timestamp: int, value: {accum: string}, issued: [int], revoked: [int]}] - in order of timestamps,
+///     delta: <revocation registry delta json> - all entries merged into one delta, null if there are no entries
+/// }
+pub fn get_revocation_history(pool_handle: PoolHandle,
+                              wallet_handle: WalletHandle,
+                              rev_reg_def_id: &str,
+                              from: i64,
+                              to: i64,
+                              options_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_revocation_history(command_handle, pool_handle, wallet_handle, rev_reg_def_id, from, to, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_revocation_history(command_handle: CommandHandle,
+                           pool_handle: PoolHandle,
+                           wallet_handle: WalletHandle,
+                           rev_reg_def_id: &str,
+                           from: i64,
+                           to: i64,
+                           options_json: &str,
+                           cb: Option<ResponseStringCB>) -> ErrorCode {
+    let rev_reg_def_id = c_str!(rev_reg_def_id);
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(
+        unsafe {
+            cache::indy_get_revocation_history(command_handle, pool_handle, wallet_handle, rev_reg_def_id.as_ptr(), from, to, options_json.as_ptr(), cb)
+        }
+    )
+}
+
+/// Purge revocation registry state cache used by `get_revocation_history`.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `wallet_handle` - wallet handle (created by open_wallet).
+/// * `options_json` -
+///  {
+///    maxAge: (int, optional, -1 by default) Purge cached data if older than this many seconds. -1 means purge all.
+///  }
+pub fn purge_rev_reg_state_cache(wallet_handle: WalletHandle, options_json: &str) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _purge_rev_reg_state_cache(command_handle, wallet_handle, options_json, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _purge_rev_reg_state_cache(command_handle: CommandHandle, wallet_handle: WalletHandle, options_json: &str, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let options_json = c_str!(options_json);
+
+    ErrorCode::from(unsafe { cache::indy_purge_rev_reg_state_cache(command_handle, wallet_handle, options_json.as_ptr(), cb) })
+}
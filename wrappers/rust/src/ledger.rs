@@ -6,12 +6,15 @@ use std::ptr::null;
 use futures::Future;
 
 use ffi::ledger;
-use ffi::{ResponseStringCB,
+use ffi::{ResponseBoolCB,
+          ResponseEmptyCB,
+          ResponseI32CB,
+          ResponseStringCB,
           ResponseStringStringCB,
           ResponseStringStringU64CB};
 
 use utils::callbacks::{ClosureHandler, ResultHandler};
-use {WalletHandle, CommandHandle, PoolHandle};
+use {WalletHandle, CommandHandle, PoolHandle, SearchHandle};
 
 /// Signs and submits request message to validator pool.
 ///
@@ -73,6 +76,57 @@ fn _submit_request(command_handle: CommandHandle, pool_handle: PoolHandle, reque
     ErrorCode::from(unsafe { ledger::indy_submit_request(command_handle, pool_handle, request_json.as_ptr(), cb) })
 }
 
+/// Publishes write request to validator pool and waits until written transaction
+/// can be read back with valid state proof.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `request_json` - Request data json (signed).
+/// * `options_json` - (optional) confirmation options: {"timeout": seconds, "interval": milliseconds}.
+///
+/// # Returns
+/// Write reply as json.
+pub fn submit_request_and_confirm(pool_handle: PoolHandle, request_json: &str, options_json: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_request_and_confirm(command_handle, pool_handle, request_json, options_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_request_and_confirm(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, options_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let options_json_str = opt_c_str!(options_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_submit_request_and_confirm(command_handle, pool_handle, request_json.as_ptr(), opt_c_ptr!(options_json, options_json_str), cb)
+    })
+}
+
+/// Sends several requests to validator pool concurrently and returns all replies at once.
+/// No more requests than `batch_limit` of the pool config (`conn_limit` by default) are in flight at the same time.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by Pool::open_ledger).
+/// * `requests_json` - array of requests. Every request is either json object or string with json.
+///
+/// # Returns
+/// Array of results in the same order as requests. Every result is either
+/// `{"response": string}` or `{"error": {"code": int, "message": string}}`.
+pub fn submit_requests_batch(pool_handle: PoolHandle, requests_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _submit_requests_batch(command_handle, pool_handle, requests_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _submit_requests_batch(command_handle: CommandHandle, pool_handle: PoolHandle, requests_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let requests_json = c_str!(requests_json);
+
+    ErrorCode::from(unsafe { ledger::indy_submit_requests_batch(command_handle, pool_handle, requests_json.as_ptr(), cb) })
+}
+
 pub fn submit_action(pool_handle: PoolHandle, request_json: &str, nodes: Option<&str>, wait_timeout: Option<i32>) -> Box<Future<Item=String, Error=IndyError>> {
     let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
 
@@ -242,6 +296,28 @@ fn _build_get_nym_request(command_handle: CommandHandle, submitter_did: Option<&
     ErrorCode::from(unsafe { ledger::indy_build_get_nym_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), target_did.as_ptr(), cb) })
 }
 
+/// Parse a GET_NYM response to get nym data in the normalized form
+/// independent of reply and transaction format versions.
+///
+/// # Arguments
+/// * `get_nym_response` - response of GET_NYM request.
+///
+/// # Returns
+/// NYM json: {did, verkey, role, seqNo, txnTime}.
+pub fn parse_get_nym_response(get_nym_response: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_nym_response(command_handle, get_nym_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_nym_response(command_handle: CommandHandle, get_nym_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_nym_response = c_str!(get_nym_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_nym_response(command_handle, get_nym_response.as_ptr(), cb) })
+}
+
 /// Builds a GET_TXN request. Request to get any transaction by its seq_no.
 ///
 /// # Arguments
@@ -269,6 +345,28 @@ fn _build_get_txn_request(command_handle: CommandHandle, submitter_did: Option<&
     ErrorCode::from(unsafe { ledger::indy_build_get_txn_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), opt_c_ptr!(ledger_type, ledger_type_str), seq_no, cb) })
 }
 
+/// Parse a GET_TXN response to get transaction data in the normalized form
+/// independent of reply and transaction format versions.
+///
+/// # Arguments
+/// * `get_txn_response` - response of GET_TXN request.
+///
+/// # Returns
+/// Transaction json: {type, seqNo, txnTime, txnId, from, reqId, data}.
+pub fn parse_get_txn_response(get_txn_response: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_txn_response(command_handle, get_txn_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_txn_response(command_handle: CommandHandle, get_txn_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_txn_response = c_str!(get_txn_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_txn_response(command_handle, get_txn_response.as_ptr(), cb) })
+}
+
 /// Builds an ATTRIB request. Request to add attribute to a NYM record.
 ///
 /// # Arguments
@@ -346,6 +444,28 @@ fn _build_get_attrib_request(command_handle: CommandHandle, submitter_did: Optio
     })
 }
 
+/// Parse a GET_ATTRIB response to get attrib data in the normalized form
+/// independent of reply and transaction format versions.
+///
+/// # Arguments
+/// * `get_attrib_response` - response of GET_ATTRIB request.
+///
+/// # Returns
+/// Attribute json: {did, raw, hash, enc, seqNo, txnTime} with one of raw, hash and enc set.
+pub fn parse_get_attrib_response(get_attrib_response: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _parse_get_attrib_response(command_handle, get_attrib_response, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _parse_get_attrib_response(command_handle: CommandHandle, get_attrib_response: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let get_attrib_response = c_str!(get_attrib_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_attrib_response(command_handle, get_attrib_response.as_ptr(), cb) })
+}
+
 /// Builds a SCHEMA request. Request to add Credential's schema.
 ///
 /// # Arguments
@@ -529,6 +649,8 @@ fn _parse_get_cred_def_response(command_handle: CommandHandle, get_cred_def_resp
 /// * `data` - Data associated with the Node: {
 ///     alias: string - Node's alias
 ///     blskey: string - (Optional) BLS multi-signature key as base58-encoded string.
+///     blskey_pop: string - (Optional) BLS key proof of possession as base58-encoded string.
+///                 Must be specified together with blskey (look at `generate_bls_keys` to get the keys).
 ///     client_ip: string - (Optional) Node's client listener IP address.
 ///     client_port: string - (Optional) Node's client listener port.
 ///     node_ip: string - (Optional) The IP address other Nodes use to communicate with this Node.
@@ -554,6 +676,62 @@ fn _build_node_request(command_handle: CommandHandle, submitter_did: &str, targe
     ErrorCode::from(unsafe { ledger::indy_build_node_request(command_handle, submitter_did.as_ptr(), target_did.as_ptr(), data.as_ptr(), cb) })
 }
 
+/// Generates BLS keys of a validator node to be used in NODE transaction.
+///
+/// # Arguments
+/// * `seed` - (Optional) seed of the node. Keys are derived from the seed the same way as validator node does it.
+///            Random keys are generated if seed is not specified.
+///
+/// # Returns
+/// BLS key and its proof of possession as base58-encoded strings.
+pub fn generate_bls_keys(seed: Option<&str>) -> Box<Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _generate_bls_keys(command_handle, seed, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _generate_bls_keys(command_handle: CommandHandle, seed: Option<&str>, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let seed_str = opt_c_str!(seed);
+
+    ErrorCode::from(unsafe { ledger::indy_generate_bls_keys(command_handle, opt_c_ptr!(seed, seed_str), cb) })
+}
+
+/// Builds genesis transactions of a new network: NODE transactions of the pool ledger
+/// and NYM transactions of trustees and node stewards of the domain ledger.
+///
+/// # Arguments
+/// * `nodes_config` - description of the network: {
+///     trustee_seeds: array<string> - seeds of trustee DIDs. At least one is required.
+///     nodes: [{
+///         alias: string - unique node name,
+///         seed: string - seed of node keys,
+///         steward_seed: string - seed of node steward DID,
+///         node_ip: string,
+///         node_port: int,
+///         client_ip: string,
+///         client_port: int,
+///         services: array<string> - (Optional) The service of the Node. VALIDATOR is the only supported one now.
+///     }]
+/// }
+///
+/// # Returns
+/// Pool and domain ledger genesis transactions, one json per line.
+pub fn build_genesis_transactions(nodes_config: &str) -> Box<Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _build_genesis_transactions(command_handle, nodes_config, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _build_genesis_transactions(command_handle: CommandHandle, nodes_config: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let nodes_config = c_str!(nodes_config);
+
+    ErrorCode::from(unsafe { ledger::indy_build_genesis_transactions(command_handle, nodes_config.as_ptr(), cb) })
+}
+
 /// Builds a GET_VALIDATOR_INFO request.
 ///
 /// # Arguments
@@ -714,6 +892,58 @@ fn _build_pool_upgrade_request(command_handle: CommandHandle,
     })
 }
 
+/// Builds a LEDGERS_FREEZE request. Request to freeze list of ledgers (sent by Trustee).
+/// Frozen ledgers can't be written anymore, but their transactions are still available for reading.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `ledgers_ids` - list of ids of ledgers to freeze as json array, for example: [10, 11].
+///                Base ledgers (0 - pool, 1 - domain, 2 - config, 3 - audit) can't be frozen.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_ledgers_freeze_request(submitter_did: &str, ledgers_ids: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_ledgers_freeze_request(command_handle, submitter_did, ledgers_ids, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_ledgers_freeze_request(command_handle: CommandHandle, submitter_did: &str, ledgers_ids: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let ledgers_ids = c_str!(ledgers_ids);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_ledgers_freeze_request(command_handle, submitter_did.as_ptr(), ledgers_ids.as_ptr(), cb)
+    })
+}
+
+/// Builds a GET_FROZEN_LEDGERS request. Request to get list of frozen ledgers.
+/// Frozen ledgers are defined by LEDGERS_FREEZE request.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_frozen_ledgers_request(submitter_did: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_frozen_ledgers_request(command_handle, submitter_did, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_frozen_ledgers_request(command_handle: CommandHandle, submitter_did: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_frozen_ledgers_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), cb)
+    })
+}
+
 /// Builds a REVOC_REG_DEF request. Request to add the definition of revocation registry
 /// to an exists credential definition.
 ///
@@ -962,6 +1192,142 @@ fn _parse_get_revoc_reg_delta_response(command_handle: CommandHandle, get_revoc_
     ErrorCode::from(unsafe { ledger::indy_parse_get_revoc_reg_delta_response(command_handle, get_revoc_reg_delta_response.as_ptr(), cb) })
 }
 
+/// Builds a request to add a Rich Schema object: JSON-LD context, rich schema, encoding, mapping,
+/// credential definition or presentation definition.
+///
+/// # Arguments
+/// * `submitter_did` - Identifier (DID) of the transaction author as base58-encoded string.
+///                Actual request sender may differ if Endorser is used (look at `append_request_endorser`)
+/// * `id` - identifier of the object. Must match `@id` field of content if it is present.
+/// * `content` - JSON-LD content of the object. Content of JSON-LD context must contain `@context` field.
+/// * `rs_name` - name of the object.
+/// * `rs_version` - version of the object.
+/// * `rs_type` - type of the object: "ctx", "sch", "enc", "map", "cdf" or "pdf".
+/// * `ver` - (Optional) version of the object format. "1" is used by default.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_rich_schema_request(submitter_did: &str, id: &str, content: &str, rs_name: &str, rs_version: &str,
+                                 rs_type: &str, ver: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_rich_schema_request(command_handle, submitter_did, id, content, rs_name, rs_version, rs_type, ver, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_rich_schema_request(command_handle: CommandHandle, submitter_did: &str, id: &str, content: &str, rs_name: &str, rs_version: &str,
+                              rs_type: &str, ver: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did = c_str!(submitter_did);
+    let id = c_str!(id);
+    let content = c_str!(content);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+    let rs_type = c_str!(rs_type);
+    let ver_str = opt_c_str!(ver);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_rich_schema_request(command_handle,
+                                               submitter_did.as_ptr(),
+                                               id.as_ptr(),
+                                               content.as_ptr(),
+                                               rs_name.as_ptr(),
+                                               rs_version.as_ptr(),
+                                               rs_type.as_ptr(),
+                                               opt_c_ptr!(ver, ver_str),
+                                               cb)
+    })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_ID request. Request to get Rich Schema object by its identifier.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `id` - identifier of the object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_id_request(submitter_did: Option<&str>, id: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_id_request(command_handle, submitter_did, id, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_id_request(command_handle: CommandHandle, submitter_did: Option<&str>, id: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let id = c_str!(id);
+
+    ErrorCode::from(unsafe { ledger::indy_build_get_rich_schema_object_by_id_request(command_handle, opt_c_ptr!(submitter_did, submitter_did_str), id.as_ptr(), cb) })
+}
+
+/// Builds a GET_RICH_SCHEMA_OBJECT_BY_METADATA request. Request to get Rich Schema object by its type, name and version.
+///
+/// # Arguments
+/// * `submitter_did` - (Optional) DID of the read request sender (if not provided then default Libindy DID will be used).
+/// * `rs_type` - type of the object (look at `build_rich_schema_request`).
+/// * `rs_name` - name of the object.
+/// * `rs_version` - version of the object.
+///
+/// # Returns
+/// Request result as json.
+pub fn build_get_rich_schema_object_by_metadata_request(submitter_did: Option<&str>, rs_type: &str, rs_name: &str, rs_version: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_get_rich_schema_object_by_metadata_request(command_handle, submitter_did, rs_type, rs_name, rs_version, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_get_rich_schema_object_by_metadata_request(command_handle: CommandHandle, submitter_did: Option<&str>, rs_type: &str, rs_name: &str,
+                                                     rs_version: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let submitter_did_str = opt_c_str!(submitter_did);
+    let rs_type = c_str!(rs_type);
+    let rs_name = c_str!(rs_name);
+    let rs_version = c_str!(rs_version);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_get_rich_schema_object_by_metadata_request(command_handle,
+                                                                      opt_c_ptr!(submitter_did, submitter_did_str),
+                                                                      rs_type.as_ptr(),
+                                                                      rs_name.as_ptr(),
+                                                                      rs_version.as_ptr(),
+                                                                      cb)
+    })
+}
+
+/// Parse a GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA response.
+///
+/// # Arguments
+/// * `get_rich_schema_object_response` - response of GET_RICH_SCHEMA_OBJECT_BY_ID or GET_RICH_SCHEMA_OBJECT_BY_METADATA request.
+///
+/// # Returns
+/// Rich Schema object Id and Rich Schema object json.
+/// {
+///     id: identifier of the object
+///     content: JSON-LD content of the object as string
+///     rsName: name of the object
+///     rsVersion: version of the object
+///     rsType: type of the object
+///     ver: version of the object format
+///     seqNo: (Optional) sequence number of the object transaction on the ledger
+///     txnTime: (Optional) time of the object transaction on the ledger
+/// }
+pub fn parse_get_rich_schema_object_response(get_rich_schema_object_response: &str) -> Box<Future<Item=(String, String), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string_string();
+
+    let err = _parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response, cb);
+
+    ResultHandler::str_str(command_handle, err, receiver)
+}
+
+fn _parse_get_rich_schema_object_response(command_handle: CommandHandle, get_rich_schema_object_response: &str, cb: Option<ResponseStringStringCB>) -> ErrorCode {
+    let get_rich_schema_object_response = c_str!(get_rich_schema_object_response);
+
+    ErrorCode::from(unsafe { ledger::indy_parse_get_rich_schema_object_response(command_handle, get_rich_schema_object_response.as_ptr(), cb) })
+}
+
 /// Parse transaction response to fetch metadata.
 /// The important use case for this method is validation of Node's response freshens.
 ///
@@ -1170,6 +1536,41 @@ fn _build_get_auth_rule_request(command_handle: CommandHandle,
     })
 }
 
+/// Predicts whether the ledger accepts write request from the submitter without sending it.
+///
+/// Auth rules of the pool are read with GET_AUTH_RULE request and cached for the pool handle.
+/// Constraints of the rules applying to the request are evaluated against role info of request signers.
+///
+/// EXPERIMENTAL
+///
+/// # Arguments
+/// * `pool_handle` - pool handle (created by open_pool_ledger).
+/// * `request_json` - write request (NYM, NODE, ATTRIB, SCHEMA or CRED_DEF).
+/// * `submitter_role_info` - role info of request signers
+///  {
+///     role: (string, optional) role of the submitter (TRUSTEE, STEWARD, ENDORSER, NETWORK_MONITOR or role code), no role by default,
+///     isOwner: (bool, optional, false by default) the submitter is an owner of changed ledger entry,
+///     offLedger: (bool, optional, false by default) the submitter DID isn't written on the ledger,
+///     additionalSigners: (optional) [{role, isOwner, offLedger}] - other signers of the request (Endorser, multi-signature)
+///  }
+///
+/// # Returns
+/// Auth check result json containing `authorized` flag and `actions` with satisfied and missing constraints of each action.
+pub fn check_request_auth(pool_handle: PoolHandle, request_json: &str, submitter_role_info: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _check_request_auth(command_handle, pool_handle, request_json, submitter_role_info, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _check_request_auth(command_handle: CommandHandle, pool_handle: PoolHandle, request_json: &str, submitter_role_info: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let submitter_role_info = c_str!(submitter_role_info);
+
+    ErrorCode::from(unsafe { ledger::indy_check_request_auth(command_handle, pool_handle, request_json.as_ptr(), submitter_role_info.as_ptr(), cb) })
+}
+
 /// Builds a TXN_AUTHR_AGRMT request. Request to add a new version of Transaction Author Agreement to the ledger.
 ///
 /// # Arguments
@@ -1385,6 +1786,60 @@ fn _append_txn_author_agreement_acceptance_to_request(command_handle: CommandHan
     })
 }
 
+/// Set transaction author agreement acceptance for the pool session.
+/// Once set, acceptance is appended to every write request of the domain ledger
+/// sent with `sign_and_submit_request` through this pool handle
+/// if the request doesn't contain its own acceptance yet.
+///
+/// Acceptance is checked against the latest transaction author agreement and
+/// acceptance mechanisms list read from the ledger.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `text` and `version`: (optional) raw data about TAA from ledger.
+///     These parameters should be passed together.
+///     These parameters are required if taa_digest parameter is omitted.
+/// * `taa_digest`: (optional) digest on text and version. This parameter is required if text and version parameters are omitted.
+/// * `mechanism`: mechanism how user has accepted the TAA
+/// * `time`: UTC timestamp when user has accepted the TAA. Note that the time portion will be discarded to avoid a privacy risk.
+pub fn set_txn_author_agreement_acceptance(pool_handle: PoolHandle,
+                                           text: Option<&str>,
+                                           version: Option<&str>,
+                                           taa_digest: Option<&str>,
+                                           mechanism: &str,
+                                           time: u64) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _set_txn_author_agreement_acceptance(command_handle, pool_handle, text, version, taa_digest, mechanism, time, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _set_txn_author_agreement_acceptance(command_handle: CommandHandle,
+                                        pool_handle: PoolHandle,
+                                        text: Option<&str>,
+                                        version: Option<&str>,
+                                        taa_digest: Option<&str>,
+                                        mechanism: &str,
+                                        time: u64,
+                                        cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let text_str = opt_c_str!(text);
+    let version_str = opt_c_str!(version);
+    let taa_digest_str = opt_c_str!(taa_digest);
+    let mechanism = c_str!(mechanism);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_set_txn_author_agreement_acceptance(command_handle,
+                                                         pool_handle,
+                                                         opt_c_ptr!(text, text_str),
+                                                         opt_c_ptr!(version, version_str),
+                                                         opt_c_ptr!(taa_digest, taa_digest_str),
+                                                         mechanism.as_ptr(),
+                                                         time,
+                                                         cb)
+    })
+}
+
 /// Append Endorser to an existing request.
 ///
 /// An author of request still is a `DID` used as a `submitter_did` parameter for the building of the request.
@@ -1423,4 +1878,198 @@ fn _append_request_endorser(command_handle: CommandHandle,
                                              endorser_did.as_ptr(),
                                              cb)
     })
-}
\ No newline at end of file
+}
+
+/// Wraps request into bundle which Transaction Author hands over to Endorser.
+///
+/// Note: Endorser must be appended to the request (see `append_request_endorser`)
+/// and the request must be signed by its author before building of the bundle.
+///
+/// # Arguments
+/// * `request_json`: request with Endorser appended signed by its author.
+/// * `metadata_json`: (Optional) arbitrary json passed to the Endorser along with the request.
+/// # Returns
+/// Bundle json containing the request, its author and endorser, transaction type
+/// and human-readable summary of the request operation.
+pub fn build_endorsement_bundle(request_json: &str, metadata_json: Option<&str>) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _build_endorsement_bundle(command_handle, request_json, metadata_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _build_endorsement_bundle(command_handle: CommandHandle, request_json: &str, metadata_json: Option<&str>, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let request_json = c_str!(request_json);
+    let metadata_json_str = opt_c_str!(metadata_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_build_endorsement_bundle(command_handle, request_json.as_ptr(), opt_c_ptr!(metadata_json, metadata_json_str), cb)
+    })
+}
+
+/// Reviews endorsement bundle built by Transaction Author.
+/// Verifies all signatures of the request against verkeys of signers written on the ledger.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `bundle_json`: endorsement bundle built by `build_endorsement_bundle`.
+/// # Returns
+/// Review json containing summary of the request and results of signatures verification.
+pub fn review_endorsement_bundle(pool_handle: PoolHandle, bundle_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _review_endorsement_bundle(command_handle, pool_handle, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _review_endorsement_bundle(command_handle: CommandHandle, pool_handle: PoolHandle, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe { ledger::indy_review_endorsement_bundle(command_handle, pool_handle, bundle_json.as_ptr(), cb) })
+}
+
+/// Endorses request of endorsement bundle built by Transaction Author.
+/// Checks Endorser of the request, verifies its signatures and adds multi signature of the Endorser.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `wallet_handle`: wallet handle (created by open_wallet).
+/// * `endorser_did`: DID of the Endorser stored in the wallet.
+/// * `bundle_json`: endorsement bundle built by `build_endorsement_bundle`.
+/// # Returns
+/// Request json signed by the Endorser.
+pub fn endorse_request(pool_handle: PoolHandle, wallet_handle: WalletHandle, endorser_did: &str, bundle_json: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _endorse_request(command_handle, pool_handle, wallet_handle, endorser_did, bundle_json, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _endorse_request(command_handle: CommandHandle, pool_handle: PoolHandle, wallet_handle: WalletHandle, endorser_did: &str, bundle_json: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let endorser_did = c_str!(endorser_did);
+    let bundle_json = c_str!(bundle_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_endorse_request(command_handle, pool_handle, wallet_handle, endorser_did.as_ptr(), bundle_json.as_ptr(), cb)
+    })
+}
+
+/// Verifies that a transaction is included into the ledger by checking its Merkle audit path
+/// against the ledger root hash (RFC 6962 inclusion proof).
+///
+/// `result` field of a write reply or `data` field of GET_TXN reply can be passed as is:
+/// if `audit_path_json` is omitted `auditPath` field of the transaction is used.
+/// `rootHash` field of the transaction is never used, as it comes with the data being verified.
+///
+/// # Arguments
+/// * `root_hash`: base58 encoded root hash of the ledger of `ledger_size` size the caller trusts
+///                (e.g. verified by BLS multi-signature of nodes).
+/// * `txn_json`: ledger transaction json.
+/// * `audit_path_json`: (Optional) json array of base58 encoded hashes of the audit path.
+/// * `ledger_size`: size of the ledger the root hash corresponds to.
+///                  Pass 0 to use the sequence number of the transaction.
+/// # Returns
+/// true if the transaction is included into the ledger.
+pub fn verify_txn_inclusion(root_hash: &str,
+                            txn_json: &str,
+                            audit_path_json: Option<&str>,
+                            ledger_size: i32) -> Box<Future<Item=bool, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_bool();
+
+    let err = _verify_txn_inclusion(command_handle, root_hash, txn_json, audit_path_json, ledger_size, cb);
+
+    ResultHandler::bool(command_handle, err, receiver)
+}
+
+fn _verify_txn_inclusion(command_handle: CommandHandle,
+                         root_hash: &str,
+                         txn_json: &str,
+                         audit_path_json: Option<&str>,
+                         ledger_size: i32,
+                         cb: Option<ResponseBoolCB>) -> ErrorCode {
+    let root_hash = c_str!(root_hash);
+    let txn_json = c_str!(txn_json);
+    let audit_path_json_str = opt_c_str!(audit_path_json);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_verify_txn_inclusion(command_handle,
+                                          root_hash.as_ptr(),
+                                          txn_json.as_ptr(),
+                                          opt_c_ptr!(audit_path_json, audit_path_json_str),
+                                          ledger_size,
+                                          cb)
+    })
+}
+
+/// Opens an iterator over the transactions of a ledger.
+///
+/// Transactions are fetched from the pool by batches with CATCHUP_REQ messages
+/// and are verified against the ledger root agreed by f+1 nodes before they are returned.
+/// The ledger is always fetched starting from the first transaction, as it is needed for verification.
+/// Only one iterator over the same ledger of the pool can be open at a time.
+///
+/// # Arguments
+/// * `pool_handle`: pool handle (created by open_pool_ledger).
+/// * `ledger_type`: (Optional) type of the ledger to iterate over.
+///     Values: DOMAIN - used default, POOL, CONFIG, any number
+/// * `from`: sequence number of the first transaction to return (starting from 1).
+/// * `to`: sequence number of the last transaction to return. Pass -1 to iterate up to the ledger size.
+/// # Returns
+/// * `iterator_handle` - Ledger iterator handle that can be used later
+///   to fetch transactions by small batches (with ledger_iterator_next)
+pub fn open_ledger_iterator(pool_handle: PoolHandle, ledger_type: Option<&str>, from: i64, to: i64) -> Box<Future<Item=SearchHandle, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_handle();
+
+    let err = _open_ledger_iterator(command_handle, pool_handle, ledger_type, from, to, cb);
+
+    ResultHandler::handle(command_handle, err, receiver)
+}
+
+fn _open_ledger_iterator(command_handle: CommandHandle, pool_handle: PoolHandle, ledger_type: Option<&str>, from: i64, to: i64, cb: Option<ResponseI32CB>) -> ErrorCode {
+    let ledger_type_str = opt_c_str!(ledger_type);
+
+    ErrorCode::from(unsafe {
+        ledger::indy_open_ledger_iterator(command_handle, pool_handle, opt_c_ptr!(ledger_type, ledger_type_str), from, to, cb)
+    })
+}
+
+/// Fetches next batch of verified transactions from the ledger iterator.
+///
+/// # Arguments
+/// * `iterator_handle`: ledger iterator handle (created by open_ledger_iterator).
+/// # Returns
+/// * `txns_json` - json array of ledger transactions. Empty array means that the iteration is completed.
+pub fn ledger_iterator_next(iterator_handle: SearchHandle) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _ledger_iterator_next(command_handle, iterator_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _ledger_iterator_next(command_handle: CommandHandle, iterator_handle: SearchHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ledger::indy_ledger_iterator_next(command_handle, iterator_handle, cb)
+    })
+}
+
+/// Closes the ledger iterator.
+///
+/// # Arguments
+/// * `iterator_handle`: ledger iterator handle (created by open_ledger_iterator).
+pub fn close_ledger_iterator(iterator_handle: SearchHandle) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _close_ledger_iterator(command_handle, iterator_handle, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _close_ledger_iterator(command_handle: CommandHandle, iterator_handle: SearchHandle, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe {
+        ledger::indy_close_ledger_iterator(command_handle, iterator_handle, cb)
+    })
+}
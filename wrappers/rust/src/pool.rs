@@ -160,3 +160,96 @@ fn _set_protocol_version(command_handle: CommandHandle, protocol_version: usize,
       pool::indy_set_protocol_version(command_handle, protocol_version, cb)
     })
 }
+
+/// Returns health and latency metrics collected for opened pool since it was opened.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle returned by open_ledger.
+///
+/// # Returns
+/// Metrics json with current catchup state, number of consensus failures and per-node
+/// ACK/NACK/REJECT/reply/timeout counters and round-trip latency histograms.
+pub fn get_pool_metrics(pool_handle: PoolHandle) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _get_pool_metrics(command_handle, pool_handle, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _get_pool_metrics(command_handle: CommandHandle, pool_handle: PoolHandle, cb: Option<ResponseStringCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_get_pool_metrics(command_handle, pool_handle, cb) })
+}
+
+/// Persists snapshot of pool ledger built from locally cached transactions.
+/// Next open_pool_ledger call restores pool from the snapshot without replaying of cached transactions.
+///
+/// # Arguments
+/// * `pool_name` - Name of the pool ledger configuration.
+///
+/// # Returns
+/// Snapshot info json with number of pool ledger transactions, merkle root, timestamp and node aliases.
+pub fn create_pool_snapshot(pool_name: &str) -> Box<Future<Item=String, Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec_string();
+
+    let err = _create_pool_snapshot(command_handle, pool_name, cb);
+
+    ResultHandler::str(command_handle, err, receiver)
+}
+
+fn _create_pool_snapshot(command_handle: CommandHandle, pool_name: &str, cb: Option<ResponseStringCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+
+    ErrorCode::from(unsafe { pool::indy_create_pool_snapshot(command_handle, pool_name.as_ptr(), cb) })
+}
+
+/// Registers callback which is called on every state transition of opened pool
+/// (e.g. catchup is started or finished, pool is terminated because nodes are unreachable or pool is closed).
+/// Only one callback can be registered for pool, registering of new one replaces previous.
+/// Callback is unregistered automatically after "closed" event.
+///
+/// # Arguments
+/// * `pool_handle` - pool handle returned by open_pool_ledger.
+/// * `event_cb` - callback that takes pool handle and event json:
+/// {
+///     "poolHandle": int, pool handle,
+///     "poolName": string, name of the pool ledger configuration,
+///     "state": string, new state of pool:
+///         "initialization", "getting_catchup_target", "sync_catchup", "active", "terminated" or "closed",
+///     "previousState": string, previous state of pool,
+///     "timestamp": int, unix timestamp of the transition,
+///     "nodes": [string], (only for "active" state) aliases of pool nodes known after catchup
+/// }
+pub fn register_pool_event_callback(pool_handle: PoolHandle, event_cb: pool::PoolEventCB) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_pool_event_callback(command_handle, pool_handle, event_cb, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_pool_event_callback(command_handle: CommandHandle, pool_handle: PoolHandle, event_cb: pool::PoolEventCB, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    ErrorCode::from(unsafe { pool::indy_register_pool_event_callback(command_handle, pool_handle, Some(event_cb), cb) })
+}
+
+/// Registers callback which is called on every state transition of the pool opened with the given configuration
+/// including transitions during opening, so it can be called before open_pool_ledger to get initial catchup events.
+/// Only one callback can be registered for pool configuration, registering of new one replaces previous.
+/// Callback is unregistered automatically after "closed" event.
+///
+/// # Arguments
+/// * `pool_name` - Name of the pool ledger configuration.
+/// * `event_cb` - callback that takes pool handle and event json (see `register_pool_event_callback`).
+pub fn register_pool_event_callback_by_name(pool_name: &str, event_cb: pool::PoolEventCB) -> Box<Future<Item=(), Error=IndyError>> {
+    let (receiver, command_handle, cb) = ClosureHandler::cb_ec();
+
+    let err = _register_pool_event_callback_by_name(command_handle, pool_name, event_cb, cb);
+
+    ResultHandler::empty(command_handle, err, receiver)
+}
+
+fn _register_pool_event_callback_by_name(command_handle: CommandHandle, pool_name: &str, event_cb: pool::PoolEventCB, cb: Option<ResponseEmptyCB>) -> ErrorCode {
+    let pool_name = c_str!(pool_name);
+
+    ErrorCode::from(unsafe { pool::indy_register_pool_event_callback_by_name(command_handle, pool_name.as_ptr(), Some(event_cb), cb) })
+}